@@ -20,6 +20,8 @@ use crate::event::EventfdFlags;
 #[cfg(any(bsd, linux_kernel, target_os = "wasi"))]
 use crate::event::FdSetElement;
 use crate::event::{PollFd, Timespec};
+#[cfg(linux_kernel)]
+use crate::event::{SigSet, SignalfdFlags};
 use crate::io;
 #[cfg(any(linux_kernel, target_os = "illumos", target_os = "redox"))]
 use crate::utils::as_ptr;
@@ -86,6 +88,41 @@ pub(crate) fn eventfd(initval: u32, flags: EventfdFlags) -> io::Result<OwnedFd>
     }
 }
 
+#[cfg(linux_kernel)]
+syscall! {
+    fn signalfd4(
+        fd: c::c_int,
+        mask: *const SigSet,
+        sizemask: c::size_t,
+        flags: c::c_int
+    ) via SYS_signalfd4 -> c::c_int
+}
+
+#[cfg(linux_kernel)]
+pub(crate) unsafe fn signalfd(mask: &SigSet, flags: SignalfdFlags) -> io::Result<OwnedFd> {
+    // Use the raw syscall so that we pass the kernel's sigset size rather
+    // than depending on how the libc interprets a `sigset_t`.
+    ret_owned_fd(signalfd4(
+        -1,
+        as_ptr(mask),
+        core::mem::size_of::<SigSet>(),
+        bitflags_bits!(flags),
+    ))
+}
+
+#[cfg(linux_kernel)]
+pub(crate) unsafe fn signalfd_set_mask(fd: BorrowedFd<'_>, mask: &SigSet) -> io::Result<()> {
+    // When passed an existing signalfd, `signalfd4` returns that same file
+    // descriptor, so there's nothing to take ownership of.
+    ret_c_int(signalfd4(
+        borrowed_fd(fd),
+        as_ptr(mask),
+        core::mem::size_of::<SigSet>(),
+        0,
+    ))
+    .map(|_| ())
+}
+
 #[cfg(bsd)]
 pub(crate) fn kqueue() -> io::Result<OwnedFd> {
     unsafe { ret_owned_fd(c::kqueue()) }
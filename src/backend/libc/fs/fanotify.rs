@@ -0,0 +1,178 @@
+//! fanotify support for working with fanotify objects.
+
+use crate::backend::c;
+use bitflags::bitflags;
+
+bitflags! {
+    /// `FAN_*` for use with [`fanotify::init`].
+    ///
+    /// [`fanotify::init`]: crate::fs::fanotify::init
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct InitFlags: u32 {
+        /// `FAN_CLOEXEC`
+        const CLOEXEC = bitcast!(c::FAN_CLOEXEC);
+        /// `FAN_NONBLOCK`
+        const NONBLOCK = bitcast!(c::FAN_NONBLOCK);
+
+        /// `FAN_CLASS_NOTIF`
+        const CLASS_NOTIF = bitcast!(c::FAN_CLASS_NOTIF);
+        /// `FAN_CLASS_CONTENT`
+        const CLASS_CONTENT = bitcast!(c::FAN_CLASS_CONTENT);
+        /// `FAN_CLASS_PRE_CONTENT`
+        const CLASS_PRE_CONTENT = bitcast!(c::FAN_CLASS_PRE_CONTENT);
+
+        /// `FAN_UNLIMITED_QUEUE`
+        const UNLIMITED_QUEUE = bitcast!(c::FAN_UNLIMITED_QUEUE);
+        /// `FAN_UNLIMITED_MARKS`
+        const UNLIMITED_MARKS = bitcast!(c::FAN_UNLIMITED_MARKS);
+        /// `FAN_ENABLE_AUDIT`
+        const ENABLE_AUDIT = bitcast!(c::FAN_ENABLE_AUDIT);
+
+        /// `FAN_REPORT_PIDFD` (since Linux 5.15)
+        const REPORT_PIDFD = bitcast!(c::FAN_REPORT_PIDFD);
+        /// `FAN_REPORT_TID`
+        const REPORT_TID = bitcast!(c::FAN_REPORT_TID);
+        /// `FAN_REPORT_FID` (since Linux 5.1)
+        const REPORT_FID = bitcast!(c::FAN_REPORT_FID);
+        /// `FAN_REPORT_DIR_FID` (since Linux 5.9)
+        const REPORT_DIR_FID = bitcast!(c::FAN_REPORT_DIR_FID);
+        /// `FAN_REPORT_NAME` (since Linux 5.9)
+        const REPORT_NAME = bitcast!(c::FAN_REPORT_NAME);
+        /// `FAN_REPORT_TARGET_FID` (since Linux 5.17)
+        const REPORT_TARGET_FID = bitcast!(c::FAN_REPORT_TARGET_FID);
+
+        /// `FAN_REPORT_DFID_NAME`
+        const REPORT_DFID_NAME = bitcast!(c::FAN_REPORT_DFID_NAME);
+        /// `FAN_REPORT_DFID_NAME_TARGET`
+        const REPORT_DFID_NAME_TARGET = bitcast!(c::FAN_REPORT_DFID_NAME_TARGET);
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
+bitflags! {
+    /// `FAN_MARK_*` for use with [`fanotify::mark`].
+    ///
+    /// [`fanotify::mark`]: crate::fs::fanotify::mark
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct MarkFlags: u32 {
+        /// `FAN_MARK_ADD`
+        const ADD = bitcast!(c::FAN_MARK_ADD);
+        /// `FAN_MARK_REMOVE`
+        const REMOVE = bitcast!(c::FAN_MARK_REMOVE);
+        /// `FAN_MARK_DONT_FOLLOW`
+        const DONT_FOLLOW = bitcast!(c::FAN_MARK_DONT_FOLLOW);
+        /// `FAN_MARK_ONLYDIR`
+        const ONLYDIR = bitcast!(c::FAN_MARK_ONLYDIR);
+        /// `FAN_MARK_IGNORED_MASK`
+        const IGNORED_MASK = bitcast!(c::FAN_MARK_IGNORED_MASK);
+        /// `FAN_MARK_IGNORED_SURV_MODIFY`
+        const IGNORED_SURV_MODIFY = bitcast!(c::FAN_MARK_IGNORED_SURV_MODIFY);
+        /// `FAN_MARK_FLUSH`
+        const FLUSH = bitcast!(c::FAN_MARK_FLUSH);
+        /// `FAN_MARK_EVICTABLE` (since Linux 5.19)
+        const EVICTABLE = bitcast!(c::FAN_MARK_EVICTABLE);
+        /// `FAN_MARK_IGNORE` (since Linux 6.0)
+        const IGNORE = bitcast!(c::FAN_MARK_IGNORE);
+
+        /// `FAN_MARK_INODE`
+        const INODE = bitcast!(c::FAN_MARK_INODE);
+        /// `FAN_MARK_MOUNT`
+        const MOUNT = bitcast!(c::FAN_MARK_MOUNT);
+        /// `FAN_MARK_FILESYSTEM` (since Linux 4.20)
+        const FILESYSTEM = bitcast!(c::FAN_MARK_FILESYSTEM);
+
+        /// `FAN_MARK_IGNORE_SURV`
+        const IGNORE_SURV = bitcast!(c::FAN_MARK_IGNORE_SURV);
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
+bitflags! {
+    /// `FAN_*` event masks for use with [`fanotify::mark`] and
+    /// [`fanotify::Reader`].
+    ///
+    /// [`fanotify::mark`]: crate::fs::fanotify::mark
+    /// [`fanotify::Reader`]: crate::fs::fanotify::Reader
+    #[repr(transparent)]
+    #[derive(Default, Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct EventMask: u64 {
+        /// `FAN_ACCESS`
+        const ACCESS = c::FAN_ACCESS;
+        /// `FAN_MODIFY`
+        const MODIFY = c::FAN_MODIFY;
+        /// `FAN_ATTRIB` (since Linux 5.1)
+        const ATTRIB = c::FAN_ATTRIB;
+        /// `FAN_CLOSE_WRITE`
+        const CLOSE_WRITE = c::FAN_CLOSE_WRITE;
+        /// `FAN_CLOSE_NOWRITE`
+        const CLOSE_NOWRITE = c::FAN_CLOSE_NOWRITE;
+        /// `FAN_OPEN`
+        const OPEN = c::FAN_OPEN;
+        /// `FAN_MOVED_FROM` (since Linux 5.1)
+        const MOVED_FROM = c::FAN_MOVED_FROM;
+        /// `FAN_MOVED_TO` (since Linux 5.1)
+        const MOVED_TO = c::FAN_MOVED_TO;
+        /// `FAN_CREATE` (since Linux 5.1)
+        const CREATE = c::FAN_CREATE;
+        /// `FAN_DELETE` (since Linux 5.1)
+        const DELETE = c::FAN_DELETE;
+        /// `FAN_DELETE_SELF` (since Linux 5.1)
+        const DELETE_SELF = c::FAN_DELETE_SELF;
+        /// `FAN_MOVE_SELF` (since Linux 5.1)
+        const MOVE_SELF = c::FAN_MOVE_SELF;
+        /// `FAN_OPEN_EXEC` (since Linux 5.0)
+        const OPEN_EXEC = c::FAN_OPEN_EXEC;
+
+        /// `FAN_Q_OVERFLOW`
+        const QUEUE_OVERFLOW = c::FAN_Q_OVERFLOW;
+        /// `FAN_FS_ERROR` (since Linux 5.16)
+        const FS_ERROR = c::FAN_FS_ERROR;
+
+        /// `FAN_OPEN_PERM`
+        const OPEN_PERM = c::FAN_OPEN_PERM;
+        /// `FAN_ACCESS_PERM`
+        const ACCESS_PERM = c::FAN_ACCESS_PERM;
+        /// `FAN_OPEN_EXEC_PERM` (since Linux 5.0)
+        const OPEN_EXEC_PERM = c::FAN_OPEN_EXEC_PERM;
+
+        /// `FAN_EVENT_ON_CHILD`
+        const EVENT_ON_CHILD = c::FAN_EVENT_ON_CHILD;
+        /// `FAN_RENAME` (since Linux 5.17)
+        const RENAME = c::FAN_RENAME;
+        /// `FAN_ONDIR`
+        const ONDIR = c::FAN_ONDIR;
+
+        /// `FAN_CLOSE`
+        const CLOSE = c::FAN_CLOSE;
+        /// `FAN_MOVE`
+        const MOVE = c::FAN_MOVE;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
+bitflags! {
+    /// `FAN_*` responses for use with [`fanotify::write_response`].
+    ///
+    /// [`fanotify::write_response`]: crate::fs::fanotify::write_response
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct Response: u32 {
+        /// `FAN_ALLOW`
+        const ALLOW = c::FAN_ALLOW;
+        /// `FAN_DENY`
+        const DENY = c::FAN_DENY;
+        /// `FAN_AUDIT`
+        const AUDIT = c::FAN_AUDIT;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
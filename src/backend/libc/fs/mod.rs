@@ -1,5 +1,7 @@
 #[cfg(all(feature = "alloc", not(any(target_os = "espidf", target_os = "redox"))))]
 pub(crate) mod dir;
+#[cfg(target_os = "linux")]
+pub mod fanotify;
 #[cfg(linux_kernel)]
 pub mod inotify;
 #[cfg(not(any(
@@ -14,6 +14,8 @@ use crate::ffi::CString;
 use crate::fs::Access;
 #[cfg(not(any(target_os = "espidf", target_os = "redox")))]
 use crate::fs::AtFlags;
+#[cfg(linux_kernel)]
+use crate::fs::CacheStat;
 #[cfg(not(any(
     netbsdlike,
     target_os = "dragonfly",
@@ -1149,6 +1151,36 @@ pub(crate) fn chmodat(
     }
 }
 
+#[cfg(linux_kernel)]
+pub(crate) fn fchmodat2(
+    dirfd: BorrowedFd<'_>,
+    path: &CStr,
+    mode: Mode,
+    flags: AtFlags,
+) -> io::Result<()> {
+    // Use `c::syscall` rather than `c::fchmodat` because some libc
+    // implementations emulate `AT_SYMLINK_NOFOLLOW` using `/proc` when
+    // `fchmodat2` isn't available.
+    const SYS_FCHMODAT2: c::c_long = linux_raw_sys::general::__NR_fchmodat2 as c::c_long;
+
+    syscall! {
+        fn fchmodat2(
+            base_dirfd: c::c_int,
+            pathname: *const ffi::c_char,
+            mode: c::mode_t,
+            flags: c::c_int
+        ) via SYS_FCHMODAT2 -> c::c_int
+    }
+    unsafe {
+        ret(fchmodat2(
+            borrowed_fd(dirfd),
+            c_str(path),
+            mode.bits() as c::mode_t,
+            bitflags_bits!(flags),
+        ))
+    }
+}
+
 #[cfg(apple)]
 pub(crate) fn fclonefileat(
     srcfd: BorrowedFd<'_>,
@@ -1220,6 +1252,43 @@ pub(crate) fn mknodat(
     }
 }
 
+#[cfg(linux_kernel)]
+pub(crate) fn cachestat(
+    fd: BorrowedFd<'_>,
+    offset: u64,
+    len: Option<NonZeroU64>,
+) -> io::Result<CacheStat> {
+    use linux_raw_sys::general::{cachestat, cachestat_range};
+
+    const SYS_CACHESTAT: c::c_long = linux_raw_sys::general::__NR_cachestat as c::c_long;
+
+    syscall! {
+        fn cachestat(
+            fd: c::c_int,
+            cstat_range: *const cachestat_range,
+            cstat: *mut cachestat,
+            flags: c::c_uint
+        ) via SYS_CACHESTAT -> c::c_int
+    }
+
+    let range = cachestat_range {
+        off: offset,
+        len: len.map_or(0, NonZeroU64::get),
+    };
+    let mut cstat = MaybeUninit::<cachestat>::uninit();
+    unsafe {
+        ret(cachestat(borrowed_fd(fd), &range, cstat.as_mut_ptr(), 0))?;
+        let cstat = cstat.assume_init();
+        Ok(CacheStat {
+            cached: cstat.nr_cache,
+            dirty: cstat.nr_dirty,
+            writeback: cstat.nr_writeback,
+            evicted: cstat.nr_evicted,
+            recently_evicted: cstat.nr_recently_evicted,
+        })
+    }
+}
+
 #[cfg(linux_kernel)]
 pub(crate) fn copy_file_range(
     fd_in: BorrowedFd<'_>,
@@ -2684,6 +2753,46 @@ fn fix_negative_stat_nsecs(mut stat: Stat) -> Stat {
     stat
 }
 
+#[inline]
+#[cfg(target_os = "linux")]
+pub(crate) fn fanotify_init(
+    flags: super::fanotify::InitFlags,
+    event_flags: OFlags,
+) -> io::Result<OwnedFd> {
+    // SAFETY: `fanotify_init` has no safety preconditions.
+    unsafe {
+        ret_owned_fd(c::fanotify_init(
+            bitflags_bits!(flags),
+            bitflags_bits!(event_flags),
+        ))
+    }
+}
+
+#[inline]
+#[cfg(target_os = "linux")]
+pub(crate) fn fanotify_mark(
+    fanotify_fd: BorrowedFd<'_>,
+    flags: super::fanotify::MarkFlags,
+    mask: super::fanotify::EventMask,
+    dirfd: BorrowedFd<'_>,
+    path: Option<&CStr>,
+) -> io::Result<()> {
+    // SAFETY: The fds and path we are passing are guaranteed valid by the
+    // type system.
+    unsafe {
+        ret(c::fanotify_mark(
+            borrowed_fd(fanotify_fd),
+            bitflags_bits!(flags),
+            mask.bits(),
+            borrowed_fd(dirfd),
+            match path {
+                Some(path) => c_str(path),
+                None => core::ptr::null(),
+            },
+        ))
+    }
+}
+
 #[inline]
 #[cfg(linux_kernel)]
 pub(crate) fn inotify_init1(flags: super::inotify::CreateFlags) -> io::Result<OwnedFd> {
@@ -0,0 +1,94 @@
+//! libc syscalls supporting `rustix::landlock`.
+
+use crate::backend::c;
+use crate::backend::conv::{borrowed_fd, ret, ret_c_int, ret_owned_fd};
+use crate::fd::{BorrowedFd, OwnedFd};
+use crate::io;
+use crate::landlock::RestrictSelfFlags;
+use core::mem::size_of;
+use core::ptr::null;
+use linux_raw_sys::landlock::{
+    landlock_net_port_attr, landlock_path_beneath_attr, landlock_rule_type, landlock_ruleset_attr,
+    LANDLOCK_CREATE_RULESET_VERSION,
+};
+
+syscall! {
+    fn landlock_create_ruleset(
+        attr: *const c::c_void,
+        size: c::size_t,
+        flags: u32
+    ) via SYS_landlock_create_ruleset -> c::c_int
+}
+
+syscall! {
+    fn landlock_add_rule(
+        ruleset_fd: c::c_int,
+        rule_type: c::c_uint,
+        rule_attr: *const c::c_void,
+        flags: u32
+    ) via SYS_landlock_add_rule -> c::c_int
+}
+
+syscall! {
+    fn landlock_restrict_self(
+        ruleset_fd: c::c_int,
+        flags: u32
+    ) via SYS_landlock_restrict_self -> c::c_int
+}
+
+#[inline]
+pub(crate) fn create_ruleset(attr: &landlock_ruleset_attr) -> io::Result<OwnedFd> {
+    unsafe {
+        ret_owned_fd(landlock_create_ruleset(
+            (attr as *const landlock_ruleset_attr).cast(),
+            size_of::<landlock_ruleset_attr>(),
+            0,
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn abi_version() -> io::Result<c::c_int> {
+    unsafe {
+        ret_c_int(landlock_create_ruleset(
+            null(),
+            0,
+            LANDLOCK_CREATE_RULESET_VERSION,
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn add_rule_path_beneath(
+    ruleset: BorrowedFd<'_>,
+    attr: &landlock_path_beneath_attr,
+) -> io::Result<()> {
+    unsafe {
+        ret(landlock_add_rule(
+            borrowed_fd(ruleset),
+            landlock_rule_type::LANDLOCK_RULE_PATH_BENEATH as c::c_uint,
+            (attr as *const landlock_path_beneath_attr).cast(),
+            0,
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn add_rule_net_port(
+    ruleset: BorrowedFd<'_>,
+    attr: &landlock_net_port_attr,
+) -> io::Result<()> {
+    unsafe {
+        ret(landlock_add_rule(
+            borrowed_fd(ruleset),
+            landlock_rule_type::LANDLOCK_RULE_NET_PORT as c::c_uint,
+            (attr as *const landlock_net_port_attr).cast(),
+            0,
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn restrict_self(ruleset: BorrowedFd<'_>, flags: RestrictSelfFlags) -> io::Result<()> {
+    unsafe { ret(landlock_restrict_self(borrowed_fd(ruleset), flags.bits())) }
+}
@@ -2,6 +2,17 @@
 
 #[cfg(not(target_os = "redox"))]
 use super::types::Advice;
+#[cfg(all(
+    linux_kernel,
+    any(
+        target_arch = "aarch64",
+        target_arch = "riscv64",
+        target_arch = "s390x",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    )
+))]
+use super::types::MemfdSecretFlags;
 #[cfg(any(linux_kernel, freebsdlike, netbsdlike))]
 use super::types::MlockAllFlags;
 #[cfg(any(target_os = "emscripten", target_os = "linux"))]
@@ -10,13 +21,15 @@ use super::types::{MapFlags, MprotectFlags, MsyncFlags, ProtFlags};
 #[cfg(linux_kernel)]
 use super::types::{MlockFlags, UserfaultfdFlags};
 use crate::backend::c;
-#[cfg(linux_kernel)]
-use crate::backend::conv::ret_owned_fd;
 use crate::backend::conv::{borrowed_fd, no_fd, ret};
+#[cfg(linux_kernel)]
+use crate::backend::conv::{ret_c_int, ret_owned_fd, ret_usize};
 use crate::fd::BorrowedFd;
 #[cfg(linux_kernel)]
 use crate::fd::OwnedFd;
 use crate::io;
+#[cfg(linux_kernel)]
+use crate::pid::Pid;
 
 #[cfg(not(target_os = "redox"))]
 pub(crate) fn madvise(addr: *mut c::c_void, len: usize, advice: Advice) -> io::Result<()> {
@@ -242,3 +255,203 @@ pub(crate) fn mlockall(flags: MlockAllFlags) -> io::Result<()> {
 pub(crate) fn munlockall() -> io::Result<()> {
     unsafe { ret(c::munlockall()) }
 }
+
+#[cfg(linux_kernel)]
+#[inline]
+pub(crate) unsafe fn mbind(
+    addr: *mut c::c_void,
+    len: usize,
+    mode: u32,
+    nodemask: *const c::c_ulong,
+    maxnode: usize,
+    flags: u32,
+) -> io::Result<()> {
+    syscall! {
+        fn mbind(
+            addr: *mut c::c_void,
+            len: c::c_ulong,
+            mode: c::c_ulong,
+            nodemask: *const c::c_ulong,
+            maxnode: c::c_ulong,
+            flags: c::c_uint
+        ) via SYS_mbind -> c::c_long
+    }
+    ret(mbind(addr, len as _, mode.into(), nodemask, maxnode as _, flags) as c::c_int)
+}
+
+#[cfg(linux_kernel)]
+#[inline]
+pub(crate) fn set_mempolicy(
+    mode: c::c_int,
+    nodemask: *const c::c_ulong,
+    maxnode: usize,
+) -> io::Result<()> {
+    syscall! {
+        fn set_mempolicy(
+            mode: c::c_int,
+            nodemask: *const c::c_ulong,
+            maxnode: c::c_ulong
+        ) via SYS_set_mempolicy -> c::c_long
+    }
+    unsafe { ret(set_mempolicy(mode, nodemask, maxnode as _) as c::c_int) }
+}
+
+#[cfg(linux_kernel)]
+#[inline]
+pub(crate) fn get_mempolicy(
+    mode: *mut c::c_int,
+    nodemask: *mut c::c_ulong,
+    maxnode: usize,
+    addr: *const c::c_void,
+    flags: u32,
+) -> io::Result<()> {
+    syscall! {
+        fn get_mempolicy(
+            mode: *mut c::c_int,
+            nodemask: *mut c::c_ulong,
+            maxnode: c::c_ulong,
+            addr: *const c::c_void,
+            flags: c::c_ulong
+        ) via SYS_get_mempolicy -> c::c_long
+    }
+    unsafe { ret(get_mempolicy(mode, nodemask, maxnode as _, addr, flags.into()) as c::c_int) }
+}
+
+#[cfg(linux_kernel)]
+#[inline]
+pub(crate) fn migrate_pages(
+    pid: Option<Pid>,
+    maxnode: usize,
+    old_nodes: *const c::c_ulong,
+    new_nodes: *const c::c_ulong,
+) -> io::Result<usize> {
+    syscall! {
+        fn migrate_pages(
+            pid: c::pid_t,
+            maxnode: c::c_ulong,
+            old_nodes: *const c::c_ulong,
+            new_nodes: *const c::c_ulong
+        ) via SYS_migrate_pages -> c::ssize_t
+    }
+    unsafe {
+        ret_usize(migrate_pages(
+            Pid::as_raw(pid),
+            maxnode as _,
+            old_nodes,
+            new_nodes,
+        ))
+    }
+}
+
+#[cfg(linux_kernel)]
+#[inline]
+pub(crate) unsafe fn move_pages(
+    pid: Option<Pid>,
+    count: usize,
+    pages: *const *const c::c_void,
+    nodes: *const c::c_int,
+    status: *mut c::c_int,
+    flags: c::c_int,
+) -> io::Result<usize> {
+    syscall! {
+        fn move_pages(
+            pid: c::pid_t,
+            count: c::c_ulong,
+            pages: *const *const c::c_void,
+            nodes: *const c::c_int,
+            status: *mut c::c_int,
+            flags: c::c_int
+        ) via SYS_move_pages -> c::ssize_t
+    }
+    ret_usize(move_pages(
+        Pid::as_raw(pid),
+        count as _,
+        pages,
+        nodes,
+        status,
+        flags,
+    ))
+}
+
+#[cfg(linux_kernel)]
+#[inline]
+pub(crate) fn pkey_alloc(flags: c::c_uint, access_rights: c::c_uint) -> io::Result<c::c_int> {
+    syscall! {
+        fn pkey_alloc(
+            flags: c::c_uint,
+            access_rights: c::c_uint
+        ) via SYS_pkey_alloc -> c::c_int
+    }
+    unsafe { ret_c_int(pkey_alloc(flags, access_rights)) }
+}
+
+#[cfg(linux_kernel)]
+#[inline]
+pub(crate) fn pkey_free(pkey: c::c_int) -> io::Result<()> {
+    syscall! {
+        fn pkey_free(
+            pkey: c::c_int
+        ) via SYS_pkey_free -> c::c_int
+    }
+    unsafe { ret(pkey_free(pkey)) }
+}
+
+/// # Safety
+///
+/// `pkey_mprotect` is primarily unsafe due to the `ptr` parameter, as
+/// anything working with memory pointed to by raw pointers is unsafe.
+#[cfg(linux_kernel)]
+#[inline]
+pub(crate) unsafe fn pkey_mprotect(
+    ptr: *mut c::c_void,
+    len: usize,
+    flags: MprotectFlags,
+    pkey: c::c_int,
+) -> io::Result<()> {
+    syscall! {
+        fn pkey_mprotect(
+            addr: *mut c::c_void,
+            len: usize,
+            prot: c::c_int,
+            pkey: c::c_int
+        ) via SYS_pkey_mprotect -> c::c_int
+    }
+    ret(pkey_mprotect(ptr, len, bitflags_bits!(flags), pkey))
+}
+
+#[cfg(linux_kernel)]
+#[inline]
+pub(crate) fn mseal(addr: *mut c::c_void, len: usize) -> io::Result<()> {
+    const SYS_MSEAL: c::c_long = linux_raw_sys::general::__NR_mseal as c::c_long;
+
+    syscall! {
+        fn mseal(
+            addr: *mut c::c_void,
+            len: usize,
+            flags: c::c_ulong
+        ) via SYS_MSEAL -> c::c_int
+    }
+    unsafe { ret(mseal(addr, len, 0)) }
+}
+
+#[cfg(all(
+    linux_kernel,
+    any(
+        target_arch = "aarch64",
+        target_arch = "riscv64",
+        target_arch = "s390x",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    )
+))]
+#[inline]
+pub(crate) fn memfd_secret(flags: MemfdSecretFlags) -> io::Result<OwnedFd> {
+    const SYS_MEMFD_SECRET: c::c_long = linux_raw_sys::general::__NR_memfd_secret as c::c_long;
+
+    syscall! {
+        fn memfd_secret(
+            flags: c::c_uint
+        ) via SYS_MEMFD_SECRET -> c::c_int
+    }
+    unsafe { ret_owned_fd(memfd_secret(flags.bits())) }
+}
@@ -472,6 +472,29 @@ bitflags! {
     }
 }
 
+#[cfg(linux_kernel)]
+#[cfg(any(
+    target_arch = "aarch64",
+    target_arch = "riscv64",
+    target_arch = "s390x",
+    target_arch = "x86",
+    target_arch = "x86_64"
+))]
+bitflags! {
+    /// `O_*` flags for use with [`memfd_secret`].
+    ///
+    /// [`memfd_secret`]: crate::mm::memfd_secret
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct MemfdSecretFlags: u32 {
+        /// `O_CLOEXEC`
+        const CLOEXEC = bitcast!(c::O_CLOEXEC);
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
 #[cfg(any(linux_kernel, freebsdlike, netbsdlike))]
 bitflags! {
     /// `MCL_*` flags for use with [`mlockall`].
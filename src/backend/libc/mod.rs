@@ -112,6 +112,9 @@ pub(crate) mod io;
 #[cfg(all(linux_kernel, not(target_os = "android")))]
 #[cfg(feature = "io_uring")]
 pub(crate) mod io_uring;
+#[cfg(linux_kernel)]
+#[cfg(feature = "landlock")]
+pub(crate) mod landlock;
 #[cfg(not(any(
     windows,
     target_os = "espidf",
@@ -124,6 +127,9 @@ pub(crate) mod mm;
 #[cfg(linux_kernel)]
 #[cfg(feature = "mount")]
 pub(crate) mod mount;
+#[cfg(any(linux_kernel, target_os = "dragonfly", target_os = "netbsd"))]
+#[cfg(feature = "mq")]
+pub(crate) mod mq;
 #[cfg(not(target_os = "wasi"))]
 #[cfg(feature = "net")]
 pub(crate) mod net;
@@ -141,6 +147,9 @@ pub(crate) mod pipe;
 #[cfg(not(windows))]
 #[cfg(feature = "process")]
 pub(crate) mod process;
+#[cfg(linux_kernel)]
+#[cfg(feature = "ptrace")]
+pub(crate) mod ptrace;
 #[cfg(not(windows))]
 #[cfg(not(target_os = "wasi"))]
 #[cfg(feature = "pty")]
@@ -148,10 +157,25 @@ pub(crate) mod pty;
 #[cfg(not(windows))]
 #[cfg(feature = "rand")]
 pub(crate) mod rand;
+#[cfg(linux_kernel)]
+#[cfg(feature = "seccomp")]
+pub(crate) mod seccomp;
 #[cfg(not(windows))]
 #[cfg(not(target_os = "wasi"))]
 #[cfg(feature = "system")]
 pub(crate) mod system;
+#[cfg(all(
+    linux_kernel,
+    any(
+        target_arch = "aarch64",
+        target_arch = "arm",
+        target_arch = "riscv64",
+        target_arch = "x86",
+        all(target_arch = "x86_64", target_pointer_width = "64"),
+    )
+))]
+#[cfg(feature = "sysv")]
+pub(crate) mod sysv;
 #[cfg(not(any(windows, target_os = "horizon", target_os = "vita")))]
 #[cfg(feature = "termios")]
 pub(crate) mod termios;
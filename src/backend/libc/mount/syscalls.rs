@@ -1,9 +1,15 @@
 use crate::backend::c;
 use crate::backend::conv::{borrowed_fd, c_str, ret, ret_owned_fd};
+#[cfg(linux_kernel)]
+use crate::fd::AsRawFd as _;
 use crate::fd::{BorrowedFd, OwnedFd};
 use crate::ffi::CStr;
 use crate::io;
+#[cfg(linux_kernel)]
+use crate::mount::MountAttr;
 use core::ptr::null;
+#[cfg(linux_kernel)]
+use linux_raw_sys::general::mount_attr;
 
 #[cfg(linux_kernel)]
 pub(crate) fn mount(
@@ -101,6 +107,87 @@ pub(crate) fn open_tree(
     unsafe { ret_owned_fd(open_tree(borrowed_fd(dfd), c_str(filename), flags.bits())) }
 }
 
+#[cfg(linux_kernel)]
+pub(crate) fn open_tree_attr(
+    dfd: BorrowedFd<'_>,
+    filename: &CStr,
+    flags: super::types::OpenTreeFlags,
+    attr: Option<&MountAttr<'_>>,
+) -> io::Result<OwnedFd> {
+    const SYS_OPEN_TREE_ATTR: c::c_long = linux_raw_sys::general::__NR_open_tree_attr as c::c_long;
+
+    syscall! {
+        fn open_tree_attr(
+            dfd: c::c_int,
+            filename: *const c::c_char,
+            flags: c::c_uint,
+            attr: *const mount_attr,
+            size: usize
+        ) via SYS_OPEN_TREE_ATTR -> c::c_int
+    }
+
+    let attr = attr.map(raw_mount_attr);
+    let (attr, size) = match &attr {
+        Some(attr) => (
+            attr as *const mount_attr,
+            core::mem::size_of::<mount_attr>(),
+        ),
+        None => (null(), 0),
+    };
+    unsafe {
+        ret_owned_fd(open_tree_attr(
+            borrowed_fd(dfd),
+            c_str(filename),
+            flags.bits(),
+            attr,
+            size,
+        ))
+    }
+}
+
+#[cfg(linux_kernel)]
+pub(crate) fn mount_setattr(
+    dfd: BorrowedFd<'_>,
+    path: &CStr,
+    flags: super::types::MountSetattrFlags,
+    attr: &MountAttr<'_>,
+) -> io::Result<()> {
+    syscall! {
+        fn mount_setattr(
+            dfd: c::c_int,
+            path: *const c::c_char,
+            flags: c::c_uint,
+            attr: *const mount_attr,
+            size: usize
+        ) via SYS_mount_setattr -> c::c_int
+    }
+
+    let attr = raw_mount_attr(attr);
+    unsafe {
+        ret(mount_setattr(
+            borrowed_fd(dfd),
+            c_str(path),
+            flags.bits(),
+            &attr,
+            core::mem::size_of::<mount_attr>(),
+        ))
+    }
+}
+
+/// Convert a `MountAttr` into a `struct mount_attr`.
+///
+/// If there's no `userns_fd`, use a value the kernel rejects if
+/// `MOUNT_ATTR_IDMAP` is set, rather than 0, which is a valid fd.
+#[cfg(linux_kernel)]
+fn raw_mount_attr(attr: &MountAttr<'_>) -> mount_attr {
+    mount_attr {
+        attr_set: attr.attr_set.bits().into(),
+        attr_clr: attr.attr_clr.bits().into(),
+        propagation: attr.propagation.bits().into(),
+        userns_fd: attr.userns_fd.map_or(u64::MAX, |fd| fd.as_raw_fd() as u64),
+    }
+}
+
 #[cfg(linux_kernel)]
 pub(crate) fn fspick(
     dfd: BorrowedFd<'_>,
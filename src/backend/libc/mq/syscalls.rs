@@ -0,0 +1,219 @@
+use crate::ffi::CStr;
+
+use super::types::MqOFlags;
+use crate::backend::c;
+use crate::backend::conv::{borrowed_fd, c_str, ret, ret_owned_fd, ret_usize};
+use crate::fd::{BorrowedFd, OwnedFd};
+use crate::fs::Mode;
+use crate::io;
+use crate::mq::{MqAttr, Notification};
+use crate::timespec::Timespec;
+use core::mem::zeroed;
+use core::ptr::null;
+
+fn to_mq_attr(attr: &MqAttr) -> c::mq_attr {
+    // SAFETY: `mq_attr` is a plain C struct, for which all zeros is a valid
+    // value.
+    let mut raw: c::mq_attr = unsafe { zeroed() };
+    raw.mq_flags = attr.flags.bits() as _;
+    raw.mq_maxmsg = attr.maxmsg as _;
+    raw.mq_msgsize = attr.msgsize as _;
+    raw.mq_curmsgs = attr.curmsgs as _;
+    raw
+}
+
+fn from_mq_attr(raw: &c::mq_attr) -> MqAttr {
+    MqAttr {
+        flags: MqOFlags::from_bits_retain(raw.mq_flags as _),
+        maxmsg: raw.mq_maxmsg as _,
+        msgsize: raw.mq_msgsize as _,
+        curmsgs: raw.mq_curmsgs as _,
+    }
+}
+
+pub(crate) fn mq_open(
+    name: &CStr,
+    oflags: MqOFlags,
+    mode: Mode,
+    attr: Option<&MqAttr>,
+) -> io::Result<OwnedFd> {
+    // `mode_t` may be narrower than `c_int`, and can't be passed directly to
+    // a variadic function.
+    let mode: c::c_uint = mode.bits() as _;
+
+    let attr = attr.map(to_mq_attr);
+    let attr_ptr: *const c::mq_attr = match &attr {
+        Some(attr) => attr,
+        None => null(),
+    };
+
+    unsafe {
+        ret_owned_fd(c::mq_open(
+            c_str(name),
+            bitflags_bits!(oflags),
+            mode,
+            attr_ptr,
+        ))
+    }
+}
+
+pub(crate) fn mq_unlink(name: &CStr) -> io::Result<()> {
+    unsafe { ret(c::mq_unlink(c_str(name))) }
+}
+
+pub(crate) fn mq_timedsend(
+    mqdes: BorrowedFd<'_>,
+    msg: &[u8],
+    priority: u32,
+    abs_timeout: Option<&Timespec>,
+) -> io::Result<()> {
+    let msg_ptr = msg.as_ptr().cast::<c::c_char>();
+
+    let abs_timeout = match abs_timeout {
+        None => {
+            return unsafe { ret(c::mq_send(borrowed_fd(mqdes), msg_ptr, msg.len(), priority)) }
+        }
+        Some(abs_timeout) => abs_timeout,
+    };
+
+    // If we don't have to fix y2038 on this platform, `Timespec` is the same
+    // as `c::timespec` and it's easy.
+    #[cfg(not(fix_y2038))]
+    let timeout_ptr = crate::timespec::as_libc_timespec_ptr(abs_timeout);
+
+    // If we do have to fix y2038 on this platform, convert to `c::timespec`.
+    #[cfg(fix_y2038)]
+    let converted_timeout = c::timespec {
+        tv_sec: abs_timeout
+            .tv_sec
+            .try_into()
+            .map_err(|_| io::Errno::OVERFLOW)?,
+        tv_nsec: abs_timeout.tv_nsec as _,
+    };
+    #[cfg(fix_y2038)]
+    let timeout_ptr: *const c::timespec = &converted_timeout;
+
+    unsafe {
+        ret(c::mq_timedsend(
+            borrowed_fd(mqdes),
+            msg_ptr,
+            msg.len(),
+            priority,
+            timeout_ptr,
+        ))
+    }
+}
+
+pub(crate) unsafe fn mq_timedreceive(
+    mqdes: BorrowedFd<'_>,
+    buf: (*mut u8, usize),
+    priority: &mut u32,
+    abs_timeout: Option<&Timespec>,
+) -> io::Result<usize> {
+    let buf_ptr = buf.0.cast::<c::c_char>();
+
+    let abs_timeout = match abs_timeout {
+        None => return ret_usize(c::mq_receive(borrowed_fd(mqdes), buf_ptr, buf.1, priority)),
+        Some(abs_timeout) => abs_timeout,
+    };
+
+    // If we don't have to fix y2038 on this platform, `Timespec` is the same
+    // as `c::timespec` and it's easy.
+    #[cfg(not(fix_y2038))]
+    let timeout_ptr = crate::timespec::as_libc_timespec_ptr(abs_timeout);
+
+    // If we do have to fix y2038 on this platform, convert to `c::timespec`.
+    #[cfg(fix_y2038)]
+    let converted_timeout = c::timespec {
+        tv_sec: abs_timeout
+            .tv_sec
+            .try_into()
+            .map_err(|_| io::Errno::OVERFLOW)?,
+        tv_nsec: abs_timeout.tv_nsec as _,
+    };
+    #[cfg(fix_y2038)]
+    let timeout_ptr: *const c::timespec = &converted_timeout;
+
+    ret_usize(c::mq_timedreceive(
+        borrowed_fd(mqdes),
+        buf_ptr,
+        buf.1,
+        priority,
+        timeout_ptr,
+    ))
+}
+
+pub(crate) fn mq_getsetattr(
+    mqdes: BorrowedFd<'_>,
+    new_attr: Option<&MqAttr>,
+) -> io::Result<MqAttr> {
+    // SAFETY: `mq_attr` is a plain C struct, for which all zeros is a valid
+    // value.
+    let mut old_attr: c::mq_attr = unsafe { zeroed() };
+    unsafe {
+        match new_attr {
+            Some(new_attr) => ret(c::mq_setattr(
+                borrowed_fd(mqdes),
+                &to_mq_attr(new_attr),
+                &mut old_attr,
+            ))?,
+            None => ret(c::mq_getattr(borrowed_fd(mqdes), &mut old_attr))?,
+        }
+    }
+    Ok(from_mq_attr(&old_attr))
+}
+
+pub(crate) fn mq_notify(
+    mqdes: BorrowedFd<'_>,
+    notification: Option<Notification<'_>>,
+) -> io::Result<()> {
+    let event = match notification {
+        None => None,
+        Some(notification) => {
+            // SAFETY: `sigevent` is a plain C struct, for which all zeros is
+            // a valid value.
+            let mut event: c::sigevent = unsafe { zeroed() };
+            match notification {
+                Notification::None => event.sigev_notify = c::SIGEV_NONE,
+                Notification::Signal { signal, value } => {
+                    event.sigev_notify = c::SIGEV_SIGNAL;
+                    event.sigev_signo = signal.as_raw();
+                    event.sigev_value.sival_ptr = value;
+                }
+                #[cfg(linux_kernel)]
+                Notification::Thread { socket, cookie } => {
+                    use crate::fd::AsRawFd as _;
+
+                    // libc's `SIGEV_THREAD` runs a function on a new thread,
+                    // so this form is only available with the raw syscall.
+                    event.sigev_notify = c::SIGEV_THREAD;
+                    event.sigev_signo = socket.as_raw_fd();
+                    event.sigev_value.sival_ptr = cookie.as_ptr() as *mut c::c_void;
+                }
+                #[cfg(not(linux_kernel))]
+                Notification::Thread { .. } => return Err(io::Errno::NOTSUP),
+            }
+            Some(event)
+        }
+    };
+    let event_ptr: *const c::sigevent = match &event {
+        Some(event) => event,
+        None => null(),
+    };
+
+    #[cfg(linux_kernel)]
+    {
+        syscall! {
+            fn mq_notify(
+                mqdes: BorrowedFd<'_>,
+                sevp: *const c::sigevent
+            ) via SYS_mq_notify -> c::c_int
+        }
+        unsafe { ret(mq_notify(mqdes, event_ptr)) }
+    }
+
+    #[cfg(not(linux_kernel))]
+    unsafe {
+        ret(c::mq_notify(borrowed_fd(mqdes), event_ptr))
+    }
+}
@@ -126,6 +126,26 @@ pub(crate) unsafe fn with_recv_msghdr<R>(
     res
 }
 
+/// Create a message header intended to receive a datagram without recording
+/// the sender's address.
+///
+/// The returned `msghdr` will contain raw pointers to the memory referenced by
+/// `iov` and `control`.
+#[cfg(target_os = "linux")]
+pub(crate) fn noaddr_recv_msghdr(
+    iov: &mut [IoSliceMut<'_>],
+    control: &mut RecvAncillaryBuffer<'_>,
+) -> c::msghdr {
+    control.clear();
+
+    let mut h = zero_msghdr();
+    h.msg_iov = iov.as_mut_ptr().cast();
+    h.msg_iovlen = msg_iov_len(iov.len());
+    h.msg_control = control.as_control_ptr().cast();
+    h.msg_controllen = msg_control_len(control.control_len());
+    h
+}
+
 /// Create a message header intended to send without an address.
 ///
 /// The returned `msghdr` will contain raw pointers to the memory
@@ -113,6 +113,11 @@ bitflags! {
         const TRUNC = bitcast!(c::MSG_TRUNC);
         /// `MSG_WAITALL`
         const WAITALL = bitcast!(c::MSG_WAITALL);
+        /// `MSG_WAITFORONE`, for use with [`recvmmsg`].
+        ///
+        /// [`recvmmsg`]: crate::net::recvmmsg
+        #[cfg(target_os = "linux")]
+        const WAITFORONE = bitcast!(c::MSG_WAITFORONE);
 
         /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
         const _ = !0;
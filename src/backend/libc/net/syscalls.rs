@@ -9,11 +9,13 @@ use crate::backend::conv::{borrowed_fd, ret, ret_owned_fd, ret_send_recv, send_r
 use crate::fd::{BorrowedFd, OwnedFd};
 use crate::io;
 use crate::net::addr::SocketAddrArg;
-#[cfg(target_os = "linux")]
-use crate::net::MMsgHdr;
 use crate::net::{
     AddressFamily, Protocol, Shutdown, SocketAddrAny, SocketAddrBuf, SocketFlags, SocketType,
 };
+#[cfg(target_os = "linux")]
+use crate::net::{MMsgHdr, RecvMMsgHdr};
+#[cfg(target_os = "linux")]
+use crate::timespec::Timespec;
 use crate::utils::as_ptr;
 use core::mem::{size_of, MaybeUninit};
 use core::ptr::null_mut;
@@ -281,6 +283,54 @@ pub(crate) fn sendmmsg(
     }
 }
 
+#[cfg(target_os = "linux")]
+pub(crate) fn recvmmsg(
+    sockfd: BorrowedFd<'_>,
+    msgs: &mut [RecvMMsgHdr<'_>],
+    flags: RecvFlags,
+    timeout: Option<&Timespec>,
+) -> io::Result<usize> {
+    // Linux's `recvmmsg` mutates the timeout argument. Our public interface
+    // does not do this, because it's not portable to other platforms, so we
+    // create a temporary value to hide this behavior.
+
+    // If we don't have to fix y2038 on this platform, `Timespec` is the same
+    // as `c::timespec` and it's easy.
+    #[cfg(not(fix_y2038))]
+    let mut timeout = timeout.copied();
+    #[cfg(not(fix_y2038))]
+    let timeout_ptr: *mut c::timespec = match &mut timeout {
+        None => null_mut(),
+        Some(timeout) => (timeout as *mut Timespec).cast(),
+    };
+
+    // If we do have to fix y2038 on this platform, convert to `c::timespec`.
+    #[cfg(fix_y2038)]
+    let mut converted_timeout;
+    #[cfg(fix_y2038)]
+    let timeout_ptr: *mut c::timespec = match timeout {
+        None => null_mut(),
+        Some(timeout) => {
+            converted_timeout = c::timespec {
+                tv_sec: timeout.tv_sec.try_into().map_err(|_| io::Errno::OVERFLOW)?,
+                tv_nsec: timeout.tv_nsec as _,
+            };
+            &mut converted_timeout
+        }
+    };
+
+    unsafe {
+        ret_u32(c::recvmmsg(
+            borrowed_fd(sockfd),
+            msgs.as_mut_ptr() as _,
+            msgs.len().try_into().unwrap_or(c::c_uint::MAX),
+            bitflags_bits!(flags),
+            timeout_ptr,
+        ))
+        .map(|ret| ret as usize)
+    }
+}
+
 #[cfg(not(any(
     apple,
     windows,
@@ -3,7 +3,11 @@
 use crate::backend::c;
 #[cfg(not(any(target_os = "fuchsia", target_os = "wasi")))]
 use crate::backend::conv::borrowed_fd;
-#[cfg(any(target_os = "linux", feature = "fs"))]
+#[cfg(any(
+    target_os = "linux",
+    all(linux_kernel, feature = "alloc"),
+    feature = "fs"
+))]
 use crate::backend::conv::c_str;
 #[cfg(all(feature = "alloc", feature = "fs", not(target_os = "wasi")))]
 use crate::backend::conv::ret_discarded_char_ptr;
@@ -17,14 +21,18 @@ use crate::backend::conv::ret_discarded_char_ptr;
 use crate::backend::conv::ret_infallible;
 #[cfg(not(target_os = "wasi"))]
 use crate::backend::conv::ret_pid_t;
-#[cfg(all(feature = "alloc", not(target_os = "wasi")))]
+#[cfg(any(linux_kernel, all(feature = "alloc", not(target_os = "wasi"))))]
 use crate::backend::conv::ret_usize;
 use crate::backend::conv::{ret, ret_c_int};
 #[cfg(not(any(target_os = "fuchsia", target_os = "wasi")))]
 use crate::fd::BorrowedFd;
 #[cfg(target_os = "linux")]
 use crate::fd::{AsRawFd as _, OwnedFd, RawFd};
-#[cfg(any(target_os = "linux", feature = "fs"))]
+#[cfg(any(
+    target_os = "linux",
+    all(linux_kernel, feature = "alloc"),
+    feature = "fs"
+))]
 use crate::ffi::CStr;
 #[cfg(feature = "fs")]
 use crate::fs::Mode;
@@ -78,6 +86,13 @@ use core::mem::MaybeUninit;
 use {
     super::super::conv::ret_owned_fd, crate::process::PidfdFlags, crate::process::PidfdGetfdFlags,
 };
+#[cfg(linux_kernel)]
+use {
+    crate::backend::MAX_IOV,
+    crate::io::{IoSlice, IoSliceMut},
+    crate::process::RemoteIoVec,
+    core::cmp::min,
+};
 
 #[cfg(feature = "fs")]
 #[cfg(not(target_os = "wasi"))]
@@ -559,6 +574,12 @@ pub(crate) fn kill_process(pid: Pid, sig: Signal) -> io::Result<()> {
     unsafe { ret(c::kill(pid.as_raw_nonzero().get(), sig.as_raw())) }
 }
 
+#[cfg(not(any(target_os = "espidf", target_os = "wasi")))]
+#[inline]
+pub(crate) fn raise(sig: Signal) -> io::Result<()> {
+    unsafe { ret(c::raise(sig.as_raw())) }
+}
+
 #[cfg(not(any(target_os = "espidf", target_os = "wasi")))]
 #[inline]
 pub(crate) fn kill_process_group(pid: Pid, sig: Signal) -> io::Result<()> {
@@ -620,13 +641,128 @@ pub(crate) fn pidfd_open(pid: Pid, flags: PidfdFlags) -> io::Result<OwnedFd> {
     }
 }
 
+#[cfg(all(feature = "alloc", linux_kernel))]
+#[inline]
+pub(crate) unsafe fn execve(
+    path: &CStr,
+    argv: *const *const u8,
+    envp: *const *const u8,
+) -> io::Errno {
+    c::execve(c_str(path), argv.cast(), envp.cast());
+    io::Errno::last_os_error()
+}
+
+#[cfg(all(feature = "alloc", feature = "fs", linux_kernel))]
+#[inline]
+pub(crate) unsafe fn execveat(
+    dirfd: BorrowedFd<'_>,
+    path: &CStr,
+    argv: *const *const u8,
+    envp: *const *const u8,
+    flags: crate::fs::AtFlags,
+) -> io::Errno {
+    syscall! {
+        fn execveat(
+            fd: c::c_int,
+            path: *const c::c_char,
+            argv: *const *const c::c_char,
+            envp: *const *const c::c_char,
+            flags: c::c_int
+        ) via SYS_execveat -> c::c_int
+    }
+
+    execveat(
+        borrowed_fd(dirfd),
+        c_str(path),
+        argv.cast(),
+        envp.cast(),
+        bitflags_bits!(flags),
+    );
+    io::Errno::last_os_error()
+}
+
+#[cfg(linux_kernel)]
+#[inline]
+pub(crate) fn exit_group(status: c::c_int) -> ! {
+    unsafe { c::_exit(status) }
+}
+
+/// Create a child process with `clone3` and have it immediately `execve`.
+///
+/// On success, returns the child's PID, its pidfd, and the reading end of a
+/// `O_CLOEXEC` pipe. If the `execve` fails, the child writes the errno value
+/// to the pipe before exiting, so the pipe is closed without any data
+/// exactly when the `execve` succeeded.
+///
+/// # Safety
+///
+/// `args` must not contain `CLONE_VM`, `CLONE_THREAD`, or any other flags
+/// which would cause the child to share memory with the parent, and any
+/// pointers in it must be valid. `argv` and `envp` must be valid
+/// NULL-terminated arrays of NUL-terminated strings.
+#[cfg(all(feature = "alloc", target_os = "linux"))]
+pub(crate) unsafe fn clone3_spawn(
+    args: &mut linux_raw_sys::general::clone_args,
+    path: &CStr,
+    argv: *const *const u8,
+    envp: *const *const u8,
+) -> io::Result<(Pid, OwnedFd, OwnedFd)> {
+    use crate::fd::FromRawFd as _;
+
+    syscall! {
+        fn clone3(
+            args: *mut linux_raw_sys::general::clone_args,
+            size: c::size_t
+        ) via SYS_clone3 -> c::c_long
+    }
+
+    let mut pipe = MaybeUninit::<[OwnedFd; 2]>::uninit();
+    ret(c::pipe2(pipe.as_mut_ptr().cast::<i32>(), c::O_CLOEXEC))?;
+    let [reader, writer] = pipe.assume_init();
+
+    let mut pidfd = MaybeUninit::<c::c_int>::uninit();
+    args.pidfd = pidfd.as_mut_ptr() as usize as u64;
+
+    let pid = clone3(args, core::mem::size_of_val(args));
+    if pid == -1 {
+        return Err(io::Errno::last_os_error());
+    }
+
+    if pid == 0 {
+        // We're in the child. It has its own copy of the address space, but
+        // it may share that with other threads' locks, so only make raw
+        // syscalls between here and the `execve`.
+        c::syscall(c::SYS_execve, c_str(path), argv, envp);
+        let bytes = io::Errno::last_os_error().raw_os_error().to_ne_bytes();
+        c::syscall(
+            c::SYS_write,
+            writer.as_raw_fd(),
+            bytes.as_ptr(),
+            bytes.len(),
+        );
+        c::syscall(c::SYS_exit_group, 127);
+        unreachable!()
+    }
+
+    drop(writer);
+    Ok((
+        Pid::from_raw_unchecked(pid as c::pid_t),
+        OwnedFd::from_raw_fd(pidfd.assume_init()),
+        reader,
+    ))
+}
+
 #[cfg(target_os = "linux")]
-pub(crate) fn pidfd_send_signal(pidfd: BorrowedFd<'_>, sig: Signal) -> io::Result<()> {
+pub(crate) fn pidfd_send_signal(
+    pidfd: BorrowedFd<'_>,
+    sig: Signal,
+    info: Option<&linux_raw_sys::general::siginfo_t>,
+) -> io::Result<()> {
     syscall! {
         fn pidfd_send_signal(
             pid: c::pid_t,
             sig: c::c_int,
-            info: *const c::siginfo_t,
+            info: *const linux_raw_sys::general::siginfo_t,
             flags: c::c_int
         ) via SYS_pidfd_send_signal -> c::c_int
     }
@@ -634,7 +770,7 @@ pub(crate) fn pidfd_send_signal(pidfd: BorrowedFd<'_>, sig: Signal) -> io::Resul
         ret(pidfd_send_signal(
             borrowed_fd(pidfd),
             sig.as_raw(),
-            core::ptr::null(),
+            crate::utils::option_as_ptr(info),
             0,
         ))
     }
@@ -662,6 +798,40 @@ pub(crate) fn pidfd_getfd(
     }
 }
 
+#[cfg(linux_kernel)]
+pub(crate) fn process_vm_readv(
+    pid: Pid,
+    local: &mut [IoSliceMut<'_>],
+    remote: &[RemoteIoVec],
+) -> io::Result<usize> {
+    unsafe {
+        ret_usize(c::process_vm_readv(
+            pid.as_raw_nonzero().get(),
+            local.as_ptr().cast::<c::iovec>(),
+            min(local.len(), MAX_IOV) as _,
+            remote.as_ptr().cast::<c::iovec>(),
+            min(remote.len(), MAX_IOV) as _,
+            0,
+        ))
+    }
+}
+
+#[cfg(linux_kernel)]
+pub(crate) unsafe fn process_vm_writev(
+    pid: Pid,
+    local: &[IoSlice<'_>],
+    remote: &[RemoteIoVec],
+) -> io::Result<usize> {
+    ret_usize(c::process_vm_writev(
+        pid.as_raw_nonzero().get(),
+        local.as_ptr().cast::<c::iovec>(),
+        min(local.len(), MAX_IOV) as _,
+        remote.as_ptr().cast::<c::iovec>(),
+        min(remote.len(), MAX_IOV) as _,
+        0,
+    ))
+}
+
 #[cfg(target_os = "linux")]
 pub(crate) fn pivot_root(new_root: &CStr, put_old: &CStr) -> io::Result<()> {
     syscall! {
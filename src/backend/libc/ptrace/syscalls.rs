@@ -0,0 +1,27 @@
+//! libc syscalls supporting `rustix::ptrace`.
+
+use crate::backend::c;
+use crate::backend::conv::ret_usize;
+use crate::io;
+use crate::pid::Pid;
+
+#[inline]
+pub(crate) unsafe fn ptrace(
+    request: c::c_uint,
+    pid: Pid,
+    addr: *mut c::c_void,
+    data: *mut c::c_void,
+) -> io::Result<usize> {
+    // `libc::ptrace` is variadic, and on some platforms it handles the
+    // `PTRACE_PEEK*` requests differently, so use the syscall directly.
+    syscall! {
+        fn ptrace(
+            request: c::c_uint,
+            pid: c::pid_t,
+            addr: *mut c::c_void,
+            data: *mut c::c_void
+        ) via SYS_ptrace -> c::c_long
+    }
+
+    ret_usize(ptrace(request, pid.as_raw_nonzero().get(), addr, data) as isize)
+}
@@ -0,0 +1,45 @@
+use bitflags::bitflags;
+use linux_raw_sys::ptrace;
+
+bitflags! {
+    /// `PTRACE_O_*` constants for use with [`seize`] and [`set_options`].
+    ///
+    /// [`seize`]: crate::ptrace::seize
+    /// [`set_options`]: crate::ptrace::set_options
+    #[repr(transparent)]
+    #[derive(Default, Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct PtraceOptions: u32 {
+        /// `PTRACE_O_TRACESYSGOOD`
+        const TRACESYSGOOD = ptrace::PTRACE_O_TRACESYSGOOD;
+
+        /// `PTRACE_O_TRACEFORK`
+        const TRACEFORK = ptrace::PTRACE_O_TRACEFORK;
+
+        /// `PTRACE_O_TRACEVFORK`
+        const TRACEVFORK = ptrace::PTRACE_O_TRACEVFORK;
+
+        /// `PTRACE_O_TRACECLONE`
+        const TRACECLONE = ptrace::PTRACE_O_TRACECLONE;
+
+        /// `PTRACE_O_TRACEEXEC`
+        const TRACEEXEC = ptrace::PTRACE_O_TRACEEXEC;
+
+        /// `PTRACE_O_TRACEVFORKDONE`
+        const TRACEVFORKDONE = ptrace::PTRACE_O_TRACEVFORKDONE;
+
+        /// `PTRACE_O_TRACEEXIT`
+        const TRACEEXIT = ptrace::PTRACE_O_TRACEEXIT;
+
+        /// `PTRACE_O_TRACESECCOMP`
+        const TRACESECCOMP = ptrace::PTRACE_O_TRACESECCOMP;
+
+        /// `PTRACE_O_EXITKILL`
+        const EXITKILL = ptrace::PTRACE_O_EXITKILL;
+
+        /// `PTRACE_O_SUSPEND_SECCOMP`
+        const SUSPEND_SECCOMP = ptrace::PTRACE_O_SUSPEND_SECCOMP;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
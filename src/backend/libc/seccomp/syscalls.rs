@@ -0,0 +1,72 @@
+//! libc syscalls supporting `rustix::seccomp`.
+
+use super::types::SeccompFilterFlags;
+use crate::backend::c;
+use crate::backend::conv::{ret, ret_c_int, ret_owned_fd};
+use crate::fd::OwnedFd;
+use crate::io;
+use core::mem::MaybeUninit;
+use linux_raw_sys::ptrace::{
+    seccomp_notif_sizes, sock_fprog, SECCOMP_GET_ACTION_AVAIL, SECCOMP_GET_NOTIF_SIZES,
+    SECCOMP_SET_MODE_FILTER,
+};
+
+syscall! {
+    fn seccomp(
+        operation: c::c_uint,
+        flags: c::c_uint,
+        args: *mut c::c_void
+    ) via SYS_seccomp -> c::c_int
+}
+
+#[inline]
+pub(crate) fn seccomp_set_mode_filter(
+    flags: SeccompFilterFlags,
+    prog: &sock_fprog,
+) -> io::Result<c::c_int> {
+    unsafe {
+        ret_c_int(seccomp(
+            SECCOMP_SET_MODE_FILTER,
+            flags.bits(),
+            prog as *const sock_fprog as *mut _,
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn seccomp_set_mode_filter_listener(
+    flags: SeccompFilterFlags,
+    prog: &sock_fprog,
+) -> io::Result<OwnedFd> {
+    unsafe {
+        ret_owned_fd(seccomp(
+            SECCOMP_SET_MODE_FILTER,
+            flags.bits(),
+            prog as *const sock_fprog as *mut _,
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn seccomp_get_action_avail(action: u32) -> io::Result<()> {
+    unsafe {
+        ret(seccomp(
+            SECCOMP_GET_ACTION_AVAIL,
+            0,
+            &action as *const u32 as *mut _,
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn seccomp_get_notif_sizes() -> io::Result<seccomp_notif_sizes> {
+    let mut sizes = MaybeUninit::<seccomp_notif_sizes>::uninit();
+    unsafe {
+        ret(seccomp(
+            SECCOMP_GET_NOTIF_SIZES,
+            0,
+            sizes.as_mut_ptr().cast(),
+        ))?;
+        Ok(sizes.assume_init())
+    }
+}
@@ -0,0 +1,334 @@
+//! libc syscalls supporting `rustix::sysv`.
+
+use super::types::{
+    ipc64_perm, msqid64_ds, semid64_ds, shmid64_ds, IpcFlags, MsgFlags, ShmatFlags, GETALL, IPC_64,
+    IPC_RMID, IPC_SET, IPC_STAT, SETALL, SETVAL,
+};
+use crate::backend::c;
+use crate::backend::conv::{ret, ret_c_int, ret_usize};
+use crate::fs::Mode;
+use crate::io;
+use crate::pid::Pid;
+use crate::sysv::{IpcPerm, Key, MsgId, MsqidDs, SemBuf, SemId, SemidDs, ShmId, ShmidDs};
+use crate::timespec::Timespec;
+use crate::ugid::{Gid, Uid};
+use core::mem::zeroed;
+use core::ptr::null_mut;
+
+/// Combines the low and high halves of a time field.
+#[cfg(target_pointer_width = "64")]
+macro_rules! time {
+    ($low:expr, $high:expr) => {
+        $low as i64
+    };
+}
+
+/// Combines the low and high halves of a time field.
+#[cfg(target_pointer_width = "32")]
+macro_rules! time {
+    ($low:expr, $high:expr) => {
+        ((u64::from($high) << 32) | u64::from($low)) as i64
+    };
+}
+
+fn perm_from(raw: &ipc64_perm) -> IpcPerm {
+    IpcPerm {
+        key: Key::from_raw(raw.key),
+        uid: Uid::from_raw(raw.uid),
+        gid: Gid::from_raw(raw.gid),
+        cuid: Uid::from_raw(raw.cuid),
+        cgid: Gid::from_raw(raw.cgid),
+        mode: Mode::from_bits_retain(raw.mode.into()),
+        seq: raw.seq,
+    }
+}
+
+fn perm_into(perm: &IpcPerm, raw: &mut ipc64_perm) {
+    raw.uid = perm.uid.as_raw();
+    raw.gid = perm.gid.as_raw();
+    raw.mode = perm.mode.bits() as _;
+}
+
+syscall! {
+    fn shmctl(
+        shmid: c::c_int,
+        cmd: c::c_int,
+        buf: *mut shmid64_ds
+    ) via SYS_shmctl -> c::c_int
+}
+
+syscall! {
+    fn semctl(
+        semid: c::c_int,
+        semnum: c::c_int,
+        cmd: c::c_int,
+        arg: *mut c::c_void
+    ) via SYS_semctl -> c::c_int
+}
+
+syscall! {
+    fn msgctl(
+        msqid: c::c_int,
+        cmd: c::c_int,
+        buf: *mut msqid64_ds
+    ) via SYS_msgctl -> c::c_int
+}
+
+pub(crate) fn shmget(key: Key, size: usize, flags: IpcFlags, mode: Mode) -> io::Result<ShmId> {
+    unsafe {
+        ret_c_int(c::shmget(
+            key.as_raw(),
+            size,
+            (flags.bits() | mode.bits()) as c::c_int,
+        ))
+        .map(ShmId::from_raw)
+    }
+}
+
+pub(crate) unsafe fn shmat(
+    shmid: ShmId,
+    addr: *mut c::c_void,
+    flags: ShmatFlags,
+) -> io::Result<*mut c::c_void> {
+    let res = c::shmat(shmid.as_raw(), addr, bitflags_bits!(flags));
+    if res as isize == -1 {
+        Err(io::Errno::last_os_error())
+    } else {
+        Ok(res)
+    }
+}
+
+pub(crate) unsafe fn shmdt(addr: *mut c::c_void) -> io::Result<()> {
+    ret(c::shmdt(addr))
+}
+
+pub(crate) fn shmctl_stat(shmid: ShmId) -> io::Result<ShmidDs> {
+    unsafe {
+        let mut raw: shmid64_ds = zeroed();
+        ret(shmctl(shmid.as_raw(), IPC_STAT | IPC_64, &mut raw))?;
+        Ok(ShmidDs {
+            perm: perm_from(&raw.shm_perm),
+            segsz: raw.shm_segsz,
+            atime: time!(raw.shm_atime, raw.shm_atime_high),
+            dtime: time!(raw.shm_dtime, raw.shm_dtime_high),
+            ctime: time!(raw.shm_ctime, raw.shm_ctime_high),
+            cpid: Pid::from_raw(raw.shm_cpid),
+            lpid: Pid::from_raw(raw.shm_lpid),
+            nattch: raw.shm_nattch as usize,
+        })
+    }
+}
+
+pub(crate) fn shmctl_set(shmid: ShmId, buf: &ShmidDs) -> io::Result<()> {
+    unsafe {
+        let mut raw: shmid64_ds = zeroed();
+        perm_into(&buf.perm, &mut raw.shm_perm);
+        ret(shmctl(shmid.as_raw(), IPC_SET | IPC_64, &mut raw))
+    }
+}
+
+pub(crate) fn shmctl_rmid(shmid: ShmId) -> io::Result<()> {
+    unsafe { ret(shmctl(shmid.as_raw(), IPC_RMID, null_mut())) }
+}
+
+pub(crate) fn semget(key: Key, nsems: c::c_int, flags: IpcFlags, mode: Mode) -> io::Result<SemId> {
+    unsafe {
+        ret_c_int(c::semget(
+            key.as_raw(),
+            nsems,
+            (flags.bits() | mode.bits()) as c::c_int,
+        ))
+        .map(SemId::from_raw)
+    }
+}
+
+pub(crate) fn semop(semid: SemId, ops: &[SemBuf]) -> io::Result<()> {
+    // `semop` doesn't modify `ops`, despite taking a `*mut`.
+    unsafe {
+        ret(c::semop(
+            semid.as_raw(),
+            ops.as_ptr() as *mut c::sembuf,
+            ops.len(),
+        ))
+    }
+}
+
+pub(crate) fn semtimedop(semid: SemId, ops: &[SemBuf], timeout: &Timespec) -> io::Result<()> {
+    // libc's `semtimedop` isn't available everywhere, so use the raw
+    // syscall. `Timespec` has the layout of `__kernel_timespec`.
+    #[cfg(target_pointer_width = "32")]
+    {
+        // TODO: Upstream this to the libc crate.
+        #[allow(non_upper_case_globals)]
+        const SYS_semtimedop_time64: i32 = linux_raw_sys::general::__NR_semtimedop_time64 as i32;
+
+        syscall! {
+            fn semtimedop_time64(
+                semid: c::c_int,
+                sops: *const SemBuf,
+                nsops: usize,
+                timeout: *const Timespec
+            ) via SYS_semtimedop_time64 -> c::c_int
+        }
+
+        unsafe {
+            ret(semtimedop_time64(
+                semid.as_raw(),
+                ops.as_ptr(),
+                ops.len(),
+                timeout,
+            ))
+        }
+    }
+
+    #[cfg(target_pointer_width = "64")]
+    {
+        syscall! {
+            fn semtimedop(
+                semid: c::c_int,
+                sops: *const SemBuf,
+                nsops: usize,
+                timeout: *const Timespec
+            ) via SYS_semtimedop -> c::c_int
+        }
+
+        unsafe { ret(semtimedop(semid.as_raw(), ops.as_ptr(), ops.len(), timeout)) }
+    }
+}
+
+pub(crate) fn semctl_stat(semid: SemId) -> io::Result<SemidDs> {
+    unsafe {
+        let mut raw: semid64_ds = zeroed();
+        ret(semctl(
+            semid.as_raw(),
+            0,
+            IPC_STAT | IPC_64,
+            (&mut raw as *mut semid64_ds).cast(),
+        ))?;
+        Ok(SemidDs {
+            perm: perm_from(&raw.sem_perm),
+            otime: time!(raw.sem_otime, raw.sem_otime_high),
+            ctime: time!(raw.sem_ctime, raw.sem_ctime_high),
+            nsems: raw.sem_nsems as usize,
+        })
+    }
+}
+
+pub(crate) fn semctl_set(semid: SemId, buf: &SemidDs) -> io::Result<()> {
+    unsafe {
+        let mut raw: semid64_ds = zeroed();
+        perm_into(&buf.perm, &mut raw.sem_perm);
+        ret(semctl(
+            semid.as_raw(),
+            0,
+            IPC_SET | IPC_64,
+            (&mut raw as *mut semid64_ds).cast(),
+        ))
+    }
+}
+
+pub(crate) fn semctl_rmid(semid: SemId) -> io::Result<()> {
+    unsafe { ret(semctl(semid.as_raw(), 0, IPC_RMID, null_mut())) }
+}
+
+pub(crate) fn semctl_get(semid: SemId, semnum: u16, cmd: c::c_int) -> io::Result<c::c_int> {
+    unsafe { ret_c_int(semctl(semid.as_raw(), semnum.into(), cmd, null_mut())) }
+}
+
+pub(crate) fn semctl_setval(semid: SemId, semnum: u16, val: c::c_int) -> io::Result<()> {
+    // The kernel takes `union semun` as an `unsigned long`, and reads `val`
+    // from the low 32 bits, except on 64-bit big-endian platforms, where it
+    // reads the high 32 bits.
+    #[cfg(not(all(target_pointer_width = "64", target_endian = "big")))]
+    let arg = val as u32 as usize;
+    #[cfg(all(target_pointer_width = "64", target_endian = "big"))]
+    let arg = (val as u32 as usize) << 32;
+
+    unsafe {
+        ret(semctl(
+            semid.as_raw(),
+            semnum.into(),
+            SETVAL,
+            arg as *mut c::c_void,
+        ))
+    }
+}
+
+pub(crate) unsafe fn semctl_getall(semid: SemId, vals: *mut u16) -> io::Result<()> {
+    ret(semctl(semid.as_raw(), 0, GETALL, vals.cast()))
+}
+
+pub(crate) unsafe fn semctl_setall(semid: SemId, vals: *const u16) -> io::Result<()> {
+    ret(semctl(semid.as_raw(), 0, SETALL, vals as *mut c::c_void))
+}
+
+pub(crate) fn msgget(key: Key, flags: IpcFlags, mode: Mode) -> io::Result<MsgId> {
+    unsafe {
+        ret_c_int(c::msgget(
+            key.as_raw(),
+            (flags.bits() | mode.bits()) as c::c_int,
+        ))
+        .map(MsgId::from_raw)
+    }
+}
+
+pub(crate) unsafe fn msgsnd(
+    msqid: MsgId,
+    msgp: *const c::c_void,
+    msgsz: usize,
+    flags: MsgFlags,
+) -> io::Result<()> {
+    ret(c::msgsnd(
+        msqid.as_raw(),
+        msgp,
+        msgsz,
+        bitflags_bits!(flags),
+    ))
+}
+
+pub(crate) unsafe fn msgrcv(
+    msqid: MsgId,
+    msgp: *mut c::c_void,
+    msgsz: usize,
+    msgtyp: c::c_long,
+    flags: MsgFlags,
+) -> io::Result<usize> {
+    ret_usize(c::msgrcv(
+        msqid.as_raw(),
+        msgp,
+        msgsz,
+        msgtyp,
+        bitflags_bits!(flags),
+    ))
+}
+
+pub(crate) fn msgctl_stat(msqid: MsgId) -> io::Result<MsqidDs> {
+    unsafe {
+        let mut raw: msqid64_ds = zeroed();
+        ret(msgctl(msqid.as_raw(), IPC_STAT | IPC_64, &mut raw))?;
+        Ok(MsqidDs {
+            perm: perm_from(&raw.msg_perm),
+            stime: time!(raw.msg_stime, raw.msg_stime_high),
+            rtime: time!(raw.msg_rtime, raw.msg_rtime_high),
+            ctime: time!(raw.msg_ctime, raw.msg_ctime_high),
+            cbytes: raw.msg_cbytes as usize,
+            qnum: raw.msg_qnum as usize,
+            qbytes: raw.msg_qbytes as usize,
+            lspid: Pid::from_raw(raw.msg_lspid),
+            lrpid: Pid::from_raw(raw.msg_lrpid),
+        })
+    }
+}
+
+pub(crate) fn msgctl_set(msqid: MsgId, buf: &MsqidDs) -> io::Result<()> {
+    unsafe {
+        let mut raw: msqid64_ds = zeroed();
+        perm_into(&buf.perm, &mut raw.msg_perm);
+        raw.msg_qbytes = buf.qbytes as _;
+        ret(msgctl(msqid.as_raw(), IPC_SET | IPC_64, &mut raw))
+    }
+}
+
+pub(crate) fn msgctl_rmid(msqid: MsgId) -> io::Result<()> {
+    unsafe { ret(msgctl(msqid.as_raw(), IPC_RMID, null_mut())) }
+}
@@ -0,0 +1,211 @@
+use crate::backend::c;
+use crate::ffi;
+use bitflags::bitflags;
+
+// The `*ctl` functions are called with the raw syscalls, because libc
+// implementations disagree about the layouts of `struct shmid_ds` and
+// friends, and some redefine `IPC_STAT` to select time64 layouts. So the
+// command constants and structs here are the kernel's, copied from the Linux
+// headers.
+
+pub(crate) const IPC_RMID: ffi::c_int = 0;
+pub(crate) const IPC_SET: ffi::c_int = 1;
+pub(crate) const IPC_STAT: ffi::c_int = 2;
+
+pub(crate) const GETPID: ffi::c_int = 11;
+pub(crate) const GETVAL: ffi::c_int = 12;
+pub(crate) const GETALL: ffi::c_int = 13;
+pub(crate) const GETNCNT: ffi::c_int = 14;
+pub(crate) const GETZCNT: ffi::c_int = 15;
+pub(crate) const SETVAL: ffi::c_int = 16;
+pub(crate) const SETALL: ffi::c_int = 17;
+
+/// On architectures where `shmctl`, `semctl`, and `msgctl` are the "old"
+/// syscalls which support multiple struct layouts, `IPC_64` selects the
+/// layout of the `*64_ds` structs below.
+#[cfg(target_arch = "arm")]
+pub(crate) const IPC_64: ffi::c_int = 0x100;
+#[cfg(not(target_arch = "arm"))]
+pub(crate) const IPC_64: ffi::c_int = 0;
+
+bitflags! {
+    /// `IPC_*` and `SHM_*` constants for use with [`shmget`], [`semget`], and
+    /// [`msgget`].
+    ///
+    /// [`shmget`]: crate::sysv::shmget
+    /// [`semget`]: crate::sysv::semget
+    /// [`msgget`]: crate::sysv::msgget
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct IpcFlags: ffi::c_uint {
+        /// `IPC_CREAT`
+        #[doc(alias = "CREAT")]
+        const CREATE = bitcast!(c::IPC_CREAT);
+
+        /// `IPC_EXCL`
+        const EXCL = bitcast!(c::IPC_EXCL);
+
+        /// `SHM_HUGETLB`
+        const SHM_HUGETLB = bitcast!(c::SHM_HUGETLB);
+
+        /// `SHM_NORESERVE`
+        const SHM_NORESERVE = bitcast!(c::SHM_NORESERVE);
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
+bitflags! {
+    /// `SHM_*` constants for use with [`shmat`].
+    ///
+    /// [`shmat`]: crate::sysv::shmat
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct ShmatFlags: ffi::c_uint {
+        /// `SHM_RDONLY`
+        const RDONLY = bitcast!(c::SHM_RDONLY);
+
+        /// `SHM_RND`
+        const RND = bitcast!(c::SHM_RND);
+
+        /// `SHM_REMAP`
+        const REMAP = bitcast!(c::SHM_REMAP);
+
+        /// `SHM_EXEC`
+        #[cfg(not(target_env = "uclibc"))]
+        const EXEC = bitcast!(c::SHM_EXEC);
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
+bitflags! {
+    /// `IPC_NOWAIT` and `SEM_UNDO` for use in [`SemBuf`].
+    ///
+    /// [`SemBuf`]: crate::sysv::SemBuf
+    #[repr(transparent)]
+    #[derive(Default, Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct SemFlags: ffi::c_short {
+        /// `IPC_NOWAIT`
+        const NOWAIT = c::IPC_NOWAIT as ffi::c_short;
+
+        /// `SEM_UNDO`
+        const UNDO = c::SEM_UNDO as ffi::c_short;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
+bitflags! {
+    /// `IPC_NOWAIT` and `MSG_*` constants for use with [`msgsnd`] and
+    /// [`msgrcv`].
+    ///
+    /// [`msgsnd`]: crate::sysv::msgsnd
+    /// [`msgrcv`]: crate::sysv::msgrcv
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct MsgFlags: ffi::c_uint {
+        /// `IPC_NOWAIT`
+        const NOWAIT = bitcast!(c::IPC_NOWAIT);
+
+        /// `MSG_NOERROR`
+        const NOERROR = bitcast!(c::MSG_NOERROR);
+
+        /// `MSG_EXCEPT`
+        const EXCEPT = bitcast!(c::MSG_EXCEPT);
+
+        /// `MSG_COPY`
+        #[cfg(not(target_env = "uclibc"))]
+        const COPY = bitcast!(c::MSG_COPY);
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
+/// `struct ipc64_perm`
+#[repr(C)]
+#[derive(Copy, Clone)]
+#[allow(non_camel_case_types)]
+pub(crate) struct ipc64_perm {
+    pub(crate) key: ffi::c_int,
+    pub(crate) uid: ffi::c_uint,
+    pub(crate) gid: ffi::c_uint,
+    pub(crate) cuid: ffi::c_uint,
+    pub(crate) cgid: ffi::c_uint,
+    pub(crate) mode: linux_raw_sys::general::__kernel_mode_t,
+    pub(crate) __pad1: [u8; 4 - core::mem::size_of::<linux_raw_sys::general::__kernel_mode_t>()],
+    pub(crate) seq: ffi::c_ushort,
+    pub(crate) __pad2: ffi::c_ushort,
+    pub(crate) __unused1: ffi::c_ulong,
+    pub(crate) __unused2: ffi::c_ulong,
+}
+
+/// `struct shmid64_ds`
+#[repr(C)]
+#[derive(Copy, Clone)]
+#[allow(non_camel_case_types)]
+pub(crate) struct shmid64_ds {
+    pub(crate) shm_perm: ipc64_perm,
+    pub(crate) shm_segsz: usize,
+    pub(crate) shm_atime: ffi::c_ulong,
+    #[cfg(target_pointer_width = "32")]
+    pub(crate) shm_atime_high: ffi::c_ulong,
+    pub(crate) shm_dtime: ffi::c_ulong,
+    #[cfg(target_pointer_width = "32")]
+    pub(crate) shm_dtime_high: ffi::c_ulong,
+    pub(crate) shm_ctime: ffi::c_ulong,
+    #[cfg(target_pointer_width = "32")]
+    pub(crate) shm_ctime_high: ffi::c_ulong,
+    pub(crate) shm_cpid: ffi::c_int,
+    pub(crate) shm_lpid: ffi::c_int,
+    pub(crate) shm_nattch: ffi::c_ulong,
+    pub(crate) __unused4: ffi::c_ulong,
+    pub(crate) __unused5: ffi::c_ulong,
+}
+
+/// `struct semid64_ds`
+///
+/// On x86_64, the `*_high` fields are unused padding.
+#[repr(C)]
+#[derive(Copy, Clone)]
+#[allow(non_camel_case_types)]
+pub(crate) struct semid64_ds {
+    pub(crate) sem_perm: ipc64_perm,
+    pub(crate) sem_otime: ffi::c_ulong,
+    #[cfg(any(target_pointer_width = "32", target_arch = "x86_64"))]
+    pub(crate) sem_otime_high: ffi::c_ulong,
+    pub(crate) sem_ctime: ffi::c_ulong,
+    #[cfg(any(target_pointer_width = "32", target_arch = "x86_64"))]
+    pub(crate) sem_ctime_high: ffi::c_ulong,
+    pub(crate) sem_nsems: ffi::c_ulong,
+    pub(crate) __unused3: ffi::c_ulong,
+    pub(crate) __unused4: ffi::c_ulong,
+}
+
+/// `struct msqid64_ds`
+#[repr(C)]
+#[derive(Copy, Clone)]
+#[allow(non_camel_case_types)]
+pub(crate) struct msqid64_ds {
+    pub(crate) msg_perm: ipc64_perm,
+    pub(crate) msg_stime: ffi::c_ulong,
+    #[cfg(target_pointer_width = "32")]
+    pub(crate) msg_stime_high: ffi::c_ulong,
+    pub(crate) msg_rtime: ffi::c_ulong,
+    #[cfg(target_pointer_width = "32")]
+    pub(crate) msg_rtime_high: ffi::c_ulong,
+    pub(crate) msg_ctime: ffi::c_ulong,
+    #[cfg(target_pointer_width = "32")]
+    pub(crate) msg_ctime_high: ffi::c_ulong,
+    pub(crate) msg_cbytes: ffi::c_ulong,
+    pub(crate) msg_qnum: ffi::c_ulong,
+    pub(crate) msg_qbytes: ffi::c_ulong,
+    pub(crate) msg_lspid: ffi::c_int,
+    pub(crate) msg_lrpid: ffi::c_int,
+    pub(crate) __unused4: ffi::c_ulong,
+    pub(crate) __unused5: ffi::c_ulong,
+}
@@ -7,6 +7,8 @@ use crate::backend::conv::ret;
 use crate::io;
 #[cfg(any(freebsdlike, linux_kernel, target_os = "fuchsia"))]
 use crate::pid::Pid;
+#[cfg(linux_kernel)]
+use crate::signal::Signal;
 #[cfg(not(any(
     apple,
     freebsdlike,
@@ -20,7 +22,7 @@ use crate::pid::Pid;
 )))]
 use crate::thread::ClockId;
 #[cfg(linux_kernel)]
-use crate::thread::{Cpuid, MembarrierCommand, MembarrierQuery};
+use crate::thread::{Cpuid, MembarrierCommand, MembarrierQuery, RawSchedAttr, SchedPolicy};
 #[cfg(not(target_os = "redox"))]
 use crate::thread::{NanosleepRelativeResult, Timespec};
 #[cfg(all(target_env = "gnu", fix_y2038))]
@@ -329,6 +331,27 @@ pub(crate) fn gettid() -> Pid {
     }
 }
 
+#[cfg(linux_kernel)]
+#[inline]
+pub(crate) fn tgkill(pid: Pid, tid: Pid, sig: Signal) -> io::Result<()> {
+    // `tgkill` wasn't supported in glibc until 2.30, so use `syscall`.
+    syscall! {
+        fn tgkill(
+            pid: c::pid_t,
+            tid: c::pid_t,
+            sig: c::c_int
+        ) via SYS_tgkill -> c::c_int
+    }
+
+    unsafe {
+        ret(tgkill(
+            pid.as_raw_nonzero().get(),
+            tid.as_raw_nonzero().get(),
+            sig.as_raw(),
+        ))
+    }
+}
+
 #[cfg(linux_kernel)]
 #[inline]
 pub(crate) fn setns(fd: BorrowedFd<'_>, nstype: c::c_int) -> io::Result<c::c_int> {
@@ -740,6 +763,97 @@ pub(crate) fn sched_yield() {
     }
 }
 
+// musl's `sched_setscheduler` and `sched_getparam` always fail with `ENOSYS`,
+// and libc has no wrappers for `sched_setattr` and `sched_getattr`, so call
+// the syscalls directly.
+#[cfg(linux_kernel)]
+pub(crate) fn sched_setattr(pid: Option<Pid>, attr: &RawSchedAttr) -> io::Result<()> {
+    syscall! {
+        fn sched_setattr(
+            pid: c::pid_t,
+            attr: *const RawSchedAttr,
+            flags: c::c_uint
+        ) via SYS_sched_setattr -> c::c_int
+    }
+
+    unsafe { ret(sched_setattr(Pid::as_raw(pid), attr, 0)) }
+}
+
+#[cfg(linux_kernel)]
+pub(crate) fn sched_getattr(pid: Option<Pid>, attr: &mut RawSchedAttr) -> io::Result<()> {
+    syscall! {
+        fn sched_getattr(
+            pid: c::pid_t,
+            attr: *mut RawSchedAttr,
+            size: c::c_uint,
+            flags: c::c_uint
+        ) via SYS_sched_getattr -> c::c_int
+    }
+
+    unsafe {
+        ret(sched_getattr(
+            Pid::as_raw(pid),
+            attr,
+            core::mem::size_of::<RawSchedAttr>() as c::c_uint,
+            0,
+        ))
+    }
+}
+
+#[cfg(linux_kernel)]
+pub(crate) fn sched_setscheduler(
+    pid: Option<Pid>,
+    policy: SchedPolicy,
+    priority: c::c_int,
+) -> io::Result<()> {
+    syscall! {
+        fn sched_setscheduler(
+            pid: c::pid_t,
+            policy: c::c_int,
+            param: *const c::sched_param
+        ) via SYS_sched_setscheduler -> c::c_int
+    }
+
+    let param = c::sched_param {
+        sched_priority: priority,
+    };
+    unsafe {
+        ret(sched_setscheduler(
+            Pid::as_raw(pid),
+            policy as c::c_int,
+            &param,
+        ))
+    }
+}
+
+#[cfg(linux_kernel)]
+pub(crate) fn sched_getparam(pid: Option<Pid>) -> io::Result<c::c_int> {
+    syscall! {
+        fn sched_getparam(
+            pid: c::pid_t,
+            param: *mut c::sched_param
+        ) via SYS_sched_getparam -> c::c_int
+    }
+
+    let mut param = MaybeUninit::<c::sched_param>::uninit();
+    unsafe {
+        ret(sched_getparam(Pid::as_raw(pid), param.as_mut_ptr()))?;
+        Ok(param.assume_init().sched_priority)
+    }
+}
+
+#[cfg(linux_kernel)]
+#[inline]
+pub(crate) fn sched_get_priority_min(policy: SchedPolicy) -> io::Result<c::c_int> {
+    unsafe { ret_c_int(c::sched_get_priority_min(policy as c::c_int)) }
+}
+
+#[cfg(linux_kernel)]
+#[inline]
+pub(crate) fn sched_get_priority_max(policy: SchedPolicy) -> io::Result<c::c_int> {
+    unsafe { ret_c_int(c::sched_get_priority_max(policy as c::c_int)) }
+}
+
 // The `membarrier` syscall has a third argument, but it's only used when
 // the `flags` argument is `MEMBARRIER_CMD_FLAG_CPU`.
 #[cfg(linux_kernel)]
@@ -39,6 +39,80 @@ pub enum MembarrierCommand {
     RegisterPrivateExpeditedRseq = c::MEMBARRIER_CMD_REGISTER_PRIVATE_EXPEDITED_RSEQ as u32,
 }
 
+/// `SCHED_*` constants for use with [`sched_setattr`] and
+/// [`sched_setscheduler`].
+///
+/// [`sched_setattr`]: crate::thread::sched_setattr
+/// [`sched_setscheduler`]: crate::thread::sched_setscheduler
+#[cfg(linux_kernel)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+#[repr(u32)]
+#[non_exhaustive]
+pub enum SchedPolicy {
+    /// `SCHED_OTHER`
+    #[doc(alias = "SCHED_NORMAL")]
+    #[default]
+    Other = linux_raw_sys::general::SCHED_NORMAL,
+    /// `SCHED_FIFO`
+    Fifo = linux_raw_sys::general::SCHED_FIFO,
+    /// `SCHED_RR`
+    Rr = linux_raw_sys::general::SCHED_RR,
+    /// `SCHED_BATCH`
+    Batch = linux_raw_sys::general::SCHED_BATCH,
+    /// `SCHED_IDLE`
+    Idle = linux_raw_sys::general::SCHED_IDLE,
+    /// `SCHED_DEADLINE` (since Linux 3.14)
+    Deadline = linux_raw_sys::general::SCHED_DEADLINE,
+    /// `SCHED_EXT` (since Linux 6.12)
+    Ext = linux_raw_sys::general::SCHED_EXT,
+}
+
+#[cfg(linux_kernel)]
+impl SchedPolicy {
+    /// Convert a raw `SCHED_*` value into a `SchedPolicy`, if known.
+    #[inline]
+    pub const fn from_raw(raw: u32) -> Option<Self> {
+        match raw {
+            linux_raw_sys::general::SCHED_NORMAL => Some(Self::Other),
+            linux_raw_sys::general::SCHED_FIFO => Some(Self::Fifo),
+            linux_raw_sys::general::SCHED_RR => Some(Self::Rr),
+            linux_raw_sys::general::SCHED_BATCH => Some(Self::Batch),
+            linux_raw_sys::general::SCHED_IDLE => Some(Self::Idle),
+            linux_raw_sys::general::SCHED_DEADLINE => Some(Self::Deadline),
+            linux_raw_sys::general::SCHED_EXT => Some(Self::Ext),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(linux_kernel)]
+bitflags::bitflags! {
+    /// `SCHED_FLAG_*` constants for use with [`SchedAttr`].
+    ///
+    /// [`SchedAttr`]: crate::thread::SchedAttr
+    #[repr(transparent)]
+    #[derive(Default, Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct SchedFlags: u64 {
+        /// `SCHED_FLAG_RESET_ON_FORK`
+        const RESET_ON_FORK = linux_raw_sys::general::SCHED_FLAG_RESET_ON_FORK as u64;
+        /// `SCHED_FLAG_RECLAIM`
+        const RECLAIM = linux_raw_sys::general::SCHED_FLAG_RECLAIM as u64;
+        /// `SCHED_FLAG_DL_OVERRUN`
+        const DL_OVERRUN = linux_raw_sys::general::SCHED_FLAG_DL_OVERRUN as u64;
+        /// `SCHED_FLAG_KEEP_POLICY` (since Linux 5.3)
+        const KEEP_POLICY = linux_raw_sys::general::SCHED_FLAG_KEEP_POLICY as u64;
+        /// `SCHED_FLAG_KEEP_PARAMS` (since Linux 5.3)
+        const KEEP_PARAMS = linux_raw_sys::general::SCHED_FLAG_KEEP_PARAMS as u64;
+        /// `SCHED_FLAG_UTIL_CLAMP_MIN` (since Linux 5.3)
+        const UTIL_CLAMP_MIN = linux_raw_sys::general::SCHED_FLAG_UTIL_CLAMP_MIN as u64;
+        /// `SCHED_FLAG_UTIL_CLAMP_MAX` (since Linux 5.3)
+        const UTIL_CLAMP_MAX = linux_raw_sys::general::SCHED_FLAG_UTIL_CLAMP_MAX as u64;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
 /// A CPU identifier as a raw integer.
 #[cfg(linux_kernel)]
 pub type RawCpuid = u32;
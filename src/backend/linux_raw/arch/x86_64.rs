@@ -292,3 +292,39 @@ pub(in crate::backend) unsafe fn syscall6_readonly(
     );
     FromAsm::from_asm(r0)
 }
+
+/// Read the `PKRU` register with the `rdpkru` instruction.
+///
+/// # Safety
+///
+/// The CPU must support `PKU`, and the OS must have enabled it (`OSPKE`).
+#[cfg(feature = "mm")]
+#[inline]
+pub(in crate::backend) unsafe fn rdpkru() -> u32 {
+    let pkru;
+    asm!(
+        "rdpkru",
+        in("ecx") 0_u32,
+        lateout("eax") pkru,
+        lateout("edx") _,
+        options(nomem, nostack, preserves_flags)
+    );
+    pkru
+}
+
+/// Write the `PKRU` register with the `wrpkru` instruction.
+///
+/// # Safety
+///
+/// The CPU must support `PKU`, and the OS must have enabled it (`OSPKE`).
+#[cfg(feature = "mm")]
+#[inline]
+pub(in crate::backend) unsafe fn wrpkru(pkru: u32) {
+    asm!(
+        "wrpkru",
+        in("eax") pkru,
+        in("ecx") 0_u32,
+        in("edx") 0_u32,
+        options(nostack, preserves_flags)
+    );
+}
@@ -15,7 +15,12 @@
 
 use super::c;
 use super::fd::{AsRawFd as _, BorrowedFd, FromRawFd as _, RawFd};
-#[cfg(any(feature = "event", feature = "runtime", feature = "system"))]
+#[cfg(any(
+    feature = "event",
+    feature = "process",
+    feature = "runtime",
+    feature = "system"
+))]
 use super::io::errno::try_decode_error;
 #[cfg(target_pointer_width = "64")]
 use super::io::errno::try_decode_u64;
@@ -35,11 +40,16 @@ use crate::clockid::ClockId;
 use crate::fd::OwnedFd;
 use crate::ffi::CStr;
 use crate::io;
-#[cfg(any(feature = "process", feature = "runtime", feature = "termios"))]
+#[cfg(any(
+    feature = "process",
+    feature = "runtime",
+    feature = "termios",
+    feature = "thread"
+))]
 use crate::pid::Pid;
 #[cfg(feature = "process")]
 use crate::process::Resource;
-#[cfg(any(feature = "process", feature = "runtime"))]
+#[cfg(any(feature = "process", feature = "runtime", feature = "thread"))]
 use crate::signal::Signal;
 use crate::utils::{as_mut_ptr, as_ptr};
 use core::mem::MaybeUninit;
@@ -340,6 +350,20 @@ pub(crate) mod fs {
         }
     }
 
+    impl<'a, Num: ArgNumber> From<crate::fs::fanotify::InitFlags> for ArgReg<'a, Num> {
+        #[inline]
+        fn from(flags: crate::fs::fanotify::InitFlags) -> Self {
+            c_uint(flags.bits())
+        }
+    }
+
+    impl<'a, Num: ArgNumber> From<crate::fs::fanotify::MarkFlags> for ArgReg<'a, Num> {
+        #[inline]
+        fn from(flags: crate::fs::fanotify::MarkFlags) -> Self {
+            c_uint(flags.bits())
+        }
+    }
+
     impl<'a, Num: ArgNumber> From<crate::fs::inotify::CreateFlags> for ArgReg<'a, Num> {
         #[inline]
         fn from(flags: crate::fs::inotify::CreateFlags) -> Self {
@@ -491,6 +515,14 @@ impl<'a, Num: ArgNumber> From<crate::backend::mount::types::OpenTreeFlags> for A
     }
 }
 
+#[cfg(feature = "mount")]
+impl<'a, Num: ArgNumber> From<crate::backend::mount::types::MountSetattrFlags> for ArgReg<'a, Num> {
+    #[inline]
+    fn from(flags: crate::backend::mount::types::MountSetattrFlags) -> Self {
+        c_uint(flags.bits())
+    }
+}
+
 #[cfg(feature = "mount")]
 impl<'a, Num: ArgNumber> From<crate::backend::mount::types::FsPickFlags> for ArgReg<'a, Num> {
     #[inline]
@@ -576,6 +608,14 @@ impl<'a, Num: ArgNumber> From<crate::event::EventfdFlags> for ArgReg<'a, Num> {
     }
 }
 
+#[cfg(feature = "event")]
+impl<'a, Num: ArgNumber> From<crate::event::SignalfdFlags> for ArgReg<'a, Num> {
+    #[inline]
+    fn from(flags: crate::event::SignalfdFlags) -> Self {
+        c_uint(flags.bits())
+    }
+}
+
 #[cfg(feature = "event")]
 impl<'a, Num: ArgNumber> From<crate::event::epoll::CreateFlags> for ArgReg<'a, Num> {
     #[inline]
@@ -687,7 +727,12 @@ impl<'a, Num: ArgNumber> From<Resource> for ArgReg<'a, Num> {
     }
 }
 
-#[cfg(any(feature = "process", feature = "runtime", feature = "termios"))]
+#[cfg(any(
+    feature = "process",
+    feature = "runtime",
+    feature = "termios",
+    feature = "thread"
+))]
 impl<'a, Num: ArgNumber> From<Pid> for ArgReg<'a, Num> {
     #[inline]
     fn from(pid: Pid) -> Self {
@@ -701,7 +746,7 @@ pub(super) fn negative_pid<'a, Num: ArgNumber>(pid: Pid) -> ArgReg<'a, Num> {
     pass_usize(pid.as_raw_nonzero().get().wrapping_neg() as usize)
 }
 
-#[cfg(any(feature = "process", feature = "runtime"))]
+#[cfg(any(feature = "process", feature = "runtime", feature = "thread"))]
 impl<'a, Num: ArgNumber> From<Signal> for ArgReg<'a, Num> {
     #[inline]
     fn from(sig: Signal) -> Self {
@@ -892,7 +937,12 @@ pub(super) unsafe fn ret(raw: RetReg<R0>) -> io::Result<()> {
 ///
 /// The caller must ensure that this is the return value of a syscall which
 /// doesn't return on success.
-#[cfg(any(feature = "event", feature = "runtime", feature = "system"))]
+#[cfg(any(
+    feature = "event",
+    feature = "process",
+    feature = "runtime",
+    feature = "system"
+))]
 #[inline]
 pub(super) unsafe fn ret_error(raw: RetReg<R0>) -> io::Errno {
     try_decode_error(raw)
@@ -6,10 +6,10 @@
 #![allow(unsafe_code, clippy::undocumented_unsafe_blocks)]
 
 use crate::backend::conv::{
-    by_ref, c_int, c_uint, opt_mut, opt_ref, pass_usize, ret, ret_c_int, ret_error, ret_owned_fd,
-    ret_usize, size_of, slice_mut, zero,
+    by_ref, c_int, c_uint, no_fd, opt_mut, opt_ref, pass_usize, ret, ret_c_int, ret_error,
+    ret_owned_fd, ret_usize, size_of, slice_mut, zero,
 };
-use crate::event::{epoll, EventfdFlags, FdSetElement, PollFd, Timespec};
+use crate::event::{epoll, EventfdFlags, FdSetElement, PollFd, SigSet, SignalfdFlags, Timespec};
 use crate::fd::{BorrowedFd, OwnedFd};
 use crate::io;
 use core::ptr::null_mut;
@@ -338,6 +338,31 @@ pub(crate) fn eventfd(initval: u32, flags: EventfdFlags) -> io::Result<OwnedFd>
     unsafe { ret_owned_fd(syscall_readonly!(__NR_eventfd2, c_uint(initval), flags)) }
 }
 
+#[inline]
+pub(crate) unsafe fn signalfd(mask: &SigSet, flags: SignalfdFlags) -> io::Result<OwnedFd> {
+    ret_owned_fd(syscall_readonly!(
+        __NR_signalfd4,
+        no_fd(),
+        by_ref(mask),
+        size_of::<SigSet, _>(),
+        flags
+    ))
+}
+
+#[inline]
+pub(crate) unsafe fn signalfd_set_mask(fd: BorrowedFd<'_>, mask: &SigSet) -> io::Result<()> {
+    // When passed an existing signalfd, `signalfd4` returns that same file
+    // descriptor, so there's nothing to take ownership of.
+    ret_c_int(syscall_readonly!(
+        __NR_signalfd4,
+        fd,
+        by_ref(mask),
+        size_of::<SigSet, _>(),
+        c_uint(0)
+    ))
+    .map(|_| ())
+}
+
 #[inline]
 pub(crate) fn pause() {
     unsafe {
@@ -19,3 +19,20 @@ bitflags! {
         const _ = !0;
     }
 }
+
+bitflags! {
+    /// `SFD_*` flags for use with [`signalfd_with`].
+    ///
+    /// [`signalfd_with`]: crate::event::signalfd_with
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct SignalfdFlags: ffi::c_uint {
+        /// `SFD_CLOEXEC`
+        const CLOEXEC = linux_raw_sys::general::O_CLOEXEC;
+        /// `SFD_NONBLOCK`
+        const NONBLOCK = linux_raw_sys::general::O_NONBLOCK;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
@@ -0,0 +1,183 @@
+//! fanotify support for working with fanotify objects.
+//!
+//! linux-raw-sys doesn't yet have bindings for `linux/fanotify.h`, so the
+//! values here are copied from the Linux headers.
+
+use crate::ffi;
+use bitflags::bitflags;
+
+bitflags! {
+    /// `FAN_*` for use with [`fanotify::init`].
+    ///
+    /// [`fanotify::init`]: crate::fs::fanotify::init
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct InitFlags: ffi::c_uint {
+        /// `FAN_CLOEXEC`
+        const CLOEXEC = 0x0000_0001;
+        /// `FAN_NONBLOCK`
+        const NONBLOCK = 0x0000_0002;
+
+        /// `FAN_CLASS_NOTIF`
+        const CLASS_NOTIF = 0x0000_0000;
+        /// `FAN_CLASS_CONTENT`
+        const CLASS_CONTENT = 0x0000_0004;
+        /// `FAN_CLASS_PRE_CONTENT`
+        const CLASS_PRE_CONTENT = 0x0000_0008;
+
+        /// `FAN_UNLIMITED_QUEUE`
+        const UNLIMITED_QUEUE = 0x0000_0010;
+        /// `FAN_UNLIMITED_MARKS`
+        const UNLIMITED_MARKS = 0x0000_0020;
+        /// `FAN_ENABLE_AUDIT`
+        const ENABLE_AUDIT = 0x0000_0040;
+
+        /// `FAN_REPORT_PIDFD` (since Linux 5.15)
+        const REPORT_PIDFD = 0x0000_0080;
+        /// `FAN_REPORT_TID`
+        const REPORT_TID = 0x0000_0100;
+        /// `FAN_REPORT_FID` (since Linux 5.1)
+        const REPORT_FID = 0x0000_0200;
+        /// `FAN_REPORT_DIR_FID` (since Linux 5.9)
+        const REPORT_DIR_FID = 0x0000_0400;
+        /// `FAN_REPORT_NAME` (since Linux 5.9)
+        const REPORT_NAME = 0x0000_0800;
+        /// `FAN_REPORT_TARGET_FID` (since Linux 5.17)
+        const REPORT_TARGET_FID = 0x0000_1000;
+
+        /// `FAN_REPORT_DFID_NAME`
+        const REPORT_DFID_NAME = Self::REPORT_DIR_FID.bits() | Self::REPORT_NAME.bits();
+        /// `FAN_REPORT_DFID_NAME_TARGET`
+        const REPORT_DFID_NAME_TARGET = Self::REPORT_DFID_NAME.bits()
+            | Self::REPORT_FID.bits()
+            | Self::REPORT_TARGET_FID.bits();
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
+bitflags! {
+    /// `FAN_MARK_*` for use with [`fanotify::mark`].
+    ///
+    /// [`fanotify::mark`]: crate::fs::fanotify::mark
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct MarkFlags: ffi::c_uint {
+        /// `FAN_MARK_ADD`
+        const ADD = 0x0000_0001;
+        /// `FAN_MARK_REMOVE`
+        const REMOVE = 0x0000_0002;
+        /// `FAN_MARK_DONT_FOLLOW`
+        const DONT_FOLLOW = 0x0000_0004;
+        /// `FAN_MARK_ONLYDIR`
+        const ONLYDIR = 0x0000_0008;
+        /// `FAN_MARK_IGNORED_MASK`
+        const IGNORED_MASK = 0x0000_0020;
+        /// `FAN_MARK_IGNORED_SURV_MODIFY`
+        const IGNORED_SURV_MODIFY = 0x0000_0040;
+        /// `FAN_MARK_FLUSH`
+        const FLUSH = 0x0000_0080;
+        /// `FAN_MARK_EVICTABLE` (since Linux 5.19)
+        const EVICTABLE = 0x0000_0200;
+        /// `FAN_MARK_IGNORE` (since Linux 6.0)
+        const IGNORE = 0x0000_0400;
+
+        /// `FAN_MARK_INODE`
+        const INODE = 0x0000_0000;
+        /// `FAN_MARK_MOUNT`
+        const MOUNT = 0x0000_0010;
+        /// `FAN_MARK_FILESYSTEM` (since Linux 4.20)
+        const FILESYSTEM = 0x0000_0100;
+
+        /// `FAN_MARK_IGNORE_SURV`
+        const IGNORE_SURV = Self::IGNORE.bits() | Self::IGNORED_SURV_MODIFY.bits();
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
+bitflags! {
+    /// `FAN_*` event masks for use with [`fanotify::mark`] and
+    /// [`fanotify::Reader`].
+    ///
+    /// [`fanotify::mark`]: crate::fs::fanotify::mark
+    /// [`fanotify::Reader`]: crate::fs::fanotify::Reader
+    #[repr(transparent)]
+    #[derive(Default, Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct EventMask: u64 {
+        /// `FAN_ACCESS`
+        const ACCESS = 0x0000_0001;
+        /// `FAN_MODIFY`
+        const MODIFY = 0x0000_0002;
+        /// `FAN_ATTRIB` (since Linux 5.1)
+        const ATTRIB = 0x0000_0004;
+        /// `FAN_CLOSE_WRITE`
+        const CLOSE_WRITE = 0x0000_0008;
+        /// `FAN_CLOSE_NOWRITE`
+        const CLOSE_NOWRITE = 0x0000_0010;
+        /// `FAN_OPEN`
+        const OPEN = 0x0000_0020;
+        /// `FAN_MOVED_FROM` (since Linux 5.1)
+        const MOVED_FROM = 0x0000_0040;
+        /// `FAN_MOVED_TO` (since Linux 5.1)
+        const MOVED_TO = 0x0000_0080;
+        /// `FAN_CREATE` (since Linux 5.1)
+        const CREATE = 0x0000_0100;
+        /// `FAN_DELETE` (since Linux 5.1)
+        const DELETE = 0x0000_0200;
+        /// `FAN_DELETE_SELF` (since Linux 5.1)
+        const DELETE_SELF = 0x0000_0400;
+        /// `FAN_MOVE_SELF` (since Linux 5.1)
+        const MOVE_SELF = 0x0000_0800;
+        /// `FAN_OPEN_EXEC` (since Linux 5.0)
+        const OPEN_EXEC = 0x0000_1000;
+
+        /// `FAN_Q_OVERFLOW`
+        const QUEUE_OVERFLOW = 0x0000_4000;
+        /// `FAN_FS_ERROR` (since Linux 5.16)
+        const FS_ERROR = 0x0000_8000;
+
+        /// `FAN_OPEN_PERM`
+        const OPEN_PERM = 0x0001_0000;
+        /// `FAN_ACCESS_PERM`
+        const ACCESS_PERM = 0x0002_0000;
+        /// `FAN_OPEN_EXEC_PERM` (since Linux 5.0)
+        const OPEN_EXEC_PERM = 0x0004_0000;
+
+        /// `FAN_EVENT_ON_CHILD`
+        const EVENT_ON_CHILD = 0x0800_0000;
+        /// `FAN_RENAME` (since Linux 5.17)
+        const RENAME = 0x1000_0000;
+        /// `FAN_ONDIR`
+        const ONDIR = 0x4000_0000;
+
+        /// `FAN_CLOSE`
+        const CLOSE = Self::CLOSE_WRITE.bits() | Self::CLOSE_NOWRITE.bits();
+        /// `FAN_MOVE`
+        const MOVE = Self::MOVED_FROM.bits() | Self::MOVED_TO.bits();
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
+bitflags! {
+    /// `FAN_*` responses for use with [`fanotify::write_response`].
+    ///
+    /// [`fanotify::write_response`]: crate::fs::fanotify::write_response
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct Response: u32 {
+        /// `FAN_ALLOW`
+        const ALLOW = 0x01;
+        /// `FAN_DENY`
+        const DENY = 0x02;
+        /// `FAN_AUDIT`
+        const AUDIT = 0x10;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
@@ -1,5 +1,6 @@
 #[cfg(feature = "alloc")]
 pub(crate) mod dir;
+pub mod fanotify;
 pub mod inotify;
 pub(crate) mod makedev;
 pub(crate) mod syscalls;
@@ -31,9 +31,10 @@ use crate::ffi::CStr;
 #[cfg(any(target_arch = "aarch64", target_arch = "riscv64"))]
 use crate::fs::CWD;
 use crate::fs::{
-    inotify, Access, Advice, AtFlags, FallocateFlags, FileType, FlockOperation, Fsid, Gid,
-    MemfdFlags, Mode, OFlags, RenameFlags, ResolveFlags, SealFlags, SeekFrom, Stat, StatFs,
-    StatVfs, StatVfsMountFlags, Statx, StatxFlags, Timestamps, Uid, XattrFlags,
+    fanotify, inotify, Access, Advice, AtFlags, CacheStat, FallocateFlags, FileType,
+    FlockOperation, Fsid, Gid, MemfdFlags, Mode, OFlags, RenameFlags, ResolveFlags, SealFlags,
+    SeekFrom, Stat, StatFs, StatVfs, StatVfsMountFlags, Statx, StatxFlags, Timestamps, Uid,
+    XattrFlags,
 };
 use crate::io;
 use core::mem::MaybeUninit;
@@ -41,8 +42,9 @@ use core::num::NonZeroU64;
 #[cfg(any(target_arch = "mips64", target_arch = "mips64r6"))]
 use linux_raw_sys::general::stat as linux_stat64;
 use linux_raw_sys::general::{
-    open_how, AT_EACCESS, AT_FDCWD, AT_REMOVEDIR, AT_SYMLINK_NOFOLLOW, F_ADD_SEALS, F_GETFL,
-    F_GET_SEALS, F_SETFL, SEEK_CUR, SEEK_DATA, SEEK_END, SEEK_HOLE, SEEK_SET, STATX__RESERVED,
+    cachestat, cachestat_range, open_how, AT_EACCESS, AT_FDCWD, AT_REMOVEDIR, AT_SYMLINK_NOFOLLOW,
+    F_ADD_SEALS, F_GETFL, F_GET_SEALS, F_SETFL, SEEK_CUR, SEEK_DATA, SEEK_END, SEEK_HOLE, SEEK_SET,
+    STATX__RESERVED,
 };
 #[cfg(target_pointer_width = "32")]
 use {
@@ -136,6 +138,16 @@ pub(crate) fn chmodat(
     unsafe { ret(syscall_readonly!(__NR_fchmodat, dirfd, path, mode)) }
 }
 
+#[inline]
+pub(crate) fn fchmodat2(
+    dirfd: BorrowedFd<'_>,
+    path: &CStr,
+    mode: Mode,
+    flags: AtFlags,
+) -> io::Result<()> {
+    unsafe { ret(syscall_readonly!(__NR_fchmodat2, dirfd, path, mode, flags)) }
+}
+
 #[inline]
 pub(crate) fn fchmod(fd: BorrowedFd<'_>, mode: Mode) -> io::Result<()> {
     unsafe { ret(syscall_readonly!(__NR_fchmod, fd, mode)) }
@@ -1477,6 +1489,36 @@ pub(crate) fn copy_file_range(
     }
 }
 
+#[inline]
+pub(crate) fn cachestat(
+    fd: BorrowedFd<'_>,
+    offset: u64,
+    len: Option<NonZeroU64>,
+) -> io::Result<CacheStat> {
+    let range = cachestat_range {
+        off: offset,
+        len: len.map_or(0, NonZeroU64::get),
+    };
+    let mut cstat = MaybeUninit::<cachestat>::uninit();
+    unsafe {
+        ret(syscall!(
+            __NR_cachestat,
+            fd,
+            by_ref(&range),
+            &mut cstat,
+            c_uint(0)
+        ))?;
+        let cstat = cstat.assume_init();
+        Ok(CacheStat {
+            cached: cstat.nr_cache,
+            dirty: cstat.nr_dirty,
+            writeback: cstat.nr_writeback,
+            evicted: cstat.nr_evicted,
+            recently_evicted: cstat.nr_recently_evicted,
+        })
+    }
+}
+
 #[inline]
 pub(crate) fn memfd_create(name: &CStr, flags: MemfdFlags) -> io::Result<OwnedFd> {
     unsafe { ret_owned_fd(syscall_readonly!(__NR_memfd_create, name, flags)) }
@@ -1511,6 +1553,47 @@ pub(crate) fn sendfile(
     }
 }
 
+#[inline]
+pub(crate) fn fanotify_init(
+    flags: fanotify::InitFlags,
+    event_flags: OFlags,
+) -> io::Result<OwnedFd> {
+    unsafe { ret_owned_fd(syscall_readonly!(__NR_fanotify_init, flags, event_flags)) }
+}
+
+#[inline]
+pub(crate) fn fanotify_mark(
+    fanotify_fd: BorrowedFd<'_>,
+    flags: fanotify::MarkFlags,
+    mask: fanotify::EventMask,
+    dirfd: BorrowedFd<'_>,
+    path: Option<&CStr>,
+) -> io::Result<()> {
+    #[cfg(target_pointer_width = "32")]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_fanotify_mark,
+            fanotify_fd,
+            flags,
+            hi(mask.bits()),
+            lo(mask.bits()),
+            dirfd,
+            path
+        ))
+    }
+    #[cfg(target_pointer_width = "64")]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_fanotify_mark,
+            fanotify_fd,
+            flags,
+            pass_usize(mask.bits() as usize),
+            dirfd,
+            path
+        ))
+    }
+}
+
 #[inline]
 pub(crate) fn inotify_init1(flags: inotify::CreateFlags) -> io::Result<OwnedFd> {
     unsafe { ret_owned_fd(syscall_readonly!(__NR_inotify_init1, flags)) }
@@ -0,0 +1,84 @@
+//! linux_raw syscalls supporting `rustix::landlock`.
+//!
+//! # Safety
+//!
+//! See the `rustix::backend` module documentation for details.
+#![allow(unsafe_code)]
+#![allow(clippy::undocumented_unsafe_blocks)]
+
+use crate::backend::conv::{by_ref, c_uint, ret, ret_c_int, ret_owned_fd, size_of, zero};
+use crate::fd::{BorrowedFd, OwnedFd};
+use crate::ffi::c_int;
+use crate::io;
+use crate::landlock::RestrictSelfFlags;
+use linux_raw_sys::landlock::{
+    landlock_net_port_attr, landlock_path_beneath_attr, landlock_rule_type, landlock_ruleset_attr,
+    LANDLOCK_CREATE_RULESET_VERSION,
+};
+
+#[inline]
+pub(crate) fn create_ruleset(attr: &landlock_ruleset_attr) -> io::Result<OwnedFd> {
+    unsafe {
+        ret_owned_fd(syscall_readonly!(
+            __NR_landlock_create_ruleset,
+            by_ref(attr),
+            size_of::<landlock_ruleset_attr, _>(),
+            zero()
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn abi_version() -> io::Result<c_int> {
+    unsafe {
+        ret_c_int(syscall_readonly!(
+            __NR_landlock_create_ruleset,
+            zero(),
+            zero(),
+            c_uint(LANDLOCK_CREATE_RULESET_VERSION)
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn add_rule_path_beneath(
+    ruleset: BorrowedFd<'_>,
+    attr: &landlock_path_beneath_attr,
+) -> io::Result<()> {
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_landlock_add_rule,
+            ruleset,
+            c_uint(landlock_rule_type::LANDLOCK_RULE_PATH_BENEATH as u32),
+            by_ref(attr),
+            zero()
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn add_rule_net_port(
+    ruleset: BorrowedFd<'_>,
+    attr: &landlock_net_port_attr,
+) -> io::Result<()> {
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_landlock_add_rule,
+            ruleset,
+            c_uint(landlock_rule_type::LANDLOCK_RULE_NET_PORT as u32),
+            by_ref(attr),
+            zero()
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn restrict_self(ruleset: BorrowedFd<'_>, flags: RestrictSelfFlags) -> io::Result<()> {
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_landlock_restrict_self,
+            ruleset,
+            c_uint(flags.bits())
+        ))
+    }
+}
@@ -6,6 +6,14 @@
 #![allow(unsafe_code)]
 #![allow(clippy::undocumented_unsafe_blocks)]
 
+#[cfg(any(
+    target_arch = "aarch64",
+    target_arch = "riscv64",
+    target_arch = "s390x",
+    target_arch = "x86",
+    target_arch = "x86_64"
+))]
+use super::types::MemfdSecretFlags;
 use super::types::{
     Advice, MapFlags, MlockAllFlags, MlockFlags, MprotectFlags, MremapFlags, MsyncFlags, ProtFlags,
     UserfaultfdFlags,
@@ -13,10 +21,13 @@ use super::types::{
 use crate::backend::c;
 #[cfg(target_pointer_width = "64")]
 use crate::backend::conv::loff_t_from_u64;
-use crate::backend::conv::{c_uint, no_fd, pass_usize, ret, ret_owned_fd, ret_void_star};
+use crate::backend::conv::{
+    c_int, c_uint, no_fd, pass_usize, ret, ret_c_int, ret_owned_fd, ret_usize, ret_void_star, zero,
+};
 use crate::fd::{BorrowedFd, OwnedFd};
 use crate::ffi::c_void;
 use crate::io;
+use crate::pid::Pid;
 use linux_raw_sys::general::{MAP_ANONYMOUS, MREMAP_FIXED};
 
 #[inline]
@@ -237,3 +248,195 @@ pub(crate) fn mlockall(flags: MlockAllFlags) -> io::Result<()> {
 pub(crate) fn munlockall() -> io::Result<()> {
     unsafe { ret(syscall_readonly!(__NR_munlockall)) }
 }
+
+#[inline]
+pub(crate) unsafe fn mbind(
+    addr: *mut c_void,
+    len: usize,
+    mode: u32,
+    nodemask: *const c::c_ulong,
+    maxnode: usize,
+    flags: u32,
+) -> io::Result<()> {
+    ret(syscall!(
+        __NR_mbind,
+        addr,
+        pass_usize(len),
+        c_uint(mode),
+        nodemask,
+        pass_usize(maxnode),
+        c_uint(flags)
+    ))
+}
+
+#[inline]
+pub(crate) fn set_mempolicy(
+    mode: c::c_int,
+    nodemask: *const c::c_ulong,
+    maxnode: usize,
+) -> io::Result<()> {
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_set_mempolicy,
+            c_int(mode),
+            nodemask,
+            pass_usize(maxnode)
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn get_mempolicy(
+    mode: *mut c::c_int,
+    nodemask: *mut c::c_ulong,
+    maxnode: usize,
+    addr: *const c_void,
+    flags: u32,
+) -> io::Result<()> {
+    unsafe {
+        ret(syscall!(
+            __NR_get_mempolicy,
+            mode,
+            nodemask,
+            pass_usize(maxnode),
+            addr,
+            c_uint(flags)
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn migrate_pages(
+    pid: Option<Pid>,
+    maxnode: usize,
+    old_nodes: *const c::c_ulong,
+    new_nodes: *const c::c_ulong,
+) -> io::Result<usize> {
+    unsafe {
+        ret_usize(syscall!(
+            __NR_migrate_pages,
+            c_int(Pid::as_raw(pid)),
+            pass_usize(maxnode),
+            old_nodes,
+            new_nodes
+        ))
+    }
+}
+
+#[inline]
+pub(crate) unsafe fn move_pages(
+    pid: Option<Pid>,
+    count: usize,
+    pages: *const *const c_void,
+    nodes: *const c::c_int,
+    status: *mut c::c_int,
+    flags: c::c_int,
+) -> io::Result<usize> {
+    ret_usize(syscall!(
+        __NR_move_pages,
+        c_int(Pid::as_raw(pid)),
+        pass_usize(count),
+        pages,
+        nodes,
+        status,
+        c_int(flags)
+    ))
+}
+
+#[inline]
+pub(crate) fn pkey_alloc(flags: c::c_uint, access_rights: c::c_uint) -> io::Result<c::c_int> {
+    unsafe {
+        ret_c_int(syscall_readonly!(
+            __NR_pkey_alloc,
+            c_uint(flags),
+            c_uint(access_rights)
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn pkey_free(pkey: c::c_int) -> io::Result<()> {
+    unsafe { ret(syscall_readonly!(__NR_pkey_free, c_int(pkey))) }
+}
+
+/// # Safety
+///
+/// `pkey_mprotect` is primarily unsafe due to the `addr` parameter, as
+/// anything working with memory pointed to by raw pointers is unsafe.
+#[inline]
+pub(crate) unsafe fn pkey_mprotect(
+    addr: *mut c_void,
+    len: usize,
+    flags: MprotectFlags,
+    pkey: c::c_int,
+) -> io::Result<()> {
+    ret(syscall!(
+        __NR_pkey_mprotect,
+        addr,
+        pass_usize(len),
+        flags,
+        c_int(pkey)
+    ))
+}
+
+/// Test whether the CPU supports protection keys and the OS has enabled them,
+/// so that `rdpkru` and `wrpkru` can be used.
+#[cfg(target_arch = "x86_64")]
+fn pkru_supported() -> bool {
+    use core::arch::x86_64::{__cpuid, __cpuid_count};
+    use core::sync::atomic::{AtomicU8, Ordering};
+
+    // 0 means not yet known, 1 means unsupported, 2 means supported.
+    static PKRU_SUPPORTED: AtomicU8 = AtomicU8::new(0);
+
+    match PKRU_SUPPORTED.load(Ordering::Relaxed) {
+        0 => {
+            // `CPUID.(EAX=07H,ECX=0H):ECX.OSPKE[bit 4]`.
+            #[allow(unused_unsafe)]
+            let supported =
+                unsafe { __cpuid(0).eax >= 7 && __cpuid_count(7, 0).ecx & (1 << 4) != 0 };
+            PKRU_SUPPORTED.store(if supported { 2 } else { 1 }, Ordering::Relaxed);
+            supported
+        }
+        n => n == 2,
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+#[inline]
+pub(crate) fn pkru_read() -> io::Result<u32> {
+    if !pkru_supported() {
+        return Err(io::Errno::INVAL);
+    }
+    // SAFETY: We checked that `PKRU` is supported.
+    Ok(unsafe { crate::backend::arch::asm::rdpkru() })
+}
+
+#[cfg(target_arch = "x86_64")]
+#[inline]
+pub(crate) unsafe fn pkru_write(pkru: u32) -> io::Result<()> {
+    if !pkru_supported() {
+        return Err(io::Errno::INVAL);
+    }
+    // SAFETY: We checked that `PKRU` is supported, and the caller upholds
+    // the requirements for the new access rights.
+    crate::backend::arch::asm::wrpkru(pkru);
+    Ok(())
+}
+
+#[inline]
+pub(crate) fn mseal(addr: *mut c_void, len: usize) -> io::Result<()> {
+    unsafe { ret(syscall_readonly!(__NR_mseal, addr, pass_usize(len), zero())) }
+}
+
+#[cfg(any(
+    target_arch = "aarch64",
+    target_arch = "riscv64",
+    target_arch = "s390x",
+    target_arch = "x86",
+    target_arch = "x86_64"
+))]
+#[inline]
+pub(crate) fn memfd_secret(flags: MemfdSecretFlags) -> io::Result<OwnedFd> {
+    unsafe { ret_owned_fd(syscall_readonly!(__NR_memfd_secret, c_uint(flags.bits()))) }
+}
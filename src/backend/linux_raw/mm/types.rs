@@ -265,6 +265,28 @@ bitflags! {
     }
 }
 
+#[cfg(any(
+    target_arch = "aarch64",
+    target_arch = "riscv64",
+    target_arch = "s390x",
+    target_arch = "x86",
+    target_arch = "x86_64"
+))]
+bitflags! {
+    /// `O_*` flags for use with [`memfd_secret`].
+    ///
+    /// [`memfd_secret`]: crate::mm::memfd_secret
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct MemfdSecretFlags: u32 {
+        /// `O_CLOEXEC`
+        const CLOEXEC = linux_raw_sys::general::O_CLOEXEC;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
 bitflags! {
     /// `MCL_*` flags for use with [`mlockall`].
     ///
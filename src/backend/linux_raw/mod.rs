@@ -43,10 +43,14 @@ pub(crate) mod fs;
 pub(crate) mod io;
 #[cfg(feature = "io_uring")]
 pub(crate) mod io_uring;
+#[cfg(feature = "landlock")]
+pub(crate) mod landlock;
 #[cfg(feature = "mm")]
 pub(crate) mod mm;
 #[cfg(feature = "mount")]
 pub(crate) mod mount;
+#[cfg(feature = "mq")]
+pub(crate) mod mq;
 #[cfg(feature = "net")]
 pub(crate) mod net;
 #[cfg(any(
@@ -61,16 +65,32 @@ pub(crate) mod param;
 pub(crate) mod pipe;
 #[cfg(feature = "process")]
 pub(crate) mod process;
+#[cfg(feature = "ptrace")]
+pub(crate) mod ptrace;
 #[cfg(feature = "pty")]
 pub(crate) mod pty;
 #[cfg(feature = "rand")]
 pub(crate) mod rand;
 #[cfg(feature = "runtime")]
 pub(crate) mod runtime;
+#[cfg(feature = "seccomp")]
+pub(crate) mod seccomp;
 #[cfg(feature = "shm")]
 pub(crate) mod shm;
 #[cfg(feature = "system")]
 pub(crate) mod system;
+#[cfg(all(
+    linux_kernel,
+    any(
+        target_arch = "aarch64",
+        target_arch = "arm",
+        target_arch = "riscv64",
+        target_arch = "x86",
+        all(target_arch = "x86_64", target_pointer_width = "64"),
+    )
+))]
+#[cfg(feature = "sysv")]
+pub(crate) mod sysv;
 #[cfg(feature = "termios")]
 pub(crate) mod termios;
 #[cfg(feature = "thread")]
@@ -6,10 +6,12 @@
 #![allow(unsafe_code)]
 #![allow(clippy::undocumented_unsafe_blocks)]
 
-use crate::backend::conv::{ret, ret_owned_fd, slice, zero};
-use crate::fd::{BorrowedFd, OwnedFd};
+use crate::backend::conv::{by_ref, opt_ref, pass_usize, ret, ret_owned_fd, size_of, slice, zero};
+use crate::fd::{AsRawFd as _, BorrowedFd, OwnedFd};
 use crate::ffi::CStr;
 use crate::io;
+use crate::mount::MountAttr;
+use linux_raw_sys::general::mount_attr;
 
 #[inline]
 pub(crate) fn mount(
@@ -79,6 +81,64 @@ pub(crate) fn open_tree(
     unsafe { ret_owned_fd(syscall_readonly!(__NR_open_tree, dfd, filename, flags)) }
 }
 
+#[inline]
+pub(crate) fn open_tree_attr(
+    dfd: BorrowedFd<'_>,
+    filename: &CStr,
+    flags: super::types::OpenTreeFlags,
+    attr: Option<&MountAttr<'_>>,
+) -> io::Result<OwnedFd> {
+    let attr = attr.map(raw_mount_attr);
+    let size = if attr.is_some() {
+        core::mem::size_of::<mount_attr>()
+    } else {
+        0
+    };
+    unsafe {
+        ret_owned_fd(syscall_readonly!(
+            __NR_open_tree_attr,
+            dfd,
+            filename,
+            flags,
+            opt_ref(attr.as_ref()),
+            pass_usize(size)
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn mount_setattr(
+    dfd: BorrowedFd<'_>,
+    path: &CStr,
+    flags: super::types::MountSetattrFlags,
+    attr: &MountAttr<'_>,
+) -> io::Result<()> {
+    let attr = raw_mount_attr(attr);
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_mount_setattr,
+            dfd,
+            path,
+            flags,
+            by_ref(&attr),
+            size_of::<mount_attr, _>()
+        ))
+    }
+}
+
+/// Convert a `MountAttr` into a `struct mount_attr`.
+///
+/// If there's no `userns_fd`, use a value the kernel rejects if
+/// `MOUNT_ATTR_IDMAP` is set, rather than 0, which is a valid fd.
+fn raw_mount_attr(attr: &MountAttr<'_>) -> mount_attr {
+    mount_attr {
+        attr_set: attr.attr_set.bits().into(),
+        attr_clr: attr.attr_clr.bits().into(),
+        propagation: attr.propagation.bits().into(),
+        userns_fd: attr.userns_fd.map_or(u64::MAX, |fd| fd.as_raw_fd() as u64),
+    }
+}
+
 #[inline]
 pub(crate) fn fspick(
     dfd: BorrowedFd<'_>,
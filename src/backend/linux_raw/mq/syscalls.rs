@@ -0,0 +1,277 @@
+//! linux_raw syscalls supporting `rustix::mq`.
+//!
+//! # Safety
+//!
+//! See the `rustix::backend` module documentation for details.
+#![allow(unsafe_code)]
+#![allow(clippy::undocumented_unsafe_blocks)]
+
+use super::types::{mq_attr, MqOFlags};
+use crate::backend::c;
+use crate::backend::conv::{
+    by_mut, c_uint, opt_mut, opt_ref, pass_usize, ret, ret_owned_fd, ret_usize,
+};
+use crate::fd::{AsRawFd as _, BorrowedFd, OwnedFd};
+use crate::ffi::CStr;
+use crate::fs::Mode;
+use crate::io;
+use crate::mq::{MqAttr, Notification};
+use crate::timespec::Timespec;
+use core::mem::zeroed;
+use linux_raw_sys::general::{sigevent, SIGEV_NONE, SIGEV_SIGNAL, SIGEV_THREAD};
+
+/// The kernel's `mq_open` and `mq_unlink` take names relative to the mqueue
+/// filesystem, without the leading slash that POSIX requires.
+fn strip_slash(name: &CStr) -> &CStr {
+    let bytes = name.to_bytes_with_nul();
+    match bytes.first() {
+        Some(b'/') => CStr::from_bytes_with_nul(&bytes[1..]).unwrap(),
+        _ => name,
+    }
+}
+
+impl From<&MqAttr> for mq_attr {
+    fn from(attr: &MqAttr) -> Self {
+        Self {
+            mq_flags: attr.flags.bits() as _,
+            mq_maxmsg: attr.maxmsg as _,
+            mq_msgsize: attr.msgsize as _,
+            mq_curmsgs: attr.curmsgs as _,
+            __reserved: [0; 4],
+        }
+    }
+}
+
+impl From<mq_attr> for MqAttr {
+    fn from(attr: mq_attr) -> Self {
+        Self {
+            flags: MqOFlags::from_bits_retain(attr.mq_flags as _),
+            maxmsg: attr.mq_maxmsg as _,
+            msgsize: attr.mq_msgsize as _,
+            curmsgs: attr.mq_curmsgs as _,
+        }
+    }
+}
+
+#[inline]
+pub(crate) fn mq_open(
+    name: &CStr,
+    oflags: MqOFlags,
+    mode: Mode,
+    attr: Option<&MqAttr>,
+) -> io::Result<OwnedFd> {
+    let attr = attr.map(mq_attr::from);
+    unsafe {
+        ret_owned_fd(syscall_readonly!(
+            __NR_mq_open,
+            strip_slash(name),
+            c_uint(oflags.bits()),
+            mode,
+            opt_ref(attr.as_ref())
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn mq_unlink(name: &CStr) -> io::Result<()> {
+    unsafe { ret(syscall_readonly!(__NR_mq_unlink, strip_slash(name))) }
+}
+
+#[inline]
+pub(crate) fn mq_timedsend(
+    mqdes: BorrowedFd<'_>,
+    msg: &[u8],
+    priority: u32,
+    abs_timeout: Option<&Timespec>,
+) -> io::Result<()> {
+    #[cfg(target_pointer_width = "32")]
+    unsafe {
+        // If we don't have Linux 5.1, and the timeout fits in a
+        // `__kernel_old_timespec`, use plain `mq_timedsend`.
+        //
+        // We do this unconditionally, rather than trying
+        // `mq_timedsend_time64` and falling back on `Errno::NOSYS`, because
+        // seccomp configurations will sometimes abort the process on syscalls
+        // they don't recognize.
+        #[cfg(not(feature = "linux_5_1"))]
+        {
+            use linux_raw_sys::general::__kernel_old_timespec;
+
+            // If we don't have a timeout, or if we can convert the timeout to
+            // a `__kernel_old_timespec`, the use `__NR_mq_timedsend`.
+            fn convert(timeout: &Timespec) -> Option<__kernel_old_timespec> {
+                Some(__kernel_old_timespec {
+                    tv_sec: timeout.tv_sec.try_into().ok()?,
+                    tv_nsec: timeout.tv_nsec.try_into().ok()?,
+                })
+            }
+            let old_timeout = if let Some(timeout) = abs_timeout {
+                // Try to convert the timeout; if this is `Some`, we're ok!
+                convert(timeout).map(Some)
+            } else {
+                // No timeout. Ok!
+                Some(None)
+            };
+            if let Some(old_timeout) = old_timeout {
+                // Call `mq_timedsend`.
+                return ret(syscall_readonly!(
+                    __NR_mq_timedsend,
+                    mqdes,
+                    msg.as_ptr(),
+                    pass_usize(msg.len()),
+                    c_uint(priority),
+                    opt_ref(old_timeout.as_ref())
+                ));
+            }
+        }
+
+        // We either have Linux 5.1 or the timeout didn't fit in
+        // `__kernel_old_timespec` so `__NR_mq_timedsend_time64` will either
+        // succeed or fail due to our having no other options.
+        ret(syscall_readonly!(
+            __NR_mq_timedsend_time64,
+            mqdes,
+            msg.as_ptr(),
+            pass_usize(msg.len()),
+            c_uint(priority),
+            opt_ref(abs_timeout)
+        ))
+    }
+
+    #[cfg(target_pointer_width = "64")]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_mq_timedsend,
+            mqdes,
+            msg.as_ptr(),
+            pass_usize(msg.len()),
+            c_uint(priority),
+            opt_ref(abs_timeout)
+        ))
+    }
+}
+
+#[inline]
+pub(crate) unsafe fn mq_timedreceive(
+    mqdes: BorrowedFd<'_>,
+    buf: (*mut u8, usize),
+    priority: &mut u32,
+    abs_timeout: Option<&Timespec>,
+) -> io::Result<usize> {
+    #[cfg(target_pointer_width = "32")]
+    {
+        // If we don't have Linux 5.1, and the timeout fits in a
+        // `__kernel_old_timespec`, use plain `mq_timedreceive`.
+        //
+        // We do this unconditionally, rather than trying
+        // `mq_timedreceive_time64` and falling back on `Errno::NOSYS`,
+        // because seccomp configurations will sometimes abort the process on
+        // syscalls they don't recognize.
+        #[cfg(not(feature = "linux_5_1"))]
+        {
+            use linux_raw_sys::general::__kernel_old_timespec;
+
+            // If we don't have a timeout, or if we can convert the timeout to
+            // a `__kernel_old_timespec`, the use `__NR_mq_timedreceive`.
+            fn convert(timeout: &Timespec) -> Option<__kernel_old_timespec> {
+                Some(__kernel_old_timespec {
+                    tv_sec: timeout.tv_sec.try_into().ok()?,
+                    tv_nsec: timeout.tv_nsec.try_into().ok()?,
+                })
+            }
+            let old_timeout = if let Some(timeout) = abs_timeout {
+                // Try to convert the timeout; if this is `Some`, we're ok!
+                convert(timeout).map(Some)
+            } else {
+                // No timeout. Ok!
+                Some(None)
+            };
+            if let Some(old_timeout) = old_timeout {
+                // Call `mq_timedreceive`.
+                return ret_usize(syscall!(
+                    __NR_mq_timedreceive,
+                    mqdes,
+                    buf.0,
+                    pass_usize(buf.1),
+                    by_mut(priority),
+                    opt_ref(old_timeout.as_ref())
+                ));
+            }
+        }
+
+        // We either have Linux 5.1 or the timeout didn't fit in
+        // `__kernel_old_timespec` so `__NR_mq_timedreceive_time64` will
+        // either succeed or fail due to our having no other options.
+        ret_usize(syscall!(
+            __NR_mq_timedreceive_time64,
+            mqdes,
+            buf.0,
+            pass_usize(buf.1),
+            by_mut(priority),
+            opt_ref(abs_timeout)
+        ))
+    }
+
+    #[cfg(target_pointer_width = "64")]
+    {
+        ret_usize(syscall!(
+            __NR_mq_timedreceive,
+            mqdes,
+            buf.0,
+            pass_usize(buf.1),
+            by_mut(priority),
+            opt_ref(abs_timeout)
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn mq_getsetattr(
+    mqdes: BorrowedFd<'_>,
+    new_attr: Option<&MqAttr>,
+) -> io::Result<MqAttr> {
+    let new_attr = new_attr.map(mq_attr::from);
+    let mut old_attr = mq_attr::default();
+    unsafe {
+        ret(syscall!(
+            __NR_mq_getsetattr,
+            mqdes,
+            opt_ref(new_attr.as_ref()),
+            opt_mut(Some(&mut old_attr))
+        ))?;
+    }
+    Ok(old_attr.into())
+}
+
+#[inline]
+pub(crate) fn mq_notify(
+    mqdes: BorrowedFd<'_>,
+    notification: Option<Notification<'_>>,
+) -> io::Result<()> {
+    let notification = notification.map(|notification| {
+        // SAFETY: `sigevent` is a plain C struct, for which all zeros is a
+        // valid value.
+        let mut event: sigevent = unsafe { zeroed() };
+        match notification {
+            Notification::None => event.sigev_notify = SIGEV_NONE as c::c_int,
+            Notification::Signal { signal, value } => {
+                event.sigev_notify = SIGEV_SIGNAL as c::c_int;
+                event.sigev_signo = signal.as_raw();
+                event.sigev_value.sival_ptr = value as *mut c::c_void;
+            }
+            Notification::Thread { socket, cookie } => {
+                event.sigev_notify = SIGEV_THREAD as c::c_int;
+                event.sigev_signo = socket.as_raw_fd();
+                event.sigev_value.sival_ptr = cookie.as_ptr() as *mut c::c_void;
+            }
+        }
+        event
+    });
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_mq_notify,
+            mqdes,
+            opt_ref(notification.as_ref())
+        ))
+    }
+}
@@ -0,0 +1,49 @@
+use crate::ffi;
+use bitflags::bitflags;
+
+bitflags! {
+    /// `O_*` constants for use with [`mq::open`] and [`mq::MqAttr`].
+    ///
+    /// [`mq::open`]: crate::mq::open
+    /// [`mq::MqAttr`]: crate::mq::MqAttr
+    #[repr(transparent)]
+    #[derive(Default, Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct MqOFlags: ffi::c_uint {
+        /// `O_CREAT`
+        #[doc(alias = "CREAT")]
+        const CREATE = linux_raw_sys::general::O_CREAT;
+
+        /// `O_EXCL`
+        const EXCL = linux_raw_sys::general::O_EXCL;
+
+        /// `O_RDONLY`
+        const RDONLY = linux_raw_sys::general::O_RDONLY;
+
+        /// `O_WRONLY`
+        const WRONLY = linux_raw_sys::general::O_WRONLY;
+
+        /// `O_RDWR`
+        const RDWR = linux_raw_sys::general::O_RDWR;
+
+        /// `O_NONBLOCK`
+        const NONBLOCK = linux_raw_sys::general::O_NONBLOCK;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
+/// `struct mq_attr`
+///
+/// linux-raw-sys doesn't have bindings for `linux/mqueue.h`, so this is
+/// copied from the Linux headers.
+#[repr(C)]
+#[derive(Default, Copy, Clone)]
+#[allow(non_camel_case_types)]
+pub(crate) struct mq_attr {
+    pub(crate) mq_flags: ffi::c_long,
+    pub(crate) mq_maxmsg: ffi::c_long,
+    pub(crate) mq_msgsize: ffi::c_long,
+    pub(crate) mq_curmsgs: ffi::c_long,
+    pub(crate) __reserved: [ffi::c_long; 4],
+}
@@ -64,6 +64,28 @@ pub(crate) unsafe fn with_recv_msghdr<R>(
     res
 }
 
+/// Create a message header intended to receive a datagram without recording
+/// the sender's address.
+///
+/// The returned `msghdr` will contain raw pointers to the memory referenced by
+/// `iov` and `control`.
+pub(crate) fn noaddr_recv_msghdr(
+    iov: &mut [IoSliceMut<'_>],
+    control: &mut RecvAncillaryBuffer<'_>,
+) -> c::msghdr {
+    control.clear();
+
+    c::msghdr {
+        msg_name: null_mut(),
+        msg_namelen: 0,
+        msg_iov: iov.as_mut_ptr().cast(),
+        msg_iovlen: msg_iov_len(iov.len()),
+        msg_control: control.as_control_ptr().cast(),
+        msg_controllen: msg_control_len(control.control_len()),
+        msg_flags: 0,
+    }
+}
+
 /// Create a message header intended to send without an address.
 ///
 /// The returned `msghdr` will contain raw pointers to the memory
@@ -53,6 +53,11 @@ bitflags! {
         const TRUNC = c::MSG_TRUNC;
         /// `MSG_WAITALL`
         const WAITALL = c::MSG_WAITALL;
+        /// `MSG_WAITFORONE`, for use with [`recvmmsg`].
+        ///
+        /// [`recvmmsg`]: crate::net::recvmmsg
+        // linux-raw-sys doesn't define `MSG_WAITFORONE` yet.
+        const WAITFORONE = 0x1_0000;
 
         /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
         const _ = !0;
@@ -12,19 +12,21 @@ use crate::backend::c;
 #[cfg(target_os = "linux")]
 use crate::backend::conv::slice_mut;
 use crate::backend::conv::{
-    by_mut, by_ref, c_int, c_uint, pass_usize, ret, ret_owned_fd, ret_usize, size_of, slice,
-    socklen_t, zero,
+    by_mut, by_ref, c_int, c_uint, opt_mut, pass_usize, ret, ret_owned_fd, ret_usize, size_of,
+    slice, socklen_t, zero,
 };
 use crate::backend::reg::raw_arg;
 use crate::fd::{BorrowedFd, OwnedFd};
 use crate::io::{self, IoSlice, IoSliceMut};
 use crate::net::addr::SocketAddrArg;
-#[cfg(target_os = "linux")]
-use crate::net::MMsgHdr;
 use crate::net::{
     AddressFamily, Protocol, RecvAncillaryBuffer, RecvMsg, SendAncillaryBuffer, Shutdown,
     SocketAddrAny, SocketAddrBuf, SocketFlags, SocketType,
 };
+#[cfg(target_os = "linux")]
+use crate::net::{MMsgHdr, RecvMMsgHdr};
+#[cfg(target_os = "linux")]
+use crate::timespec::Timespec;
 use core::mem::MaybeUninit;
 #[cfg(target_arch = "x86")]
 use {
@@ -32,8 +34,8 @@ use {
     crate::backend::reg::{ArgReg, SocketArg},
     linux_raw_sys::net::{
         SYS_ACCEPT, SYS_ACCEPT4, SYS_BIND, SYS_CONNECT, SYS_GETPEERNAME, SYS_GETSOCKNAME,
-        SYS_LISTEN, SYS_RECV, SYS_RECVFROM, SYS_RECVMSG, SYS_SEND, SYS_SENDMMSG, SYS_SENDMSG,
-        SYS_SENDTO, SYS_SHUTDOWN, SYS_SOCKET, SYS_SOCKETPAIR,
+        SYS_LISTEN, SYS_RECV, SYS_RECVFROM, SYS_RECVMMSG, SYS_RECVMSG, SYS_SEND, SYS_SENDMMSG,
+        SYS_SENDMSG, SYS_SENDTO, SYS_SHUTDOWN, SYS_SOCKET, SYS_SOCKETPAIR,
     },
 };
 
@@ -360,6 +362,112 @@ pub(crate) fn sendmmsg(
     result
 }
 
+#[cfg(target_os = "linux")]
+#[inline]
+pub(crate) fn recvmmsg(
+    sockfd: BorrowedFd<'_>,
+    msgs: &mut [RecvMMsgHdr<'_>],
+    flags: RecvFlags,
+    timeout: Option<&Timespec>,
+) -> io::Result<usize> {
+    let (msgs, len) = slice_mut(msgs);
+
+    #[cfg(target_pointer_width = "32")]
+    unsafe {
+        // If we don't have Linux 5.1, and the timeout fits in a
+        // `__kernel_old_timespec`, use plain `recvmmsg`.
+        //
+        // We do this unconditionally, rather than trying `recvmmsg_time64`
+        // and falling back on `Errno::NOSYS`, because seccomp configurations
+        // will sometimes abort the process on syscalls they don't recognize.
+        #[cfg(not(feature = "linux_5_1"))]
+        {
+            use linux_raw_sys::general::__kernel_old_timespec;
+
+            // If we don't have a timeout, or if we can convert the timeout to
+            // a `__kernel_old_timespec`, the use `__NR_recvmmsg`.
+            fn convert(timeout: &Timespec) -> Option<__kernel_old_timespec> {
+                Some(__kernel_old_timespec {
+                    tv_sec: timeout.tv_sec.try_into().ok()?,
+                    tv_nsec: timeout.tv_nsec.try_into().ok()?,
+                })
+            }
+            let old_timeout = if let Some(timeout) = timeout {
+                // Try to convert the timeout; if this is `Some`, we're ok!
+                convert(timeout).map(Some)
+            } else {
+                // No timeout. Ok!
+                Some(None)
+            };
+            if let Some(mut old_timeout) = old_timeout {
+                // Call `recvmmsg`.
+                //
+                // Linux's `recvmmsg` mutates the timeout argument. Our public
+                // interface does not do this, because it's not portable to
+                // other platforms, so we create a temporary value to hide this
+                // behavior.
+                #[cfg(not(target_arch = "x86"))]
+                return ret_usize(syscall!(
+                    __NR_recvmmsg,
+                    sockfd,
+                    msgs,
+                    len,
+                    flags,
+                    opt_mut(old_timeout.as_mut())
+                ));
+
+                #[cfg(target_arch = "x86")]
+                return ret_usize(syscall!(
+                    __NR_socketcall,
+                    x86_sys(SYS_RECVMMSG),
+                    slice_just_addr::<ArgReg<'_, SocketArg>, _>(&[
+                        sockfd.into(),
+                        msgs,
+                        len,
+                        flags.into(),
+                        opt_mut(old_timeout.as_mut()),
+                    ])
+                ));
+            }
+        }
+
+        // We either have Linux 5.1 or the timeout didn't fit in
+        // `__kernel_old_timespec` so `__NR_recvmmsg_time64` will either
+        // succeed or fail due to our having no other options.
+
+        // Call `recvmmsg_time64`.
+        //
+        // Linux's `recvmmsg_time64` mutates the timeout argument. Our public
+        // interface does not do this, because it's not portable to other
+        // platforms, so we create a temporary value to hide this behavior.
+        ret_usize(syscall!(
+            __NR_recvmmsg_time64,
+            sockfd,
+            msgs,
+            len,
+            flags,
+            opt_mut(timeout.copied().as_mut())
+        ))
+    }
+
+    #[cfg(target_pointer_width = "64")]
+    unsafe {
+        // Call `recvmmsg`.
+        //
+        // Linux's `recvmmsg` mutates the timeout argument. Our public
+        // interface does not do this, because it's not portable to other
+        // platforms, so we create a temporary value to hide this behavior.
+        ret_usize(syscall!(
+            __NR_recvmmsg,
+            sockfd,
+            msgs,
+            len,
+            flags,
+            opt_mut(timeout.copied().as_mut())
+        ))
+    }
+}
+
 #[inline]
 pub(crate) fn shutdown(fd: BorrowedFd<'_>, how: Shutdown) -> io::Result<()> {
     #[cfg(not(target_arch = "x86"))]
@@ -6,31 +6,41 @@
 #![allow(unsafe_code, clippy::undocumented_unsafe_blocks)]
 
 use crate::backend::c;
+#[cfg(any(feature = "alloc", feature = "runtime"))]
+use crate::backend::conv::ret_error;
 #[cfg(all(feature = "alloc", feature = "fs"))]
 use crate::backend::conv::slice_mut;
 use crate::backend::conv::{
-    by_mut, by_ref, c_int, c_uint, negative_pid, pass_usize, raw_fd, ret, ret_c_int,
-    ret_c_int_infallible, ret_infallible, ret_owned_fd, zero,
+    by_mut, by_ref, c_int, c_uint, negative_pid, opt_ref, pass_usize, raw_fd, ret, ret_c_int,
+    ret_c_int_infallible, ret_infallible, ret_owned_fd, ret_usize, slice, zero,
 };
+use crate::backend::MAX_IOV;
 use crate::fd::{AsRawFd as _, BorrowedFd, OwnedFd, RawFd};
-#[cfg(feature = "fs")]
+#[cfg(any(feature = "alloc", feature = "fs", feature = "runtime"))]
 use crate::ffi::CStr;
-use crate::io;
+#[cfg(all(feature = "fs", any(feature = "alloc", feature = "runtime")))]
+use crate::fs::AtFlags;
+use crate::io::{self, IoSlice, IoSliceMut};
 use crate::pid::RawPid;
 use crate::process::{
-    Flock, Pid, PidfdFlags, PidfdGetfdFlags, Resource, Rlimit, Uid, WaitId, WaitIdOptions,
-    WaitIdStatus, WaitOptions, WaitStatus,
+    Flock, Pid, PidfdFlags, PidfdGetfdFlags, RemoteIoVec, Resource, Rlimit, Uid, WaitId,
+    WaitIdOptions, WaitIdStatus, WaitOptions, WaitStatus,
 };
 use crate::signal::Signal;
+use core::cmp;
 use core::mem::MaybeUninit;
 use core::ptr::{null, null_mut};
-use linux_raw_sys::general::{rlimit64, PRIO_PGRP, PRIO_PROCESS, PRIO_USER, RLIM64_INFINITY};
+use linux_raw_sys::general::{
+    rlimit64, siginfo_t, PRIO_PGRP, PRIO_PROCESS, PRIO_USER, RLIM64_INFINITY,
+};
 #[cfg(feature = "fs")]
 use {crate::backend::conv::ret_c_uint_infallible, crate::fs::Mode};
 #[cfg(feature = "alloc")]
 use {
-    crate::backend::conv::{ret_usize, slice_just_addr_mut},
+    crate::backend::conv::{size_of, slice_just_addr_mut},
+    crate::fd::{AsFd as _, FromRawFd as _},
     crate::process::Gid,
+    linux_raw_sys::general::{clone_args, O_CLOEXEC},
 };
 
 #[cfg(feature = "fs")]
@@ -443,6 +453,15 @@ pub(crate) fn kill_process(pid: Pid, sig: Signal) -> io::Result<()> {
     unsafe { ret(syscall_readonly!(__NR_kill, pid, sig)) }
 }
 
+#[inline]
+pub(crate) fn raise(sig: Signal) -> io::Result<()> {
+    unsafe {
+        let pid = ret_c_int_infallible(syscall_readonly!(__NR_getpid));
+        let tid = ret_c_int_infallible(syscall_readonly!(__NR_gettid));
+        ret(syscall_readonly!(__NR_tgkill, c_int(pid), c_int(tid), sig))
+    }
+}
+
 #[inline]
 pub(crate) fn kill_process_group(pid: Pid, sig: Signal) -> io::Result<()> {
     unsafe { ret(syscall_readonly!(__NR_kill, negative_pid(pid), sig)) }
@@ -495,14 +514,109 @@ pub(crate) fn pidfd_open(pid: Pid, flags: PidfdFlags) -> io::Result<OwnedFd> {
     unsafe { ret_owned_fd(syscall_readonly!(__NR_pidfd_open, pid, flags)) }
 }
 
+#[cfg(any(feature = "alloc", feature = "runtime"))]
+#[inline]
+pub(crate) unsafe fn execve(
+    path: &CStr,
+    argv: *const *const u8,
+    envp: *const *const u8,
+) -> io::Errno {
+    ret_error(syscall_readonly!(__NR_execve, path, argv, envp))
+}
+
+#[cfg(all(feature = "fs", any(feature = "alloc", feature = "runtime")))]
+#[inline]
+pub(crate) unsafe fn execveat(
+    dirfd: BorrowedFd<'_>,
+    path: &CStr,
+    argv: *const *const u8,
+    envp: *const *const u8,
+    flags: AtFlags,
+) -> io::Errno {
+    ret_error(syscall_readonly!(
+        __NR_execveat,
+        dirfd,
+        path,
+        argv,
+        envp,
+        flags
+    ))
+}
+
+#[inline]
+pub(crate) fn exit_group(status: c::c_int) -> ! {
+    unsafe { syscall_noreturn!(__NR_exit_group, c_int(status)) }
+}
+
+/// Create a child process with `clone3` and have it immediately `execve`.
+///
+/// On success, returns the child's PID, its pidfd, and the reading end of a
+/// `O_CLOEXEC` pipe. If the `execve` fails, the child writes the errno value
+/// to the pipe before exiting, so the pipe is closed without any data
+/// exactly when the `execve` succeeded.
+///
+/// # Safety
+///
+/// `args` must not contain `CLONE_VM`, `CLONE_THREAD`, or any other flags
+/// which would cause the child to share memory with the parent, and any
+/// pointers in it must be valid. `argv` and `envp` must be valid
+/// NULL-terminated arrays of NUL-terminated strings.
+#[cfg(feature = "alloc")]
+pub(crate) unsafe fn clone3_spawn(
+    args: &mut clone_args,
+    path: &CStr,
+    argv: *const *const u8,
+    envp: *const *const u8,
+) -> io::Result<(Pid, OwnedFd, OwnedFd)> {
+    let mut pipe = MaybeUninit::<[OwnedFd; 2]>::uninit();
+    ret(syscall!(__NR_pipe2, &mut pipe, c_uint(O_CLOEXEC)))?;
+    let [reader, writer] = pipe.assume_init();
+
+    let mut pidfd = MaybeUninit::<c::c_int>::uninit();
+    args.pidfd = pidfd.as_mut_ptr() as usize as u64;
+
+    let pid = ret_c_int(syscall!(
+        __NR_clone3,
+        by_mut(args),
+        size_of::<clone_args, _>()
+    ))?;
+
+    if pid == 0 {
+        // We're in the child. It has its own copy of the address space, but
+        // it may share that with other threads' locks, so only make raw
+        // syscalls between here and the `execve`.
+        let err = ret_error(syscall_readonly!(__NR_execve, path, argv, envp));
+        let bytes = err.raw_os_error().to_ne_bytes();
+        let (bytes_addr, bytes_len) = slice(&bytes);
+        let _ = ret_usize(syscall_readonly!(
+            __NR_write,
+            writer.as_fd(),
+            bytes_addr,
+            bytes_len
+        ));
+        syscall_noreturn!(__NR_exit_group, c_int(127))
+    }
+
+    drop(writer);
+    Ok((
+        Pid::from_raw_unchecked(pid),
+        OwnedFd::from_raw_fd(pidfd.assume_init()),
+        reader,
+    ))
+}
+
 #[inline]
-pub(crate) fn pidfd_send_signal(fd: BorrowedFd<'_>, sig: Signal) -> io::Result<()> {
+pub(crate) fn pidfd_send_signal(
+    fd: BorrowedFd<'_>,
+    sig: Signal,
+    info: Option<&siginfo_t>,
+) -> io::Result<()> {
     unsafe {
         ret(syscall_readonly!(
             __NR_pidfd_send_signal,
             fd,
             sig,
-            pass_usize(0),
+            opt_ref(info),
             pass_usize(0)
         ))
     }
@@ -558,3 +672,45 @@ pub(crate) fn fcntl_getlk(fd: BorrowedFd<'_>, lock: &Flock) -> io::Result<Option
         Ok(Some(unsafe { Flock::from_raw_unchecked(curr_lock) }))
     }
 }
+
+#[inline]
+pub(crate) fn process_vm_readv(
+    pid: Pid,
+    local: &mut [IoSliceMut<'_>],
+    remote: &[RemoteIoVec],
+) -> io::Result<usize> {
+    let (local_addr, local_len) = slice(&local[..cmp::min(local.len(), MAX_IOV)]);
+    let (remote_addr, remote_len) = slice(&remote[..cmp::min(remote.len(), MAX_IOV)]);
+
+    unsafe {
+        ret_usize(syscall!(
+            __NR_process_vm_readv,
+            pid,
+            local_addr,
+            local_len,
+            remote_addr,
+            remote_len,
+            zero()
+        ))
+    }
+}
+
+#[inline]
+pub(crate) unsafe fn process_vm_writev(
+    pid: Pid,
+    local: &[IoSlice<'_>],
+    remote: &[RemoteIoVec],
+) -> io::Result<usize> {
+    let (local_addr, local_len) = slice(&local[..cmp::min(local.len(), MAX_IOV)]);
+    let (remote_addr, remote_len) = slice(&remote[..cmp::min(remote.len(), MAX_IOV)]);
+
+    ret_usize(syscall!(
+        __NR_process_vm_writev,
+        pid,
+        local_addr,
+        local_len,
+        remote_addr,
+        remote_len,
+        zero()
+    ))
+}
@@ -0,0 +1,23 @@
+//! linux_raw syscalls supporting `rustix::ptrace`.
+//!
+//! # Safety
+//!
+//! See the `rustix::backend` module documentation for details.
+#![allow(unsafe_code)]
+#![allow(clippy::undocumented_unsafe_blocks)]
+
+use crate::backend::c;
+use crate::backend::conv::{c_uint, ret_usize};
+use crate::ffi::c_void;
+use crate::io;
+use crate::pid::Pid;
+
+#[inline]
+pub(crate) unsafe fn ptrace(
+    request: c::c_uint,
+    pid: Pid,
+    addr: *mut c_void,
+    data: *mut c_void,
+) -> io::Result<usize> {
+    ret_usize(syscall!(__NR_ptrace, c_uint(request), pid, addr, data))
+}
@@ -11,14 +11,9 @@ use crate::backend::conv::by_mut;
 #[cfg(target_arch = "x86_64")]
 use crate::backend::conv::c_uint;
 use crate::backend::conv::{
-    by_ref, c_int, opt_ref, ret, ret_c_int, ret_c_int_infallible, ret_error, ret_infallible,
-    ret_void_star, size_of, zero,
+    by_ref, c_int, opt_ref, ret, ret_c_int, ret_c_int_infallible, ret_infallible, ret_void_star,
+    size_of, zero,
 };
-#[cfg(feature = "fs")]
-use crate::fd::BorrowedFd;
-use crate::ffi::CStr;
-#[cfg(feature = "fs")]
-use crate::fs::AtFlags;
 use crate::io;
 use crate::pid::{Pid, RawPid};
 use crate::runtime::{Fork, How, KernelSigSet, KernelSigaction, Siginfo, Stack};
@@ -78,32 +73,6 @@ pub(crate) unsafe fn kernel_fork() -> io::Result<Fork> {
     })
 }
 
-#[cfg(feature = "fs")]
-pub(crate) unsafe fn execveat(
-    dirfd: BorrowedFd<'_>,
-    path: &CStr,
-    args: *const *const u8,
-    env_vars: *const *const u8,
-    flags: AtFlags,
-) -> io::Errno {
-    ret_error(syscall_readonly!(
-        __NR_execveat,
-        dirfd,
-        path,
-        args,
-        env_vars,
-        flags
-    ))
-}
-
-pub(crate) unsafe fn execve(
-    path: &CStr,
-    args: *const *const u8,
-    env_vars: *const *const u8,
-) -> io::Errno {
-    ret_error(syscall_readonly!(__NR_execve, path, args, env_vars))
-}
-
 pub(crate) mod tls {
     use super::*;
     #[cfg(target_arch = "x86")]
@@ -334,11 +303,6 @@ pub(crate) unsafe fn kernel_sigtimedwait(
     }
 }
 
-#[inline]
-pub(crate) fn exit_group(code: c::c_int) -> ! {
-    unsafe { syscall_noreturn!(__NR_exit_group, c_int(code)) }
-}
-
 #[inline]
 pub(crate) unsafe fn kernel_brk(addr: *mut c::c_void) -> io::Result<*mut c_void> {
     // This is non-`readonly`, to prevent loads from being reordered past it.
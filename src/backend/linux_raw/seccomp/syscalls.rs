@@ -0,0 +1,74 @@
+//! linux_raw syscalls supporting `rustix::seccomp`.
+//!
+//! # Safety
+//!
+//! See the `rustix::backend` module documentation for details.
+#![allow(unsafe_code)]
+#![allow(clippy::undocumented_unsafe_blocks)]
+
+use super::types::SeccompFilterFlags;
+use crate::backend::conv::{by_ref, c_uint, ret, ret_c_int, ret_owned_fd, zero};
+use crate::fd::OwnedFd;
+use crate::ffi::c_int;
+use crate::io;
+use core::mem::MaybeUninit;
+use linux_raw_sys::ptrace::{
+    seccomp_notif_sizes, sock_fprog, SECCOMP_GET_ACTION_AVAIL, SECCOMP_GET_NOTIF_SIZES,
+    SECCOMP_SET_MODE_FILTER,
+};
+
+#[inline]
+pub(crate) fn seccomp_set_mode_filter(
+    flags: SeccompFilterFlags,
+    prog: &sock_fprog,
+) -> io::Result<c_int> {
+    unsafe {
+        ret_c_int(syscall!(
+            __NR_seccomp,
+            c_uint(SECCOMP_SET_MODE_FILTER),
+            c_uint(flags.bits()),
+            by_ref(prog)
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn seccomp_set_mode_filter_listener(
+    flags: SeccompFilterFlags,
+    prog: &sock_fprog,
+) -> io::Result<OwnedFd> {
+    unsafe {
+        ret_owned_fd(syscall!(
+            __NR_seccomp,
+            c_uint(SECCOMP_SET_MODE_FILTER),
+            c_uint(flags.bits()),
+            by_ref(prog)
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn seccomp_get_action_avail(action: u32) -> io::Result<()> {
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_seccomp,
+            c_uint(SECCOMP_GET_ACTION_AVAIL),
+            zero(),
+            by_ref(&action)
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn seccomp_get_notif_sizes() -> io::Result<seccomp_notif_sizes> {
+    let mut sizes = MaybeUninit::<seccomp_notif_sizes>::uninit();
+    unsafe {
+        ret(syscall!(
+            __NR_seccomp,
+            c_uint(SECCOMP_GET_NOTIF_SIZES),
+            zero(),
+            &mut sizes
+        ))?;
+        Ok(sizes.assume_init())
+    }
+}
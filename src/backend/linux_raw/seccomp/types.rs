@@ -0,0 +1,67 @@
+use crate::ffi;
+use bitflags::bitflags;
+
+bitflags! {
+    /// `SECCOMP_FILTER_FLAG_*` constants for use with [`set_mode_filter`].
+    ///
+    /// [`set_mode_filter`]: crate::seccomp::set_mode_filter
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct SeccompFilterFlags: ffi::c_uint {
+        /// `SECCOMP_FILTER_FLAG_TSYNC`
+        const TSYNC = linux_raw_sys::ptrace::SECCOMP_FILTER_FLAG_TSYNC;
+
+        /// `SECCOMP_FILTER_FLAG_LOG` (since Linux 4.14)
+        const LOG = linux_raw_sys::ptrace::SECCOMP_FILTER_FLAG_LOG;
+
+        /// `SECCOMP_FILTER_FLAG_SPEC_ALLOW` (since Linux 4.17)
+        const SPEC_ALLOW = linux_raw_sys::ptrace::SECCOMP_FILTER_FLAG_SPEC_ALLOW;
+
+        /// `SECCOMP_FILTER_FLAG_NEW_LISTENER` (since Linux 5.0)
+        const NEW_LISTENER = linux_raw_sys::ptrace::SECCOMP_FILTER_FLAG_NEW_LISTENER;
+
+        /// `SECCOMP_FILTER_FLAG_TSYNC_ESRCH` (since Linux 5.7)
+        const TSYNC_ESRCH = linux_raw_sys::ptrace::SECCOMP_FILTER_FLAG_TSYNC_ESRCH;
+
+        /// `SECCOMP_FILTER_FLAG_WAIT_KILLABLE_RECV` (since Linux 5.19)
+        const WAIT_KILLABLE_RECV = linux_raw_sys::ptrace::SECCOMP_FILTER_FLAG_WAIT_KILLABLE_RECV;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
+bitflags! {
+    /// `SECCOMP_USER_NOTIF_FLAG_*` constants for use with
+    /// [`SeccompNotifResp`].
+    ///
+    /// [`SeccompNotifResp`]: crate::seccomp::SeccompNotifResp
+    #[repr(transparent)]
+    #[derive(Default, Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct SeccompNotifRespFlags: u32 {
+        /// `SECCOMP_USER_NOTIF_FLAG_CONTINUE` (since Linux 5.5)
+        const CONTINUE = linux_raw_sys::ptrace::SECCOMP_USER_NOTIF_FLAG_CONTINUE;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
+bitflags! {
+    /// `SECCOMP_ADDFD_FLAG_*` constants for use with [`add_fd`].
+    ///
+    /// [`add_fd`]: crate::seccomp::add_fd
+    #[repr(transparent)]
+    #[derive(Default, Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct SeccompAddfdFlags: u32 {
+        /// `SECCOMP_ADDFD_FLAG_SEND` (since Linux 5.14)
+        ///
+        /// Atomically add the file descriptor and respond to the
+        /// notification, with the new file descriptor number as the
+        /// syscall's return value.
+        const SEND = linux_raw_sys::ptrace::SECCOMP_ADDFD_FLAG_SEND;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
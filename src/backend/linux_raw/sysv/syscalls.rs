@@ -0,0 +1,442 @@
+//! linux_raw syscalls supporting `rustix::sysv`.
+//!
+//! # Safety
+//!
+//! See the `rustix::backend` module documentation for details.
+#![allow(unsafe_code)]
+#![allow(clippy::undocumented_unsafe_blocks)]
+
+use super::types::{
+    ipc64_perm, msqid64_ds, semid64_ds, shmid64_ds, IpcFlags, MsgFlags, ShmatFlags, GETALL, IPC_64,
+    IPC_RMID, IPC_SET, IPC_STAT, SETALL, SETVAL,
+};
+use crate::backend::c;
+use crate::backend::conv::{
+    by_mut, by_ref, c_int, c_uint, pass_usize, ret, ret_c_int, ret_usize, ret_void_star, slice,
+    zero,
+};
+use crate::fs::Mode;
+use crate::io;
+use crate::pid::Pid;
+use crate::sysv::{IpcPerm, Key, MsgId, MsqidDs, SemBuf, SemId, SemidDs, ShmId, ShmidDs};
+use crate::timespec::Timespec;
+use crate::ugid::{Gid, Uid};
+use core::mem::zeroed;
+
+/// Combines the low and high halves of a time field.
+#[cfg(target_pointer_width = "64")]
+macro_rules! time {
+    ($low:expr, $high:expr) => {
+        $low as i64
+    };
+}
+
+/// Combines the low and high halves of a time field.
+#[cfg(target_pointer_width = "32")]
+macro_rules! time {
+    ($low:expr, $high:expr) => {
+        ((u64::from($high) << 32) | u64::from($low)) as i64
+    };
+}
+
+fn perm_from(raw: &ipc64_perm) -> IpcPerm {
+    IpcPerm {
+        key: Key::from_raw(raw.key),
+        uid: Uid::from_raw(raw.uid),
+        gid: Gid::from_raw(raw.gid),
+        cuid: Uid::from_raw(raw.cuid),
+        cgid: Gid::from_raw(raw.cgid),
+        mode: Mode::from_bits_retain(raw.mode.into()),
+        seq: raw.seq,
+    }
+}
+
+fn perm_into(perm: &IpcPerm, raw: &mut ipc64_perm) {
+    raw.uid = perm.uid.as_raw();
+    raw.gid = perm.gid.as_raw();
+    raw.mode = perm.mode.bits() as _;
+}
+
+#[inline]
+pub(crate) fn shmget(key: Key, size: usize, flags: IpcFlags, mode: Mode) -> io::Result<ShmId> {
+    unsafe {
+        ret_c_int(syscall_readonly!(
+            __NR_shmget,
+            c_int(key.as_raw()),
+            pass_usize(size),
+            c_uint(flags.bits() | mode.bits())
+        ))
+        .map(ShmId::from_raw)
+    }
+}
+
+#[inline]
+pub(crate) unsafe fn shmat(
+    shmid: ShmId,
+    addr: *mut c::c_void,
+    flags: ShmatFlags,
+) -> io::Result<*mut c::c_void> {
+    ret_void_star(syscall!(
+        __NR_shmat,
+        c_int(shmid.as_raw()),
+        addr,
+        c_uint(flags.bits())
+    ))
+}
+
+#[inline]
+pub(crate) unsafe fn shmdt(addr: *mut c::c_void) -> io::Result<()> {
+    ret(syscall!(__NR_shmdt, addr))
+}
+
+#[inline]
+pub(crate) fn shmctl_stat(shmid: ShmId) -> io::Result<ShmidDs> {
+    unsafe {
+        let mut raw: shmid64_ds = zeroed();
+        ret(syscall!(
+            __NR_shmctl,
+            c_int(shmid.as_raw()),
+            c_int(IPC_STAT | IPC_64),
+            by_mut(&mut raw)
+        ))?;
+        Ok(ShmidDs {
+            perm: perm_from(&raw.shm_perm),
+            segsz: raw.shm_segsz,
+            atime: time!(raw.shm_atime, raw.shm_atime_high),
+            dtime: time!(raw.shm_dtime, raw.shm_dtime_high),
+            ctime: time!(raw.shm_ctime, raw.shm_ctime_high),
+            cpid: Pid::from_raw(raw.shm_cpid),
+            lpid: Pid::from_raw(raw.shm_lpid),
+            nattch: raw.shm_nattch as usize,
+        })
+    }
+}
+
+#[inline]
+pub(crate) fn shmctl_set(shmid: ShmId, buf: &ShmidDs) -> io::Result<()> {
+    unsafe {
+        let mut raw: shmid64_ds = zeroed();
+        perm_into(&buf.perm, &mut raw.shm_perm);
+        ret(syscall_readonly!(
+            __NR_shmctl,
+            c_int(shmid.as_raw()),
+            c_int(IPC_SET | IPC_64),
+            by_ref(&raw)
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn shmctl_rmid(shmid: ShmId) -> io::Result<()> {
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_shmctl,
+            c_int(shmid.as_raw()),
+            c_int(IPC_RMID),
+            zero()
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn semget(key: Key, nsems: c::c_int, flags: IpcFlags, mode: Mode) -> io::Result<SemId> {
+    unsafe {
+        ret_c_int(syscall_readonly!(
+            __NR_semget,
+            c_int(key.as_raw()),
+            c_int(nsems),
+            c_uint(flags.bits() | mode.bits())
+        ))
+        .map(SemId::from_raw)
+    }
+}
+
+#[inline]
+pub(crate) fn semop(semid: SemId, ops: &[SemBuf]) -> io::Result<()> {
+    let (ops_addr, ops_len) = slice(ops);
+
+    // x86 doesn't have a `semop` syscall, so use `semtimedop` with no
+    // timeout.
+    #[cfg(target_arch = "x86")]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_semtimedop_time64,
+            c_int(semid.as_raw()),
+            ops_addr,
+            ops_len,
+            zero()
+        ))
+    }
+
+    #[cfg(not(target_arch = "x86"))]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_semop,
+            c_int(semid.as_raw()),
+            ops_addr,
+            ops_len
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn semtimedop(semid: SemId, ops: &[SemBuf], timeout: &Timespec) -> io::Result<()> {
+    let (ops_addr, ops_len) = slice(ops);
+
+    #[cfg(target_pointer_width = "32")]
+    unsafe {
+        // If we don't have Linux 5.1, and the timeout fits in a
+        // `__kernel_old_timespec`, use plain `semtimedop`. x86 doesn't have
+        // a plain `semtimedop`.
+        //
+        // We do this unconditionally, rather than trying
+        // `semtimedop_time64` and falling back on `Errno::NOSYS`, because
+        // seccomp configurations will sometimes abort the process on
+        // syscalls they don't recognize.
+        #[cfg(all(not(feature = "linux_5_1"), not(target_arch = "x86")))]
+        {
+            use linux_raw_sys::general::__kernel_old_timespec;
+
+            // If we can convert the timeout to a `__kernel_old_timespec`,
+            // use `__NR_semtimedop`.
+            fn convert(timeout: &Timespec) -> Option<__kernel_old_timespec> {
+                Some(__kernel_old_timespec {
+                    tv_sec: timeout.tv_sec.try_into().ok()?,
+                    tv_nsec: timeout.tv_nsec.try_into().ok()?,
+                })
+            }
+            if let Some(old_timeout) = convert(timeout) {
+                return ret(syscall_readonly!(
+                    __NR_semtimedop,
+                    c_int(semid.as_raw()),
+                    ops_addr,
+                    ops_len,
+                    by_ref(&old_timeout)
+                ));
+            }
+        }
+
+        // We either have Linux 5.1 or the timeout didn't fit in
+        // `__kernel_old_timespec` so `__NR_semtimedop_time64` will either
+        // succeed or fail due to our having no other options.
+        ret(syscall_readonly!(
+            __NR_semtimedop_time64,
+            c_int(semid.as_raw()),
+            ops_addr,
+            ops_len,
+            by_ref(timeout)
+        ))
+    }
+
+    #[cfg(target_pointer_width = "64")]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_semtimedop,
+            c_int(semid.as_raw()),
+            ops_addr,
+            ops_len,
+            by_ref(timeout)
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn semctl_stat(semid: SemId) -> io::Result<SemidDs> {
+    unsafe {
+        let mut raw: semid64_ds = zeroed();
+        ret(syscall!(
+            __NR_semctl,
+            c_int(semid.as_raw()),
+            c_int(0),
+            c_int(IPC_STAT | IPC_64),
+            by_mut(&mut raw)
+        ))?;
+        Ok(SemidDs {
+            perm: perm_from(&raw.sem_perm),
+            otime: time!(raw.sem_otime, raw.sem_otime_high),
+            ctime: time!(raw.sem_ctime, raw.sem_ctime_high),
+            nsems: raw.sem_nsems as usize,
+        })
+    }
+}
+
+#[inline]
+pub(crate) fn semctl_set(semid: SemId, buf: &SemidDs) -> io::Result<()> {
+    unsafe {
+        let mut raw: semid64_ds = zeroed();
+        perm_into(&buf.perm, &mut raw.sem_perm);
+        ret(syscall_readonly!(
+            __NR_semctl,
+            c_int(semid.as_raw()),
+            c_int(0),
+            c_int(IPC_SET | IPC_64),
+            by_ref(&raw)
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn semctl_rmid(semid: SemId) -> io::Result<()> {
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_semctl,
+            c_int(semid.as_raw()),
+            c_int(0),
+            c_int(IPC_RMID),
+            zero()
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn semctl_get(semid: SemId, semnum: u16, cmd: c::c_int) -> io::Result<c::c_int> {
+    unsafe {
+        ret_c_int(syscall_readonly!(
+            __NR_semctl,
+            c_int(semid.as_raw()),
+            c_int(semnum.into()),
+            c_int(cmd),
+            zero()
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn semctl_setval(semid: SemId, semnum: u16, val: c::c_int) -> io::Result<()> {
+    // The kernel takes `union semun` as an `unsigned long`, and reads `val`
+    // from the low 32 bits, except on 64-bit big-endian platforms, where it
+    // reads the high 32 bits.
+    #[cfg(not(all(target_pointer_width = "64", target_endian = "big")))]
+    let arg = val as u32 as usize;
+    #[cfg(all(target_pointer_width = "64", target_endian = "big"))]
+    let arg = (val as u32 as usize) << 32;
+
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_semctl,
+            c_int(semid.as_raw()),
+            c_int(semnum.into()),
+            c_int(SETVAL),
+            pass_usize(arg)
+        ))
+    }
+}
+
+#[inline]
+pub(crate) unsafe fn semctl_getall(semid: SemId, vals: *mut u16) -> io::Result<()> {
+    ret(syscall!(
+        __NR_semctl,
+        c_int(semid.as_raw()),
+        c_int(0),
+        c_int(GETALL),
+        vals
+    ))
+}
+
+#[inline]
+pub(crate) unsafe fn semctl_setall(semid: SemId, vals: *const u16) -> io::Result<()> {
+    ret(syscall_readonly!(
+        __NR_semctl,
+        c_int(semid.as_raw()),
+        c_int(0),
+        c_int(SETALL),
+        vals
+    ))
+}
+
+#[inline]
+pub(crate) fn msgget(key: Key, flags: IpcFlags, mode: Mode) -> io::Result<MsgId> {
+    unsafe {
+        ret_c_int(syscall_readonly!(
+            __NR_msgget,
+            c_int(key.as_raw()),
+            c_uint(flags.bits() | mode.bits())
+        ))
+        .map(MsgId::from_raw)
+    }
+}
+
+#[inline]
+pub(crate) unsafe fn msgsnd(
+    msqid: MsgId,
+    msgp: *const c::c_void,
+    msgsz: usize,
+    flags: MsgFlags,
+) -> io::Result<()> {
+    ret(syscall_readonly!(
+        __NR_msgsnd,
+        c_int(msqid.as_raw()),
+        msgp,
+        pass_usize(msgsz),
+        c_uint(flags.bits())
+    ))
+}
+
+#[inline]
+pub(crate) unsafe fn msgrcv(
+    msqid: MsgId,
+    msgp: *mut c::c_void,
+    msgsz: usize,
+    msgtyp: c::c_long,
+    flags: MsgFlags,
+) -> io::Result<usize> {
+    ret_usize(syscall!(
+        __NR_msgrcv,
+        c_int(msqid.as_raw()),
+        msgp,
+        pass_usize(msgsz),
+        pass_usize(msgtyp as usize),
+        c_uint(flags.bits())
+    ))
+}
+
+#[inline]
+pub(crate) fn msgctl_stat(msqid: MsgId) -> io::Result<MsqidDs> {
+    unsafe {
+        let mut raw: msqid64_ds = zeroed();
+        ret(syscall!(
+            __NR_msgctl,
+            c_int(msqid.as_raw()),
+            c_int(IPC_STAT | IPC_64),
+            by_mut(&mut raw)
+        ))?;
+        Ok(MsqidDs {
+            perm: perm_from(&raw.msg_perm),
+            stime: time!(raw.msg_stime, raw.msg_stime_high),
+            rtime: time!(raw.msg_rtime, raw.msg_rtime_high),
+            ctime: time!(raw.msg_ctime, raw.msg_ctime_high),
+            cbytes: raw.msg_cbytes as usize,
+            qnum: raw.msg_qnum as usize,
+            qbytes: raw.msg_qbytes as usize,
+            lspid: Pid::from_raw(raw.msg_lspid),
+            lrpid: Pid::from_raw(raw.msg_lrpid),
+        })
+    }
+}
+
+#[inline]
+pub(crate) fn msgctl_set(msqid: MsgId, buf: &MsqidDs) -> io::Result<()> {
+    unsafe {
+        let mut raw: msqid64_ds = zeroed();
+        perm_into(&buf.perm, &mut raw.msg_perm);
+        raw.msg_qbytes = buf.qbytes as _;
+        ret(syscall_readonly!(
+            __NR_msgctl,
+            c_int(msqid.as_raw()),
+            c_int(IPC_SET | IPC_64),
+            by_ref(&raw)
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn msgctl_rmid(msqid: MsgId) -> io::Result<()> {
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_msgctl,
+            c_int(msqid.as_raw()),
+            c_int(IPC_RMID),
+            zero()
+        ))
+    }
+}
@@ -14,8 +14,10 @@ use crate::backend::conv::{
 use crate::fd::BorrowedFd;
 use crate::io;
 use crate::pid::Pid;
+use crate::signal::Signal;
 use crate::thread::{
-    futex, ClockId, Cpuid, MembarrierCommand, MembarrierQuery, NanosleepRelativeResult, Timespec,
+    futex, ClockId, Cpuid, MembarrierCommand, MembarrierQuery, NanosleepRelativeResult,
+    RawSchedAttr, SchedPolicy, Timespec,
 };
 use crate::utils::as_mut_ptr;
 use core::mem::MaybeUninit;
@@ -200,6 +202,11 @@ pub(crate) fn gettid() -> Pid {
     }
 }
 
+#[inline]
+pub(crate) fn tgkill(pid: Pid, tid: Pid, sig: Signal) -> io::Result<()> {
+    unsafe { ret(syscall_readonly!(__NR_tgkill, pid, tid, sig)) }
+}
+
 /// # Safety
 ///
 /// The raw pointers must point to valid aligned memory.
@@ -517,6 +524,81 @@ pub(crate) fn sched_yield() {
     }
 }
 
+#[inline]
+pub(crate) fn sched_setattr(pid: Option<Pid>, attr: &RawSchedAttr) -> io::Result<()> {
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_sched_setattr,
+            c_int(Pid::as_raw(pid)),
+            by_ref(attr),
+            c_uint(0)
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn sched_getattr(pid: Option<Pid>, attr: &mut RawSchedAttr) -> io::Result<()> {
+    unsafe {
+        ret(syscall!(
+            __NR_sched_getattr,
+            c_int(Pid::as_raw(pid)),
+            by_mut(attr),
+            size_of::<RawSchedAttr, _>(),
+            c_uint(0)
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn sched_setscheduler(
+    pid: Option<Pid>,
+    policy: SchedPolicy,
+    priority: c::c_int,
+) -> io::Result<()> {
+    // `struct sched_param` contains just the priority.
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_sched_setscheduler,
+            c_int(Pid::as_raw(pid)),
+            c_uint(policy as u32),
+            by_ref(&priority)
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn sched_getparam(pid: Option<Pid>) -> io::Result<c::c_int> {
+    let mut priority = MaybeUninit::<c::c_int>::uninit();
+    unsafe {
+        ret(syscall!(
+            __NR_sched_getparam,
+            c_int(Pid::as_raw(pid)),
+            &mut priority
+        ))?;
+        Ok(priority.assume_init())
+    }
+}
+
+#[inline]
+pub(crate) fn sched_get_priority_min(policy: SchedPolicy) -> io::Result<c::c_int> {
+    unsafe {
+        ret_c_int(syscall_readonly!(
+            __NR_sched_get_priority_min,
+            c_uint(policy as u32)
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn sched_get_priority_max(policy: SchedPolicy) -> io::Result<c::c_int> {
+    unsafe {
+        ret_c_int(syscall_readonly!(
+            __NR_sched_get_priority_max,
+            c_uint(policy as u32)
+        ))
+    }
+}
+
 #[inline]
 pub(crate) fn membarrier_query() -> MembarrierQuery {
     unsafe {
@@ -0,0 +1,345 @@
+//! signalfd support for receiving signals as readable file descriptors.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! # fn test() -> rustix::io::Result<()> {
+//! use rustix::event::{signalfd_read, signalfd_with, SigSet, Signal, SignalfdFlags};
+//!
+//! let mut mask = SigSet::empty();
+//! mask.insert(Signal::USR1);
+//!
+//! // Block `SIGUSR1` here so that it's queued for the signalfd instead of
+//! // being delivered to a signal handler…
+//!
+//! // SAFETY: `mask` doesn't contain any signals reserved by the libc.
+//! let fd = unsafe { signalfd_with(&mask, SignalfdFlags::CLOEXEC)? };
+//!
+//! let info = signalfd_read(&fd)?;
+//! assert_eq!(info.signal(), Some(Signal::USR1));
+//! # Ok(())
+//! # }
+//! ```
+#![allow(unsafe_code)]
+
+use crate::fd::{AsFd, OwnedFd};
+use crate::io::{self, read, Errno};
+use crate::{backend, ffi};
+use core::fmt;
+use core::mem::{size_of, MaybeUninit};
+
+pub use crate::kernel_sigset::KernelSigSet as SigSet;
+pub use crate::signal::Signal;
+pub use backend::event::types::SignalfdFlags;
+
+/// `signalfd(-1, mask, 0)`—Creates a file descriptor for accepting signals.
+///
+/// See [`signalfd_with`] to pass additional flags.
+///
+/// # Safety
+///
+/// `mask` must not contain any signals reserved by the libc, since reading
+/// from the resulting file descriptor consumes them.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/signalfd.2.html
+#[inline]
+pub unsafe fn signalfd(mask: &SigSet) -> io::Result<OwnedFd> {
+    backend::event::syscalls::signalfd(mask, SignalfdFlags::empty())
+}
+
+/// `signalfd(-1, mask, flags)`—Creates a file descriptor for accepting
+/// signals, with flags.
+///
+/// The signals in `mask` should be blocked, with `sigprocmask` or
+/// `pthread_sigmask`, so that they are queued for the signalfd rather than
+/// being delivered according to their default dispositions.
+///
+/// # Safety
+///
+/// `mask` must not contain any signals reserved by the libc, since reading
+/// from the resulting file descriptor consumes them.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/signalfd.2.html
+#[doc(alias = "signalfd4")]
+#[inline]
+pub unsafe fn signalfd_with(mask: &SigSet, flags: SignalfdFlags) -> io::Result<OwnedFd> {
+    backend::event::syscalls::signalfd(mask, flags)
+}
+
+/// `signalfd(fd, mask, 0)`—Replaces the set of signals accepted by an
+/// existing signalfd.
+///
+/// # Safety
+///
+/// `mask` must not contain any signals reserved by the libc, since reading
+/// from the file descriptor consumes them.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/signalfd.2.html
+#[inline]
+pub unsafe fn signalfd_set_mask<Fd: AsFd>(fd: Fd, mask: &SigSet) -> io::Result<()> {
+    backend::event::syscalls::signalfd_set_mask(fd.as_fd(), mask)
+}
+
+/// `read(fd, &mut signalfd_siginfo, sizeof(signalfd_siginfo))`—Reads one
+/// pending signal from a signalfd.
+///
+/// If no signals are pending:
+///  - If the file descriptor was created with [`SignalfdFlags::NONBLOCK`],
+///    this will fail with [`Errno::AGAIN`].
+///  - Otherwise this will block until a signal in the mask is pending.
+#[inline]
+pub fn signalfd_read<Fd: AsFd>(fd: Fd) -> io::Result<SignalfdSiginfo> {
+    let mut info = MaybeUninit::<SignalfdSiginfo>::uninit();
+
+    // SAFETY: `SignalfdSiginfo` is a plain-old-data type, so it's valid to
+    // view it as a byte buffer.
+    let buf = unsafe {
+        core::slice::from_raw_parts_mut(
+            info.as_mut_ptr().cast::<MaybeUninit<u8>>(),
+            size_of::<SignalfdSiginfo>(),
+        )
+    };
+
+    let (init, _) = read(fd, buf)?;
+
+    // The kernel only ever returns whole `signalfd_siginfo` records.
+    if init.len() != size_of::<SignalfdSiginfo>() {
+        return Err(Errno::INVAL);
+    }
+
+    // SAFETY: The kernel filled in the whole struct.
+    Ok(unsafe { info.assume_init() })
+}
+
+/// `struct signalfd_siginfo`—Information about a signal read from a
+/// signalfd.
+#[doc(alias = "signalfd_siginfo")]
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct SignalfdSiginfo {
+    ssi_signo: u32,
+    ssi_errno: i32,
+    ssi_code: i32,
+    ssi_pid: u32,
+    ssi_uid: u32,
+    ssi_fd: i32,
+    ssi_tid: u32,
+    ssi_band: u32,
+    ssi_overrun: u32,
+    ssi_trapno: u32,
+    ssi_status: i32,
+    ssi_int: i32,
+    ssi_ptr: u64,
+    ssi_utime: u64,
+    ssi_stime: u64,
+    ssi_addr: u64,
+    ssi_addr_lsb: u16,
+    __pad2: u16,
+    ssi_syscall: i32,
+    ssi_call_addr: u64,
+    ssi_arch: u32,
+    __pad: [u8; 28],
+}
+
+impl SignalfdSiginfo {
+    /// Returns the raw signal number.
+    #[inline]
+    pub fn signo(&self) -> ffi::c_int {
+        self.ssi_signo as ffi::c_int
+    }
+
+    /// Returns the signal, if it's one of the named constant [`Signal`]
+    /// values.
+    ///
+    /// This returns `None` for real-time signals; use [`Self::signo`] to
+    /// obtain their raw numbers.
+    #[inline]
+    pub fn signal(&self) -> Option<Signal> {
+        Signal::from_named_raw(self.signo())
+    }
+
+    /// Returns the error number, which is typically unused.
+    #[inline]
+    pub fn errno(&self) -> i32 {
+        self.ssi_errno
+    }
+
+    /// Returns the signal code, such as `SI_USER` or `SI_QUEUE`.
+    #[inline]
+    pub fn code(&self) -> i32 {
+        self.ssi_code
+    }
+
+    /// Returns the process ID of the sender.
+    #[inline]
+    pub fn pid(&self) -> u32 {
+        self.ssi_pid
+    }
+
+    /// Returns the real user ID of the sender.
+    #[inline]
+    pub fn uid(&self) -> u32 {
+        self.ssi_uid
+    }
+
+    /// Returns the file descriptor, for `SIGIO`.
+    #[inline]
+    pub fn fd(&self) -> i32 {
+        self.ssi_fd
+    }
+
+    /// Returns the kernel timer ID, for POSIX timers.
+    #[inline]
+    pub fn tid(&self) -> u32 {
+        self.ssi_tid
+    }
+
+    /// Returns the band event, for `SIGIO`.
+    #[inline]
+    pub fn band(&self) -> u32 {
+        self.ssi_band
+    }
+
+    /// Returns the POSIX timer overrun count.
+    #[inline]
+    pub fn overrun(&self) -> u32 {
+        self.ssi_overrun
+    }
+
+    /// Returns the trap number that caused a hardware-generated signal.
+    #[inline]
+    pub fn trapno(&self) -> u32 {
+        self.ssi_trapno
+    }
+
+    /// Returns the exit status or signal, for `SIGCHLD`.
+    #[inline]
+    pub fn status(&self) -> i32 {
+        self.ssi_status
+    }
+
+    /// Returns the integer sent by `sigqueue`.
+    #[inline]
+    pub fn int(&self) -> i32 {
+        self.ssi_int
+    }
+
+    /// Returns the pointer sent by `sigqueue`.
+    #[inline]
+    pub fn ptr(&self) -> u64 {
+        self.ssi_ptr
+    }
+
+    /// Returns the user CPU time consumed, for `SIGCHLD`.
+    #[inline]
+    pub fn utime(&self) -> u64 {
+        self.ssi_utime
+    }
+
+    /// Returns the system CPU time consumed, for `SIGCHLD`.
+    #[inline]
+    pub fn stime(&self) -> u64 {
+        self.ssi_stime
+    }
+
+    /// Returns the address that generated the signal, for hardware-generated
+    /// signals.
+    #[inline]
+    pub fn addr(&self) -> u64 {
+        self.ssi_addr
+    }
+
+    /// Returns the least significant bit of the address, for `SIGBUS`.
+    #[inline]
+    pub fn addr_lsb(&self) -> u16 {
+        self.ssi_addr_lsb
+    }
+
+    /// Returns the system call number, for `SIGSYS` from seccomp.
+    #[inline]
+    pub fn syscall(&self) -> i32 {
+        self.ssi_syscall
+    }
+
+    /// Returns the address of the system call instruction, for `SIGSYS` from
+    /// seccomp.
+    #[inline]
+    pub fn call_addr(&self) -> u64 {
+        self.ssi_call_addr
+    }
+
+    /// Returns the `AUDIT_ARCH_*` value of the system call, for `SIGSYS`
+    /// from seccomp.
+    #[inline]
+    pub fn arch(&self) -> u32 {
+        self.ssi_arch
+    }
+}
+
+impl fmt::Debug for SignalfdSiginfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SignalfdSiginfo")
+            .field("signo", &self.ssi_signo)
+            .field("errno", &self.ssi_errno)
+            .field("code", &self.ssi_code)
+            .field("pid", &self.ssi_pid)
+            .field("uid", &self.ssi_uid)
+            .field("fd", &self.ssi_fd)
+            .field("tid", &self.ssi_tid)
+            .field("band", &self.ssi_band)
+            .field("overrun", &self.ssi_overrun)
+            .field("trapno", &self.ssi_trapno)
+            .field("status", &self.ssi_status)
+            .field("int", &self.ssi_int)
+            .field("ptr", &self.ssi_ptr)
+            .field("utime", &self.ssi_utime)
+            .field("stime", &self.ssi_stime)
+            .field("addr", &self.ssi_addr)
+            .field("addr_lsb", &self.ssi_addr_lsb)
+            .field("syscall", &self.ssi_syscall)
+            .field("call_addr", &self.ssi_call_addr)
+            .field("arch", &self.ssi_arch)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_layouts() {
+        use libc as c;
+
+        check_renamed_type!(SignalfdSiginfo, signalfd_siginfo);
+        check_renamed_struct_field!(SignalfdSiginfo, signalfd_siginfo, ssi_signo);
+        check_renamed_struct_field!(SignalfdSiginfo, signalfd_siginfo, ssi_errno);
+        check_renamed_struct_field!(SignalfdSiginfo, signalfd_siginfo, ssi_code);
+        check_renamed_struct_field!(SignalfdSiginfo, signalfd_siginfo, ssi_pid);
+        check_renamed_struct_field!(SignalfdSiginfo, signalfd_siginfo, ssi_uid);
+        check_renamed_struct_field!(SignalfdSiginfo, signalfd_siginfo, ssi_fd);
+        check_renamed_struct_field!(SignalfdSiginfo, signalfd_siginfo, ssi_tid);
+        check_renamed_struct_field!(SignalfdSiginfo, signalfd_siginfo, ssi_band);
+        check_renamed_struct_field!(SignalfdSiginfo, signalfd_siginfo, ssi_overrun);
+        check_renamed_struct_field!(SignalfdSiginfo, signalfd_siginfo, ssi_trapno);
+        check_renamed_struct_field!(SignalfdSiginfo, signalfd_siginfo, ssi_status);
+        check_renamed_struct_field!(SignalfdSiginfo, signalfd_siginfo, ssi_int);
+        check_renamed_struct_field!(SignalfdSiginfo, signalfd_siginfo, ssi_ptr);
+        check_renamed_struct_field!(SignalfdSiginfo, signalfd_siginfo, ssi_utime);
+        check_renamed_struct_field!(SignalfdSiginfo, signalfd_siginfo, ssi_stime);
+        check_renamed_struct_field!(SignalfdSiginfo, signalfd_siginfo, ssi_addr);
+        check_renamed_struct_field!(SignalfdSiginfo, signalfd_siginfo, ssi_addr_lsb);
+        check_renamed_struct_field!(SignalfdSiginfo, signalfd_siginfo, ssi_syscall);
+        check_renamed_struct_field!(SignalfdSiginfo, signalfd_siginfo, ssi_call_addr);
+        check_renamed_struct_field!(SignalfdSiginfo, signalfd_siginfo, ssi_arch);
+    }
+}
@@ -416,7 +416,8 @@ pub fn utimensat<P: path::Arg, Fd: AsFd>(
 ///
 /// Platform support for flags varies widely, for example on Linux
 /// [`AtFlags::SYMLINK_NOFOLLOW`] is not implemented and therefore
-/// [`io::Errno::OPNOTSUPP`] will be returned.
+/// [`io::Errno::OPNOTSUPP`] will be returned. On Linux 6.6 and later,
+/// [`chmodat_with`] supports flags.
 ///
 /// # References
 ///  - [POSIX]
@@ -436,6 +437,33 @@ pub fn chmodat<P: path::Arg, Fd: AsFd>(
     path.into_with_c_str(|path| backend::fs::syscalls::chmodat(dirfd.as_fd(), path, mode, flags))
 }
 
+/// `fchmodat2(dirfd, path, mode, flags)`—Sets file or directory
+/// permissions, with flags.
+///
+/// Unlike [`chmodat`], this supports [`AtFlags::SYMLINK_NOFOLLOW`] and
+/// [`AtFlags::EMPTY_PATH`]. Most filesystems don't support changing the
+/// permissions of a symlink, so with [`AtFlags::SYMLINK_NOFOLLOW`], this
+/// fails with [`io::Errno::OPNOTSUPP`] if `path` is a symlink.
+///
+/// This function returns [`io::Errno::NOSYS`] if `fchmodat2` is not
+/// available, such as on Linux before 6.6.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/fchmodat2.2.html
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "fchmodat2")]
+pub fn chmodat_with<P: path::Arg, Fd: AsFd>(
+    dirfd: Fd,
+    path: P,
+    mode: Mode,
+    flags: AtFlags,
+) -> io::Result<()> {
+    path.into_with_c_str(|path| backend::fs::syscalls::fchmodat2(dirfd.as_fd(), path, mode, flags))
+}
+
 /// `fclonefileat(src, dst_dir, dst, flags)`—Efficiently copies between files.
 ///
 /// # References
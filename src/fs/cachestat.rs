@@ -0,0 +1,51 @@
+use crate::{backend, io};
+use backend::fd::AsFd;
+use core::num::NonZeroU64;
+
+/// `struct cachestat`—Page cache statistics for a range of a file, returned
+/// by [`cachestat`].
+///
+/// All of the counts are in pages.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+#[doc(alias = "cachestat")]
+pub struct CacheStat {
+    /// `nr_cache`—The number of pages in the page cache.
+    #[doc(alias = "nr_cache")]
+    pub cached: u64,
+
+    /// `nr_dirty`—The number of dirty pages.
+    #[doc(alias = "nr_dirty")]
+    pub dirty: u64,
+
+    /// `nr_writeback`—The number of pages marked for writeback.
+    #[doc(alias = "nr_writeback")]
+    pub writeback: u64,
+
+    /// `nr_evicted`—The number of pages which have been evicted from the
+    /// page cache.
+    #[doc(alias = "nr_evicted")]
+    pub evicted: u64,
+
+    /// `nr_recently_evicted`—The number of evicted pages which would still be
+    /// in the page cache if they had been accessed less recently.
+    #[doc(alias = "nr_recently_evicted")]
+    pub recently_evicted: u64,
+}
+
+/// `cachestat(fd, &cachestat_range { off: offset, len }, cstat, 0)`—Queries
+/// the page cache status of a range of a file.
+///
+/// If `len` is `None`, the range extends to the end of the file.
+///
+/// This function returns [`io::Errno::NOSYS`] if `cachestat` is not
+/// available, such as on Linux before 6.5.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/cachestat.2.html
+#[inline]
+pub fn cachestat<Fd: AsFd>(fd: Fd, offset: u64, len: Option<NonZeroU64>) -> io::Result<CacheStat> {
+    backend::fs::syscalls::cachestat(fd.as_fd(), offset, len)
+}
@@ -0,0 +1,692 @@
+//! fanotify support for working with fanotify objects.
+//!
+//! # Examples
+//!
+//! ```
+//! use rustix::fs::{fanotify, OFlags, CWD};
+//! use rustix::io;
+//! use std::mem::MaybeUninit;
+//!
+//! # fn test() -> io::Result<()> {
+//! // Create a fanotify object. In this example, we use `NONBLOCK` so that the
+//! // reader fails with `WOULDBLOCK` when no events are ready. Otherwise it
+//! // will block until at least one event is ready.
+//! let fanotify = fanotify::init(
+//!     fanotify::InitFlags::CLOEXEC | fanotify::InitFlags::NONBLOCK,
+//!     OFlags::RDONLY | OFlags::CLOEXEC,
+//! )?;
+//!
+//! // Add a mark for a directory to watch.
+//! fanotify::mark(
+//!     &fanotify,
+//!     fanotify::MarkFlags::ADD,
+//!     fanotify::EventMask::OPEN | fanotify::EventMask::EVENT_ON_CHILD,
+//!     CWD,
+//!     "/path/to/some/directory/to/watch",
+//! )?;
+//!
+//! // Generate some events in the watched directory…
+//!
+//! // Loop over pending events.
+//! let mut buf = [MaybeUninit::uninit(); 4096];
+//! let mut iter = fanotify::Reader::new(&fanotify, &mut buf);
+//! loop {
+//!     let event = match iter.next() {
+//!         // Stop iterating if there are no more events for now.
+//!         Err(io::Errno::WOULDBLOCK) => break,
+//!         Err(e) => return Err(e),
+//!         Ok(event) => event,
+//!     };
+//!
+//!     // Use `event`…
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # References
+//!  - [Linux]
+//!
+//! [Linux]: https://man7.org/linux/man-pages/man7/fanotify.7.html
+
+#![allow(unsafe_code)]
+
+pub use crate::backend::fs::fanotify::{EventMask, InitFlags, MarkFlags, Response};
+use crate::backend::fs::syscalls;
+use crate::fd::{AsFd, AsRawFd as _, BorrowedFd, FromRawFd as _, OwnedFd};
+use crate::ffi::CStr;
+use crate::fs::{Fsid, OFlags};
+use crate::io::{self, read, write, Errno};
+use crate::pid::Pid;
+use core::mem::{size_of, MaybeUninit};
+use core::slice;
+
+/// `FAN_EVENT_INFO_TYPE_FID`
+const FAN_EVENT_INFO_TYPE_FID: u8 = 1;
+/// `FAN_EVENT_INFO_TYPE_DFID_NAME`
+const FAN_EVENT_INFO_TYPE_DFID_NAME: u8 = 2;
+/// `FAN_EVENT_INFO_TYPE_DFID`
+const FAN_EVENT_INFO_TYPE_DFID: u8 = 3;
+/// `FAN_EVENT_INFO_TYPE_PIDFD`
+const FAN_EVENT_INFO_TYPE_PIDFD: u8 = 4;
+/// `FAN_EVENT_INFO_TYPE_ERROR`
+const FAN_EVENT_INFO_TYPE_ERROR: u8 = 5;
+/// `FAN_EVENT_INFO_TYPE_OLD_DFID_NAME`
+const FAN_EVENT_INFO_TYPE_OLD_DFID_NAME: u8 = 10;
+/// `FAN_EVENT_INFO_TYPE_NEW_DFID_NAME`
+const FAN_EVENT_INFO_TYPE_NEW_DFID_NAME: u8 = 12;
+
+/// `struct fanotify_event_metadata`
+#[repr(C)]
+#[allow(non_camel_case_types)]
+struct fanotify_event_metadata {
+    event_len: u32,
+    vers: u8,
+    reserved: u8,
+    metadata_len: u16,
+    mask: u64,
+    fd: i32,
+    pid: i32,
+}
+
+/// `struct fanotify_event_info_header`
+#[repr(C)]
+#[allow(non_camel_case_types)]
+struct fanotify_event_info_header {
+    info_type: u8,
+    pad: u8,
+    len: u16,
+}
+
+/// `struct fanotify_response`
+#[repr(C)]
+#[allow(non_camel_case_types)]
+struct fanotify_response {
+    fd: i32,
+    response: u32,
+}
+
+/// `fanotify_init(flags, event_flags)`—Creates a new fanotify object.
+///
+/// `event_flags` are the `O_*` flags used to open the file descriptors
+/// included in events. Use the [`InitFlags::CLOEXEC`] flag to prevent the
+/// resulting file descriptor from being implicitly passed across `exec`
+/// boundaries.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/fanotify_init.2.html
+#[doc(alias = "fanotify_init")]
+#[inline]
+pub fn init(flags: InitFlags, event_flags: OFlags) -> io::Result<OwnedFd> {
+    syscalls::fanotify_init(flags, event_flags)
+}
+
+/// `fanotify_mark(fanotify, flags, mask, dirfd, path)`—Adds, removes, or
+/// modifies a mark on a filesystem object.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/fanotify_mark.2.html
+#[doc(alias = "fanotify_mark")]
+#[inline]
+pub fn mark<P: crate::path::Arg, Fd: AsFd, DirFd: AsFd>(
+    fanotify: Fd,
+    flags: MarkFlags,
+    mask: EventMask,
+    dirfd: DirFd,
+    path: P,
+) -> io::Result<()> {
+    path.into_with_c_str(|path| {
+        syscalls::fanotify_mark(fanotify.as_fd(), flags, mask, dirfd.as_fd(), Some(path))
+    })
+}
+
+/// `fanotify_mark(fanotify, flags, mask, fd, NULL)`—Adds, removes, or
+/// modifies a mark on the filesystem object referred to by `fd`.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/fanotify_mark.2.html
+#[doc(alias = "fanotify_mark")]
+#[inline]
+pub fn mark_fd<Fd: AsFd, MarkFd: AsFd>(
+    fanotify: Fd,
+    flags: MarkFlags,
+    mask: EventMask,
+    fd: MarkFd,
+) -> io::Result<()> {
+    syscalls::fanotify_mark(fanotify.as_fd(), flags, mask, fd.as_fd(), None)
+}
+
+/// `write(fanotify, &fanotify_response { fd, response })`—Responds to a
+/// permission event.
+///
+/// `fd` is the file descriptor of the permission event, from
+/// [`Event::fd`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man7/fanotify.7.html
+#[doc(alias = "fanotify_response")]
+pub fn write_response<Fd: AsFd>(
+    fanotify: Fd,
+    fd: BorrowedFd<'_>,
+    response: Response,
+) -> io::Result<()> {
+    let response = fanotify_response {
+        fd: fd.as_raw_fd(),
+        response: response.bits(),
+    };
+
+    // SAFETY: `fanotify_response` is a plain C struct with no padding.
+    let bytes = unsafe {
+        slice::from_raw_parts(
+            (&response as *const fanotify_response).cast::<u8>(),
+            size_of::<fanotify_response>(),
+        )
+    };
+
+    match write(fanotify, bytes)? {
+        n if n == bytes.len() => Ok(()),
+        _ => Err(Errno::IO),
+    }
+}
+
+/// A fanotify event iterator implemented with the read syscall.
+///
+/// Events which are read into the buffer but not returned by [`next`] have
+/// their file descriptors closed when the `Reader` is dropped.
+///
+/// See the [`RawDir`] API for more details and usage examples as this API is
+/// based on it.
+///
+/// [`next`]: Self::next
+/// [`RawDir`]: crate::fs::raw_dir::RawDir
+pub struct Reader<'buf, Fd: AsFd> {
+    fd: Fd,
+    buf: &'buf mut [MaybeUninit<u8>],
+    initialized: usize,
+    offset: usize,
+}
+
+impl<'buf, Fd: AsFd> Reader<'buf, Fd> {
+    /// Create a new iterator from the given file descriptor and buffer.
+    pub fn new(fd: Fd, buf: &'buf mut [MaybeUninit<u8>]) -> Self {
+        Self {
+            fd,
+            buf,
+            initialized: 0,
+            offset: 0,
+        }
+    }
+
+    /// Read the next fanotify event.
+    ///
+    /// This is similar to [`Iterator::next`] except that it doesn't return an
+    /// `Option`, because the stream doesn't have an ending. It always returns
+    /// events or errors.
+    ///
+    /// If there are no events in the buffer and none ready to be read:
+    ///  - If the file descriptor was opened with [`InitFlags::NONBLOCK`],
+    ///    this will fail with [`Errno::AGAIN`].
+    ///  - Otherwise this will block until at least one event is ready or an
+    ///    error occurs.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> io::Result<Event<'_>> {
+        if self.is_buffer_empty() {
+            match read(self.fd.as_fd(), &mut *self.buf).map(|(init, _)| init.len()) {
+                Ok(0) => return Err(Errno::INVAL),
+                Ok(bytes_read) => {
+                    self.initialized = bytes_read;
+                    self.offset = 0;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+
+        // SAFETY: The first `initialized` bytes of the buffer were
+        // initialized by the `read` above.
+        let bytes =
+            unsafe { slice::from_raw_parts(self.buf.as_ptr().cast::<u8>(), self.initialized) };
+
+        // SAFETY: The bytes at `offset` are the start of an event that hasn't
+        // been returned yet, so we're the owners of its file descriptors.
+        let (event, event_len) = unsafe { Event::parse(&bytes[self.offset..]) };
+        self.offset += event_len;
+
+        Ok(event)
+    }
+
+    /// Returns true if the internal buffer is empty and will be refilled when
+    /// calling [`next`]. This is useful to avoid further blocking reads.
+    ///
+    /// [`next`]: Self::next
+    pub fn is_buffer_empty(&self) -> bool {
+        self.offset >= self.initialized
+    }
+}
+
+impl<'buf, Fd: AsFd> Drop for Reader<'buf, Fd> {
+    fn drop(&mut self) {
+        // Close the file descriptors of any events we haven't returned.
+        while !self.is_buffer_empty() {
+            drop(self.next());
+        }
+    }
+}
+
+/// A fanotify event.
+#[doc(alias = "fanotify_event_metadata")]
+#[derive(Debug)]
+pub struct Event<'a> {
+    mask: EventMask,
+    fd: Option<OwnedFd>,
+    pid: i32,
+    pidfd: Option<OwnedFd>,
+    info: &'a [u8],
+}
+
+impl<'a> Event<'a> {
+    /// Parse the event at the start of `bytes`, and return it along with its
+    /// length.
+    ///
+    /// # Safety
+    ///
+    /// `bytes` must start with an event read from a fanotify file descriptor,
+    /// and the caller must own the file descriptors in it.
+    unsafe fn parse(bytes: &'a [u8]) -> (Self, usize) {
+        // Assumption: the kernel will not give us partial events.
+        let metadata = bytes[..size_of::<fanotify_event_metadata>()]
+            .as_ptr()
+            .cast::<fanotify_event_metadata>()
+            .read_unaligned();
+        let event_len = metadata.event_len as usize;
+        let info = &bytes[usize::from(metadata.metadata_len)..event_len];
+
+        let fd = if metadata.fd >= 0 {
+            Some(OwnedFd::from_raw_fd(metadata.fd))
+        } else {
+            None
+        };
+
+        let pidfd = InfoIter::new(info).find_map(|(info_type, data)| {
+            if info_type != FAN_EVENT_INFO_TYPE_PIDFD {
+                return None;
+            }
+            let pidfd = i32::from_ne_bytes(data.get(..4)?.try_into().unwrap());
+            if pidfd >= 0 {
+                Some(OwnedFd::from_raw_fd(pidfd))
+            } else {
+                None
+            }
+        });
+
+        let event = Self {
+            mask: EventMask::from_bits_retain(metadata.mask),
+            fd,
+            pid: metadata.pid,
+            pidfd,
+            info,
+        };
+        (event, event_len)
+    }
+
+    /// Returns a description of the events.
+    #[inline]
+    pub fn mask(&self) -> EventMask {
+        self.mask
+    }
+
+    /// Returns the file descriptor of the object the event occurred on, if
+    /// any.
+    ///
+    /// This is `None` for queue overflow events, and when the fanotify object
+    /// was created with a `REPORT_*FID` flag.
+    #[inline]
+    pub fn fd(&self) -> Option<BorrowedFd<'_>> {
+        self.fd.as_ref().map(AsFd::as_fd)
+    }
+
+    /// Takes ownership of the file descriptor of the object the event
+    /// occurred on, if any.
+    #[inline]
+    pub fn take_fd(&mut self) -> Option<OwnedFd> {
+        self.fd.take()
+    }
+
+    /// Returns the ID of the process that caused the event, or of the thread
+    /// if the fanotify object was created with [`InitFlags::REPORT_TID`].
+    ///
+    /// This is `None` if the process is in a different PID namespace.
+    #[inline]
+    pub fn pid(&self) -> Option<Pid> {
+        Pid::from_raw(self.pid)
+    }
+
+    /// Returns the pidfd of the process that caused the event, if the
+    /// fanotify object was created with [`InitFlags::REPORT_PIDFD`] and a
+    /// pidfd could be created.
+    #[inline]
+    pub fn pidfd(&self) -> Option<BorrowedFd<'_>> {
+        self.pidfd.as_ref().map(AsFd::as_fd)
+    }
+
+    /// Takes ownership of the pidfd of the process that caused the event, if
+    /// any.
+    #[inline]
+    pub fn take_pidfd(&mut self) -> Option<OwnedFd> {
+        self.pidfd.take()
+    }
+
+    /// Returns an iterator over the information records of the event.
+    #[inline]
+    pub fn info(&self) -> impl Iterator<Item = Info<'_>> + '_ {
+        InfoIter::new(self.info).map(move |(info_type, data)| Info::parse(info_type, data, self))
+    }
+}
+
+/// An iterator over the raw information records of an event.
+struct InfoIter<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> InfoIter<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+}
+
+impl<'a> Iterator for InfoIter<'a> {
+    type Item = (u8, &'a [u8]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let header = self.bytes.get(..size_of::<fanotify_event_info_header>())?;
+        // SAFETY: `header` has the size of a `fanotify_event_info_header`.
+        let header = unsafe {
+            header
+                .as_ptr()
+                .cast::<fanotify_event_info_header>()
+                .read_unaligned()
+        };
+        let len = usize::from(header.len);
+        if len < size_of::<fanotify_event_info_header>() || len > self.bytes.len() {
+            self.bytes = &[];
+            return None;
+        }
+
+        let data = &self.bytes[size_of::<fanotify_event_info_header>()..len];
+        self.bytes = &self.bytes[len..];
+        Some((header.info_type, data))
+    }
+}
+
+/// An information record of a fanotify [`Event`].
+#[doc(alias = "fanotify_event_info_header")]
+#[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
+pub enum Info<'a> {
+    /// `FAN_EVENT_INFO_TYPE_FID`—The file handle of the object the event
+    /// occurred on.
+    Fid(Fid<'a>),
+
+    /// `FAN_EVENT_INFO_TYPE_DFID_NAME`—The file handle of the directory
+    /// containing the object the event occurred on, and the name of the
+    /// object in it.
+    DfidName(Fid<'a>, &'a CStr),
+
+    /// `FAN_EVENT_INFO_TYPE_DFID`—The file handle of the directory
+    /// containing the object the event occurred on.
+    Dfid(Fid<'a>),
+
+    /// `FAN_EVENT_INFO_TYPE_OLD_DFID_NAME`—The file handle of the directory
+    /// and the name an object was renamed from.
+    OldDfidName(Fid<'a>, &'a CStr),
+
+    /// `FAN_EVENT_INFO_TYPE_NEW_DFID_NAME`—The file handle of the directory
+    /// and the name an object was renamed to.
+    NewDfidName(Fid<'a>, &'a CStr),
+
+    /// `FAN_EVENT_INFO_TYPE_PIDFD`—The pidfd of the process that caused the
+    /// event, if one could be created.
+    ///
+    /// This is the same file descriptor as [`Event::pidfd`].
+    Pidfd(Option<BorrowedFd<'a>>),
+
+    /// `FAN_EVENT_INFO_TYPE_ERROR`—A filesystem error, and the number of
+    /// errors that occurred since the last error event.
+    Error(Errno, u32),
+
+    /// An information record which isn't recognized, or which is malformed.
+    Unknown(u8, &'a [u8]),
+}
+
+impl<'a> Info<'a> {
+    fn parse(info_type: u8, data: &'a [u8], event: &'a Event<'_>) -> Self {
+        let parsed = match info_type {
+            FAN_EVENT_INFO_TYPE_FID => Fid::parse(data).map(|(fid, _)| Self::Fid(fid)),
+            FAN_EVENT_INFO_TYPE_DFID => Fid::parse(data).map(|(fid, _)| Self::Dfid(fid)),
+            FAN_EVENT_INFO_TYPE_DFID_NAME => {
+                Fid::parse_with_name(data).map(|(fid, name)| Self::DfidName(fid, name))
+            }
+            FAN_EVENT_INFO_TYPE_OLD_DFID_NAME => {
+                Fid::parse_with_name(data).map(|(fid, name)| Self::OldDfidName(fid, name))
+            }
+            FAN_EVENT_INFO_TYPE_NEW_DFID_NAME => {
+                Fid::parse_with_name(data).map(|(fid, name)| Self::NewDfidName(fid, name))
+            }
+            FAN_EVENT_INFO_TYPE_PIDFD => Some(Self::Pidfd(event.pidfd())),
+            FAN_EVENT_INFO_TYPE_ERROR => Self::parse_error(data),
+            _ => None,
+        };
+        parsed.unwrap_or(Self::Unknown(info_type, data))
+    }
+
+    /// Parse a `fanotify_event_info_error` after its header.
+    fn parse_error(data: &'a [u8]) -> Option<Self> {
+        let error = i32::from_ne_bytes(data.get(..4)?.try_into().unwrap());
+        let error_count = u32::from_ne_bytes(data.get(4..8)?.try_into().unwrap());
+        Some(Self::Error(Errno::from_raw_os_error(error), error_count))
+    }
+}
+
+/// A filesystem ID and file handle, from a fanotify [`Info`] record.
+#[doc(alias = "fanotify_event_info_fid")]
+#[derive(Debug, Clone, Copy)]
+pub struct Fid<'a> {
+    fsid: Fsid,
+    handle_type: i32,
+    handle: &'a [u8],
+}
+
+impl<'a> Fid<'a> {
+    /// Parse a `fanotify_event_info_fid` after its header, and return it along
+    /// with the remaining bytes.
+    fn parse(data: &'a [u8]) -> Option<(Self, &'a [u8])> {
+        let fsid = data.get(..size_of::<Fsid>())?;
+        // SAFETY: `fsid` has the size of a `Fsid`, which is plain data.
+        let fsid = unsafe { fsid.as_ptr().cast::<Fsid>().read_unaligned() };
+        let data = &data[size_of::<Fsid>()..];
+
+        // Parse the `struct file_handle`.
+        let handle_bytes = u32::from_ne_bytes(data.get(..4)?.try_into().unwrap());
+        let handle_type = i32::from_ne_bytes(data.get(4..8)?.try_into().unwrap());
+        let handle_end = usize::try_from(handle_bytes).ok()?.checked_add(8)?;
+        let handle = data.get(8..handle_end)?;
+
+        let fid = Self {
+            fsid,
+            handle_type,
+            handle,
+        };
+        Some((fid, &data[handle_end..]))
+    }
+
+    /// Parse a `fanotify_event_info_fid` followed by a NUL-terminated name.
+    fn parse_with_name(data: &'a [u8]) -> Option<(Self, &'a CStr)> {
+        let (fid, rest) = Self::parse(data)?;
+        let nul = rest.iter().position(|b| *b == b'\0')?;
+        let name = CStr::from_bytes_with_nul(&rest[..=nul]).ok()?;
+        Some((fid, name))
+    }
+
+    /// Returns the ID of the filesystem containing the object.
+    #[inline]
+    pub fn fsid(&self) -> Fsid {
+        self.fsid
+    }
+
+    /// Returns the type of the file handle.
+    #[inline]
+    pub fn handle_type(&self) -> i32 {
+        self.handle_type
+    }
+
+    /// Returns the bytes of the file handle, such as for use with
+    /// `open_by_handle_at`.
+    #[inline]
+    pub fn handle(&self) -> &'a [u8] {
+        self.handle
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[test]
+    fn fanotify_layouts() {
+        use libc as c;
+
+        #[cfg(not(any(target_env = "musl", target_env = "ohos")))]
+        {
+            check_renamed_type!(fanotify_event_metadata, fanotify_event_metadata);
+            check_renamed_struct_field!(
+                fanotify_event_metadata,
+                fanotify_event_metadata,
+                event_len
+            );
+            check_renamed_struct_field!(fanotify_event_metadata, fanotify_event_metadata, vers);
+            check_renamed_struct_field!(fanotify_event_metadata, fanotify_event_metadata, reserved);
+            check_renamed_struct_field!(
+                fanotify_event_metadata,
+                fanotify_event_metadata,
+                metadata_len
+            );
+            check_renamed_struct_field!(fanotify_event_metadata, fanotify_event_metadata, mask);
+            check_renamed_struct_field!(fanotify_event_metadata, fanotify_event_metadata, fd);
+            check_renamed_struct_field!(fanotify_event_metadata, fanotify_event_metadata, pid);
+        }
+
+        check_renamed_type!(fanotify_event_info_header, fanotify_event_info_header);
+        check_renamed_struct_field!(
+            fanotify_event_info_header,
+            fanotify_event_info_header,
+            info_type
+        );
+        check_renamed_struct_field!(fanotify_event_info_header, fanotify_event_info_header, pad);
+        check_renamed_struct_field!(fanotify_event_info_header, fanotify_event_info_header, len);
+
+        check_renamed_type!(fanotify_response, fanotify_response);
+        check_renamed_struct_field!(fanotify_response, fanotify_response, fd);
+        check_renamed_struct_field!(fanotify_response, fanotify_response, response);
+
+        assert_eq!(FAN_EVENT_INFO_TYPE_FID, c::FAN_EVENT_INFO_TYPE_FID);
+        assert_eq!(
+            FAN_EVENT_INFO_TYPE_DFID_NAME,
+            c::FAN_EVENT_INFO_TYPE_DFID_NAME
+        );
+        assert_eq!(FAN_EVENT_INFO_TYPE_DFID, c::FAN_EVENT_INFO_TYPE_DFID);
+        assert_eq!(FAN_EVENT_INFO_TYPE_PIDFD, c::FAN_EVENT_INFO_TYPE_PIDFD);
+        assert_eq!(FAN_EVENT_INFO_TYPE_ERROR, c::FAN_EVENT_INFO_TYPE_ERROR);
+        assert_eq!(
+            FAN_EVENT_INFO_TYPE_OLD_DFID_NAME,
+            c::FAN_EVENT_INFO_TYPE_OLD_DFID_NAME
+        );
+        assert_eq!(
+            FAN_EVENT_INFO_TYPE_NEW_DFID_NAME,
+            c::FAN_EVENT_INFO_TYPE_NEW_DFID_NAME
+        );
+    }
+
+    #[test]
+    fn fanotify_flags() {
+        use libc as c;
+
+        assert_eq!(InitFlags::CLOEXEC.bits(), c::FAN_CLOEXEC as _);
+        assert_eq!(InitFlags::NONBLOCK.bits(), c::FAN_NONBLOCK as _);
+        assert_eq!(InitFlags::CLASS_CONTENT.bits(), c::FAN_CLASS_CONTENT as _);
+        assert_eq!(
+            InitFlags::CLASS_PRE_CONTENT.bits(),
+            c::FAN_CLASS_PRE_CONTENT as _
+        );
+        assert_eq!(
+            InitFlags::UNLIMITED_QUEUE.bits(),
+            c::FAN_UNLIMITED_QUEUE as _
+        );
+        assert_eq!(
+            InitFlags::UNLIMITED_MARKS.bits(),
+            c::FAN_UNLIMITED_MARKS as _
+        );
+        assert_eq!(InitFlags::ENABLE_AUDIT.bits(), c::FAN_ENABLE_AUDIT as _);
+        assert_eq!(InitFlags::REPORT_PIDFD.bits(), c::FAN_REPORT_PIDFD as _);
+        assert_eq!(InitFlags::REPORT_TID.bits(), c::FAN_REPORT_TID as _);
+        assert_eq!(InitFlags::REPORT_FID.bits(), c::FAN_REPORT_FID as _);
+        assert_eq!(InitFlags::REPORT_DIR_FID.bits(), c::FAN_REPORT_DIR_FID as _);
+        assert_eq!(InitFlags::REPORT_NAME.bits(), c::FAN_REPORT_NAME as _);
+        assert_eq!(
+            InitFlags::REPORT_TARGET_FID.bits(),
+            c::FAN_REPORT_TARGET_FID as _
+        );
+
+        assert_eq!(MarkFlags::ADD.bits(), c::FAN_MARK_ADD as _);
+        assert_eq!(MarkFlags::REMOVE.bits(), c::FAN_MARK_REMOVE as _);
+        assert_eq!(MarkFlags::DONT_FOLLOW.bits(), c::FAN_MARK_DONT_FOLLOW as _);
+        assert_eq!(MarkFlags::ONLYDIR.bits(), c::FAN_MARK_ONLYDIR as _);
+        assert_eq!(
+            MarkFlags::IGNORED_MASK.bits(),
+            c::FAN_MARK_IGNORED_MASK as _
+        );
+        assert_eq!(
+            MarkFlags::IGNORED_SURV_MODIFY.bits(),
+            c::FAN_MARK_IGNORED_SURV_MODIFY as _
+        );
+        assert_eq!(MarkFlags::FLUSH.bits(), c::FAN_MARK_FLUSH as _);
+        assert_eq!(MarkFlags::EVICTABLE.bits(), c::FAN_MARK_EVICTABLE as _);
+        assert_eq!(MarkFlags::IGNORE.bits(), c::FAN_MARK_IGNORE as _);
+        assert_eq!(MarkFlags::MOUNT.bits(), c::FAN_MARK_MOUNT as _);
+        assert_eq!(MarkFlags::FILESYSTEM.bits(), c::FAN_MARK_FILESYSTEM as _);
+
+        assert_eq!(EventMask::ACCESS.bits(), c::FAN_ACCESS);
+        assert_eq!(EventMask::MODIFY.bits(), c::FAN_MODIFY);
+        assert_eq!(EventMask::ATTRIB.bits(), c::FAN_ATTRIB);
+        assert_eq!(EventMask::CLOSE_WRITE.bits(), c::FAN_CLOSE_WRITE);
+        assert_eq!(EventMask::CLOSE_NOWRITE.bits(), c::FAN_CLOSE_NOWRITE);
+        assert_eq!(EventMask::OPEN.bits(), c::FAN_OPEN);
+        assert_eq!(EventMask::MOVED_FROM.bits(), c::FAN_MOVED_FROM);
+        assert_eq!(EventMask::MOVED_TO.bits(), c::FAN_MOVED_TO);
+        assert_eq!(EventMask::CREATE.bits(), c::FAN_CREATE);
+        assert_eq!(EventMask::DELETE.bits(), c::FAN_DELETE);
+        assert_eq!(EventMask::DELETE_SELF.bits(), c::FAN_DELETE_SELF);
+        assert_eq!(EventMask::MOVE_SELF.bits(), c::FAN_MOVE_SELF);
+        assert_eq!(EventMask::OPEN_EXEC.bits(), c::FAN_OPEN_EXEC);
+        assert_eq!(EventMask::QUEUE_OVERFLOW.bits(), c::FAN_Q_OVERFLOW);
+        assert_eq!(EventMask::FS_ERROR.bits(), c::FAN_FS_ERROR);
+        assert_eq!(EventMask::OPEN_PERM.bits(), c::FAN_OPEN_PERM);
+        assert_eq!(EventMask::ACCESS_PERM.bits(), c::FAN_ACCESS_PERM);
+        assert_eq!(EventMask::OPEN_EXEC_PERM.bits(), c::FAN_OPEN_EXEC_PERM);
+        assert_eq!(EventMask::EVENT_ON_CHILD.bits(), c::FAN_EVENT_ON_CHILD);
+        assert_eq!(EventMask::RENAME.bits(), c::FAN_RENAME);
+        assert_eq!(EventMask::ONDIR.bits(), c::FAN_ONDIR);
+
+        assert_eq!(Response::ALLOW.bits(), c::FAN_ALLOW);
+        assert_eq!(Response::DENY.bits(), c::FAN_DENY);
+        assert_eq!(Response::AUDIT.bits(), c::FAN_AUDIT);
+    }
+}
@@ -2,6 +2,8 @@
 
 mod abs;
 mod at;
+#[cfg(linux_kernel)]
+mod cachestat;
 mod constants;
 #[cfg(linux_kernel)]
 mod copy_file_range;
@@ -19,6 +21,8 @@ mod dir;
     target_os = "vita",
 )))]
 mod fadvise;
+#[cfg(target_os = "linux")]
+pub mod fanotify;
 pub(crate) mod fcntl;
 #[cfg(apple)]
 mod fcntl_apple;
@@ -67,6 +71,8 @@ mod xattr;
 
 pub use abs::*;
 pub use at::*;
+#[cfg(linux_kernel)]
+pub use cachestat::{cachestat, CacheStat};
 pub use constants::*;
 #[cfg(linux_kernel)]
 pub use copy_file_range::copy_file_range;
@@ -0,0 +1,315 @@
+//! Provided-buffer rings, registered with `IORING_REGISTER_PBUF_RING`.
+
+use super::ring::Mapping;
+use super::{
+    buf_ring_tail_struct, io_uring_buf, io_uring_buf_reg, io_uring_cqe, io_uring_ptr,
+    io_uring_register_with, IoringCqeFlags, IoringRegisterFlags, IoringRegisterOp,
+    IORING_CQE_BUFFER_SHIFT,
+};
+use crate::fd::{AsFd, BorrowedFd, OwnedFd};
+use crate::io;
+use core::ffi::c_void;
+use core::mem::{size_of, ManuallyDrop};
+use core::ptr::addr_of_mut;
+use core::sync::atomic::{AtomicU16, Ordering};
+
+/// A ring of buffers provided to the kernel, for operations which select a
+/// buffer with [`IoringSqeFlags::BUFFER_SELECT`], such as
+/// [`Sqe::recv_multishot`].
+///
+/// `BufRing` allocates the ring and a pool of `entries` buffers of
+/// `buf_len` bytes each, registers the ring as buffer group `bgid`, and
+/// initially provides all of the buffers. When a completion consumes a
+/// buffer, [`BufRing::buffer_id`] decodes its ID from the completion's flags;
+/// once the data has been used, [`BufRing::recycle`] provides the buffer
+/// again.
+///
+/// The ring is unregistered when the `BufRing` is dropped. No operations
+/// selecting buffers from the group may be in flight at that point.
+///
+/// [`IoringSqeFlags::BUFFER_SELECT`]: super::IoringSqeFlags::BUFFER_SELECT
+/// [`Sqe::recv_multishot`]: super::Sqe::recv_multishot
+pub struct BufRing {
+    ring_fd: OwnedFd,
+    ring: ManuallyDrop<Mapping>,
+    bufs: ManuallyDrop<Mapping>,
+    bgid: u16,
+    entries: u16,
+    buf_len: u32,
+
+    /// Our copy of the tail, which we publish after adding entries.
+    local_tail: u16,
+}
+
+// SAFETY: `BufRing` exclusively owns its mappings, and the kernel only reads
+// the tail, which is accessed atomically.
+unsafe impl Send for BufRing {}
+
+impl BufRing {
+    /// Allocate and register a ring of `entries` buffers of `buf_len` bytes
+    /// each, as buffer group `bgid` of the io_uring instance `ring_fd`.
+    ///
+    /// `entries` must be a power of two, no larger than 32768.
+    ///
+    /// This was added in Linux 5.19.
+    ///
+    /// # References
+    ///  - [Linux]
+    ///
+    /// [Linux]: https://man7.org/linux/man-pages/man3/io_uring_register_buf_ring.3.html
+    #[doc(alias = "IORING_REGISTER_PBUF_RING")]
+    pub fn new<Fd: AsFd>(ring_fd: Fd, bgid: u16, entries: u16, buf_len: u32) -> io::Result<Self> {
+        Self::register(ring_fd.as_fd(), bgid, entries, buf_len, false)
+    }
+
+    /// Like [`BufRing::new`], but with the ring allocated by the kernel and
+    /// mapped with `IOU_PBUF_RING_MMAP`.
+    ///
+    /// This was added in Linux 6.4.
+    ///
+    /// # References
+    ///  - [Linux]
+    ///
+    /// [Linux]: https://man7.org/linux/man-pages/man3/io_uring_register_buf_ring.3.html
+    #[doc(alias = "IOU_PBUF_RING_MMAP")]
+    pub fn new_mapped<Fd: AsFd>(
+        ring_fd: Fd,
+        bgid: u16,
+        entries: u16,
+        buf_len: u32,
+    ) -> io::Result<Self> {
+        Self::register(ring_fd.as_fd(), bgid, entries, buf_len, true)
+    }
+
+    fn register(
+        ring_fd: BorrowedFd<'_>,
+        bgid: u16,
+        entries: u16,
+        buf_len: u32,
+        kernel_mapped: bool,
+    ) -> io::Result<Self> {
+        if !entries.is_power_of_two() || entries > 32768 || buf_len == 0 {
+            return Err(io::Errno::INVAL);
+        }
+
+        // Keep our own reference to the io_uring instance, so that we can
+        // unregister the ring when we're dropped.
+        let ring_fd = io::fcntl_dupfd_cloexec(ring_fd, 0)?;
+
+        let ring_len = usize::from(entries) * size_of::<io_uring_buf>();
+        let bufs_len = usize::from(entries)
+            .checked_mul(buf_len as usize)
+            .ok_or(io::Errno::NOMEM)?;
+        let bufs = Mapping::anonymous(bufs_len)?;
+
+        let mut reg = io_uring_buf_reg {
+            ring_entries: entries.into(),
+            bgid,
+            ..Default::default()
+        };
+        let ring = if kernel_mapped {
+            reg.flags = IOU_PBUF_RING_MMAP;
+            None
+        } else {
+            let ring = Mapping::anonymous(ring_len)?;
+            reg.ring_addr = io_uring_ptr::new(ring.ptr.as_ptr());
+            Some(ring)
+        };
+
+        // SAFETY: `reg` is a valid `io_uring_buf_reg`, and the ring it points
+        // to, if any, is owned by the `BufRing` until it's unregistered.
+        unsafe {
+            io_uring_register_with(
+                &ring_fd,
+                IoringRegisterOp::RegisterPbufRing,
+                IoringRegisterFlags::empty(),
+                (&reg as *const io_uring_buf_reg).cast::<c_void>(),
+                1,
+            )?;
+        }
+
+        let ring = match ring {
+            Some(ring) => ring,
+            None => {
+                let offset = u64::from(super::sys::IORING_OFF_PBUF_RING)
+                    | u64::from(bgid) << super::sys::IORING_OFF_PBUF_SHIFT;
+                match Mapping::ring(ring_fd.as_fd(), ring_len, offset) {
+                    Ok(ring) => ring,
+                    Err(err) => {
+                        unregister(ring_fd.as_fd(), bgid).ok();
+                        return Err(err);
+                    }
+                }
+            }
+        };
+
+        let mut this = Self {
+            ring_fd,
+            ring: ManuallyDrop::new(ring),
+            bufs: ManuallyDrop::new(bufs),
+            bgid,
+            entries,
+            buf_len,
+            local_tail: 0,
+        };
+        for bid in 0..entries {
+            this.add(bid);
+        }
+        this.publish();
+        Ok(this)
+    }
+
+    /// Return the buffer group ID.
+    #[inline]
+    pub fn bgid(&self) -> u16 {
+        self.bgid
+    }
+
+    /// Return the number of buffers.
+    #[inline]
+    pub fn entries(&self) -> u16 {
+        self.entries
+    }
+
+    /// Return the size of each buffer.
+    #[inline]
+    pub fn buf_len(&self) -> u32 {
+        self.buf_len
+    }
+
+    /// Return the ID of the buffer a completion consumed, if it has
+    /// [`IoringCqeFlags::BUFFER`].
+    #[inline]
+    pub fn buffer_id(cqe: &io_uring_cqe) -> Option<u16> {
+        if cqe.flags.contains(IoringCqeFlags::BUFFER) {
+            Some((cqe.flags.bits() >> IORING_CQE_BUFFER_SHIFT) as u16)
+        } else {
+            None
+        }
+    }
+
+    /// Return the first `len` bytes of the buffer with ID `bid`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bid` isn't less than [`BufRing::entries`], or if `len` is
+    /// greater than [`BufRing::buf_len`].
+    ///
+    /// # Safety
+    ///
+    /// The buffer must have been consumed by a completion, and not recycled
+    /// since, so that the kernel isn't writing to it.
+    #[inline]
+    pub unsafe fn buffer(&self, bid: u16, len: usize) -> &[u8] {
+        assert!(bid < self.entries);
+        assert!(len <= self.buf_len as usize);
+        core::slice::from_raw_parts(self.buf_ptr(bid).cast::<u8>(), len)
+    }
+
+    /// Provide the buffer with ID `bid` to the kernel again.
+    ///
+    /// The buffer is published immediately, with the `Release` ordering the
+    /// kernel expects for the ring's tail.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bid` isn't less than [`BufRing::entries`].
+    #[inline]
+    pub fn recycle(&mut self, bid: u16) {
+        assert!(bid < self.entries);
+        self.add(bid);
+        self.publish();
+    }
+
+    /// Write the entry for `bid` at our local tail, without publishing it.
+    fn add(&mut self, bid: u16) {
+        let index = self.local_tail & (self.entries - 1);
+        // SAFETY: `index` is within the ring, and we only write the fields
+        // of the entry, since the tail overlaps the first entry's `resv`.
+        unsafe {
+            let buf = self
+                .ring
+                .ptr
+                .as_ptr()
+                .cast::<io_uring_buf>()
+                .add(usize::from(index));
+            addr_of_mut!((*buf).addr).write(io_uring_ptr::new(self.buf_ptr(bid)));
+            addr_of_mut!((*buf).len).write(self.buf_len);
+            addr_of_mut!((*buf).bid).write(bid);
+        }
+        self.local_tail = self.local_tail.wrapping_add(1);
+    }
+
+    /// Publish the entries added since the last publish.
+    fn publish(&mut self) {
+        // Synchronize with the kernel's load of the tail, so that it sees the
+        // entries written by `add`.
+        self.tail().store(self.local_tail, Ordering::Release);
+    }
+
+    fn tail(&self) -> &AtomicU16 {
+        let ring = self.ring.ptr.as_ptr().cast::<buf_ring_tail_struct>();
+        // SAFETY: The tail is within the ring, overlapping the first entry's
+        // `resv` field, and the ring lives as long as `self`.
+        unsafe { &*addr_of_mut!((*ring).tail).cast::<AtomicU16>() }
+    }
+
+    fn buf_ptr(&self, bid: u16) -> *mut c_void {
+        // SAFETY: Callers check that `bid` is less than `self.entries`.
+        unsafe {
+            self.bufs
+                .ptr
+                .as_ptr()
+                .cast::<u8>()
+                .add(usize::from(bid) * self.buf_len as usize)
+                .cast()
+        }
+    }
+}
+
+impl Drop for BufRing {
+    fn drop(&mut self) {
+        // If unregistering fails, the kernel may still be using the memory,
+        // so leak it.
+        if unregister(self.ring_fd.as_fd(), self.bgid).is_ok() {
+            // SAFETY: The ring is no longer registered, and the mappings
+            // aren't used after this.
+            unsafe {
+                ManuallyDrop::drop(&mut self.ring);
+                ManuallyDrop::drop(&mut self.bufs);
+            }
+        }
+    }
+}
+
+impl core::fmt::Debug for BufRing {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("BufRing")
+            .field("bgid", &self.bgid)
+            .field("entries", &self.entries)
+            .field("buf_len", &self.buf_len)
+            .finish_non_exhaustive()
+    }
+}
+
+/// `IOU_PBUF_RING_MMAP`
+const IOU_PBUF_RING_MMAP: u16 =
+    super::sys::io_uring_register_pbuf_ring_flags::IOU_PBUF_RING_MMAP as u16;
+
+fn unregister(ring_fd: BorrowedFd<'_>, bgid: u16) -> io::Result<()> {
+    let reg = io_uring_buf_reg {
+        bgid,
+        ..Default::default()
+    };
+    // SAFETY: `reg` is a valid `io_uring_buf_reg`.
+    unsafe {
+        io_uring_register_with(
+            ring_fd,
+            IoringRegisterOp::UnregisterPbufRing,
+            IoringRegisterFlags::empty(),
+            (&reg as *const io_uring_buf_reg).cast::<c_void>(),
+            1,
+        )
+    }
+    .map(|_| ())
+}
@@ -0,0 +1,266 @@
+//! Registered file and buffer tables, with `IORING_REGISTER_FILES2` and
+//! `IORING_REGISTER_BUFFERS2`.
+
+use super::{
+    io_uring_ptr, io_uring_register, io_uring_rsrc_register, io_uring_rsrc_update2, sys,
+    FixedBuffer, FixedFile, IoringRegisterOp, IoringRsrcFlags, Sqe, IORING_FILE_INDEX_ALLOC,
+};
+use crate::fd::{AsFd, BorrowedFd, OwnedFd, RawFd};
+use crate::io::{self, IoSliceMut};
+use core::ffi::c_void;
+use core::marker::PhantomData;
+use core::mem::size_of;
+use core::ops::Range;
+use core::ptr::null;
+
+/// A ring's registered file table.
+///
+/// The table starts out empty, with every slot sparse. Files may be placed
+/// in it with [`FixedFiles::update`], by operations such as
+/// [`Sqe::openat_direct`] and [`Sqe::accept_direct`], or with
+/// [`FixedFiles::alloc`], which picks free slots from the range set by
+/// [`FixedFiles::set_alloc_range`]. A registered file can be turned back
+/// into a file descriptor with [`Sqe::fixed_fd_install`], and removed with
+/// [`FixedFiles::remove`] or [`Sqe::close_fixed`].
+///
+/// The table is unregistered when the `FixedFiles` is dropped.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://www.man7.org/linux/man-pages/man3/io_uring_register_files.3.html
+#[doc(alias = "IORING_REGISTER_FILES2")]
+#[derive(Debug)]
+pub struct FixedFiles {
+    ring_fd: OwnedFd,
+    len: u32,
+}
+
+impl FixedFiles {
+    /// Register a sparse file table with `len` slots with the io_uring
+    /// instance `ring_fd`.
+    ///
+    /// This was added in Linux 5.19.
+    #[doc(alias = "IORING_RSRC_REGISTER_SPARSE")]
+    pub fn new<Fd: AsFd>(ring_fd: Fd, len: u32) -> io::Result<Self> {
+        // Keep our own reference to the io_uring instance, so that we can
+        // unregister the table when we're dropped.
+        let ring_fd = io::fcntl_dupfd_cloexec(ring_fd, 0)?;
+
+        let reg = io_uring_rsrc_register {
+            nr: len,
+            flags: IoringRsrcFlags::REGISTER_SPARSE,
+            ..Default::default()
+        };
+        // SAFETY: `reg` is a valid `io_uring_rsrc_register`, with no data.
+        unsafe {
+            io_uring_register(
+                &ring_fd,
+                IoringRegisterOp::RegisterFiles2,
+                (&reg as *const io_uring_rsrc_register).cast::<c_void>(),
+                size_of::<io_uring_rsrc_register>() as u32,
+            )?;
+        }
+        Ok(Self { ring_fd, len })
+    }
+
+    /// Return the number of slots in the table.
+    #[inline]
+    pub fn len(&self) -> u32 {
+        self.len
+    }
+
+    /// Test whether the table has no slots.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Place `fds` in the slots starting at `offset`, returning the number
+    /// of slots updated.
+    ///
+    /// The table holds its own references to the files. An fd of
+    /// [`IORING_REGISTER_FILES_SKIP`] leaves its slot unchanged.
+    ///
+    /// [`IORING_REGISTER_FILES_SKIP`]: super::IORING_REGISTER_FILES_SKIP
+    #[doc(alias = "IORING_REGISTER_FILES_UPDATE2")]
+    pub fn update(&self, offset: FixedFile, fds: &[BorrowedFd<'_>]) -> io::Result<u32> {
+        // SAFETY: `BorrowedFd` is `repr(transparent)` over `RawFd`.
+        unsafe { self.update_raw(offset, fds.as_ptr().cast(), fds.len()) }
+    }
+
+    /// Remove the file in `file`, leaving the slot sparse.
+    pub fn remove(&self, file: FixedFile) -> io::Result<()> {
+        let fd: RawFd = -1;
+        // SAFETY: -1 is the value which clears a slot.
+        unsafe { self.update_raw(file, &fd, 1) }.map(|_| ())
+    }
+
+    /// Set the range of slots which [`FixedFiles::alloc`] and the `*_direct`
+    /// operations allocate from, when asked to pick a slot.
+    ///
+    /// If `range.start` is greater than `range.end`, this fails with
+    /// [`io::Errno::INVAL`].
+    ///
+    /// This was added in Linux 6.0.
+    #[doc(alias = "IORING_REGISTER_FILE_ALLOC_RANGE")]
+    pub fn set_alloc_range(&self, range: Range<u32>) -> io::Result<()> {
+        let len = range.end.checked_sub(range.start).ok_or(io::Errno::INVAL)?;
+        let arg = sys::io_uring_file_index_range {
+            off: range.start,
+            len,
+            resv: 0,
+        };
+        // SAFETY: `arg` is a valid `io_uring_file_index_range`.
+        unsafe {
+            io_uring_register(
+                &self.ring_fd,
+                IoringRegisterOp::RegisterFileAllocRange,
+                (&arg as *const sys::io_uring_file_index_range).cast::<c_void>(),
+                0,
+            )
+        }
+        .map(|_| ())
+    }
+
+    /// Return an `IORING_OP_FILES_UPDATE` entry which places `fds` in free
+    /// slots.
+    ///
+    /// When the entry completes, the kernel has overwritten each element of
+    /// `fds` it placed with the index of its slot, and the completion's `res`
+    /// is the number of files placed.
+    ///
+    /// This was added in Linux 5.19.
+    #[inline]
+    pub fn alloc<'a>(&self, fds: &'a mut [RawFd]) -> Sqe<'a> {
+        Sqe::files_update(fds, IORING_FILE_INDEX_ALLOC as u32)
+    }
+
+    unsafe fn update_raw(
+        &self,
+        offset: FixedFile,
+        fds: *const RawFd,
+        len: usize,
+    ) -> io::Result<u32> {
+        let update = io_uring_rsrc_update2 {
+            offset: offset.0,
+            data: io_uring_ptr::new(fds as *mut c_void),
+            nr: len.try_into().map_err(|_| io::Errno::INVAL)?,
+            ..Default::default()
+        };
+        io_uring_register(
+            &self.ring_fd,
+            IoringRegisterOp::RegisterFilesUpdate2,
+            (&update as *const io_uring_rsrc_update2).cast::<c_void>(),
+            size_of::<io_uring_rsrc_update2>() as u32,
+        )
+    }
+}
+
+impl Drop for FixedFiles {
+    fn drop(&mut self) {
+        // SAFETY: Unregistering the files takes no argument.
+        unsafe {
+            io_uring_register(&self.ring_fd, IoringRegisterOp::UnregisterFiles, null(), 0).ok();
+        }
+    }
+}
+
+/// A ring's registered buffer table, for use with [`Sqe::read_fixed`] and
+/// [`Sqe::write_fixed`].
+///
+/// The kernel pins the buffers' memory for as long as they're registered.
+/// The table is unregistered when the `FixedBuffers` is dropped.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://www.man7.org/linux/man-pages/man3/io_uring_register_buffers.3.html
+#[doc(alias = "IORING_REGISTER_BUFFERS2")]
+#[derive(Debug)]
+pub struct FixedBuffers<'buf> {
+    ring_fd: OwnedFd,
+    len: u16,
+    _marker: PhantomData<&'buf mut [u8]>,
+}
+
+impl<'buf> FixedBuffers<'buf> {
+    /// Register `bufs` as the buffer table of the io_uring instance
+    /// `ring_fd`.
+    ///
+    /// Buffer `i` of the table is `bufs[i]`, which is identified by the
+    /// `FixedBuffer` returned from [`FixedBuffers::get`]`(i)`.
+    ///
+    /// This was added in Linux 5.13.
+    pub fn new<Fd: AsFd>(ring_fd: Fd, bufs: &[IoSliceMut<'buf>]) -> io::Result<Self> {
+        // Buffer indices are `u16`s.
+        let len = u16::try_from(bufs.len()).map_err(|_| io::Errno::INVAL)?;
+
+        // Keep our own reference to the io_uring instance, so that we can
+        // unregister the table when we're dropped.
+        let ring_fd = io::fcntl_dupfd_cloexec(ring_fd, 0)?;
+
+        let reg = io_uring_rsrc_register {
+            nr: len.into(),
+            data: io_uring_ptr::new(bufs.as_ptr() as *mut c_void),
+            ..Default::default()
+        };
+        // SAFETY: `reg` points to `len` `iovec`s, as `IoSliceMut` is
+        // `repr(transparent)` over `iovec`.
+        unsafe {
+            io_uring_register(
+                &ring_fd,
+                IoringRegisterOp::RegisterBuffers2,
+                (&reg as *const io_uring_rsrc_register).cast::<c_void>(),
+                size_of::<io_uring_rsrc_register>() as u32,
+            )?;
+        }
+        Ok(Self {
+            ring_fd,
+            len,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Return the number of buffers in the table.
+    #[inline]
+    pub fn len(&self) -> u16 {
+        self.len
+    }
+
+    /// Test whether the table has no buffers.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Return the index of buffer `i`, if it's in the table.
+    #[inline]
+    pub fn get(&self, i: usize) -> Option<FixedBuffer> {
+        match u16::try_from(i) {
+            Ok(i) if i < self.len => Some(FixedBuffer(i)),
+            _ => None,
+        }
+    }
+
+    /// Return the indices of all the buffers in the table.
+    #[inline]
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = FixedBuffer> {
+        (0..self.len).map(FixedBuffer)
+    }
+}
+
+impl Drop for FixedBuffers<'_> {
+    fn drop(&mut self) {
+        // SAFETY: Unregistering the buffers takes no argument.
+        unsafe {
+            io_uring_register(
+                &self.ring_fd,
+                IoringRegisterOp::UnregisterBuffers,
+                null(),
+                0,
+            )
+            .ok();
+        }
+    }
+}
@@ -25,6 +25,14 @@
 #![allow(unsafe_code)]
 
 mod bindgen_types;
+#[cfg(feature = "mm")]
+mod buf_ring;
+mod fixed;
+mod probe;
+mod restrictions;
+#[cfg(feature = "mm")]
+mod ring;
+mod sqe;
 
 use crate::fd::{AsFd, BorrowedFd, OwnedFd, RawFd};
 use crate::utils::option_as_ptr;
@@ -56,6 +64,16 @@ pub use crate::thread::futex::{
     WaitvFlags as FutexWaitvFlags,
 };
 pub use crate::timespec::{Nsecs, Secs, Timespec};
+#[cfg(feature = "mm")]
+#[cfg_attr(docsrs, doc(cfg(feature = "mm")))]
+pub use buf_ring::BufRing;
+pub use fixed::{FixedBuffers, FixedFiles};
+pub use probe::{probe, Probe};
+pub use restrictions::Restrictions;
+#[cfg(feature = "mm")]
+#[cfg_attr(docsrs, doc(cfg(feature = "mm")))]
+pub use ring::{Completions, Ring};
+pub use sqe::{FixedBuffer, FixedFile, Sqe, SqeFd};
 
 mod sys {
     pub(super) use linux_raw_sys::io_uring::*;
@@ -2150,9 +2168,18 @@ mod tests {
         );
 
         check_renamed_type!(tail_or_bufs_struct, io_uring_buf_ring__bindgen_ty_1);
-        check_renamed_type!(
+        check_renamed_struct!(
             buf_ring_tail_struct,
-            io_uring_buf_ring__bindgen_ty_1__bindgen_ty_1
+            io_uring_buf_ring__bindgen_ty_1__bindgen_ty_1,
+            resv1,
+            resv2,
+            resv3,
+            tail
+        );
+        // `BufRing` relies on the tail overlapping the first entry's `resv`.
+        static_assertions::const_assert_eq!(
+            memoffset::offset_of!(buf_ring_tail_struct, tail),
+            memoffset::offset_of!(io_uring_buf, resv)
         );
         check_renamed_type!(
             buf_ring_bufs_struct,
@@ -0,0 +1,129 @@
+//! Opcode probing, with `IORING_REGISTER_PROBE`.
+
+use super::{io_uring_register, IoringOp, IoringOpFlags, IoringRegisterOp};
+use crate::fd::AsFd;
+use crate::io;
+use core::ffi::c_void;
+
+/// The number of opcodes the kernel can describe; opcodes are `u8`s.
+const MAX_OPS: usize = 256;
+
+/// `io_uring_register(ring_fd, IORING_REGISTER_PROBE, …)`—Query which
+/// operations an io_uring instance supports.
+///
+/// This was added in Linux 5.6.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://www.man7.org/linux/man-pages/man3/io_uring_register_probe.3.html
+#[doc(alias = "IORING_REGISTER_PROBE")]
+pub fn probe<Fd: AsFd>(ring_fd: Fd) -> io::Result<Probe> {
+    // The kernel may report opcodes newer than any `IoringOp`, so read the
+    // result as plain integers rather than as an `io_uring_probe`.
+    let mut raw = RawProbe {
+        last_op: 0,
+        ops_len: 0,
+        resv: 0,
+        resv2: [0; 3],
+        ops: [RawProbeOp::default(); MAX_OPS],
+    };
+
+    // SAFETY: `raw` has the layout of an `io_uring_probe` with `MAX_OPS`
+    // entries, and is zeroed as the kernel requires.
+    unsafe {
+        io_uring_register(
+            ring_fd,
+            IoringRegisterOp::RegisterProbe,
+            (&mut raw as *mut RawProbe).cast::<c_void>(),
+            MAX_OPS as u32,
+        )?;
+    }
+
+    let mut probe = Probe {
+        last_op: raw.last_op,
+        flags: [IoringOpFlags::empty(); MAX_OPS],
+    };
+    for op in &raw.ops[..usize::from(raw.ops_len)] {
+        probe.flags[usize::from(op.op)] = IoringOpFlags::from_bits_retain(op.flags);
+    }
+    Ok(probe)
+}
+
+/// The set of operations an io_uring instance supports, as returned by
+/// [`probe`].
+#[derive(Clone)]
+pub struct Probe {
+    last_op: u8,
+    flags: [IoringOpFlags; MAX_OPS],
+}
+
+impl Probe {
+    /// Test whether `op` is supported.
+    #[inline]
+    pub fn is_supported(&self, op: IoringOp) -> bool {
+        self.op_flags(op).contains(IoringOpFlags::SUPPORTED)
+    }
+
+    /// Return the flags the kernel reported for `op`.
+    ///
+    /// Operations the kernel doesn't know about have no flags.
+    #[inline]
+    pub fn op_flags(&self, op: IoringOp) -> IoringOpFlags {
+        self.flags[op as usize]
+    }
+
+    /// Return the highest opcode the kernel knows about.
+    ///
+    /// This may be greater than any [`IoringOp`] value.
+    #[inline]
+    pub fn last_op(&self) -> u8 {
+        self.last_op
+    }
+}
+
+impl core::fmt::Debug for Probe {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Probe")
+            .field("last_op", &self.last_op)
+            .finish_non_exhaustive()
+    }
+}
+
+/// `struct io_uring_probe`, with integer opcodes.
+#[repr(C)]
+struct RawProbe {
+    last_op: u8,
+    ops_len: u8,
+    resv: u16,
+    resv2: [u32; 3],
+    ops: [RawProbeOp; MAX_OPS],
+}
+
+/// `struct io_uring_probe_op`, with an integer opcode.
+#[repr(C)]
+#[derive(Copy, Clone, Default)]
+struct RawProbeOp {
+    op: u8,
+    resv: u8,
+    flags: u16,
+    resv2: u32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io_uring::{io_uring_probe, io_uring_probe_op};
+    use core::mem::{align_of, size_of};
+
+    #[test]
+    fn test_layouts() {
+        assert_eq!(size_of::<RawProbeOp>(), size_of::<io_uring_probe_op>());
+        assert_eq!(align_of::<RawProbeOp>(), align_of::<io_uring_probe_op>());
+        assert_eq!(
+            size_of::<RawProbe>(),
+            size_of::<io_uring_probe>() + MAX_OPS * size_of::<io_uring_probe_op>()
+        );
+        assert_eq!(align_of::<RawProbe>(), align_of::<io_uring_probe>());
+    }
+}
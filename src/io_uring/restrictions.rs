@@ -0,0 +1,173 @@
+//! Ring restrictions, with `IORING_REGISTER_RESTRICTIONS`.
+
+use super::{
+    io_uring_register, io_uring_restriction, register_or_sqe_op_or_sqe_flags_union, sys, IoringOp,
+    IoringRegisterOp, IoringRestrictionOp, IoringSqeFlags,
+};
+use crate::fd::AsFd;
+use crate::io;
+use core::ffi::c_void;
+use core::ptr::null;
+
+/// The number of `IoringOp` values.
+const NUM_OPS: usize = sys::io_uring_op::IORING_OP_LAST as usize;
+
+/// The number of `IoringRegisterOp` values.
+const NUM_REGISTER_OPS: usize = sys::io_uring_register_op::IORING_REGISTER_LAST as usize;
+
+/// A builder for the restrictions of an io_uring instance.
+///
+/// Restrictions limit which operations may be submitted to a ring, which
+/// [`IoringSqeFlags`] they may use, and which [`io_uring_register`]
+/// operations may be performed on it. Anything not explicitly allowed is
+/// rejected with [`io::Errno::ACCESS`].
+///
+/// Restrictions may only be applied to a ring created with
+/// [`IoringSetupFlags::R_DISABLED`], and only once. Resources such as fixed
+/// files and buffers may still be registered before the restrictions are
+/// applied.
+///
+/// # Examples
+///
+/// ```no_run
+/// # #[cfg(feature = "mm")]
+/// # fn example() -> rustix::io::Result<()> {
+/// use rustix::io_uring::{
+///     io_uring_params, IoringOp, IoringSetupFlags, Restrictions, Ring,
+/// };
+///
+/// let mut params = io_uring_params::default();
+/// params.flags = IoringSetupFlags::R_DISABLED;
+/// let ring = Ring::with_params(8, params)?;
+///
+/// Restrictions::new()
+///     .allow_op(IoringOp::Read)
+///     .allow_op(IoringOp::Write)
+///     .apply(&ring)?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://www.man7.org/linux/man-pages/man2/io_uring_register.2.html
+/// [`IoringSetupFlags::R_DISABLED`]: super::IoringSetupFlags::R_DISABLED
+#[doc(alias = "IORING_REGISTER_RESTRICTIONS")]
+#[derive(Debug, Clone)]
+pub struct Restrictions {
+    ops: [Option<IoringOp>; NUM_OPS],
+    register_ops: [Option<IoringRegisterOp>; NUM_REGISTER_OPS],
+    sqe_flags_allowed: IoringSqeFlags,
+    sqe_flags_required: IoringSqeFlags,
+}
+
+impl Restrictions {
+    /// Start a set of restrictions which allows nothing.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            ops: [None; NUM_OPS],
+            register_ops: [None; NUM_REGISTER_OPS],
+            sqe_flags_allowed: IoringSqeFlags::empty(),
+            sqe_flags_required: IoringSqeFlags::empty(),
+        }
+    }
+
+    /// Allow submitting `op`.
+    #[doc(alias = "IORING_RESTRICTION_SQE_OP")]
+    #[inline]
+    pub fn allow_op(mut self, op: IoringOp) -> Self {
+        self.ops[op as usize] = Some(op);
+        self
+    }
+
+    /// Allow performing the `io_uring_register` operation `op`.
+    #[doc(alias = "IORING_RESTRICTION_REGISTER_OP")]
+    #[inline]
+    pub fn allow_register_op(mut self, op: IoringRegisterOp) -> Self {
+        self.register_ops[op as usize] = Some(op);
+        self
+    }
+
+    /// Allow submitting entries with any of `flags`.
+    #[doc(alias = "IORING_RESTRICTION_SQE_FLAGS_ALLOWED")]
+    #[inline]
+    pub fn allow_sqe_flags(mut self, flags: IoringSqeFlags) -> Self {
+        self.sqe_flags_allowed |= flags;
+        self
+    }
+
+    /// Require submitted entries to have all of `flags`.
+    #[doc(alias = "IORING_RESTRICTION_SQE_FLAGS_REQUIRED")]
+    #[inline]
+    pub fn require_sqe_flags(mut self, flags: IoringSqeFlags) -> Self {
+        self.sqe_flags_required |= flags;
+        self
+    }
+
+    /// Register these restrictions with the disabled io_uring instance
+    /// `ring_fd`, and then enable it with `IORING_REGISTER_ENABLE_RINGS`.
+    ///
+    /// This was added in Linux 5.10.
+    #[doc(alias = "IORING_REGISTER_ENABLE_RINGS")]
+    pub fn apply<Fd: AsFd>(&self, ring_fd: Fd) -> io::Result<()> {
+        let ring_fd = ring_fd.as_fd();
+
+        let mut entries = [io_uring_restriction::default(); NUM_OPS + NUM_REGISTER_OPS + 2];
+        let mut len = 0;
+        let mut push = |opcode, arg| {
+            entries[len] = io_uring_restriction {
+                opcode,
+                register_or_sqe_op_or_sqe_flags: arg,
+                ..Default::default()
+            };
+            len += 1;
+        };
+
+        for &sqe_op in self.ops.iter().flatten() {
+            push(
+                IoringRestrictionOp::SqeOp,
+                register_or_sqe_op_or_sqe_flags_union { sqe_op },
+            );
+        }
+        for &register_op in self.register_ops.iter().flatten() {
+            push(
+                IoringRestrictionOp::RegisterOp,
+                register_or_sqe_op_or_sqe_flags_union { register_op },
+            );
+        }
+        push(
+            IoringRestrictionOp::SqeFlagsAllowed,
+            register_or_sqe_op_or_sqe_flags_union {
+                sqe_flags: self.sqe_flags_allowed,
+            },
+        );
+        push(
+            IoringRestrictionOp::SqeFlagsRequired,
+            register_or_sqe_op_or_sqe_flags_union {
+                sqe_flags: self.sqe_flags_required,
+            },
+        );
+
+        // SAFETY: `entries` holds `len` valid restrictions, and enabling the
+        // ring takes no argument.
+        unsafe {
+            io_uring_register(
+                ring_fd,
+                IoringRegisterOp::RegisterRestrictions,
+                entries.as_ptr().cast::<c_void>(),
+                len as u32,
+            )?;
+            io_uring_register(ring_fd, IoringRegisterOp::RegisterEnableRings, null(), 0)?;
+        }
+        Ok(())
+    }
+}
+
+impl Default for Restrictions {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,590 @@
+//! A safe owner for an io_uring instance and its mapped queues.
+
+use super::{
+    io_uring_cqe, io_uring_enter, io_uring_params, io_uring_ptr, io_uring_setup, io_uring_sqe,
+    IoringEnterFlags, IoringSetupFlags, IoringSqFlags, IORING_OFF_CQ_RING, IORING_OFF_SQES,
+    IORING_OFF_SQ_RING,
+};
+use crate::fd::{AsFd, BorrowedFd, OwnedFd};
+use crate::io;
+use crate::mm::{mmap, mmap_anonymous, munmap, MapFlags, ProtFlags};
+use core::ffi::c_void;
+use core::mem::size_of;
+use core::ptr::{null_mut, NonNull};
+use core::sync::atomic::{fence, AtomicU32, Ordering};
+
+/// An io_uring instance, with its submission and completion queues mapped
+/// into memory.
+///
+/// `Ring` takes care of the memory mappings and of publishing and consuming
+/// queue entries with the memory ordering the kernel expects. Entries are
+/// queued with [`Ring::push`], submitted with [`Ring::submit`] or
+/// [`Ring::submit_and_wait`], and their completions are consumed with
+/// [`Ring::completions`].
+///
+/// If the completion queue fills up, the kernel holds further completions
+/// in an overflow list, and sets [`IoringSqFlags::CQ_OVERFLOW`], which
+/// [`Ring::cq_has_overflow`] tests for. [`Ring::submit`] and
+/// [`Ring::submit_and_wait`] move them into the completion queue when there's
+/// room. Kernels without [`IoringFeatureFlags::NODROP`] drop them instead,
+/// and count them in [`Ring::cq_overflow`].
+///
+/// [`IoringFeatureFlags::NODROP`]: super::IoringFeatureFlags::NODROP
+///
+/// # Examples
+///
+/// ```no_run
+/// use rustix::io_uring::{Ring, Sqe};
+///
+/// # fn main() -> rustix::io::Result<()> {
+/// let mut ring = Ring::new(8)?;
+///
+/// // SAFETY: A `Nop` doesn't refer to any memory or file descriptors.
+/// unsafe { ring.push(Sqe::nop().user_data(42).as_raw())? };
+/// ring.submit_and_wait(1)?;
+///
+/// for cqe in ring.completions() {
+///     assert_eq!(cqe.user_data.u64_(), 42);
+///     assert_eq!(cqe.res, 0);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct Ring {
+    sq: SubmissionQueue,
+    cq: CompletionQueue,
+    params: io_uring_params,
+
+    // The mappings are unmapped before the file descriptor is closed.
+    _sq_ring: Mapping,
+    _cq_ring: Option<Mapping>,
+    _sqes: Mapping,
+    fd: OwnedFd,
+}
+
+struct SubmissionQueue {
+    head: NonNull<AtomicU32>,
+    tail: NonNull<AtomicU32>,
+    flags: NonNull<AtomicU32>,
+    mask: u32,
+    entries: u32,
+
+    /// The indirection array, or `None` with `IORING_SETUP_NO_SQARRAY`.
+    array: Option<NonNull<u32>>,
+    sqes: NonNull<io_uring_sqe>,
+
+    /// Our copy of the tail, including entries not yet published to the
+    /// kernel.
+    local_tail: u32,
+}
+
+struct CompletionQueue {
+    head: NonNull<AtomicU32>,
+    tail: NonNull<AtomicU32>,
+    overflow: NonNull<AtomicU32>,
+    mask: u32,
+    cqes: NonNull<io_uring_cqe>,
+}
+
+/// A memory mapping which is unmapped when dropped.
+pub(super) struct Mapping {
+    pub(super) ptr: NonNull<c_void>,
+    pub(super) len: usize,
+}
+
+impl Mapping {
+    /// Map `len` bytes of the ring at `offset`.
+    pub(super) fn ring(fd: BorrowedFd<'_>, len: usize, offset: u64) -> io::Result<Self> {
+        // SAFETY: We pass a null `ptr`, so this doesn't replace any existing
+        // mapping.
+        let ptr = unsafe {
+            mmap(
+                null_mut(),
+                len,
+                ProtFlags::READ | ProtFlags::WRITE,
+                MapFlags::SHARED | MapFlags::POPULATE,
+                fd,
+                offset,
+            )?
+        };
+        Ok(Self {
+            ptr: NonNull::new(ptr).ok_or(io::Errno::NOMEM)?,
+            len,
+        })
+    }
+
+    /// Allocate `len` bytes of memory for use with `IORING_SETUP_NO_MMAP`.
+    pub(super) fn anonymous(len: usize) -> io::Result<Self> {
+        // SAFETY: We pass a null `ptr`, so this doesn't replace any existing
+        // mapping.
+        let ptr = unsafe {
+            mmap_anonymous(
+                null_mut(),
+                len,
+                ProtFlags::READ | ProtFlags::WRITE,
+                MapFlags::PRIVATE | MapFlags::POPULATE,
+            )?
+        };
+        Ok(Self {
+            ptr: NonNull::new(ptr).ok_or(io::Errno::NOMEM)?,
+            len,
+        })
+    }
+
+    /// Return a pointer to the `T` at byte offset `offset`.
+    fn at<T>(&self, offset: u32) -> io::Result<NonNull<T>> {
+        if offset as usize + size_of::<T>() > self.len {
+            return Err(io::Errno::INVAL);
+        }
+        // SAFETY: We checked that `offset` is within the mapping.
+        unsafe {
+            Ok(NonNull::new_unchecked(
+                self.ptr.as_ptr().cast::<u8>().add(offset as usize).cast(),
+            ))
+        }
+    }
+}
+
+impl Drop for Mapping {
+    fn drop(&mut self) {
+        // SAFETY: We own the mapping, and its owner doesn't hand out any
+        // references into it which outlive the owner.
+        unsafe {
+            munmap(self.ptr.as_ptr(), self.len).ok();
+        }
+    }
+}
+
+// SAFETY: `Ring` exclusively owns its mappings, and all access to them goes
+// through `&mut self` or atomics.
+unsafe impl Send for Ring {}
+
+impl Ring {
+    /// Create a new io_uring instance with room for at least `entries`
+    /// submission queue entries, and default parameters.
+    ///
+    /// # References
+    ///  - [Linux]
+    ///
+    /// [Linux]: https://www.man7.org/linux/man-pages/man2/io_uring_setup.2.html
+    #[inline]
+    pub fn new(entries: u32) -> io::Result<Self> {
+        Self::with_params(entries, io_uring_params::default())
+    }
+
+    /// Create a new io_uring instance with room for at least `entries`
+    /// submission queue entries, using the flags and other settings in
+    /// `params`.
+    ///
+    /// `params` is typically used to set [`IoringSetupFlags::SQPOLL`] with
+    /// `sq_thread_idle`, or [`IoringSetupFlags::CQSIZE`] with `cq_entries`.
+    /// With [`IoringSetupFlags::NO_MMAP`] (since Linux 6.5), the queues are
+    /// allocated by `Ring` rather than mapped from the kernel; on kernels
+    /// before Linux 6.13, they must each fit in a single page.
+    ///
+    /// [`IoringSetupFlags::ATTACH_WQ`],
+    /// [`IoringSetupFlags::REGISTERED_FD_ONLY`],
+    /// [`IoringSetupFlags::SQE128`], and [`IoringSetupFlags::CQE32`] aren't
+    /// supported, and fail with [`io::Errno::INVAL`].
+    ///
+    /// # References
+    ///  - [Linux]
+    ///
+    /// [Linux]: https://www.man7.org/linux/man-pages/man2/io_uring_setup.2.html
+    pub fn with_params(entries: u32, mut params: io_uring_params) -> io::Result<Self> {
+        if params.flags.intersects(
+            IoringSetupFlags::ATTACH_WQ
+                | IoringSetupFlags::REGISTERED_FD_ONLY
+                | IoringSetupFlags::SQE128
+                | IoringSetupFlags::CQE32,
+        ) {
+            return Err(io::Errno::INVAL);
+        }
+
+        let no_mmap = params.flags.contains(IoringSetupFlags::NO_MMAP);
+        let user_memory = if no_mmap {
+            let (sqes_len, rings_len) = no_mmap_sizes(entries, &params)?;
+            let sqes = Mapping::anonymous(sqes_len)?;
+            let rings = Mapping::anonymous(rings_len)?;
+            params.sq_off.user_addr = io_uring_ptr::new(sqes.ptr.as_ptr());
+            params.cq_off.user_addr = io_uring_ptr::new(rings.ptr.as_ptr());
+            Some((sqes, rings))
+        } else {
+            None
+        };
+
+        // SAFETY: We rejected `IORING_SETUP_ATTACH_WQ`, so the kernel doesn't
+        // use `params.wq_fd`, and with `IORING_SETUP_NO_MMAP`, the memory at
+        // the `user_addr`s is owned by `user_memory`.
+        let fd = unsafe { io_uring_setup(entries, &mut params)? };
+
+        let cq_ring_len =
+            params.cq_off.cqes as usize + params.cq_entries as usize * size_of::<io_uring_cqe>();
+        // Without an indirection array, the SQ ring fields all precede the
+        // CQEs.
+        let sq_ring_len = if params.flags.contains(IoringSetupFlags::NO_SQARRAY) {
+            cq_ring_len
+        } else {
+            params.sq_off.array as usize + params.sq_entries as usize * 4
+        };
+        let sqes_len = params.sq_entries as usize * size_of::<io_uring_sqe>();
+
+        let (sq_ring, cq_ring, sqes_mapping) = match user_memory {
+            Some((sqes, rings)) => {
+                if sq_ring_len.max(cq_ring_len) > rings.len || sqes_len > sqes.len {
+                    return Err(io::Errno::INVAL);
+                }
+                (rings, None, sqes)
+            }
+            None => {
+                let single_mmap = params
+                    .features
+                    .contains(super::IoringFeatureFlags::SINGLE_MMAP);
+                let (sq_ring, cq_ring) = if single_mmap {
+                    let len = sq_ring_len.max(cq_ring_len);
+                    (Mapping::ring(fd.as_fd(), len, IORING_OFF_SQ_RING)?, None)
+                } else {
+                    (
+                        Mapping::ring(fd.as_fd(), sq_ring_len, IORING_OFF_SQ_RING)?,
+                        Some(Mapping::ring(fd.as_fd(), cq_ring_len, IORING_OFF_CQ_RING)?),
+                    )
+                };
+                let sqes = Mapping::ring(fd.as_fd(), sqes_len, IORING_OFF_SQES)?;
+                (sq_ring, cq_ring, sqes)
+            }
+        };
+
+        let sq_off = &params.sq_off;
+        // SAFETY: The kernel initializes the ring masks before returning from
+        // `io_uring_setup`.
+        let sq = unsafe {
+            SubmissionQueue {
+                head: sq_ring.at(sq_off.head)?,
+                tail: sq_ring.at(sq_off.tail)?,
+                flags: sq_ring.at(sq_off.flags)?,
+                mask: sq_ring.at::<u32>(sq_off.ring_mask)?.as_ptr().read(),
+                entries: params.sq_entries,
+                array: if params.flags.contains(IoringSetupFlags::NO_SQARRAY) {
+                    None
+                } else {
+                    Some(sq_ring.at(sq_off.array)?)
+                },
+                sqes: sqes_mapping.at(0)?,
+                local_tail: sq_ring
+                    .at::<AtomicU32>(sq_off.tail)?
+                    .as_ref()
+                    .load(Ordering::Relaxed),
+            }
+        };
+
+        let cq_off = &params.cq_off;
+        let cq_mapping = cq_ring.as_ref().unwrap_or(&sq_ring);
+        // SAFETY: See above.
+        let cq = unsafe {
+            CompletionQueue {
+                head: cq_mapping.at(cq_off.head)?,
+                tail: cq_mapping.at(cq_off.tail)?,
+                overflow: cq_mapping.at(cq_off.overflow)?,
+                mask: cq_mapping.at::<u32>(cq_off.ring_mask)?.as_ptr().read(),
+                cqes: cq_mapping.at(cq_off.cqes)?,
+            }
+        };
+
+        Ok(Self {
+            sq,
+            cq,
+            params,
+            _sq_ring: sq_ring,
+            _cq_ring: cq_ring,
+            _sqes: sqes_mapping,
+            fd,
+        })
+    }
+
+    /// Return the parameters the kernel filled in when the ring was set up,
+    /// including the actual queue sizes and the supported
+    /// [`IoringFeatureFlags`].
+    ///
+    /// [`IoringFeatureFlags`]: super::IoringFeatureFlags
+    #[inline]
+    pub fn params(&self) -> &io_uring_params {
+        &self.params
+    }
+
+    /// Return the number of entries which can be pushed before the
+    /// submission queue is full.
+    #[inline]
+    pub fn sq_space_left(&self) -> u32 {
+        // Synchronize with the kernel's consumption of entries, so that we
+        // don't overwrite them before it's done reading them.
+        let head = self.sq_head().load(Ordering::Acquire);
+        self.sq.entries - self.sq.local_tail.wrapping_sub(head)
+    }
+
+    /// Copy `sqe` into the submission queue.
+    ///
+    /// Entries are usually constructed with [`Sqe`], and passed in with
+    /// [`Sqe::as_raw`].
+    ///
+    /// [`Sqe`]: super::Sqe
+    /// [`Sqe::as_raw`]: super::Sqe::as_raw
+    ///
+    /// The entry isn't visible to the kernel until [`Ring::submit`] or
+    /// [`Ring::submit_and_wait`] is called. If the submission queue is full,
+    /// this fails with [`io::Errno::AGAIN`].
+    ///
+    /// # Safety
+    ///
+    /// Any memory or file descriptors that `sqe` refers to must remain valid,
+    /// and must not be accessed in ways that conflict with the operation,
+    /// until its completion is consumed.
+    pub unsafe fn push(&mut self, sqe: &io_uring_sqe) -> io::Result<()> {
+        if self.sq_space_left() == 0 {
+            return Err(io::Errno::AGAIN);
+        }
+
+        let index = self.sq.local_tail & self.sq.mask;
+        self.sq.sqes.as_ptr().add(index as usize).write(*sqe);
+        if let Some(array) = self.sq.array {
+            array.as_ptr().add(index as usize).write(index);
+        }
+        self.sq.local_tail = self.sq.local_tail.wrapping_add(1);
+        Ok(())
+    }
+
+    /// `io_uring_enter(fd, to_submit, 0, flags)`—Submit the pushed entries.
+    ///
+    /// With [`IoringSetupFlags::SQPOLL`], this only makes a syscall if the
+    /// kernel's polling thread needs to be woken up.
+    ///
+    /// Returns the number of entries submitted.
+    ///
+    /// # References
+    ///  - [Linux]
+    ///
+    /// [Linux]: https://www.man7.org/linux/man-pages/man2/io_uring_enter.2.html
+    #[inline]
+    pub fn submit(&mut self) -> io::Result<u32> {
+        self.submit_and_wait(0)
+    }
+
+    /// `io_uring_enter(fd, to_submit, want, IORING_ENTER_GETEVENTS)`—Submit
+    /// the pushed entries, and wait until at least `want` completions are
+    /// available.
+    ///
+    /// Returns the number of entries submitted.
+    ///
+    /// # References
+    ///  - [Linux]
+    ///
+    /// [Linux]: https://www.man7.org/linux/man-pages/man2/io_uring_enter.2.html
+    pub fn submit_and_wait(&mut self, want: u32) -> io::Result<u32> {
+        let to_submit = self.flush();
+
+        let mut flags = IoringEnterFlags::empty();
+        // Getting events flushes any overflowed completions into the
+        // completion queue.
+        let overflow = self.cq_has_overflow();
+        if self.params.flags.contains(IoringSetupFlags::SQPOLL) {
+            // Order the tail store above before the flags load below, so that
+            // either we see `IORING_SQ_NEED_WAKEUP`, or the polling thread
+            // sees the new tail.
+            fence(Ordering::SeqCst);
+            let sq_flags = IoringSqFlags::from_bits_retain(self.sq_flags().load(Ordering::Relaxed));
+            if sq_flags.contains(IoringSqFlags::NEED_WAKEUP) {
+                flags |= IoringEnterFlags::SQ_WAKEUP;
+            } else if want == 0 && !overflow {
+                return Ok(to_submit);
+            }
+        }
+        if want > 0 || overflow {
+            flags |= IoringEnterFlags::GETEVENTS;
+        }
+
+        // SAFETY: The entries being submitted were pushed with `push`, whose
+        // caller promised that they're valid, and `flags` doesn't contain any
+        // `EXT_ARG` flags.
+        let submitted = unsafe { io_uring_enter(&self.fd, to_submit, want, flags)? };
+
+        if self.params.flags.contains(IoringSetupFlags::SQPOLL) {
+            Ok(to_submit)
+        } else {
+            Ok(submitted)
+        }
+    }
+
+    /// Publish the pushed entries to the kernel, and return how many there
+    /// are which haven't been consumed yet.
+    fn flush(&mut self) -> u32 {
+        // Synchronize with the kernel's load of the tail, so that it sees the
+        // entries written by `push`.
+        self.sq_tail().store(self.sq.local_tail, Ordering::Release);
+        self.sq
+            .local_tail
+            .wrapping_sub(self.sq_head().load(Ordering::Acquire))
+    }
+
+    /// Return an iterator over the available completion queue entries.
+    ///
+    /// Each entry is removed from the completion queue as it's returned.
+    /// Completions which overflowed aren't included until they're moved into
+    /// the completion queue; see [`Ring::cq_has_overflow`].
+    #[inline]
+    pub fn completions(&mut self) -> Completions<'_> {
+        // Synchronize with the kernel's store of the tail, so that we see the
+        // entries it wrote.
+        let tail = self.cq_tail().load(Ordering::Acquire);
+        let head = self.cq_head().load(Ordering::Relaxed);
+        Completions {
+            ring: self,
+            head,
+            tail,
+        }
+    }
+
+    /// Test whether the kernel is holding completions which didn't fit in the
+    /// completion queue, which the next [`Ring::submit`] or
+    /// [`Ring::submit_and_wait`] will move into it once there's room.
+    #[inline]
+    pub fn cq_has_overflow(&self) -> bool {
+        let sq_flags = IoringSqFlags::from_bits_retain(self.sq_flags().load(Ordering::Relaxed));
+        sq_flags.contains(IoringSqFlags::CQ_OVERFLOW)
+    }
+
+    /// Return the number of completions the kernel has dropped because the
+    /// completion queue was full.
+    ///
+    /// Kernels with [`IoringFeatureFlags::NODROP`] (since Linux 5.5) only
+    /// drop completions if they can't allocate memory to hold them.
+    ///
+    /// [`IoringFeatureFlags::NODROP`]: super::IoringFeatureFlags::NODROP
+    #[inline]
+    pub fn cq_overflow(&self) -> u32 {
+        // SAFETY: The counter is within the CQ ring mapping, which lives as
+        // long as `self`.
+        unsafe { self.cq.overflow.as_ref() }.load(Ordering::Acquire)
+    }
+
+    #[inline]
+    fn sq_head(&self) -> &AtomicU32 {
+        // SAFETY: The head is within the SQ ring mapping, which lives as long
+        // as `self`.
+        unsafe { self.sq.head.as_ref() }
+    }
+
+    #[inline]
+    fn sq_tail(&self) -> &AtomicU32 {
+        // SAFETY: See `sq_head`.
+        unsafe { self.sq.tail.as_ref() }
+    }
+
+    #[inline]
+    fn sq_flags(&self) -> &AtomicU32 {
+        // SAFETY: See `sq_head`.
+        unsafe { self.sq.flags.as_ref() }
+    }
+
+    #[inline]
+    fn cq_head(&self) -> &AtomicU32 {
+        // SAFETY: The head is within the CQ ring mapping, which lives as long
+        // as `self`.
+        unsafe { self.cq.head.as_ref() }
+    }
+
+    #[inline]
+    fn cq_tail(&self) -> &AtomicU32 {
+        // SAFETY: See `cq_head`.
+        unsafe { self.cq.tail.as_ref() }
+    }
+}
+
+impl AsFd for Ring {
+    #[inline]
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        self.fd.as_fd()
+    }
+}
+
+impl core::fmt::Debug for Ring {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Ring")
+            .field("fd", &self.fd)
+            .field("params", &self.params)
+            .finish_non_exhaustive()
+    }
+}
+
+/// An iterator over completion queue entries, returned by
+/// [`Ring::completions`].
+pub struct Completions<'ring> {
+    ring: &'ring mut Ring,
+    head: u32,
+    tail: u32,
+}
+
+impl Iterator for Completions<'_> {
+    type Item = io_uring_cqe;
+
+    fn next(&mut self) -> Option<io_uring_cqe> {
+        if self.head == self.tail {
+            return None;
+        }
+
+        let index = self.head & self.ring.cq.mask;
+        // SAFETY: The kernel wrote this entry before publishing `tail`, and
+        // won't overwrite it until we publish a head past it.
+        let cqe = unsafe { &*self.ring.cq.cqes.as_ptr().add(index as usize) };
+        let cqe = io_uring_cqe {
+            user_data: cqe.user_data,
+            res: cqe.res,
+            flags: cqe.flags,
+            big_cqe: Default::default(),
+        };
+
+        // Synchronize with the kernel's load of the head, so that it doesn't
+        // overwrite the entry before we're done reading it.
+        self.head = self.head.wrapping_add(1);
+        self.ring.cq_head().store(self.head, Ordering::Release);
+        Some(cqe)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.tail.wrapping_sub(self.head) as usize;
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for Completions<'_> {}
+
+impl core::iter::FusedIterator for Completions<'_> {}
+
+/// Compute the sizes of the SQE array and of the rings for
+/// `IORING_SETUP_NO_MMAP`, following the kernel's `rings_size`.
+fn no_mmap_sizes(entries: u32, params: &io_uring_params) -> io::Result<(usize, usize)> {
+    const MAX_ENTRIES: u32 = 32768;
+    const MAX_CQ_ENTRIES: u32 = 2 * MAX_ENTRIES;
+    // An upper bound on the size of the kernel's `struct io_rings` header,
+    // and on its cache line alignment.
+    const RINGS_HEADER: usize = 256;
+
+    let clamp = params.flags.contains(IoringSetupFlags::CLAMP);
+    if entries == 0 || (entries > MAX_ENTRIES && !clamp) {
+        return Err(io::Errno::INVAL);
+    }
+    let sq_entries = entries.min(MAX_ENTRIES).next_power_of_two();
+    let cq_entries = if params.flags.contains(IoringSetupFlags::CQSIZE) {
+        if params.cq_entries == 0 || (params.cq_entries > MAX_CQ_ENTRIES && !clamp) {
+            return Err(io::Errno::INVAL);
+        }
+        params.cq_entries.min(MAX_CQ_ENTRIES).next_power_of_two()
+    } else {
+        2 * sq_entries
+    };
+
+    let sqes_len = sq_entries as usize * size_of::<io_uring_sqe>();
+    let cqes_end = RINGS_HEADER + cq_entries as usize * size_of::<io_uring_cqe>();
+    let array_start = (cqes_end + RINGS_HEADER - 1) & !(RINGS_HEADER - 1);
+    let rings_len = array_start + sq_entries as usize * 4;
+    Ok((sqes_len, rings_len))
+}
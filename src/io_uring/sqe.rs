@@ -0,0 +1,1257 @@
+//! Typed constructors for [`io_uring_sqe`].
+
+use super::{
+    addr3_struct, cmd_op_struct, io_uring_ptr, io_uring_sqe, io_uring_user_data, open_how, Advice,
+    AtFlags, EpollEvent, FutexWait, FutexWaitFlags, IoringAcceptFlags, IoringAsyncCancelFlags,
+    IoringFixedFdFlags, IoringFsyncFlags, IoringMsgringCmds, IoringMsgringFlags, IoringOp,
+    IoringPollFlags, IoringRecvFlags, IoringSendFlags, IoringSqeFlags, IoringTimeoutFlags, Mode,
+    MsgHdr, OFlags, RecvFlags, RenameFlags, SendFlags, SocketFlags, SpliceFlags, Statx, StatxFlags,
+    Timespec, XattrFlags, IORING_FILE_INDEX_ALLOC,
+};
+use crate::event::PollFlags;
+use crate::fd::{AsFd, AsRawFd, BorrowedFd, IntoRawFd, OwnedFd, RawFd};
+use crate::ffi::{c_void, CStr};
+use crate::fs::FallocateFlags;
+use crate::io::{IoSlice, IoSliceMut, ReadWriteFlags};
+use crate::net::{AddressFamily, Protocol, Shutdown, SocketAddrAny, SocketType};
+use core::fmt;
+use core::marker::PhantomData;
+use core::mem::MaybeUninit;
+use core::sync::atomic::AtomicU32;
+
+/// The index of a file in a ring's registered file table.
+///
+/// Operations which take a [`SqeFd`] use a `FixedFile` with
+/// `IOSQE_FIXED_FILE`, and the `*_direct` constructors install their result
+/// into a `FixedFile` slot instead of returning a file descriptor.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[repr(transparent)]
+pub struct FixedFile(pub u32);
+
+/// The index of a buffer in a ring's registered buffer table, for use with
+/// [`Sqe::read_fixed`] and [`Sqe::write_fixed`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[repr(transparent)]
+pub struct FixedBuffer(pub u16);
+
+/// A file operand of an [`Sqe`]: either a file descriptor, or an index in the
+/// ring's registered file table.
+#[derive(Debug, Copy, Clone)]
+pub enum SqeFd<'fd> {
+    /// A file descriptor.
+    Fd(BorrowedFd<'fd>),
+
+    /// A registered file, used with `IOSQE_FIXED_FILE`.
+    Fixed(FixedFile),
+}
+
+impl<'fd> From<BorrowedFd<'fd>> for SqeFd<'fd> {
+    #[inline]
+    fn from(fd: BorrowedFd<'fd>) -> Self {
+        Self::Fd(fd)
+    }
+}
+
+impl<'fd, T: AsFd> From<&'fd T> for SqeFd<'fd> {
+    #[inline]
+    fn from(fd: &'fd T) -> Self {
+        Self::Fd(fd.as_fd())
+    }
+}
+
+impl From<FixedFile> for SqeFd<'_> {
+    #[inline]
+    fn from(file: FixedFile) -> Self {
+        Self::Fixed(file)
+    }
+}
+
+impl SqeFd<'_> {
+    /// Return the value for a file descriptor field, and whether it's a
+    /// registered file index.
+    #[inline]
+    fn raw(self) -> (RawFd, bool) {
+        match self {
+            Self::Fd(fd) => (fd.as_raw_fd(), false),
+            Self::Fixed(FixedFile(index)) => (index as RawFd, true),
+        }
+    }
+}
+
+/// A submission queue entry with its operation-specific fields filled in.
+///
+/// Each constructor corresponds to an [`IoringOp`], and sets exactly the
+/// fields the kernel reads for that operation. Borrowed buffers, paths, and
+/// file descriptors are tracked by the `'a` lifetime, however that only covers
+/// the `Sqe` itself; the kernel may access them until the operation's
+/// completion is consumed, which is what the safety conditions of
+/// [`Ring::push`] and [`io_uring_enter`] are about.
+///
+/// Use [`Sqe::as_raw`] or [`Sqe::into_raw`] to get the [`io_uring_sqe`] to
+/// submit.
+///
+/// [`Ring::push`]: super::Ring::push
+/// [`io_uring_enter`]: super::io_uring_enter
+#[derive(Copy, Clone)]
+#[repr(transparent)]
+pub struct Sqe<'a> {
+    sqe: io_uring_sqe,
+    _marker: PhantomData<&'a ()>,
+}
+
+impl<'a> Sqe<'a> {
+    #[inline]
+    fn op(opcode: IoringOp) -> Self {
+        Self {
+            sqe: io_uring_sqe {
+                opcode,
+                ..Default::default()
+            },
+            _marker: PhantomData,
+        }
+    }
+
+    #[inline]
+    fn op_fd(opcode: IoringOp, fd: SqeFd<'a>) -> Self {
+        let mut this = Self::op(opcode);
+        let (fd, fixed) = fd.raw();
+        this.sqe.fd = fd;
+        if fixed {
+            this.sqe.flags |= IoringSqeFlags::FIXED_FILE;
+        }
+        this
+    }
+
+    #[inline]
+    fn op_dirfd(opcode: IoringOp, dirfd: BorrowedFd<'a>, path: &'a CStr) -> Self {
+        let mut this = Self::op(opcode);
+        this.sqe.fd = dirfd.as_raw_fd();
+        this.sqe.addr_or_splice_off_in.addr = ptr(path.as_ptr());
+        this
+    }
+
+    #[inline]
+    fn op_buf(opcode: IoringOp, fd: SqeFd<'a>, buf: *const u8, len: usize) -> Self {
+        let mut this = Self::op_fd(opcode, fd);
+        this.sqe.addr_or_splice_off_in.addr = ptr(buf);
+        this.sqe.len.len = clamp_len(len);
+        this
+    }
+
+    /// Set the user data, which is passed through to the operation's
+    /// completions.
+    #[inline]
+    pub fn user_data(mut self, user_data: u64) -> Self {
+        self.sqe.user_data = io_uring_user_data::from_u64(user_data);
+        self
+    }
+
+    /// Add `IOSQE_*` flags, such as [`IoringSqeFlags::IO_LINK`].
+    ///
+    /// Flags set by the constructor, such as [`IoringSqeFlags::FIXED_FILE`],
+    /// are preserved.
+    #[inline]
+    pub fn flags(mut self, flags: IoringSqeFlags) -> Self {
+        self.sqe.flags |= flags;
+        self
+    }
+
+    /// Set the credentials to perform the operation with, registered with
+    /// [`IoringRegisterOp::RegisterPersonality`].
+    ///
+    /// [`IoringRegisterOp::RegisterPersonality`]: super::IoringRegisterOp::RegisterPersonality
+    #[inline]
+    pub fn personality(mut self, personality: u16) -> Self {
+        self.sqe.personality = personality;
+        self
+    }
+
+    /// Return a reference to the raw submission queue entry.
+    #[inline]
+    pub fn as_raw(&self) -> &io_uring_sqe {
+        &self.sqe
+    }
+
+    /// Return the raw submission queue entry.
+    #[inline]
+    pub fn into_raw(self) -> io_uring_sqe {
+        self.sqe
+    }
+
+    /// `IORING_OP_NOP`—Do nothing.
+    #[inline]
+    pub fn nop() -> Self {
+        Self::op(IoringOp::Nop)
+    }
+
+    /// `IORING_OP_READ`—Read from a file into a buffer, like `pread`.
+    ///
+    /// An `offset` of `u64::MAX` reads from the file's current position.
+    #[inline]
+    pub fn read(fd: impl Into<SqeFd<'a>>, buf: &'a mut [u8], offset: u64) -> Self {
+        let mut this = Self::op_buf(IoringOp::Read, fd.into(), buf.as_mut_ptr(), buf.len());
+        this.sqe.off_or_addr2.off = offset;
+        this
+    }
+
+    /// `IORING_OP_WRITE`—Write to a file from a buffer, like `pwrite`.
+    ///
+    /// An `offset` of `u64::MAX` writes at the file's current position.
+    #[inline]
+    pub fn write(fd: impl Into<SqeFd<'a>>, buf: &'a [u8], offset: u64) -> Self {
+        let mut this = Self::op_buf(IoringOp::Write, fd.into(), buf.as_ptr(), buf.len());
+        this.sqe.off_or_addr2.off = offset;
+        this
+    }
+
+    /// `IORING_OP_READV`—Read from a file into multiple buffers, like
+    /// `preadv2`.
+    #[inline]
+    pub fn readv(
+        fd: impl Into<SqeFd<'a>>,
+        bufs: &'a mut [IoSliceMut<'a>],
+        offset: u64,
+        flags: ReadWriteFlags,
+    ) -> Self {
+        let mut this = Self::op_buf(
+            IoringOp::Readv,
+            fd.into(),
+            bufs.as_mut_ptr().cast(),
+            bufs.len(),
+        );
+        this.sqe.off_or_addr2.off = offset;
+        this.sqe.op_flags.rw_flags = flags;
+        this
+    }
+
+    /// `IORING_OP_WRITEV`—Write to a file from multiple buffers, like
+    /// `pwritev2`.
+    #[inline]
+    pub fn writev(
+        fd: impl Into<SqeFd<'a>>,
+        bufs: &'a [IoSlice<'a>],
+        offset: u64,
+        flags: ReadWriteFlags,
+    ) -> Self {
+        let mut this = Self::op_buf(
+            IoringOp::Writev,
+            fd.into(),
+            bufs.as_ptr().cast(),
+            bufs.len(),
+        );
+        this.sqe.off_or_addr2.off = offset;
+        this.sqe.op_flags.rw_flags = flags;
+        this
+    }
+
+    /// `IORING_OP_READ_FIXED`—Read from a file into part of a registered
+    /// buffer.
+    ///
+    /// `buf` must be within the registered buffer at `index`.
+    #[inline]
+    pub fn read_fixed(
+        fd: impl Into<SqeFd<'a>>,
+        buf: &'a mut [u8],
+        offset: u64,
+        index: FixedBuffer,
+    ) -> Self {
+        let mut this = Self::op_buf(IoringOp::ReadFixed, fd.into(), buf.as_mut_ptr(), buf.len());
+        this.sqe.off_or_addr2.off = offset;
+        this.sqe.buf.buf_index = index.0;
+        this
+    }
+
+    /// `IORING_OP_WRITE_FIXED`—Write to a file from part of a registered
+    /// buffer.
+    ///
+    /// `buf` must be within the registered buffer at `index`.
+    #[inline]
+    pub fn write_fixed(
+        fd: impl Into<SqeFd<'a>>,
+        buf: &'a [u8],
+        offset: u64,
+        index: FixedBuffer,
+    ) -> Self {
+        let mut this = Self::op_buf(IoringOp::WriteFixed, fd.into(), buf.as_ptr(), buf.len());
+        this.sqe.off_or_addr2.off = offset;
+        this.sqe.buf.buf_index = index.0;
+        this
+    }
+
+    /// `IORING_OP_READ_MULTISHOT`—Repeatedly read from a file into buffers
+    /// from the provided-buffer group `buf_group` (since Linux 6.7).
+    ///
+    /// A `len` of 0 reads up to the size of each provided buffer.
+    #[inline]
+    pub fn read_multishot(fd: impl Into<SqeFd<'a>>, len: u32, offset: u64, buf_group: u16) -> Self {
+        let mut this = Self::op_fd(IoringOp::ReadMultishot, fd.into());
+        this.sqe.flags |= IoringSqeFlags::BUFFER_SELECT;
+        this.sqe.len.len = len;
+        this.sqe.off_or_addr2.off = offset;
+        this.sqe.buf.buf_group = buf_group;
+        this
+    }
+
+    /// `IORING_OP_FSYNC`—Synchronize a file's state to storage.
+    #[inline]
+    pub fn fsync(fd: impl Into<SqeFd<'a>>, flags: IoringFsyncFlags) -> Self {
+        let mut this = Self::op_fd(IoringOp::Fsync, fd.into());
+        this.sqe.op_flags.fsync_flags = flags;
+        this
+    }
+
+    /// `IORING_OP_SYNC_FILE_RANGE`—Synchronize part of a file's state to
+    /// storage.
+    ///
+    /// `flags` are `SYNC_FILE_RANGE_*` values.
+    #[inline]
+    pub fn sync_file_range(fd: impl Into<SqeFd<'a>>, offset: u64, len: u32, flags: u32) -> Self {
+        let mut this = Self::op_fd(IoringOp::SyncFileRange, fd.into());
+        this.sqe.off_or_addr2.off = offset;
+        this.sqe.len.len = len;
+        this.sqe.op_flags.sync_range_flags = flags;
+        this
+    }
+
+    /// `IORING_OP_FALLOCATE`—Manipulate the space allocated for a file.
+    #[inline]
+    pub fn fallocate(
+        fd: impl Into<SqeFd<'a>>,
+        mode: FallocateFlags,
+        offset: u64,
+        len: u64,
+    ) -> Self {
+        let mut this = Self::op_fd(IoringOp::Fallocate, fd.into());
+        this.sqe.off_or_addr2.off = offset;
+        this.sqe.addr_or_splice_off_in.splice_off_in = len;
+        this.sqe.len.len = mode.bits();
+        this
+    }
+
+    /// `IORING_OP_FADVISE`—Declare an access pattern for part of a file.
+    #[inline]
+    pub fn fadvise(fd: impl Into<SqeFd<'a>>, offset: u64, len: u32, advice: Advice) -> Self {
+        let mut this = Self::op_fd(IoringOp::Fadvise, fd.into());
+        this.sqe.off_or_addr2.off = offset;
+        this.sqe.len.len = len;
+        this.sqe.op_flags.fadvise_advice = advice;
+        this
+    }
+
+    /// `IORING_OP_MADVISE`—Declare an access pattern for a range of memory.
+    #[cfg(feature = "mm")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "mm")))]
+    #[inline]
+    pub fn madvise(addr: *mut c_void, len: u32, advice: crate::mm::Advice) -> Self {
+        let mut this = Self::op(IoringOp::Madvise);
+        this.sqe.addr_or_splice_off_in.addr = io_uring_ptr::new(addr);
+        this.sqe.len.len = len;
+        this.sqe.op_flags.sync_range_flags = advice as u32;
+        this
+    }
+
+    /// `IORING_OP_FTRUNCATE`—Set the size of a file (since Linux 6.9).
+    #[inline]
+    pub fn ftruncate(fd: impl Into<SqeFd<'a>>, len: u64) -> Self {
+        let mut this = Self::op_fd(IoringOp::Ftruncate, fd.into());
+        this.sqe.off_or_addr2.off = len;
+        this
+    }
+
+    /// `IORING_OP_SPLICE`—Move data between two files, at least one of which
+    /// is a pipe.
+    ///
+    /// An offset of `None` uses the file's current position, and must be
+    /// used for pipes.
+    #[inline]
+    pub fn splice(
+        fd_in: impl Into<SqeFd<'a>>,
+        off_in: Option<u64>,
+        fd_out: impl Into<SqeFd<'a>>,
+        off_out: Option<u64>,
+        len: u32,
+        flags: SpliceFlags,
+    ) -> Self {
+        let mut this =
+            Self::splice_common(IoringOp::Splice, fd_in.into(), fd_out.into(), len, flags);
+        this.sqe.addr_or_splice_off_in.splice_off_in = off_in.unwrap_or(u64::MAX);
+        this.sqe.off_or_addr2.off = off_out.unwrap_or(u64::MAX);
+        this
+    }
+
+    /// `IORING_OP_TEE`—Copy data between two pipes without consuming it.
+    #[inline]
+    pub fn tee(
+        fd_in: impl Into<SqeFd<'a>>,
+        fd_out: impl Into<SqeFd<'a>>,
+        len: u32,
+        flags: SpliceFlags,
+    ) -> Self {
+        Self::splice_common(IoringOp::Tee, fd_in.into(), fd_out.into(), len, flags)
+    }
+
+    #[inline]
+    fn splice_common(
+        opcode: IoringOp,
+        fd_in: SqeFd<'a>,
+        fd_out: SqeFd<'a>,
+        len: u32,
+        mut flags: SpliceFlags,
+    ) -> Self {
+        let mut this = Self::op_fd(opcode, fd_out);
+        let (fd_in, fixed) = fd_in.raw();
+        if fixed {
+            flags |= SpliceFlags::FD_IN_FIXED;
+        }
+        this.sqe.splice_fd_in_or_file_index_or_addr_len.splice_fd_in = fd_in;
+        this.sqe.len.len = len;
+        this.sqe.op_flags.splice_flags = flags;
+        this
+    }
+
+    /// `IORING_OP_OPENAT`—Open a file, like `openat`.
+    ///
+    /// [`CWD`] may be used as `dirfd`.
+    ///
+    /// [`CWD`]: crate::fs::CWD
+    #[inline]
+    pub fn openat(dirfd: BorrowedFd<'a>, path: &'a CStr, flags: OFlags, mode: Mode) -> Self {
+        let mut this = Self::op_dirfd(IoringOp::Openat, dirfd, path);
+        this.sqe.len.len = mode.bits();
+        this.sqe.op_flags.open_flags = flags;
+        this
+    }
+
+    /// `IORING_OP_OPENAT`—Open a file, and install it in the registered file
+    /// table.
+    ///
+    /// If `slot` is `None`, a free slot is allocated, and its index is
+    /// returned in the completion. `flags` may not contain
+    /// [`OFlags::CLOEXEC`].
+    #[inline]
+    pub fn openat_direct(
+        dirfd: BorrowedFd<'a>,
+        path: &'a CStr,
+        flags: OFlags,
+        mode: Mode,
+        slot: Option<FixedFile>,
+    ) -> Self {
+        Self::openat(dirfd, path, flags, mode).file_slot(slot)
+    }
+
+    /// `IORING_OP_OPENAT2`—Open a file, like `openat2`.
+    #[inline]
+    pub fn openat2(dirfd: BorrowedFd<'a>, path: &'a CStr, how: &'a open_how) -> Self {
+        let mut this = Self::op_dirfd(IoringOp::Openat2, dirfd, path);
+        this.sqe.off_or_addr2.addr2 = ptr(how);
+        this.sqe.len.len = core::mem::size_of::<open_how>() as u32;
+        this
+    }
+
+    /// `IORING_OP_OPENAT2`—Open a file, and install it in the registered file
+    /// table.
+    ///
+    /// See [`Sqe::openat_direct`] for the meaning of `slot`.
+    #[inline]
+    pub fn openat2_direct(
+        dirfd: BorrowedFd<'a>,
+        path: &'a CStr,
+        how: &'a open_how,
+        slot: Option<FixedFile>,
+    ) -> Self {
+        Self::openat2(dirfd, path, how).file_slot(slot)
+    }
+
+    /// `IORING_OP_CLOSE`—Close a file descriptor.
+    ///
+    /// `fd` is consumed, so if this entry is never submitted, the file
+    /// descriptor is leaked.
+    #[inline]
+    pub fn close(fd: OwnedFd) -> Self {
+        let mut this = Self::op(IoringOp::Close);
+        this.sqe.fd = fd.into_raw_fd();
+        this
+    }
+
+    /// `IORING_OP_CLOSE`—Remove a file from the registered file table.
+    #[inline]
+    pub fn close_fixed(file: FixedFile) -> Self {
+        Self::op(IoringOp::Close).file_slot(Some(file))
+    }
+
+    /// `IORING_OP_FIXED_FD_INSTALL`—Install a registered file as a regular
+    /// file descriptor (since Linux 6.8).
+    ///
+    /// The completion's `res` is the new file descriptor. It's
+    /// close-on-exec unless `flags` contains
+    /// [`IoringFixedFdFlags::NO_CLOEXEC`].
+    #[inline]
+    pub fn fixed_fd_install(file: FixedFile, flags: IoringFixedFdFlags) -> Self {
+        let mut this = Self::op_fd(IoringOp::FixedFdInstall, SqeFd::Fixed(file));
+        this.sqe.op_flags.install_fd_flags = flags;
+        this
+    }
+
+    /// `IORING_OP_FILES_UPDATE`—Update entries in the registered file table,
+    /// starting at `offset`.
+    ///
+    /// A file descriptor of -1 removes the entry, and
+    /// [`IORING_REGISTER_FILES_SKIP`] leaves it unchanged. If `offset` is
+    /// [`IORING_FILE_INDEX_ALLOC`], free slots are allocated, and the kernel
+    /// writes their indices back into `fds`.
+    ///
+    /// [`IORING_REGISTER_FILES_SKIP`]: super::IORING_REGISTER_FILES_SKIP
+    #[inline]
+    pub fn files_update(fds: &'a mut [RawFd], offset: u32) -> Self {
+        let mut this = Self::op(IoringOp::FilesUpdate);
+        this.sqe.addr_or_splice_off_in.addr = ptr_mut(fds.as_mut_ptr());
+        this.sqe.len.len = clamp_len(fds.len());
+        this.sqe.off_or_addr2.off = offset.into();
+        this
+    }
+
+    /// `IORING_OP_STATX`—Query a file's metadata, like `statx`.
+    #[inline]
+    pub fn statx(
+        dirfd: BorrowedFd<'a>,
+        path: &'a CStr,
+        flags: AtFlags,
+        mask: StatxFlags,
+        statx: &'a mut MaybeUninit<Statx>,
+    ) -> Self {
+        let mut this = Self::op_dirfd(IoringOp::Statx, dirfd, path);
+        this.sqe.len.len = mask.bits();
+        this.sqe.op_flags.statx_flags = flags;
+        this.sqe.off_or_addr2.addr2 = ptr_mut(statx.as_mut_ptr());
+        this
+    }
+
+    /// `IORING_OP_RENAMEAT`—Rename a file, like `renameat2`.
+    #[inline]
+    pub fn renameat(
+        old_dirfd: BorrowedFd<'a>,
+        old_path: &'a CStr,
+        new_dirfd: BorrowedFd<'a>,
+        new_path: &'a CStr,
+        flags: RenameFlags,
+    ) -> Self {
+        let mut this = Self::op_dirfd(IoringOp::Renameat, old_dirfd, old_path);
+        this.sqe.len.len = new_dirfd.as_raw_fd() as u32;
+        this.sqe.off_or_addr2.addr2 = ptr(new_path.as_ptr());
+        this.sqe.op_flags.rename_flags = flags;
+        this
+    }
+
+    /// `IORING_OP_UNLINKAT`—Remove a file or directory, like `unlinkat`.
+    #[inline]
+    pub fn unlinkat(dirfd: BorrowedFd<'a>, path: &'a CStr, flags: AtFlags) -> Self {
+        let mut this = Self::op_dirfd(IoringOp::Unlinkat, dirfd, path);
+        this.sqe.op_flags.unlink_flags = flags;
+        this
+    }
+
+    /// `IORING_OP_MKDIRAT`—Create a directory, like `mkdirat`.
+    #[inline]
+    pub fn mkdirat(dirfd: BorrowedFd<'a>, path: &'a CStr, mode: Mode) -> Self {
+        let mut this = Self::op_dirfd(IoringOp::Mkdirat, dirfd, path);
+        this.sqe.len.len = mode.bits();
+        this
+    }
+
+    /// `IORING_OP_SYMLINKAT`—Create a symlink at `new_path` that refers to
+    /// `old_path`, like `symlinkat`.
+    #[inline]
+    pub fn symlinkat(old_path: &'a CStr, new_dirfd: BorrowedFd<'a>, new_path: &'a CStr) -> Self {
+        let mut this = Self::op_dirfd(IoringOp::Symlinkat, new_dirfd, old_path);
+        this.sqe.off_or_addr2.addr2 = ptr(new_path.as_ptr());
+        this
+    }
+
+    /// `IORING_OP_LINKAT`—Create a hard link, like `linkat`.
+    #[inline]
+    pub fn linkat(
+        old_dirfd: BorrowedFd<'a>,
+        old_path: &'a CStr,
+        new_dirfd: BorrowedFd<'a>,
+        new_path: &'a CStr,
+        flags: AtFlags,
+    ) -> Self {
+        let mut this = Self::op_dirfd(IoringOp::Linkat, old_dirfd, old_path);
+        this.sqe.len.len = new_dirfd.as_raw_fd() as u32;
+        this.sqe.off_or_addr2.addr2 = ptr(new_path.as_ptr());
+        this.sqe.op_flags.hardlink_flags = flags;
+        this
+    }
+
+    /// `IORING_OP_FSETXATTR`—Set an extended attribute of a file.
+    #[inline]
+    pub fn fsetxattr(
+        fd: impl Into<SqeFd<'a>>,
+        name: &'a CStr,
+        value: &'a [u8],
+        flags: XattrFlags,
+    ) -> Self {
+        let mut this = Self::op_fd(IoringOp::Fsetxattr, fd.into());
+        this.xattr_common(name, value.as_ptr(), value.len());
+        this.sqe.op_flags.xattr_flags = flags;
+        this
+    }
+
+    /// `IORING_OP_SETXATTR`—Set an extended attribute of the file at `path`.
+    #[inline]
+    pub fn setxattr(path: &'a CStr, name: &'a CStr, value: &'a [u8], flags: XattrFlags) -> Self {
+        let mut this = Self::op(IoringOp::Setxattr);
+        this.xattr_common(name, value.as_ptr(), value.len());
+        this.sqe.op_flags.xattr_flags = flags;
+        this.set_addr3(path.as_ptr() as u64);
+        this
+    }
+
+    /// `IORING_OP_FGETXATTR`—Get an extended attribute of a file.
+    ///
+    /// The completion's `res` is the size of the value.
+    #[inline]
+    pub fn fgetxattr(fd: impl Into<SqeFd<'a>>, name: &'a CStr, value: &'a mut [u8]) -> Self {
+        let mut this = Self::op_fd(IoringOp::Fgetxattr, fd.into());
+        this.xattr_common(name, value.as_mut_ptr(), value.len());
+        this
+    }
+
+    /// `IORING_OP_GETXATTR`—Get an extended attribute of the file at `path`.
+    ///
+    /// The completion's `res` is the size of the value.
+    #[inline]
+    pub fn getxattr(path: &'a CStr, name: &'a CStr, value: &'a mut [u8]) -> Self {
+        let mut this = Self::op(IoringOp::Getxattr);
+        this.xattr_common(name, value.as_mut_ptr(), value.len());
+        this.set_addr3(path.as_ptr() as u64);
+        this
+    }
+
+    #[inline]
+    fn xattr_common(&mut self, name: &'a CStr, value: *const u8, len: usize) {
+        self.sqe.addr_or_splice_off_in.addr = ptr(name.as_ptr());
+        self.sqe.off_or_addr2.addr2 = ptr(value);
+        self.sqe.len.len = clamp_len(len);
+    }
+
+    /// `IORING_OP_POLL_ADD`—Wait for a file descriptor to become ready.
+    ///
+    /// The completion's `res` is the ready events.
+    #[inline]
+    pub fn poll_add(fd: impl Into<SqeFd<'a>>, events: PollFlags) -> Self {
+        let mut this = Self::op_fd(IoringOp::PollAdd, fd.into());
+        this.sqe.op_flags.poll32_events = poll_events(events);
+        this
+    }
+
+    /// `IORING_OP_POLL_ADD` with `IORING_POLL_ADD_MULTI`—Repeatedly wait for
+    /// a file descriptor to become ready (since Linux 5.13).
+    #[inline]
+    pub fn poll_add_multishot(fd: impl Into<SqeFd<'a>>, events: PollFlags) -> Self {
+        let mut this = Self::poll_add(fd, events);
+        this.sqe.len.poll_flags = IoringPollFlags::ADD_MULTI;
+        this
+    }
+
+    /// `IORING_OP_POLL_REMOVE`—Cancel the poll request with the given user
+    /// data.
+    #[inline]
+    pub fn poll_remove(user_data: u64) -> Self {
+        let mut this = Self::op(IoringOp::PollRemove);
+        this.sqe.addr_or_splice_off_in.user_data = io_uring_user_data::from_u64(user_data);
+        this
+    }
+
+    /// `IORING_OP_EPOLL_CTL` with `EPOLL_CTL_ADD`—Add a file descriptor to an
+    /// epoll set.
+    #[inline]
+    pub fn epoll_add(epfd: BorrowedFd<'a>, fd: BorrowedFd<'a>, event: &'a EpollEvent) -> Self {
+        let mut this = Self::epoll_ctl(epfd, fd, linux_raw_sys::general::EPOLL_CTL_ADD);
+        this.sqe.addr_or_splice_off_in.addr = ptr(event);
+        this
+    }
+
+    /// `IORING_OP_EPOLL_CTL` with `EPOLL_CTL_MOD`—Modify a file descriptor in
+    /// an epoll set.
+    #[inline]
+    pub fn epoll_mod(epfd: BorrowedFd<'a>, fd: BorrowedFd<'a>, event: &'a EpollEvent) -> Self {
+        let mut this = Self::epoll_ctl(epfd, fd, linux_raw_sys::general::EPOLL_CTL_MOD);
+        this.sqe.addr_or_splice_off_in.addr = ptr(event);
+        this
+    }
+
+    /// `IORING_OP_EPOLL_CTL` with `EPOLL_CTL_DEL`—Remove a file descriptor
+    /// from an epoll set.
+    #[inline]
+    pub fn epoll_del(epfd: BorrowedFd<'a>, fd: BorrowedFd<'a>) -> Self {
+        Self::epoll_ctl(epfd, fd, linux_raw_sys::general::EPOLL_CTL_DEL)
+    }
+
+    #[inline]
+    fn epoll_ctl(epfd: BorrowedFd<'a>, fd: BorrowedFd<'a>, op: u32) -> Self {
+        let mut this = Self::op(IoringOp::EpollCtl);
+        this.sqe.fd = epfd.as_raw_fd();
+        this.sqe.len.len = op;
+        this.sqe.off_or_addr2.off = fd.as_raw_fd() as u64;
+        this
+    }
+
+    /// `IORING_OP_TIMEOUT`—Complete after `timeout` elapses, or after
+    /// `count` other completions, whichever comes first.
+    ///
+    /// A `count` of 0 waits only for the timeout. When the timeout elapses,
+    /// the completion's `res` is `-ETIME`.
+    #[inline]
+    pub fn timeout(timeout: &'a Timespec, count: u32, flags: IoringTimeoutFlags) -> Self {
+        let mut this = Self::op(IoringOp::Timeout);
+        this.sqe.addr_or_splice_off_in.addr = ptr(timeout);
+        this.sqe.len.len = 1;
+        this.sqe.off_or_addr2.off = count.into();
+        this.sqe.op_flags.timeout_flags = flags;
+        this
+    }
+
+    /// `IORING_OP_TIMEOUT_REMOVE`—Cancel the timeout with the given user
+    /// data.
+    #[inline]
+    pub fn timeout_remove(user_data: u64) -> Self {
+        let mut this = Self::op(IoringOp::TimeoutRemove);
+        this.sqe.addr_or_splice_off_in.user_data = io_uring_user_data::from_u64(user_data);
+        this
+    }
+
+    /// `IORING_OP_TIMEOUT_REMOVE` with `IORING_TIMEOUT_UPDATE`—Change the
+    /// expiration of the timeout with the given user data.
+    ///
+    /// To update a linked timeout, include
+    /// [`IoringTimeoutFlags::LINK_TIMEOUT_UPDATE`] in `flags`.
+    #[inline]
+    pub fn timeout_update(
+        user_data: u64,
+        timeout: &'a Timespec,
+        flags: IoringTimeoutFlags,
+    ) -> Self {
+        let mut this = Self::timeout_remove(user_data);
+        this.sqe.off_or_addr2.addr2 = ptr(timeout);
+        this.sqe.op_flags.timeout_flags = if flags.contains(IoringTimeoutFlags::LINK_TIMEOUT_UPDATE)
+        {
+            flags
+        } else {
+            flags | IoringTimeoutFlags::UPDATE
+        };
+        this
+    }
+
+    /// `IORING_OP_LINK_TIMEOUT`—Cancel the previous entry, which must have
+    /// [`IoringSqeFlags::IO_LINK`], if it doesn't complete before `timeout`
+    /// elapses.
+    ///
+    /// This entry must immediately follow the one it applies to.
+    #[inline]
+    pub fn link_timeout(timeout: &'a Timespec, flags: IoringTimeoutFlags) -> Self {
+        let mut this = Self::op(IoringOp::LinkTimeout);
+        this.sqe.addr_or_splice_off_in.addr = ptr(timeout);
+        this.sqe.len.len = 1;
+        this.sqe.op_flags.timeout_flags = flags;
+        this
+    }
+
+    /// `IORING_OP_ASYNC_CANCEL`—Cancel the request with the given user data.
+    ///
+    /// With [`IoringAsyncCancelFlags::ALL`], all matching requests are
+    /// canceled, and with [`IoringAsyncCancelFlags::ANY`], `user_data` is
+    /// ignored and all requests are canceled.
+    #[inline]
+    pub fn async_cancel(user_data: u64, flags: IoringAsyncCancelFlags) -> Self {
+        let mut this = Self::op(IoringOp::AsyncCancel);
+        this.sqe.addr_or_splice_off_in.user_data = io_uring_user_data::from_u64(user_data);
+        this.sqe.op_flags.cancel_flags = flags;
+        this
+    }
+
+    /// `IORING_OP_ASYNC_CANCEL` with `IORING_ASYNC_CANCEL_FD`—Cancel a request
+    /// operating on the given file.
+    ///
+    /// With [`IoringAsyncCancelFlags::ALL`], all requests on the file are
+    /// canceled.
+    #[inline]
+    pub fn async_cancel_fd(fd: impl Into<SqeFd<'a>>, mut flags: IoringAsyncCancelFlags) -> Self {
+        let mut this = Self::op(IoringOp::AsyncCancel);
+        let (fd, fixed) = fd.into().raw();
+        flags |= IoringAsyncCancelFlags::FD;
+        if fixed {
+            flags |= IoringAsyncCancelFlags::FD_FIXED;
+        }
+        this.sqe.fd = fd;
+        this.sqe.op_flags.cancel_flags = flags;
+        this
+    }
+
+    /// `IORING_OP_MSG_RING` with `IORING_MSG_DATA`—Post a completion with
+    /// the given `res` and user data to another ring.
+    #[inline]
+    pub fn msg_ring_data(
+        ring: impl Into<SqeFd<'a>>,
+        res: i32,
+        user_data: u64,
+        flags: IoringMsgringFlags,
+    ) -> Self {
+        let mut this = Self::op_fd(IoringOp::MsgRing, ring.into());
+        this.sqe.addr_or_splice_off_in.msgring_cmd = IoringMsgringCmds::Data;
+        this.sqe.len.len = res as u32;
+        this.sqe.off_or_addr2.off = user_data;
+        this.sqe.op_flags.msg_ring_flags = flags;
+        this
+    }
+
+    /// `IORING_OP_MSG_RING` with `IORING_MSG_SEND_FD`—Install one of this
+    /// ring's registered files in another ring's registered file table.
+    ///
+    /// See [`Sqe::openat_direct`] for the meaning of `slot`. The target ring
+    /// gets a completion with the given user data.
+    #[inline]
+    pub fn msg_ring_send_fd(
+        ring: impl Into<SqeFd<'a>>,
+        file: FixedFile,
+        slot: Option<FixedFile>,
+        user_data: u64,
+        flags: IoringMsgringFlags,
+    ) -> Self {
+        let mut this = Self::op_fd(IoringOp::MsgRing, ring.into()).file_slot(slot);
+        this.sqe.addr_or_splice_off_in.msgring_cmd = IoringMsgringCmds::SendFd;
+        this.set_addr3(file.0.into());
+        this.sqe.off_or_addr2.off = user_data;
+        this.sqe.op_flags.msg_ring_flags = flags;
+        this
+    }
+
+    /// `IORING_OP_SOCKET`—Create a socket (since Linux 5.19).
+    #[inline]
+    pub fn socket(
+        domain: AddressFamily,
+        type_: SocketType,
+        flags: SocketFlags,
+        protocol: Option<Protocol>,
+    ) -> Self {
+        let mut this = Self::op(IoringOp::Socket);
+        this.sqe.fd = domain.as_raw().into();
+        this.sqe.off_or_addr2.off = (type_.as_raw() | flags.bits()).into();
+        this.sqe.len.len = protocol.map_or(0, |p| p.as_raw().get());
+        this
+    }
+
+    /// `IORING_OP_SOCKET`—Create a socket, and install it in the registered
+    /// file table (since Linux 5.19).
+    ///
+    /// See [`Sqe::openat_direct`] for the meaning of `slot`. `flags` may not
+    /// contain [`SocketFlags::CLOEXEC`].
+    #[inline]
+    pub fn socket_direct(
+        domain: AddressFamily,
+        type_: SocketType,
+        flags: SocketFlags,
+        protocol: Option<Protocol>,
+        slot: Option<FixedFile>,
+    ) -> Self {
+        Self::socket(domain, type_, flags, protocol).file_slot(slot)
+    }
+
+    /// `IORING_OP_BIND`—Bind a socket to an address (since Linux 6.11).
+    #[inline]
+    pub fn bind(fd: impl Into<SqeFd<'a>>, addr: &'a SocketAddrAny) -> Self {
+        let mut this = Self::op_fd(IoringOp::Bind, fd.into());
+        this.sqe.addr_or_splice_off_in.addr = ptr(addr.as_ptr());
+        this.sqe.off_or_addr2.off = addr.addr_len().into();
+        this
+    }
+
+    /// `IORING_OP_LISTEN`—Listen for connections on a socket (since Linux
+    /// 6.11).
+    #[inline]
+    pub fn listen(fd: impl Into<SqeFd<'a>>, backlog: i32) -> Self {
+        let mut this = Self::op_fd(IoringOp::Listen, fd.into());
+        this.sqe.len.len = backlog as u32;
+        this
+    }
+
+    /// `IORING_OP_CONNECT`—Connect a socket to an address.
+    #[inline]
+    pub fn connect(fd: impl Into<SqeFd<'a>>, addr: &'a SocketAddrAny) -> Self {
+        let mut this = Self::op_fd(IoringOp::Connect, fd.into());
+        this.sqe.addr_or_splice_off_in.addr = ptr(addr.as_ptr());
+        this.sqe.off_or_addr2.off = addr.addr_len().into();
+        this
+    }
+
+    /// `IORING_OP_ACCEPT`—Accept a connection on a listening socket.
+    ///
+    /// The completion's `res` is the new file descriptor.
+    #[inline]
+    pub fn accept(fd: impl Into<SqeFd<'a>>, flags: SocketFlags) -> Self {
+        let mut this = Self::op_fd(IoringOp::Accept, fd.into());
+        this.sqe.op_flags.accept_flags = flags;
+        this
+    }
+
+    /// `IORING_OP_ACCEPT`—Accept a connection on a listening socket, and
+    /// install it in the registered file table.
+    ///
+    /// See [`Sqe::openat_direct`] for the meaning of `slot`. `flags` may not
+    /// contain [`SocketFlags::CLOEXEC`].
+    #[inline]
+    pub fn accept_direct(
+        fd: impl Into<SqeFd<'a>>,
+        flags: SocketFlags,
+        slot: Option<FixedFile>,
+    ) -> Self {
+        Self::accept(fd, flags).file_slot(slot)
+    }
+
+    /// `IORING_OP_ACCEPT` with `IORING_ACCEPT_MULTISHOT`—Repeatedly accept
+    /// connections on a listening socket (since Linux 5.19).
+    ///
+    /// Each connection produces a completion with
+    /// [`IoringCqeFlags::MORE`] set, until the request is canceled or fails.
+    ///
+    /// [`IoringCqeFlags::MORE`]: super::IoringCqeFlags::MORE
+    #[inline]
+    pub fn accept_multishot(fd: impl Into<SqeFd<'a>>, flags: SocketFlags) -> Self {
+        let mut this = Self::accept(fd, flags);
+        this.sqe.ioprio.accept_flags = IoringAcceptFlags::MULTISHOT;
+        this
+    }
+
+    /// `IORING_OP_ACCEPT` with `IORING_ACCEPT_MULTISHOT`—Repeatedly accept
+    /// connections on a listening socket, and install them in free slots of
+    /// the registered file table (since Linux 5.19).
+    #[inline]
+    pub fn accept_multishot_direct(fd: impl Into<SqeFd<'a>>, flags: SocketFlags) -> Self {
+        Self::accept_multishot(fd, flags).file_slot(None)
+    }
+
+    /// `IORING_OP_SHUTDOWN`—Shut down part of a full-duplex connection.
+    #[inline]
+    pub fn shutdown(fd: impl Into<SqeFd<'a>>, how: Shutdown) -> Self {
+        let mut this = Self::op_fd(IoringOp::Shutdown, fd.into());
+        this.sqe.len.len = how as u32;
+        this
+    }
+
+    /// `IORING_OP_SEND`—Send data on a socket.
+    #[inline]
+    pub fn send(fd: impl Into<SqeFd<'a>>, buf: &'a [u8], flags: SendFlags) -> Self {
+        let mut this = Self::op_buf(IoringOp::Send, fd.into(), buf.as_ptr(), buf.len());
+        this.sqe.op_flags.send_flags = flags;
+        this
+    }
+
+    /// `IORING_OP_SEND_ZC`—Send data on a socket without copying it (since
+    /// Linux 6.0).
+    ///
+    /// This produces two completions: one with the result, with
+    /// [`IoringCqeFlags::MORE`] set, and a notification with
+    /// [`IoringCqeFlags::NOTIF`] set once `buf` is no longer in use.
+    ///
+    /// [`IoringCqeFlags::MORE`]: super::IoringCqeFlags::MORE
+    /// [`IoringCqeFlags::NOTIF`]: super::IoringCqeFlags::NOTIF
+    #[inline]
+    pub fn send_zc(
+        fd: impl Into<SqeFd<'a>>,
+        buf: &'a [u8],
+        flags: SendFlags,
+        zc_flags: IoringSendFlags,
+    ) -> Self {
+        let mut this = Self::send(fd, buf, flags);
+        this.sqe.opcode = IoringOp::SendZc;
+        this.sqe.ioprio.send_flags = zc_flags;
+        this
+    }
+
+    /// `IORING_OP_SENDMSG`—Send a message on a socket.
+    ///
+    /// The buffers and control data `msg` points to must also outlive the
+    /// operation.
+    #[inline]
+    pub fn sendmsg(fd: impl Into<SqeFd<'a>>, msg: &'a MsgHdr, flags: SendFlags) -> Self {
+        let mut this = Self::op_fd(IoringOp::Sendmsg, fd.into());
+        this.sqe.addr_or_splice_off_in.addr = ptr(msg);
+        this.sqe.len.len = 1;
+        this.sqe.op_flags.send_flags = flags;
+        this
+    }
+
+    /// `IORING_OP_SENDMSG_ZC`—Send a message on a socket without copying it
+    /// (since Linux 6.1).
+    ///
+    /// See [`Sqe::send_zc`] for the completions this produces.
+    #[inline]
+    pub fn sendmsg_zc(fd: impl Into<SqeFd<'a>>, msg: &'a MsgHdr, flags: SendFlags) -> Self {
+        let mut this = Self::sendmsg(fd, msg, flags);
+        this.sqe.opcode = IoringOp::SendmsgZc;
+        this
+    }
+
+    /// `IORING_OP_RECV`—Receive data from a socket.
+    #[inline]
+    pub fn recv(fd: impl Into<SqeFd<'a>>, buf: &'a mut [u8], flags: RecvFlags) -> Self {
+        let mut this = Self::op_buf(IoringOp::Recv, fd.into(), buf.as_mut_ptr(), buf.len());
+        this.sqe.op_flags.recv_flags = flags;
+        this
+    }
+
+    /// `IORING_OP_RECV` with `IORING_RECV_MULTISHOT`—Repeatedly receive data
+    /// from a socket into buffers from the provided-buffer group `buf_group`
+    /// (since Linux 6.0).
+    ///
+    /// Each completion's flags contain [`IoringCqeFlags::BUFFER`] and the ID
+    /// of the buffer that was used.
+    ///
+    /// [`IoringCqeFlags::BUFFER`]: super::IoringCqeFlags::BUFFER
+    #[inline]
+    pub fn recv_multishot(fd: impl Into<SqeFd<'a>>, flags: RecvFlags, buf_group: u16) -> Self {
+        let mut this = Self::op_fd(IoringOp::Recv, fd.into());
+        this.sqe.flags |= IoringSqeFlags::BUFFER_SELECT;
+        this.sqe.ioprio.recv_flags = IoringRecvFlags::MULTISHOT;
+        this.sqe.op_flags.recv_flags = flags;
+        this.sqe.buf.buf_group = buf_group;
+        this
+    }
+
+    /// `IORING_OP_RECVMSG`—Receive a message from a socket.
+    ///
+    /// The buffers and control data `msg` points to must also outlive the
+    /// operation.
+    #[inline]
+    pub fn recvmsg(fd: impl Into<SqeFd<'a>>, msg: &'a mut MsgHdr, flags: RecvFlags) -> Self {
+        let mut this = Self::op_fd(IoringOp::Recvmsg, fd.into());
+        this.sqe.addr_or_splice_off_in.addr = ptr_mut(msg);
+        this.sqe.len.len = 1;
+        this.sqe.op_flags.recv_flags = flags;
+        this
+    }
+
+    /// `IORING_OP_RECVMSG` with `IORING_RECV_MULTISHOT`—Repeatedly receive
+    /// messages from a socket into buffers from the provided-buffer group
+    /// `buf_group` (since Linux 6.0).
+    ///
+    /// `msg` only supplies the name and control lengths; each buffer starts
+    /// with an [`io_uring_recvmsg_out`] header.
+    ///
+    /// [`io_uring_recvmsg_out`]: super::io_uring_recvmsg_out
+    #[inline]
+    pub fn recvmsg_multishot(
+        fd: impl Into<SqeFd<'a>>,
+        msg: &'a MsgHdr,
+        flags: RecvFlags,
+        buf_group: u16,
+    ) -> Self {
+        let mut this = Self::op_fd(IoringOp::Recvmsg, fd.into());
+        this.sqe.flags |= IoringSqeFlags::BUFFER_SELECT;
+        this.sqe.addr_or_splice_off_in.addr = ptr(msg);
+        this.sqe.len.len = 1;
+        this.sqe.ioprio.recv_flags = IoringRecvFlags::MULTISHOT;
+        this.sqe.op_flags.recv_flags = flags;
+        this.sqe.buf.buf_group = buf_group;
+        this
+    }
+
+    /// `IORING_OP_PROVIDE_BUFFERS`—Add buffers to the provided-buffer group
+    /// `buf_group`.
+    ///
+    /// `bufs` is split into `buf_len`-byte buffers with consecutive IDs
+    /// starting at `first_id`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `buf_len` is zero.
+    #[inline]
+    pub fn provide_buffers(
+        bufs: &'a mut [u8],
+        buf_len: u32,
+        buf_group: u16,
+        first_id: u16,
+    ) -> Self {
+        let nbufs = bufs.len() / buf_len as usize;
+        let mut this = Self::op(IoringOp::ProvideBuffers);
+        this.sqe.fd = nbufs.try_into().unwrap_or(RawFd::MAX);
+        this.sqe.addr_or_splice_off_in.addr = ptr_mut(bufs.as_mut_ptr());
+        this.sqe.len.len = buf_len;
+        this.sqe.off_or_addr2.off = first_id.into();
+        this.sqe.buf.buf_group = buf_group;
+        this
+    }
+
+    /// `IORING_OP_REMOVE_BUFFERS`—Remove up to `nbufs` buffers from the
+    /// provided-buffer group `buf_group`.
+    #[inline]
+    pub fn remove_buffers(nbufs: u32, buf_group: u16) -> Self {
+        let mut this = Self::op(IoringOp::RemoveBuffers);
+        this.sqe.fd = nbufs as RawFd;
+        this.sqe.buf.buf_group = buf_group;
+        this
+    }
+
+    /// `IORING_OP_FUTEX_WAIT`—Wait on a futex (since Linux 6.7).
+    ///
+    /// This waits if `futex` contains `val`, until woken by a wake with a
+    /// `mask` that intersects this one.
+    #[inline]
+    pub fn futex_wait(futex: &'a AtomicU32, val: u32, mask: u32, flags: FutexWaitFlags) -> Self {
+        Self::futex_common(IoringOp::FutexWait, futex, val, mask, flags)
+    }
+
+    /// `IORING_OP_FUTEX_WAKE`—Wake up to `nr` waiters on a futex (since
+    /// Linux 6.7).
+    #[inline]
+    pub fn futex_wake(futex: &'a AtomicU32, nr: u32, mask: u32, flags: FutexWaitFlags) -> Self {
+        Self::futex_common(IoringOp::FutexWake, futex, nr, mask, flags)
+    }
+
+    #[inline]
+    fn futex_common(
+        opcode: IoringOp,
+        futex: &'a AtomicU32,
+        val: u32,
+        mask: u32,
+        flags: FutexWaitFlags,
+    ) -> Self {
+        let mut this = Self::op(opcode);
+        this.sqe.fd = (flags | FutexWaitFlags::SIZE_U32).bits() as RawFd;
+        this.sqe.addr_or_splice_off_in.addr = ptr(futex);
+        this.sqe.off_or_addr2.off = val.into();
+        this.set_addr3(mask.into());
+        this
+    }
+
+    /// `IORING_OP_FUTEX_WAITV`—Wait on multiple futexes (since Linux 6.7).
+    ///
+    /// The completion's `res` is the index of the futex that was woken.
+    #[inline]
+    pub fn futex_waitv(waiters: &'a [FutexWait]) -> Self {
+        let mut this = Self::op(IoringOp::FutexWaitv);
+        this.sqe.addr_or_splice_off_in.addr = ptr(waiters.as_ptr());
+        this.sqe.len.len = clamp_len(waiters.len());
+        this
+    }
+
+    /// `IORING_OP_WAITID`—Wait for a child process to change state (since
+    /// Linux 6.5).
+    ///
+    /// On success, the kernel fills in `status`.
+    #[cfg(feature = "process")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "process")))]
+    #[inline]
+    pub fn waitid(
+        id: crate::process::WaitId<'a>,
+        options: crate::process::WaitIdOptions,
+        status: &'a mut MaybeUninit<crate::process::WaitIdStatus>,
+    ) -> Self {
+        use crate::backend::c;
+        use crate::process::WaitId;
+
+        let (idtype, id) = match id {
+            WaitId::All => (c::P_ALL, 0),
+            WaitId::Pid(pid) => (c::P_PID, pid.as_raw_nonzero().get()),
+            WaitId::Pgid(pid) => (c::P_PGID, pid.map_or(0, |pid| pid.as_raw_nonzero().get())),
+            #[cfg(target_os = "linux")]
+            WaitId::PidFd(fd) => (c::P_PIDFD, fd.as_raw_fd()),
+            #[cfg(not(target_os = "linux"))]
+            WaitId::__EatLifetime(_) => unreachable!(),
+        };
+        let mut this = Self::op(IoringOp::Waitid);
+        this.sqe.fd = id;
+        this.sqe.len.len = idtype as u32;
+        this.sqe.splice_fd_in_or_file_index_or_addr_len.file_index = options.bits();
+        this.sqe.off_or_addr2.addr2 = ptr_mut(status.as_mut_ptr());
+        this
+    }
+
+    /// `IORING_OP_URING_CMD`—Pass a command to a file's driver.
+    ///
+    /// `cmd` is the driver-specific payload in the entry's command area; the
+    /// meaning of `cmd_op` and `cmd`, including any memory they refer to, is
+    /// defined by the driver.
+    #[inline]
+    pub fn uring_cmd(fd: impl Into<SqeFd<'a>>, cmd_op: u32, cmd: [u8; 16]) -> Self {
+        let mut this = Self::op_fd(IoringOp::UringCmd, fd.into());
+        this.sqe.off_or_addr2.cmd_op = cmd_op_struct { cmd_op, __pad1: 0 };
+        let (lo, hi) = cmd.split_at(8);
+        this.sqe.addr3_or_cmd.addr3 = addr3_struct {
+            addr3: u64::from_ne_bytes(lo.try_into().unwrap()),
+            __pad2: [u64::from_ne_bytes(hi.try_into().unwrap())],
+        };
+        this
+    }
+
+    /// Set `file_index` for a `*_direct` operation, which uses 1-based slot
+    /// numbers.
+    #[inline]
+    fn file_slot(mut self, slot: Option<FixedFile>) -> Self {
+        self.sqe.splice_fd_in_or_file_index_or_addr_len.file_index = match slot {
+            Some(FixedFile(index)) => index + 1,
+            None => IORING_FILE_INDEX_ALLOC as u32,
+        };
+        self
+    }
+
+    #[inline]
+    fn set_addr3(&mut self, addr3: u64) {
+        self.sqe.addr3_or_cmd.addr3 = addr3_struct {
+            addr3,
+            ..Default::default()
+        };
+    }
+}
+
+impl<'a> From<Sqe<'a>> for io_uring_sqe {
+    #[inline]
+    fn from(sqe: Sqe<'a>) -> Self {
+        sqe.sqe
+    }
+}
+
+impl AsRef<io_uring_sqe> for Sqe<'_> {
+    #[inline]
+    fn as_ref(&self) -> &io_uring_sqe {
+        &self.sqe
+    }
+}
+
+impl fmt::Debug for Sqe<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Sqe")
+            .field("opcode", &self.sqe.opcode)
+            .field("flags", &self.sqe.flags)
+            .field("fd", &self.sqe.fd)
+            .field("user_data", &self.sqe.user_data.u64_())
+            .finish_non_exhaustive()
+    }
+}
+
+#[inline]
+fn ptr<T>(ptr: *const T) -> io_uring_ptr {
+    io_uring_ptr::new(ptr as *mut c_void)
+}
+
+#[inline]
+fn ptr_mut<T>(ptr: *mut T) -> io_uring_ptr {
+    io_uring_ptr::new(ptr.cast())
+}
+
+/// Clamp a buffer length to the kernel's `u32` length fields; a shorter
+/// transfer is always permitted.
+#[inline]
+fn clamp_len(len: usize) -> u32 {
+    len.try_into().unwrap_or(u32::MAX)
+}
+
+/// Convert `PollFlags` to the `poll32_events` field, which the kernel
+/// half-word swaps on big-endian targets.
+#[inline]
+fn poll_events(events: PollFlags) -> u32 {
+    let events = u32::from(events.bits() as u16);
+    #[cfg(target_endian = "big")]
+    let events = events.rotate_left(16);
+    events
+}
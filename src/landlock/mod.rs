@@ -0,0 +1,205 @@
+//! Linux Landlock sandboxing.
+//!
+//! Landlock lets an unprivileged thread restrict its own access rights. A
+//! ruleset is created with [`create_ruleset`], listing the kinds of access it
+//! handles. Rules which allow some of those accesses are added to it with
+//! [`add_rule_path_beneath`] and [`add_rule_net_port`], and then it's enforced
+//! on the calling thread with [`restrict_self`]. Once enforced, handled
+//! accesses which no rule allows fail with [`io::Errno::ACCESS`].
+//!
+//! Restrictions can't be removed, and they're inherited by child processes
+//! and preserved across `execve`. Unless the thread has `CAP_SYS_ADMIN` in
+//! its user namespace, it must set the `no_new_privs` attribute, with
+//! [`set_no_new_privs`], before calling [`restrict_self`].
+//!
+//! The access rights the kernel supports depend on its Landlock ABI version,
+//! which can be queried with [`abi_version`]. Creating a ruleset which
+//! handles access rights the kernel doesn't know about fails with
+//! [`io::Errno::INVAL`].
+//!
+//! # Examples
+//!
+//! ```no_run
+//! # #[cfg(all(feature = "fs", feature = "thread"))]
+//! # fn example() -> rustix::io::Result<()> {
+//! use rustix::fs::{open, Mode, OFlags};
+//! use rustix::landlock::{
+//!     add_rule_path_beneath, create_ruleset, restrict_self, AccessFs, RestrictSelfFlags,
+//!     RulesetAttr,
+//! };
+//! use rustix::thread::set_no_new_privs;
+//!
+//! // Only allow reading files under "/usr".
+//! let ruleset = create_ruleset(&RulesetAttr {
+//!     handled_access_fs: AccessFs::READ_FILE | AccessFs::READ_DIR,
+//!     ..RulesetAttr::default()
+//! })?;
+//! let usr = open("/usr", OFlags::PATH | OFlags::CLOEXEC, Mode::empty())?;
+//! add_rule_path_beneath(&ruleset, &usr, AccessFs::READ_FILE | AccessFs::READ_DIR)?;
+//!
+//! set_no_new_privs(true)?;
+//! restrict_self(&ruleset, RestrictSelfFlags::empty())?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # References
+//!  - [Linux]
+//!  - [Linux Landlock documentation]
+//!
+//! [`set_no_new_privs`]: https://docs.rs/rustix/*/rustix/thread/fn.set_no_new_privs.html
+//! [Linux]: https://man7.org/linux/man-pages/man7/landlock.7.html
+//! [Linux Landlock documentation]: https://docs.kernel.org/userspace-api/landlock.html
+
+mod types;
+
+use crate::backend::landlock::syscalls;
+use crate::fd::{AsFd, AsRawFd as _, OwnedFd};
+use crate::io;
+use linux_raw_sys::landlock::{
+    landlock_net_port_attr, landlock_path_beneath_attr, landlock_ruleset_attr,
+};
+
+pub use types::*;
+
+/// `struct landlock_ruleset_attr`—The access rights a ruleset handles, for
+/// use with [`create_ruleset`].
+#[derive(Default, Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[doc(alias = "landlock_ruleset_attr")]
+pub struct RulesetAttr {
+    /// The filesystem accesses which are denied unless allowed by a rule.
+    pub handled_access_fs: AccessFs,
+
+    /// The network accesses which are denied unless allowed by a rule (since
+    /// ABI version 4).
+    pub handled_access_net: AccessNet,
+
+    /// The IPC scopes which are restricted (since ABI version 6).
+    pub scoped: Scope,
+}
+
+/// `landlock_create_ruleset(&attr, sizeof(attr), 0)`—Create a new Landlock
+/// ruleset.
+///
+/// This function was added in Linux 5.13.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/landlock_create_ruleset.2.html
+#[doc(alias = "landlock_create_ruleset")]
+#[inline]
+pub fn create_ruleset(attr: &RulesetAttr) -> io::Result<OwnedFd> {
+    syscalls::create_ruleset(&landlock_ruleset_attr {
+        handled_access_fs: attr.handled_access_fs.bits(),
+        handled_access_net: attr.handled_access_net.bits(),
+        scoped: attr.scoped.bits(),
+    })
+}
+
+/// `landlock_create_ruleset(NULL, 0, LANDLOCK_CREATE_RULESET_VERSION)`—Get
+/// the highest Landlock ABI version the kernel supports.
+///
+/// This fails with [`io::Errno::NOSYS`] if the kernel doesn't support
+/// Landlock, or with [`io::Errno::OPNOTSUPP`] if Landlock is supported but
+/// disabled at boot time.
+///
+/// This function was added in Linux 5.13.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/landlock_create_ruleset.2.html
+#[doc(
+    alias = "landlock_create_ruleset",
+    alias = "LANDLOCK_CREATE_RULESET_VERSION"
+)]
+#[inline]
+pub fn abi_version() -> io::Result<u32> {
+    syscalls::abi_version().map(|version| version as u32)
+}
+
+/// `landlock_add_rule(ruleset, LANDLOCK_RULE_PATH_BENEATH, &attr, 0)`—Allow
+/// filesystem accesses beneath a directory.
+///
+/// `parent` is typically a file descriptor opened with [`OFlags::PATH`].
+/// `allowed_access` must be a subset of the ruleset's
+/// [`RulesetAttr::handled_access_fs`], and if `parent` isn't a directory, it
+/// must only contain accesses which apply to files.
+///
+/// This function was added in Linux 5.13.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/landlock_add_rule.2.html
+/// [`OFlags::PATH`]: https://docs.rs/rustix/*/rustix/fs/struct.OFlags.html#associatedconstant.PATH
+#[doc(
+    alias = "landlock_add_rule",
+    alias = "LANDLOCK_RULE_PATH_BENEATH",
+    alias = "landlock_path_beneath_attr"
+)]
+#[inline]
+pub fn add_rule_path_beneath<Ruleset: AsFd, Parent: AsFd>(
+    ruleset: Ruleset,
+    parent: Parent,
+    allowed_access: AccessFs,
+) -> io::Result<()> {
+    syscalls::add_rule_path_beneath(
+        ruleset.as_fd(),
+        &landlock_path_beneath_attr {
+            allowed_access: allowed_access.bits(),
+            parent_fd: parent.as_fd().as_raw_fd(),
+        },
+    )
+}
+
+/// `landlock_add_rule(ruleset, LANDLOCK_RULE_NET_PORT, &attr, 0)`—Allow
+/// network accesses on a TCP port.
+///
+/// `allowed_access` must be a subset of the ruleset's
+/// [`RulesetAttr::handled_access_net`].
+///
+/// This function was added in Linux 6.7.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/landlock_add_rule.2.html
+#[doc(
+    alias = "landlock_add_rule",
+    alias = "LANDLOCK_RULE_NET_PORT",
+    alias = "landlock_net_port_attr"
+)]
+#[inline]
+pub fn add_rule_net_port<Ruleset: AsFd>(
+    ruleset: Ruleset,
+    port: u16,
+    allowed_access: AccessNet,
+) -> io::Result<()> {
+    syscalls::add_rule_net_port(
+        ruleset.as_fd(),
+        &landlock_net_port_attr {
+            allowed_access: allowed_access.bits(),
+            port: port.into(),
+        },
+    )
+}
+
+/// `landlock_restrict_self(ruleset, flags)`—Enforce a ruleset on the calling
+/// thread.
+///
+/// Other threads in the process aren't affected, so this is typically called
+/// before any other threads are created.
+///
+/// This function was added in Linux 5.13.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/landlock_restrict_self.2.html
+#[doc(alias = "landlock_restrict_self")]
+#[inline]
+pub fn restrict_self<Ruleset: AsFd>(ruleset: Ruleset, flags: RestrictSelfFlags) -> io::Result<()> {
+    syscalls::restrict_self(ruleset.as_fd(), flags)
+}
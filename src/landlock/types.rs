@@ -0,0 +1,129 @@
+use bitflags::bitflags;
+use linux_raw_sys::landlock as ll;
+
+bitflags! {
+    /// `LANDLOCK_ACCESS_FS_*` constants for use with [`RulesetAttr`] and
+    /// [`add_rule_path_beneath`].
+    ///
+    /// [`RulesetAttr`]: crate::landlock::RulesetAttr
+    /// [`add_rule_path_beneath`]: crate::landlock::add_rule_path_beneath
+    #[repr(transparent)]
+    #[derive(Default, Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct AccessFs: u64 {
+        /// `LANDLOCK_ACCESS_FS_EXECUTE`
+        const EXECUTE = ll::LANDLOCK_ACCESS_FS_EXECUTE as u64;
+
+        /// `LANDLOCK_ACCESS_FS_WRITE_FILE`
+        const WRITE_FILE = ll::LANDLOCK_ACCESS_FS_WRITE_FILE as u64;
+
+        /// `LANDLOCK_ACCESS_FS_READ_FILE`
+        const READ_FILE = ll::LANDLOCK_ACCESS_FS_READ_FILE as u64;
+
+        /// `LANDLOCK_ACCESS_FS_READ_DIR`
+        const READ_DIR = ll::LANDLOCK_ACCESS_FS_READ_DIR as u64;
+
+        /// `LANDLOCK_ACCESS_FS_REMOVE_DIR`
+        const REMOVE_DIR = ll::LANDLOCK_ACCESS_FS_REMOVE_DIR as u64;
+
+        /// `LANDLOCK_ACCESS_FS_REMOVE_FILE`
+        const REMOVE_FILE = ll::LANDLOCK_ACCESS_FS_REMOVE_FILE as u64;
+
+        /// `LANDLOCK_ACCESS_FS_MAKE_CHAR`
+        const MAKE_CHAR = ll::LANDLOCK_ACCESS_FS_MAKE_CHAR as u64;
+
+        /// `LANDLOCK_ACCESS_FS_MAKE_DIR`
+        const MAKE_DIR = ll::LANDLOCK_ACCESS_FS_MAKE_DIR as u64;
+
+        /// `LANDLOCK_ACCESS_FS_MAKE_REG`
+        const MAKE_REG = ll::LANDLOCK_ACCESS_FS_MAKE_REG as u64;
+
+        /// `LANDLOCK_ACCESS_FS_MAKE_SOCK`
+        const MAKE_SOCK = ll::LANDLOCK_ACCESS_FS_MAKE_SOCK as u64;
+
+        /// `LANDLOCK_ACCESS_FS_MAKE_FIFO`
+        const MAKE_FIFO = ll::LANDLOCK_ACCESS_FS_MAKE_FIFO as u64;
+
+        /// `LANDLOCK_ACCESS_FS_MAKE_BLOCK`
+        const MAKE_BLOCK = ll::LANDLOCK_ACCESS_FS_MAKE_BLOCK as u64;
+
+        /// `LANDLOCK_ACCESS_FS_MAKE_SYM`
+        const MAKE_SYM = ll::LANDLOCK_ACCESS_FS_MAKE_SYM as u64;
+
+        /// `LANDLOCK_ACCESS_FS_REFER` (since ABI version 2)
+        const REFER = ll::LANDLOCK_ACCESS_FS_REFER as u64;
+
+        /// `LANDLOCK_ACCESS_FS_TRUNCATE` (since ABI version 3)
+        const TRUNCATE = ll::LANDLOCK_ACCESS_FS_TRUNCATE as u64;
+
+        /// `LANDLOCK_ACCESS_FS_IOCTL_DEV` (since ABI version 5)
+        const IOCTL_DEV = ll::LANDLOCK_ACCESS_FS_IOCTL_DEV as u64;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
+bitflags! {
+    /// `LANDLOCK_ACCESS_NET_*` constants for use with [`RulesetAttr`] and
+    /// [`add_rule_net_port`].
+    ///
+    /// These are available since ABI version 4.
+    ///
+    /// [`RulesetAttr`]: crate::landlock::RulesetAttr
+    /// [`add_rule_net_port`]: crate::landlock::add_rule_net_port
+    #[repr(transparent)]
+    #[derive(Default, Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct AccessNet: u64 {
+        /// `LANDLOCK_ACCESS_NET_BIND_TCP`
+        const BIND_TCP = ll::LANDLOCK_ACCESS_NET_BIND_TCP as u64;
+
+        /// `LANDLOCK_ACCESS_NET_CONNECT_TCP`
+        const CONNECT_TCP = ll::LANDLOCK_ACCESS_NET_CONNECT_TCP as u64;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
+bitflags! {
+    /// `LANDLOCK_SCOPE_*` constants for use with [`RulesetAttr`].
+    ///
+    /// These are available since ABI version 6.
+    ///
+    /// [`RulesetAttr`]: crate::landlock::RulesetAttr
+    #[repr(transparent)]
+    #[derive(Default, Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct Scope: u64 {
+        /// `LANDLOCK_SCOPE_ABSTRACT_UNIX_SOCKET`
+        const ABSTRACT_UNIX_SOCKET = ll::LANDLOCK_SCOPE_ABSTRACT_UNIX_SOCKET as u64;
+
+        /// `LANDLOCK_SCOPE_SIGNAL`
+        const SIGNAL = ll::LANDLOCK_SCOPE_SIGNAL as u64;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
+bitflags! {
+    /// `LANDLOCK_RESTRICT_SELF_*` constants for use with [`restrict_self`].
+    ///
+    /// These are available since ABI version 7.
+    ///
+    /// [`restrict_self`]: crate::landlock::restrict_self
+    #[repr(transparent)]
+    #[derive(Default, Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct RestrictSelfFlags: u32 {
+        /// `LANDLOCK_RESTRICT_SELF_LOG_SAME_EXEC_OFF`
+        const LOG_SAME_EXEC_OFF = ll::LANDLOCK_RESTRICT_SELF_LOG_SAME_EXEC_OFF;
+
+        /// `LANDLOCK_RESTRICT_SELF_LOG_NEW_EXEC_ON`
+        const LOG_NEW_EXEC_ON = ll::LANDLOCK_RESTRICT_SELF_LOG_NEW_EXEC_ON;
+
+        /// `LANDLOCK_RESTRICT_SELF_LOG_SUBDOMAINS_OFF`
+        const LOG_SUBDOMAINS_OFF = ll::LANDLOCK_RESTRICT_SELF_LOG_SUBDOMAINS_OFF;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
@@ -227,6 +227,10 @@ pub mod io;
 #[cfg_attr(docsrs, doc(cfg(feature = "io_uring")))]
 pub mod io_uring;
 pub mod ioctl;
+#[cfg(linux_kernel)]
+#[cfg(feature = "landlock")]
+#[cfg_attr(docsrs, doc(cfg(feature = "landlock")))]
+pub mod landlock;
 #[cfg(not(any(
     windows,
     target_os = "espidf",
@@ -241,6 +245,10 @@ pub mod mm;
 #[cfg(feature = "mount")]
 #[cfg_attr(docsrs, doc(cfg(feature = "mount")))]
 pub mod mount;
+#[cfg(any(linux_kernel, target_os = "dragonfly", target_os = "netbsd"))]
+#[cfg(feature = "mq")]
+#[cfg_attr(docsrs, doc(cfg(feature = "mq")))]
+pub mod mq;
 #[cfg(not(target_os = "wasi"))]
 #[cfg(feature = "net")]
 #[cfg_attr(docsrs, doc(cfg(feature = "net")))]
@@ -264,6 +272,10 @@ pub mod pipe;
 #[cfg(feature = "process")]
 #[cfg_attr(docsrs, doc(cfg(feature = "process")))]
 pub mod process;
+#[cfg(linux_kernel)]
+#[cfg(feature = "ptrace")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ptrace")))]
+pub mod ptrace;
 #[cfg(not(windows))]
 #[cfg(not(target_os = "wasi"))]
 #[cfg(feature = "pty")]
@@ -273,6 +285,10 @@ pub mod pty;
 #[cfg(feature = "rand")]
 #[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
 pub mod rand;
+#[cfg(linux_kernel)]
+#[cfg(feature = "seccomp")]
+#[cfg_attr(docsrs, doc(cfg(feature = "seccomp")))]
+pub mod seccomp;
 #[cfg(not(any(
     windows,
     target_os = "android",
@@ -292,6 +308,23 @@ pub mod stdio;
 #[cfg(not(any(windows, target_os = "wasi")))]
 #[cfg_attr(docsrs, doc(cfg(feature = "system")))]
 pub mod system;
+// The `ipc64_perm` and `*64_ds` layouts in the sysv backends are only
+// correct for the architectures listed here. Others, such as powerpc64,
+// s390x, and mips64, have their own layouts in `asm/ipcbuf.h`, `asm/shmbuf.h`,
+// `asm/sembuf.h`, and `asm/msgbuf.h`, and mips64 additionally needs `IPC_64`.
+#[cfg(all(
+    linux_kernel,
+    any(
+        target_arch = "aarch64",
+        target_arch = "arm",
+        target_arch = "riscv64",
+        target_arch = "x86",
+        all(target_arch = "x86_64", target_pointer_width = "64"),
+    )
+))]
+#[cfg(feature = "sysv")]
+#[cfg_attr(docsrs, doc(cfg(feature = "sysv")))]
+pub mod sysv;
 #[cfg(not(any(windows, target_os = "horizon", target_os = "vita")))]
 #[cfg(feature = "termios")]
 #[cfg_attr(docsrs, doc(cfg(feature = "termios")))]
@@ -354,7 +387,7 @@ pub(crate) mod path;
 #[cfg(any(feature = "thread", feature = "time"))]
 mod clockid;
 #[cfg(linux_kernel)]
-#[cfg(any(feature = "io_uring", feature = "runtime"))]
+#[cfg(any(feature = "event", feature = "io_uring", feature = "runtime"))]
 mod kernel_sigset;
 #[cfg(not(any(windows, target_os = "wasi")))]
 #[cfg(any(
@@ -363,7 +396,11 @@ mod kernel_sigset;
     feature = "termios",
     feature = "thread",
     all(bsd, feature = "event"),
-    all(linux_kernel, feature = "net")
+    all(linux_kernel, feature = "mm"),
+    all(linux_kernel, feature = "net"),
+    all(linux_kernel, feature = "seccomp"),
+    all(linux_kernel, feature = "sysv"),
+    all(target_os = "linux", feature = "fs")
 ))]
 mod pid;
 #[cfg(any(feature = "process", feature = "thread"))]
@@ -374,7 +411,13 @@ mod prctl;
     feature = "io_uring",
     feature = "process",
     feature = "runtime",
-    all(bsd, feature = "event")
+    all(linux_kernel, feature = "thread"),
+    all(bsd, feature = "event"),
+    all(linux_kernel, feature = "event"),
+    all(
+        any(linux_kernel, target_os = "dragonfly", target_os = "netbsd"),
+        feature = "mq"
+    )
 ))]
 mod signal;
 #[cfg(any(
@@ -385,6 +428,7 @@ mod signal;
     feature = "thread",
     feature = "time",
     all(feature = "event", any(bsd, linux_kernel, windows, target_os = "wasi")),
+    all(feature = "net", target_os = "linux"),
     all(
         linux_raw,
         not(feature = "use-libc-auxv"),
@@ -0,0 +1,31 @@
+//! The Linux `memfd_secret` API.
+
+use crate::fd::OwnedFd;
+use crate::{backend, io};
+
+pub use backend::mm::types::MemfdSecretFlags;
+
+/// `memfd_secret(flags)`—Create an anonymous file for secret memory.
+///
+/// The returned file descriptor is sized with [`ftruncate`] and then mapped
+/// with [`mmap`] using [`MapFlags::SHARED`]. The mapped pages are removed
+/// from the kernel's direct map, so they're only accessible from the
+/// processes which map them, and they're never swapped out.
+///
+/// If the kernel doesn't support `memfd_secret`, this fails with
+/// [`io::Errno::NOSYS`]. It must also be enabled at boot time with the
+/// `secretmem.enable=1` kernel parameter before Linux 6.5.
+///
+/// This function was added in Linux 5.14.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/memfd_secret.2.html
+/// [`ftruncate`]: https://docs.rs/rustix/*/rustix/fs/fn.ftruncate.html
+/// [`mmap`]: crate::mm::mmap
+/// [`MapFlags::SHARED`]: crate::mm::MapFlags::SHARED
+#[inline]
+pub fn memfd_secret(flags: MemfdSecretFlags) -> io::Result<OwnedFd> {
+    backend::mm::syscalls::memfd_secret(flags)
+}
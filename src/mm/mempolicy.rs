@@ -0,0 +1,539 @@
+//! NUMA memory policy operations.
+
+#![allow(unsafe_code)]
+
+use crate::backend;
+use crate::ffi::{c_int, c_ulong, c_void};
+use crate::io::{self, Errno};
+use bitflags::bitflags;
+use core::{fmt, mem};
+use linux_raw_sys::mempolicy::{
+    self as sys, MPOL_F_ADDR, MPOL_F_MEMS_ALLOWED, MPOL_F_NODE, MPOL_MODE_FLAGS,
+};
+
+pub use crate::pid::Pid;
+
+/// `MPOL_*` memory policy modes for use with [`set_mempolicy`], [`mbind`],
+/// and [`get_mempolicy`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[repr(u32)]
+#[non_exhaustive]
+pub enum MemPolicyMode {
+    /// `MPOL_DEFAULT`
+    Default = sys::MPOL_DEFAULT as _,
+    /// `MPOL_PREFERRED`
+    Preferred = sys::MPOL_PREFERRED as _,
+    /// `MPOL_BIND`
+    Bind = sys::MPOL_BIND as _,
+    /// `MPOL_INTERLEAVE`
+    Interleave = sys::MPOL_INTERLEAVE as _,
+    /// `MPOL_LOCAL` (since Linux 3.8)
+    Local = sys::MPOL_LOCAL as _,
+    /// `MPOL_PREFERRED_MANY` (since Linux 5.15)
+    PreferredMany = sys::MPOL_PREFERRED_MANY as _,
+    /// `MPOL_WEIGHTED_INTERLEAVE` (since Linux 6.9)
+    WeightedInterleave = sys::MPOL_WEIGHTED_INTERLEAVE as _,
+}
+
+impl MemPolicyMode {
+    /// Convert a raw `MPOL_*` value into a `MemPolicyMode`, if it is a known
+    /// mode.
+    #[inline]
+    pub const fn from_raw(raw: u32) -> Option<Self> {
+        const DEFAULT: u32 = MemPolicyMode::Default.as_raw();
+        const PREFERRED: u32 = MemPolicyMode::Preferred.as_raw();
+        const BIND: u32 = MemPolicyMode::Bind.as_raw();
+        const INTERLEAVE: u32 = MemPolicyMode::Interleave.as_raw();
+        const LOCAL: u32 = MemPolicyMode::Local.as_raw();
+        const PREFERRED_MANY: u32 = MemPolicyMode::PreferredMany.as_raw();
+        const WEIGHTED_INTERLEAVE: u32 = MemPolicyMode::WeightedInterleave.as_raw();
+        match raw {
+            DEFAULT => Some(Self::Default),
+            PREFERRED => Some(Self::Preferred),
+            BIND => Some(Self::Bind),
+            INTERLEAVE => Some(Self::Interleave),
+            LOCAL => Some(Self::Local),
+            PREFERRED_MANY => Some(Self::PreferredMany),
+            WEIGHTED_INTERLEAVE => Some(Self::WeightedInterleave),
+            _ => None,
+        }
+    }
+
+    /// Convert a `MemPolicyMode` into its raw `MPOL_*` value.
+    #[inline]
+    pub const fn as_raw(self) -> u32 {
+        self as u32
+    }
+}
+
+bitflags! {
+    /// `MPOL_F_*` mode flags for use with [`set_mempolicy`] and [`mbind`].
+    #[repr(transparent)]
+    #[derive(Default, Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct MemPolicyFlags: u32 {
+        /// `MPOL_F_STATIC_NODES`
+        const STATIC_NODES = sys::MPOL_F_STATIC_NODES;
+        /// `MPOL_F_RELATIVE_NODES`
+        const RELATIVE_NODES = sys::MPOL_F_RELATIVE_NODES;
+        /// `MPOL_F_NUMA_BALANCING` (since Linux 5.12)
+        const NUMA_BALANCING = sys::MPOL_F_NUMA_BALANCING;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
+bitflags! {
+    /// `MPOL_MF_*` flags for use with [`mbind`].
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct MbindFlags: u32 {
+        /// `MPOL_MF_STRICT`
+        const STRICT = sys::MPOL_MF_STRICT;
+        /// `MPOL_MF_MOVE`
+        const MOVE = sys::MPOL_MF_MOVE;
+        /// `MPOL_MF_MOVE_ALL`
+        const MOVE_ALL = sys::MPOL_MF_MOVE_ALL;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
+bitflags! {
+    /// `MPOL_MF_*` flags for use with [`move_pages`].
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct MovePagesFlags: u32 {
+        /// `MPOL_MF_MOVE`
+        const MOVE = sys::MPOL_MF_MOVE;
+        /// `MPOL_MF_MOVE_ALL`
+        const MOVE_ALL = sys::MPOL_MF_MOVE_ALL;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
+/// The number of bits in a [`NodeMask`]. This matches the largest
+/// `MAX_NUMNODES` the kernel can be configured with.
+const NODE_MASK_BITS: usize = 1024;
+
+/// `NodeMask` represents a bit-mask of NUMA nodes.
+///
+/// `NodeMask`s are used by [`set_mempolicy`], [`mbind`], [`get_mempolicy`],
+/// and [`migrate_pages`], for example.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/set_mempolicy.2.html
+#[repr(transparent)]
+#[derive(Clone, Copy, Eq, PartialEq, Hash)]
+pub struct NodeMask {
+    bits: [c_ulong; NODE_MASK_BITS / (8 * mem::size_of::<c_ulong>())],
+}
+
+impl NodeMask {
+    /// The maximum number of nodes in `NodeMask`.
+    pub const MAX_NODE: usize = NODE_MASK_BITS;
+
+    /// The number of bits per element of `bits`.
+    const WORD_BITS: usize = 8 * mem::size_of::<c_ulong>();
+
+    /// Create a new and empty `NodeMask`.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            bits: [0; NODE_MASK_BITS / (8 * mem::size_of::<c_ulong>())],
+        }
+    }
+
+    /// Test to see if a node is in the `NodeMask`.
+    ///
+    /// `field` is the node id to test. Node ids of [`NodeMask::MAX_NODE`] or
+    /// more are never in the mask.
+    #[inline]
+    pub fn is_set(&self, field: usize) -> bool {
+        let (idx, offset) = (field / Self::WORD_BITS, field % Self::WORD_BITS);
+        match self.bits.get(idx) {
+            Some(word) => (word & (1 << offset)) != 0,
+            None => false,
+        }
+    }
+
+    /// Add a node to `NodeMask`.
+    ///
+    /// `field` is the node id to add. Node ids of [`NodeMask::MAX_NODE`] or
+    /// more can't name a node the kernel supports, so they're ignored.
+    #[inline]
+    pub fn set(&mut self, field: usize) {
+        let (idx, offset) = (field / Self::WORD_BITS, field % Self::WORD_BITS);
+        if let Some(word) = self.bits.get_mut(idx) {
+            *word |= 1 << offset;
+        }
+    }
+
+    /// Remove a node from `NodeMask`.
+    ///
+    /// `field` is the node id to remove. Node ids of [`NodeMask::MAX_NODE`]
+    /// or more are ignored.
+    #[inline]
+    pub fn unset(&mut self, field: usize) {
+        let (idx, offset) = (field / Self::WORD_BITS, field % Self::WORD_BITS);
+        if let Some(word) = self.bits.get_mut(idx) {
+            *word &= !(1 << offset);
+        }
+    }
+
+    /// Count the number of nodes set in the `NodeMask`.
+    #[inline]
+    pub fn count(&self) -> u32 {
+        self.bits.iter().map(|word| word.count_ones()).sum()
+    }
+
+    /// Zeroes the `NodeMask`.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.bits.fill(0)
+    }
+
+    /// The `maxnode` argument to pass to the kernel along with a `NodeMask`.
+    ///
+    /// The kernel ignores the last bit of `maxnode` bits, so we pass one more
+    /// than the number of bits we have, as libnuma does.
+    #[inline]
+    fn maxnode() -> usize {
+        NODE_MASK_BITS + 1
+    }
+}
+
+impl Default for NodeMask {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for NodeMask {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "NodeMask {{")?;
+        let mut first = true;
+        for i in 0..Self::MAX_NODE {
+            if self.is_set(i) {
+                if first {
+                    write!(f, " ")?;
+                    first = false;
+                } else {
+                    write!(f, ", ")?;
+                }
+                write!(f, "node{}", i)?;
+            }
+        }
+        write!(f, " }}")
+    }
+}
+
+/// A memory policy, as returned by [`get_mempolicy`] and
+/// [`get_mempolicy_addr`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub struct MemPolicy {
+    /// The policy mode.
+    pub mode: MemPolicyMode,
+
+    /// The mode flags the policy was set with.
+    pub flags: MemPolicyFlags,
+
+    /// The nodes the policy applies to.
+    ///
+    /// This is empty for [`MemPolicyMode::Default`] and
+    /// [`MemPolicyMode::Local`].
+    pub nodes: NodeMask,
+}
+
+impl MemPolicy {
+    /// Decode the `mode` value written by `get_mempolicy`, which contains
+    /// both the mode and the mode flags.
+    fn decode(raw: c_int, nodes: NodeMask) -> io::Result<Self> {
+        let raw = raw as u32;
+        Ok(Self {
+            // The kernel only reports modes it supports, so an unknown mode
+            // here means the kernel is newer than this version of rustix.
+            mode: MemPolicyMode::from_raw(raw & !MPOL_MODE_FLAGS).ok_or(Errno::NOTSUP)?,
+            flags: MemPolicyFlags::from_bits_retain(raw & MPOL_MODE_FLAGS),
+            nodes,
+        })
+    }
+}
+
+/// The status of a page after a call to [`move_pages`].
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+pub struct PageStatus(c_int);
+
+impl PageStatus {
+    /// Create a new `PageStatus`, to be filled in by [`move_pages`].
+    #[inline]
+    pub const fn new() -> Self {
+        Self(-(Errno::NOENT.raw_os_error()))
+    }
+
+    /// Returns the node the page is on, or the error that prevented it from
+    /// being queried or moved.
+    ///
+    /// Common errors include [`Errno::NOENT`] if the page isn't present, such
+    /// as if it was never touched, and [`Errno::FAULT`] for an address which
+    /// isn't mapped.
+    #[inline]
+    pub fn node(self) -> io::Result<u32> {
+        if self.0 >= 0 {
+            Ok(self.0 as u32)
+        } else {
+            Err(Errno::from_raw_os_error(-self.0))
+        }
+    }
+
+    /// Returns the raw status value, which is a node id or a negated `errno`
+    /// value.
+    #[inline]
+    pub const fn as_raw(self) -> c_int {
+        self.0
+    }
+}
+
+impl Default for PageStatus {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for PageStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.node() {
+            Ok(node) => f.debug_tuple("PageStatus").field(&node).finish(),
+            Err(err) => f.debug_tuple("PageStatus").field(&err).finish(),
+        }
+    }
+}
+
+/// `set_mempolicy(mode | mode_flags, nodes, maxnode)`—Sets the NUMA memory
+/// policy of the current thread.
+///
+/// For [`MemPolicyMode::Default`] and [`MemPolicyMode::Local`], `nodes` must
+/// be empty.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/set_mempolicy.2.html
+#[inline]
+pub fn set_mempolicy(
+    mode: MemPolicyMode,
+    mode_flags: MemPolicyFlags,
+    nodes: &NodeMask,
+) -> io::Result<()> {
+    backend::mm::syscalls::set_mempolicy(
+        (mode.as_raw() | mode_flags.bits()) as c_int,
+        nodes.bits.as_ptr(),
+        NodeMask::maxnode(),
+    )
+}
+
+/// `get_mempolicy(&mode, &nodes, maxnode, NULL, 0)`—Returns the NUMA memory
+/// policy of the current thread.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/get_mempolicy.2.html
+#[inline]
+pub fn get_mempolicy() -> io::Result<MemPolicy> {
+    let mut mode = 0;
+    let mut nodes = NodeMask::new();
+    backend::mm::syscalls::get_mempolicy(
+        &mut mode,
+        nodes.bits.as_mut_ptr(),
+        NodeMask::maxnode(),
+        core::ptr::null(),
+        0,
+    )?;
+    MemPolicy::decode(mode, nodes)
+}
+
+/// `get_mempolicy(&mode, &nodes, maxnode, addr, MPOL_F_ADDR)`—Returns the
+/// NUMA memory policy which applies to the page containing `addr`.
+///
+/// If no policy has been set for the page with [`mbind`], this is the policy
+/// of the current thread.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/get_mempolicy.2.html
+#[doc(alias = "get_mempolicy")]
+#[inline]
+pub fn get_mempolicy_addr(addr: *const c_void) -> io::Result<MemPolicy> {
+    let mut mode = 0;
+    let mut nodes = NodeMask::new();
+    backend::mm::syscalls::get_mempolicy(
+        &mut mode,
+        nodes.bits.as_mut_ptr(),
+        NodeMask::maxnode(),
+        addr,
+        MPOL_F_ADDR,
+    )?;
+    MemPolicy::decode(mode, nodes)
+}
+
+/// `get_mempolicy(&node, NULL, 0, addr, MPOL_F_NODE | MPOL_F_ADDR)`—Returns
+/// the node that the page containing `addr` is allocated on.
+///
+/// If the page isn't allocated yet, this allocates it, as if it were read.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/get_mempolicy.2.html
+#[doc(alias = "get_mempolicy")]
+#[inline]
+pub fn get_mempolicy_node(addr: *const c_void) -> io::Result<u32> {
+    let mut node = 0;
+    backend::mm::syscalls::get_mempolicy(
+        &mut node,
+        core::ptr::null_mut(),
+        0,
+        addr,
+        MPOL_F_NODE | MPOL_F_ADDR,
+    )?;
+    Ok(node as u32)
+}
+
+/// `get_mempolicy(NULL, &nodes, maxnode, NULL, MPOL_F_MEMS_ALLOWED)`—Returns
+/// the set of nodes the current thread is allowed to use.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/get_mempolicy.2.html
+#[doc(alias = "get_mempolicy")]
+#[inline]
+pub fn get_mems_allowed() -> io::Result<NodeMask> {
+    let mut nodes = NodeMask::new();
+    backend::mm::syscalls::get_mempolicy(
+        core::ptr::null_mut(),
+        nodes.bits.as_mut_ptr(),
+        NodeMask::maxnode(),
+        core::ptr::null(),
+        MPOL_F_MEMS_ALLOWED,
+    )?;
+    Ok(nodes)
+}
+
+/// `mbind(addr, len, mode | mode_flags, nodes, maxnode, flags)`—Sets the
+/// NUMA memory policy for a range of memory.
+///
+/// For [`MemPolicyMode::Default`] and [`MemPolicyMode::Local`], `nodes` must
+/// be empty.
+///
+/// # Safety
+///
+/// The range of memory starting at `addr`, which must be page-aligned, and
+/// extending for `len` bytes, rounded up to the applicable page size, must
+/// be valid to read with `addr`'s provenance.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/mbind.2.html
+#[inline]
+pub unsafe fn mbind(
+    addr: *mut c_void,
+    len: usize,
+    mode: MemPolicyMode,
+    mode_flags: MemPolicyFlags,
+    nodes: &NodeMask,
+    flags: MbindFlags,
+) -> io::Result<()> {
+    backend::mm::syscalls::mbind(
+        addr,
+        len,
+        mode.as_raw() | mode_flags.bits(),
+        nodes.bits.as_ptr(),
+        NodeMask::maxnode(),
+        flags.bits(),
+    )
+}
+
+/// `migrate_pages(pid, maxnode, old_nodes, new_nodes)`—Moves all pages of a
+/// process from one set of nodes to another.
+///
+/// `pid` is the process to update. If `pid` is `None`, then the current
+/// process is updated.
+///
+/// On success, returns the number of pages which couldn't be moved.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/migrate_pages.2.html
+#[inline]
+pub fn migrate_pages(
+    pid: Option<Pid>,
+    old_nodes: &NodeMask,
+    new_nodes: &NodeMask,
+) -> io::Result<usize> {
+    backend::mm::syscalls::migrate_pages(
+        pid,
+        NodeMask::maxnode(),
+        old_nodes.bits.as_ptr(),
+        new_nodes.bits.as_ptr(),
+    )
+}
+
+/// `move_pages(pid, pages.len(), pages, nodes, status, flags)`—Moves
+/// individual pages of a process to other nodes, or queries which nodes they
+/// are on.
+///
+/// `pid` is the process to update. If `pid` is `None`, then the current
+/// process is updated.
+///
+/// If `nodes` is `Some`, each page in `pages` is moved to the corresponding
+/// node in `nodes`. If `nodes` is `None`, no pages are moved, and `status`
+/// reports the node each page is currently on. Either way, the status of
+/// each page is written to the corresponding element of `status`. `nodes`
+/// and `status` must have the same length as `pages`.
+///
+/// On success, returns the number of pages which couldn't be moved.
+///
+/// # Safety
+///
+/// If `pid` is `None` and `nodes` is `Some`, each pointer in `pages` which
+/// is in a mapping must be valid to read with its provenance.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/move_pages.2.html
+#[inline]
+pub unsafe fn move_pages(
+    pid: Option<Pid>,
+    pages: &[*const c_void],
+    nodes: Option<&[c_int]>,
+    status: &mut [PageStatus],
+    flags: MovePagesFlags,
+) -> io::Result<usize> {
+    if status.len() != pages.len() || nodes.map_or(false, |nodes| nodes.len() != pages.len()) {
+        return Err(Errno::INVAL);
+    }
+
+    backend::mm::syscalls::move_pages(
+        pid,
+        pages.len(),
+        pages.as_ptr(),
+        nodes.map_or(core::ptr::null(), <[c_int]>::as_ptr),
+        status.as_mut_ptr().cast::<c_int>(),
+        flags.bits() as c_int,
+    )
+}
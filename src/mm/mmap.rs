@@ -441,3 +441,30 @@ pub fn mlockall(flags: MlockAllFlags) -> io::Result<()> {
 pub fn munlockall() -> io::Result<()> {
     backend::mm::syscalls::munlockall()
 }
+
+/// `mseal(ptr, len, 0)`—Seal a memory mapping against modification.
+///
+/// Once sealed, the mapping can't be unmapped, moved, shrunk, or expanded,
+/// and its protection flags can't be changed, for the rest of the process'
+/// lifetime. The range must be page-aligned and fully mapped.
+///
+/// On kernels which don't support `mseal`, or on 32-bit platforms, this
+/// fails with [`io::Errno::NOSYS`].
+///
+/// This function was added in Linux 6.10.
+///
+/// # Safety
+///
+/// The range of memory starting at `ptr` and extending for `len` bytes,
+/// rounded up to the applicable page size, must be valid to read with `ptr`'s
+/// provenance, and must not be owned by code which expects to unmap it.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/userspace-api/mseal.html
+#[cfg(linux_kernel)]
+#[inline]
+pub unsafe fn mseal(ptr: *mut c_void, len: usize) -> io::Result<()> {
+    backend::mm::syscalls::mseal(ptr, len)
+}
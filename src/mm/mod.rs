@@ -2,14 +2,44 @@
 
 #[cfg(not(target_os = "redox"))]
 mod madvise;
+#[cfg(all(
+    linux_kernel,
+    any(
+        target_arch = "aarch64",
+        target_arch = "riscv64",
+        target_arch = "s390x",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    )
+))]
+mod memfd_secret;
+#[cfg(linux_kernel)]
+mod mempolicy;
 mod mmap;
 mod msync;
 #[cfg(linux_kernel)]
+mod pkey;
+#[cfg(linux_kernel)]
 mod userfaultfd;
 
 #[cfg(not(target_os = "redox"))]
 pub use madvise::{madvise, Advice};
+#[cfg(all(
+    linux_kernel,
+    any(
+        target_arch = "aarch64",
+        target_arch = "riscv64",
+        target_arch = "s390x",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    )
+))]
+pub use memfd_secret::{memfd_secret, MemfdSecretFlags};
+#[cfg(linux_kernel)]
+pub use mempolicy::*;
 pub use mmap::*;
 pub use msync::{msync, MsyncFlags};
 #[cfg(linux_kernel)]
+pub use pkey::*;
+#[cfg(linux_kernel)]
 pub use userfaultfd::{userfaultfd, UserfaultfdFlags};
@@ -0,0 +1,157 @@
+//! Memory protection keys.
+
+#![allow(unsafe_code)]
+
+use crate::backend;
+use crate::ffi::c_void;
+use crate::io;
+use crate::mm::MprotectFlags;
+use bitflags::bitflags;
+
+bitflags! {
+    /// `PKEY_*` flags for use with [`pkey_alloc`] and [`pkey_set`].
+    #[repr(transparent)]
+    #[derive(Default, Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct PkeyAccessRights: u32 {
+        /// `PKEY_DISABLE_ACCESS`
+        const DISABLE_ACCESS = linux_raw_sys::general::PKEY_DISABLE_ACCESS;
+        /// `PKEY_DISABLE_WRITE`
+        const DISABLE_WRITE = linux_raw_sys::general::PKEY_DISABLE_WRITE;
+        /// `PKEY_DISABLE_EXECUTE`
+        #[cfg(any(
+            target_arch = "aarch64",
+            target_arch = "powerpc",
+            target_arch = "powerpc64"
+        ))]
+        const DISABLE_EXECUTE = linux_raw_sys::general::PKEY_DISABLE_EXECUTE;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
+/// `pkey_alloc(0, access_rights)`—Allocate a protection key.
+///
+/// The calling thread's access rights for the new key are set to
+/// `access_rights`; other threads get the default access rights, which on
+/// x86 deny all access. Pages are associated with the key with
+/// [`pkey_mprotect`].
+///
+/// If the CPU or kernel doesn't support protection keys, or all keys are
+/// allocated, this fails with [`io::Errno::NOSPC`]. If `access_rights`
+/// contains rights the architecture doesn't support, this fails with
+/// [`io::Errno::INVAL`].
+///
+/// This function was added in Linux 4.9.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/pkey_alloc.2.html
+#[inline]
+pub fn pkey_alloc(access_rights: PkeyAccessRights) -> io::Result<u32> {
+    backend::mm::syscalls::pkey_alloc(0, access_rights.bits()).map(|pkey| pkey as u32)
+}
+
+/// `pkey_free(pkey)`—Free a protection key.
+///
+/// Pages associated with `pkey` should be reassociated with another key
+/// first, because if `pkey` is allocated again, they'll be associated with
+/// the new allocation.
+///
+/// This function was added in Linux 4.9.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/pkey_free.2.html
+#[inline]
+pub fn pkey_free(pkey: u32) -> io::Result<()> {
+    backend::mm::syscalls::pkey_free(pkey as _)
+}
+
+/// `pkey_mprotect(ptr, len, flags, pkey)`—Change the protection flags of an
+/// address range, and associate it with a protection key.
+///
+/// This function was added in Linux 4.9.
+///
+/// # Safety
+///
+/// The range of memory starting at `ptr` and extending for `len` bytes,
+/// rounded up to the applicable page size, must be valid to read with `ptr`'s
+/// provenance.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/pkey_mprotect.2.html
+#[inline]
+pub unsafe fn pkey_mprotect(
+    ptr: *mut c_void,
+    len: usize,
+    flags: MprotectFlags,
+    pkey: u32,
+) -> io::Result<()> {
+    backend::mm::syscalls::pkey_mprotect(ptr, len, flags, pkey as _)
+}
+
+/// `pkey_get(pkey)`—Get the calling thread's access rights for a protection
+/// key.
+///
+/// This reads the `PKRU` register. If the CPU or kernel doesn't support
+/// protection keys, or `pkey` is out of range, this fails with
+/// [`io::Errno::INVAL`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man3/pkey_get.3.html
+#[cfg(all(linux_raw, target_arch = "x86_64"))]
+#[doc(alias = "rdpkru")]
+#[inline]
+pub fn pkey_get(pkey: u32) -> io::Result<PkeyAccessRights> {
+    let shift = pkru_shift(pkey)?;
+    let pkru = backend::mm::syscalls::pkru_read()?;
+    Ok(PkeyAccessRights::from_bits_retain((pkru >> shift) & 0x3))
+}
+
+/// `pkey_set(pkey, access_rights)`—Set the calling thread's access rights for
+/// a protection key.
+///
+/// This writes the `PKRU` register, so it takes effect immediately, without
+/// a syscall. Accesses which the new rights deny fault with `SIGSEGV`. If the
+/// CPU or kernel doesn't support protection keys, or `pkey` is out of range,
+/// this fails with [`io::Errno::INVAL`].
+///
+/// # Safety
+///
+/// Any memory associated with `pkey` which the new rights deny access to
+/// must not be accessed by the calling thread, including through references,
+/// until its rights are restored.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man3/pkey_set.3.html
+#[cfg(all(linux_raw, target_arch = "x86_64"))]
+#[doc(alias = "wrpkru")]
+#[inline]
+pub unsafe fn pkey_set(pkey: u32, access_rights: PkeyAccessRights) -> io::Result<()> {
+    let shift = pkru_shift(pkey)?;
+    if access_rights.bits() & !0x3 != 0 {
+        return Err(io::Errno::INVAL);
+    }
+    let pkru = backend::mm::syscalls::pkru_read()?;
+    let pkru = (pkru & !(0x3 << shift)) | (access_rights.bits() << shift);
+    backend::mm::syscalls::pkru_write(pkru)
+}
+
+/// `PKRU` has two bits for each of the 16 keys.
+#[cfg(all(linux_raw, target_arch = "x86_64"))]
+fn pkru_shift(pkey: u32) -> io::Result<u32> {
+    if pkey < 16 {
+        Ok(pkey * 2)
+    } else {
+        Err(io::Errno::INVAL)
+    }
+}
@@ -1,9 +1,11 @@
 //! `fsopen` and related functions in Linux's `mount` API.
 
 use crate::backend::mount::types::{
-    FsMountFlags, FsOpenFlags, FsPickFlags, MountAttrFlags, MoveMountFlags, OpenTreeFlags,
+    FsMountFlags, FsOpenFlags, FsPickFlags, MountAttrFlags, MountSetattrFlags, MoveMountFlags,
+    OpenTreeFlags,
 };
 use crate::fd::{AsFd, OwnedFd};
+use crate::mount::MountAttr;
 use crate::{backend, io, path};
 
 /// `fsopen(fs_name, flags)`
@@ -81,6 +83,52 @@ pub fn open_tree<Path: path::Arg, Fd: AsFd>(
     filename.into_with_c_str(|filename| backend::mount::syscalls::open_tree(dfd, filename, flags))
 }
 
+/// `open_tree_attr(dfd, filename, flags, attr, sizeof(*attr))`—Like
+/// [`open_tree`], but also changes the attributes of the new mount.
+///
+/// `attr` may only be used when `flags` contains
+/// [`OpenTreeFlags::OPEN_TREE_CLONE`]. This function was added in Linux 6.15.
+///
+/// # References
+///  - [Unfinished draft]
+///  - [Linux `mount_setattr`]
+///
+/// [Unfinished draft]: https://github.com/sunfishcode/linux-mount-api-documentation/blob/main/open_tree.md
+/// [Linux `mount_setattr`]: https://man7.org/linux/man-pages/man2/mount_setattr.2.html
+#[inline]
+pub fn open_tree_attr<Path: path::Arg, Fd: AsFd>(
+    dfd: Fd,
+    filename: Path,
+    flags: OpenTreeFlags,
+    attr: Option<&MountAttr<'_>>,
+) -> io::Result<OwnedFd> {
+    let dfd = dfd.as_fd();
+    filename.into_with_c_str(|filename| {
+        backend::mount::syscalls::open_tree_attr(dfd, filename, flags, attr)
+    })
+}
+
+/// `mount_setattr(dfd, path, flags, attr, sizeof(*attr))`—Changes the
+/// attributes of a mount, or with [`MountSetattrFlags::AT_RECURSIVE`], a
+/// mount tree.
+///
+/// This function was added in Linux 5.12.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/mount_setattr.2.html
+#[inline]
+pub fn mount_setattr<Path: path::Arg, Fd: AsFd>(
+    dfd: Fd,
+    path: Path,
+    flags: MountSetattrFlags,
+    attr: &MountAttr<'_>,
+) -> io::Result<()> {
+    let dfd = dfd.as_fd();
+    path.into_with_c_str(|path| backend::mount::syscalls::mount_setattr(dfd, path, flags, attr))
+}
+
 /// `fspick(dfd, path, flags)`
 ///
 /// # References
@@ -1 +1,45 @@
+use crate::fd::BorrowedFd;
+
 pub use crate::backend::mount::types::*;
+
+/// `struct mount_attr`—Mount attribute changes for use with
+/// [`mount_setattr`] and [`open_tree_attr`].
+///
+/// To change the access time behavior, include [`MOUNT_ATTR__ATIME`] in
+/// `attr_clr` along with the new access time flag in `attr_set`.
+///
+/// [`mount_setattr`]: crate::mount::mount_setattr
+/// [`open_tree_attr`]: crate::mount::open_tree_attr
+/// [`MOUNT_ATTR__ATIME`]: MountAttrFlags::MOUNT_ATTR__ATIME
+#[derive(Copy, Clone, Debug)]
+#[doc(alias = "mount_attr")]
+pub struct MountAttr<'a> {
+    /// Attributes to set.
+    pub attr_set: MountAttrFlags,
+
+    /// Attributes to clear.
+    pub attr_clr: MountAttrFlags,
+
+    /// The new propagation type, which is one of
+    /// [`MountPropagationFlags::SHARED`], [`MountPropagationFlags::PRIVATE`],
+    /// [`MountPropagationFlags::DOWNSTREAM`], or
+    /// [`MountPropagationFlags::UNBINDABLE`], or empty to leave the
+    /// propagation type unchanged.
+    pub propagation: MountPropagationFlags,
+
+    /// The user namespace to use for the idmapping, when `attr_set` contains
+    /// [`MountAttrFlags::MOUNT_ATTR_IDMAP`].
+    pub userns_fd: Option<BorrowedFd<'a>>,
+}
+
+impl Default for MountAttr<'_> {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            attr_set: MountAttrFlags::empty(),
+            attr_clr: MountAttrFlags::empty(),
+            propagation: MountPropagationFlags::empty(),
+            userns_fd: None,
+        }
+    }
+}
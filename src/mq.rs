@@ -0,0 +1,316 @@
+//! POSIX message queues
+//!
+//! Message queue descriptors are file descriptors, so they can be polled with
+//! [`event::epoll`] and friends.
+//!
+//! # Examples
+//!
+//! ```
+//! use rustix::fs::Mode;
+//! use rustix::{io, mq};
+//!
+//! # fn example() -> io::Result<()> {
+//! // Create the message queue.
+//! let mq_name = "/rustix-mq-example";
+//! let mqdes = mq::open(
+//!     mq_name,
+//!     mq::OFlags::CREATE | mq::OFlags::EXCL | mq::OFlags::RDWR,
+//!     Mode::RUSR | Mode::WUSR,
+//!     None,
+//! )?;
+//!
+//! // Send a message.
+//! mq::send(&mqdes, b"hello", 0)?;
+//!
+//! // Receive it. The buffer must be at least as large as the queue's
+//! // maximum message size.
+//! let mut buf = vec![0_u8; mq::getattr(&mqdes)?.msgsize];
+//! let (len, priority) = mq::receive(&mqdes, &mut buf)?;
+//! assert_eq!(&buf[..len], b"hello");
+//! assert_eq!(priority, 0);
+//!
+//! // Remove the message queue name.
+//! mq::unlink(mq_name)?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [`event::epoll`]: https://docs.rs/rustix/*/rustix/event/epoll/index.html
+
+#![allow(unsafe_code)]
+
+use crate::buffer::Buffer;
+use crate::fd::{AsFd, BorrowedFd, OwnedFd};
+use crate::ffi::c_void;
+use crate::{backend, io, path};
+
+pub use crate::backend::fs::types::Mode;
+pub use crate::backend::mq::types::MqOFlags as OFlags;
+pub use crate::signal::Signal;
+pub use crate::timespec::Timespec;
+
+/// `struct mq_attr`—Message queue attributes.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9799919799/basedefs/mqueue.h.html
+/// [Linux]: https://man7.org/linux/man-pages/man3/mq_getattr.3.html
+#[doc(alias = "mq_attr")]
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct MqAttr {
+    /// `mq_flags`—Either empty or [`OFlags::NONBLOCK`].
+    pub flags: OFlags,
+
+    /// `mq_maxmsg`—The maximum number of messages on the queue.
+    pub maxmsg: usize,
+
+    /// `mq_msgsize`—The maximum size of each message, in bytes.
+    pub msgsize: usize,
+
+    /// `mq_curmsgs`—The number of messages currently on the queue.
+    ///
+    /// This is ignored by [`open`] and [`setattr`].
+    pub curmsgs: usize,
+}
+
+/// How to notify a process that a message has arrived on an empty queue, for
+/// use with [`notify`].
+#[doc(alias = "sigevent")]
+#[derive(Debug, Copy, Clone)]
+#[non_exhaustive]
+pub enum Notification<'a> {
+    /// `SIGEV_NONE`—Register for notification, but don't deliver one.
+    ///
+    /// This prevents other processes from registering.
+    #[doc(alias = "SIGEV_NONE")]
+    None,
+
+    /// `SIGEV_SIGNAL`—Send `signal` to the process, with `value` in the
+    /// `si_value` field of its `siginfo_t`.
+    #[doc(alias = "SIGEV_SIGNAL")]
+    Signal {
+        /// The signal to send.
+        signal: Signal,
+        /// The value to include with the signal.
+        value: *mut c_void,
+    },
+
+    /// `SIGEV_THREAD`—Send `cookie` as a message on `socket`, a netlink
+    /// socket of protocol `NETLINK_ROUTE`, when a message arrives.
+    ///
+    /// This is the kernel interface which libc implementations use to run a
+    /// notification function on a thread; the thread receives from `socket`
+    /// and dispatches on `cookie`. It is only available on Linux, and fails
+    /// with [`io::Errno::NOTSUP`] on other platforms.
+    #[doc(alias = "SIGEV_THREAD")]
+    Thread {
+        /// The socket to send the notification on.
+        socket: BorrowedFd<'a>,
+        /// The contents of the notification message.
+        cookie: &'a [u8; 32],
+    },
+}
+
+/// `mq_open(name, oflags, mode, attr)`—Opens a message queue.
+///
+/// For portability, `name` should begin with a slash, contain no other
+/// slashes, and be no longer than an implementation-defined limit (255 on
+/// Linux).
+///
+/// Exactly one of [`OFlags::RDONLY`], [`OFlags::WRONLY`], and
+/// [`OFlags::RDWR`] should be passed. If `attr` is `None`, the queue is
+/// created with default attributes; otherwise its `maxmsg` and `msgsize`
+/// fields are used. The file descriptor will be opened with `FD_CLOEXEC`
+/// set.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9799919799/functions/mq_open.html
+/// [Linux]: https://man7.org/linux/man-pages/man3/mq_open.3.html
+#[doc(alias = "mq_open")]
+#[inline]
+pub fn open<P: path::Arg>(
+    name: P,
+    flags: OFlags,
+    mode: Mode,
+    attr: Option<&MqAttr>,
+) -> io::Result<OwnedFd> {
+    name.into_with_c_str(|name| backend::mq::syscalls::mq_open(name, flags, mode, attr))
+}
+
+/// `mq_unlink(name)`—Unlinks a message queue.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9799919799/functions/mq_unlink.html
+/// [Linux]: https://man7.org/linux/man-pages/man3/mq_unlink.3.html
+#[doc(alias = "mq_unlink")]
+#[inline]
+pub fn unlink<P: path::Arg>(name: P) -> io::Result<()> {
+    name.into_with_c_str(backend::mq::syscalls::mq_unlink)
+}
+
+/// `mq_send(mqdes, msg, priority)`—Sends a message to a message queue.
+///
+/// If the queue is full, this blocks until there is room, unless the queue
+/// was opened with [`OFlags::NONBLOCK`], in which case it fails with
+/// [`io::Errno::AGAIN`].
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9799919799/functions/mq_send.html
+/// [Linux]: https://man7.org/linux/man-pages/man3/mq_send.3.html
+#[doc(alias = "mq_send")]
+#[inline]
+pub fn send<Fd: AsFd>(mqdes: Fd, msg: &[u8], priority: u32) -> io::Result<()> {
+    backend::mq::syscalls::mq_timedsend(mqdes.as_fd(), msg, priority, None)
+}
+
+/// `mq_timedsend(mqdes, msg, priority, abs_timeout)`—Sends a message to a
+/// message queue, with a timeout.
+///
+/// `abs_timeout` is an absolute time, measured against
+/// [`ClockId::Realtime`]. If the queue is still full at that time, this
+/// fails with [`io::Errno::TIMEDOUT`].
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9799919799/functions/mq_timedsend.html
+/// [Linux]: https://man7.org/linux/man-pages/man3/mq_timedsend.3.html
+/// [`ClockId::Realtime`]: https://docs.rs/rustix/*/rustix/time/enum.ClockId.html#variant.Realtime
+#[doc(alias = "mq_timedsend")]
+#[inline]
+pub fn timedsend<Fd: AsFd>(
+    mqdes: Fd,
+    msg: &[u8],
+    priority: u32,
+    abs_timeout: &Timespec,
+) -> io::Result<()> {
+    backend::mq::syscalls::mq_timedsend(mqdes.as_fd(), msg, priority, Some(abs_timeout))
+}
+
+/// `mq_receive(mqdes, buf, &priority)`—Receives the oldest of the highest
+/// priority messages from a message queue.
+///
+/// `buf` must be at least as long as the queue's [`MqAttr::msgsize`], or
+/// this fails with [`io::Errno::MSGSIZE`]. On success, returns the received
+/// message and its priority.
+///
+/// If the queue is empty, this blocks until a message arrives, unless the
+/// queue was opened with [`OFlags::NONBLOCK`], in which case it fails
+/// with [`io::Errno::AGAIN`].
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9799919799/functions/mq_receive.html
+/// [Linux]: https://man7.org/linux/man-pages/man3/mq_receive.3.html
+#[doc(alias = "mq_receive")]
+#[inline]
+pub fn receive<Fd: AsFd, Buf: Buffer<u8>>(mqdes: Fd, buf: Buf) -> io::Result<(Buf::Output, u32)> {
+    _receive(mqdes.as_fd(), buf, None)
+}
+
+/// `mq_timedreceive(mqdes, buf, &priority, abs_timeout)`—Receives the oldest
+/// of the highest priority messages from a message queue, with a timeout.
+///
+/// `abs_timeout` is an absolute time, measured against
+/// [`ClockId::Realtime`]. If the queue is still empty at that time, this
+/// fails with [`io::Errno::TIMEDOUT`].
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9799919799/functions/mq_timedreceive.html
+/// [Linux]: https://man7.org/linux/man-pages/man3/mq_timedreceive.3.html
+/// [`ClockId::Realtime`]: https://docs.rs/rustix/*/rustix/time/enum.ClockId.html#variant.Realtime
+#[doc(alias = "mq_timedreceive")]
+#[inline]
+pub fn timedreceive<Fd: AsFd, Buf: Buffer<u8>>(
+    mqdes: Fd,
+    buf: Buf,
+    abs_timeout: &Timespec,
+) -> io::Result<(Buf::Output, u32)> {
+    _receive(mqdes.as_fd(), buf, Some(abs_timeout))
+}
+
+fn _receive<Buf: Buffer<u8>>(
+    mqdes: BorrowedFd<'_>,
+    mut buf: Buf,
+    abs_timeout: Option<&Timespec>,
+) -> io::Result<(Buf::Output, u32)> {
+    let mut priority = 0;
+    // SAFETY: `mq_timedreceive` behaves.
+    unsafe {
+        let len = backend::mq::syscalls::mq_timedreceive(
+            mqdes,
+            buf.parts_mut(),
+            &mut priority,
+            abs_timeout,
+        )?;
+        Ok((buf.assume_init(len), priority))
+    }
+}
+
+/// `mq_getattr(mqdes)`—Returns the attributes of a message queue.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9799919799/functions/mq_getattr.html
+/// [Linux]: https://man7.org/linux/man-pages/man3/mq_getattr.3.html
+#[doc(alias = "mq_getattr")]
+#[inline]
+pub fn getattr<Fd: AsFd>(mqdes: Fd) -> io::Result<MqAttr> {
+    backend::mq::syscalls::mq_getsetattr(mqdes.as_fd(), None)
+}
+
+/// `mq_setattr(mqdes, attr)`—Sets the attributes of a message queue, and
+/// returns the previous attributes.
+///
+/// Only [`MqAttr::flags`] can be changed; the other fields are ignored.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9799919799/functions/mq_setattr.html
+/// [Linux]: https://man7.org/linux/man-pages/man3/mq_setattr.3.html
+#[doc(alias = "mq_setattr")]
+#[inline]
+pub fn setattr<Fd: AsFd>(mqdes: Fd, attr: &MqAttr) -> io::Result<MqAttr> {
+    backend::mq::syscalls::mq_getsetattr(mqdes.as_fd(), Some(attr))
+}
+
+/// `mq_notify(mqdes, notification)`—Registers or unregisters the calling
+/// process for notification when a message arrives on an empty message
+/// queue.
+///
+/// Only one process may be registered for a queue at a time, and a
+/// registration is removed once a notification is delivered. Pass `None` to
+/// remove the calling process's registration.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9799919799/functions/mq_notify.html
+/// [Linux]: https://man7.org/linux/man-pages/man3/mq_notify.3.html
+#[doc(alias = "mq_notify")]
+#[inline]
+pub fn notify<Fd: AsFd>(mqdes: Fd, notification: Option<Notification<'_>>) -> io::Result<()> {
+    backend::mq::syscalls::mq_notify(mqdes.as_fd(), notification)
+}
@@ -3,13 +3,19 @@
 #![allow(unsafe_code)]
 
 #[cfg(target_os = "linux")]
-use crate::backend::net::msghdr::noaddr_msghdr;
+use crate::backend::net::msghdr::{noaddr_msghdr, noaddr_recv_msghdr};
 use crate::backend::{self, c};
 use crate::fd::{AsFd, BorrowedFd, OwnedFd};
 use crate::io::{self, IoSlice, IoSliceMut};
 use crate::net::addr::SocketAddrArg;
+#[cfg(target_os = "linux")]
+use crate::net::addr::SocketAddrStorage;
+#[cfg(target_os = "linux")]
+use crate::net::SocketAddrBuf;
 #[cfg(linux_kernel)]
 use crate::net::UCred;
+#[cfg(target_os = "linux")]
+use crate::timespec::Timespec;
 use core::iter::FusedIterator;
 use core::marker::PhantomData;
 use core::mem::{align_of, size_of, size_of_val, take, MaybeUninit};
@@ -684,6 +690,149 @@ impl<'a> MMsgHdr<'a> {
     }
 }
 
+/// An ABI-compatible wrapper for `mmsghdr`, for receiving multiple messages
+/// with [recvmmsg].
+///
+/// After a successful call to [recvmmsg], the results for each message that
+/// was received can be obtained from its header.
+#[cfg(target_os = "linux")]
+#[repr(transparent)]
+pub struct RecvMMsgHdr<'a> {
+    raw: c::mmsghdr,
+    _phantom: PhantomData<&'a mut ()>,
+}
+
+#[cfg(target_os = "linux")]
+impl<'a> RecvMMsgHdr<'a> {
+    /// Constructs a new message header which doesn't record the address of
+    /// the sender.
+    pub fn new(iov: &'a mut [IoSliceMut<'_>], control: &'a mut RecvAncillaryBuffer<'_>) -> Self {
+        Self::wrap(noaddr_recv_msghdr(iov, control))
+    }
+
+    /// Constructs a new message header which records the address of the
+    /// sender in `addr`.
+    pub fn new_with_addr(
+        addr: &'a mut MaybeUninit<SocketAddrStorage>,
+        iov: &'a mut [IoSliceMut<'_>],
+        control: &'a mut RecvAncillaryBuffer<'_>,
+    ) -> Self {
+        let mut msghdr = noaddr_recv_msghdr(iov, control);
+        msghdr.msg_name = addr.as_mut_ptr().cast();
+        msghdr.msg_namelen = bitcast!(size_of::<SocketAddrStorage>() as c::socklen_t);
+
+        Self::wrap(msghdr)
+    }
+
+    fn wrap(mut msg_hdr: c::msghdr) -> Self {
+        // The kernel never reports `MSG_CMSG_COMPAT` in `msg_flags`, so all
+        // ones can't be a valid result, and we use it to mark headers that
+        // haven't received a message yet.
+        msg_hdr.msg_flags = !0;
+
+        Self {
+            raw: c::mmsghdr {
+                msg_hdr,
+                msg_len: 0,
+            },
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Returns `true` if a message has been received into this header.
+    #[inline]
+    pub fn is_received(&self) -> bool {
+        self.raw.msg_hdr.msg_flags != !0
+    }
+
+    /// Returns the number of bytes received. This will return 0 until after a
+    /// successful call to [recvmmsg] has received a message into this header.
+    ///
+    /// When `RecvFlags::TRUNC` is in use, this may be greater than the length
+    /// of the buffers, as it reflects the number of bytes received before
+    /// truncation into the buffers.
+    #[inline]
+    pub fn bytes_received(&self) -> usize {
+        self.raw.msg_len as usize
+    }
+
+    /// Returns the flags received. This will return empty flags until after a
+    /// successful call to [recvmmsg] has received a message into this header.
+    #[inline]
+    pub fn flags(&self) -> ReturnFlags {
+        if self.is_received() {
+            ReturnFlags::from_bits_retain(bitcast!(self.raw.msg_hdr.msg_flags))
+        } else {
+            ReturnFlags::empty()
+        }
+    }
+
+    /// Returns the address of the socket the message was received from, if
+    /// any.
+    ///
+    /// This returns `None` if the header was constructed without an address
+    /// buffer, or if a message hasn't been received into this header yet.
+    pub fn address(&self) -> Option<SocketAddrAny> {
+        if !self.is_received() || self.raw.msg_hdr.msg_name.is_null() {
+            return None;
+        }
+
+        // SAFETY: The kernel wrote the address into the buffer `msg_name`
+        // points to, and its length into `msg_namelen`.
+        unsafe {
+            let buf = SocketAddrBuf {
+                len: bitcast!(self.raw.msg_hdr.msg_namelen),
+                storage: self
+                    .raw
+                    .msg_hdr
+                    .msg_name
+                    .cast::<MaybeUninit<SocketAddrStorage>>()
+                    .read(),
+            };
+            buf.into_any_option()
+        }
+    }
+
+    /// Takes the ancillary messages received along with the message.
+    ///
+    /// This returns an empty buffer if a message hasn't been received into
+    /// this header yet, or if the ancillary messages have already been taken.
+    pub fn take_control(&mut self) -> RecvAncillaryBuffer<'_> {
+        if !self.is_received() || self.raw.msg_hdr.msg_controllen == 0 {
+            return RecvAncillaryBuffer::default();
+        }
+
+        let len = self.raw.msg_hdr.msg_controllen as usize;
+        self.raw.msg_hdr.msg_controllen = 0;
+
+        // SAFETY: The kernel initialized `msg_controllen` bytes of the buffer
+        // `msg_control` points to, which was aligned by
+        // `RecvAncillaryBuffer::new`, and which we borrow for `'a`.
+        unsafe {
+            let buffer = slice::from_raw_parts_mut(
+                self.raw.msg_hdr.msg_control.cast::<MaybeUninit<u8>>(),
+                len,
+            );
+            let mut control = RecvAncillaryBuffer {
+                buffer,
+                read: 0,
+                length: 0,
+            };
+            control.set_control_len(len);
+            control
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+impl Drop for RecvMMsgHdr<'_> {
+    fn drop(&mut self) {
+        // Drop any ancillary messages which weren't taken, such as file
+        // descriptors, so that they aren't leaked.
+        drop(self.take_control());
+    }
+}
+
 /// `sendmsg(msghdr)`—Sends a message on a socket.
 ///
 /// This function is for use on connected sockets, as it doesn't have a way to
@@ -812,6 +961,37 @@ pub struct RecvMsg {
     pub address: Option<SocketAddrAny>,
 }
 
+/// `recvmmsg(msgs, flags, timeout)`—Receives multiple messages from a
+/// socket.
+///
+/// On success, this returns the number of messages received, and the results
+/// for each of them may be obtained from the first that many elements of
+/// `msgs`. Each header can only receive one message; this fails with
+/// [`io::Errno::INVAL`] if any of `msgs` has already received a message.
+///
+/// Linux only checks `timeout` after each message is received, so this may
+/// block indefinitely if no further messages arrive. Use
+/// `RecvFlags::WAITFORONE` or `RecvFlags::DONTWAIT` to avoid this.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/recvmmsg.2.html
+#[inline]
+#[cfg(target_os = "linux")]
+pub fn recvmmsg<Fd: AsFd>(
+    socket: Fd,
+    msgs: &mut [RecvMMsgHdr<'_>],
+    flags: RecvFlags,
+    timeout: Option<&Timespec>,
+) -> io::Result<usize> {
+    if msgs.iter().any(RecvMMsgHdr::is_received) {
+        return Err(io::Errno::INVAL);
+    }
+
+    backend::net::syscalls::recvmmsg(socket.as_fd(), msgs, flags, timeout)
+}
+
 /// An iterator over data in an ancillary buffer.
 pub struct AncillaryIter<'data, T> {
     /// The data we're iterating over.
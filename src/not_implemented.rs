@@ -67,7 +67,6 @@ pub mod libc_internals {
     not_implemented!(exit);
     not_implemented!(fork);
     not_implemented!(clone);
-    not_implemented!(clone3);
     not_implemented!(brk);
     not_implemented!(sigaction);
     not_implemented!(sigaltstack);
@@ -287,18 +286,7 @@ pub mod impossible {
 /// scope for rustix, but are not yet implemented. This module contains an
 /// incomplete list of such functions.
 pub mod yet {
-    not_implemented!(tgkill);
-    not_implemented!(raise);
     not_implemented!(sysctl);
-    not_implemented!(mq_open);
-    not_implemented!(mq_send);
-    not_implemented!(mq_unlink);
-    not_implemented!(recvmmsg);
-    not_implemented!(cachestat);
-    not_implemented!(fanotify_init);
-    not_implemented!(fanotify_mark);
-    not_implemented!(signalfd);
-    not_implemented!(mount_setattr);
     not_implemented!(extattr_delete_fd);
     not_implemented!(extattr_delete_link);
     not_implemented!(extattr_get_fd);
@@ -307,16 +295,6 @@ pub mod yet {
     not_implemented!(extattr_list_link);
     not_implemented!(extattr_set_fd);
     not_implemented!(extattr_set_link);
-    not_implemented!(get_mempolicy);
-    not_implemented!(mbind);
-    not_implemented!(set_mempolicy);
-    not_implemented!(migrate_pages);
-    not_implemented!(move_pages);
-    not_implemented!(fchmodat2);
-    not_implemented!(shmat);
-    not_implemented!(shmdt);
-    not_implemented!(shmget);
-    not_implemented!(shmctl);
 }
 
 /// These functions are not quite yet finished in rustix.
@@ -330,13 +308,8 @@ pub mod yet {
 ///
 /// [#1314]: https://github.com/bytecodealliance/rustix/issues/1314
 pub mod quite_yet {
-    not_implemented!(_exit);
-    not_implemented!(_Exit);
-    not_implemented!(exit_group);
     not_implemented!(sigpending);
     not_implemented!(sigsuspend);
-    not_implemented!(execveat);
-    not_implemented!(execve);
 
     /// For now, use `rustix::process::uname().nodename()` instead.
     ///
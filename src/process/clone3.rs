@@ -0,0 +1,228 @@
+//! Spawning child processes with `clone3`.
+#![allow(unsafe_code)]
+
+use super::exec::null_terminated;
+use crate::fd::{AsFd as _, AsRawFd as _, BorrowedFd, OwnedFd};
+use crate::ffi::CStr;
+use crate::process::{Pid, Signal};
+use crate::{backend, io};
+use core::mem::size_of;
+use linux_raw_sys::general::{clone_args, CLONE_INTO_CGROUP, CLONE_PIDFD, CLONE_VFORK};
+
+bitflags::bitflags! {
+    /// `CLONE_*` flags for use with [`CloneArgs`].
+    ///
+    /// This only includes flags which are compatible with the spawn-then-exec
+    /// pattern that [`CloneArgs::spawn`] implements. Flags which would cause
+    /// the child to share state with the parent, such as `CLONE_VM` and
+    /// `CLONE_THREAD`, are deliberately omitted, and unknown flags are
+    /// ignored.
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct CloneFlags: u64 {
+        /// `CLONE_NEWCGROUP`
+        const NEWCGROUP = linux_raw_sys::general::CLONE_NEWCGROUP as u64;
+        /// `CLONE_NEWIPC`
+        const NEWIPC = linux_raw_sys::general::CLONE_NEWIPC as u64;
+        /// `CLONE_NEWNET`
+        const NEWNET = linux_raw_sys::general::CLONE_NEWNET as u64;
+        /// `CLONE_NEWNS`
+        const NEWNS = linux_raw_sys::general::CLONE_NEWNS as u64;
+        /// `CLONE_NEWPID`
+        const NEWPID = linux_raw_sys::general::CLONE_NEWPID as u64;
+        /// `CLONE_NEWTIME` (since Linux 5.8)
+        const NEWTIME = linux_raw_sys::general::CLONE_NEWTIME as u64;
+        /// `CLONE_NEWUSER`
+        const NEWUSER = linux_raw_sys::general::CLONE_NEWUSER as u64;
+        /// `CLONE_NEWUTS`
+        const NEWUTS = linux_raw_sys::general::CLONE_NEWUTS as u64;
+        /// `CLONE_CLEAR_SIGHAND` (since Linux 5.5)
+        ///
+        /// Reset all signal handlers in the child to their defaults, so that
+        /// the parent's handlers can't run in the child before the `execve`.
+        const CLEAR_SIGHAND = linux_raw_sys::general::CLONE_CLEAR_SIGHAND;
+    }
+}
+
+/// `struct clone_args`—A builder for spawning a child process with
+/// `clone3`.
+///
+/// `clone3` is used with `CLONE_PIDFD` so that the child's pidfd is returned
+/// atomically along with its PID, avoiding the race between `fork` and
+/// `pidfd_open` where the PID could be reused. And it's used with
+/// `CLONE_VFORK` so that the parent doesn't resume until the child has
+/// either executed the new program or failed to.
+///
+/// Between the `clone3` and the `execve`, the child only makes the `execve`,
+/// `write`, and `exit_group` syscalls, without going through libc's wrappers
+/// for them, so unlike a general-purpose `fork`, this is safe to use in
+/// processes which contain a libc and multiple threads. Signal handlers
+/// installed in the parent can still run in the child before the `execve`;
+/// use [`CloneFlags::CLEAR_SIGHAND`] to prevent that.
+///
+/// # Examples
+///
+/// ```no_run
+/// # fn test() -> rustix::io::Result<()> {
+/// use rustix::cstr;
+/// use rustix::fd::AsFd as _;
+/// use rustix::process::{waitid, CloneArgs, CloneFlags, WaitId, WaitIdOptions};
+///
+/// let (pid, pidfd) = CloneArgs::new()
+///     .flags(CloneFlags::CLEAR_SIGHAND)
+///     .spawn(cstr!("/bin/true"), &[cstr!("true")], &[])?;
+///
+/// let status = waitid(WaitId::PidFd(pidfd.as_fd()), WaitIdOptions::EXITED)?;
+/// # let _ = (pid, status);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/clone3.2.html
+#[doc(alias = "clone3")]
+#[doc(alias = "clone_args")]
+#[derive(Clone, Debug)]
+pub struct CloneArgs<'a> {
+    flags: CloneFlags,
+    exit_signal: Signal,
+    cgroup: Option<BorrowedFd<'a>>,
+    set_tid: &'a [Pid],
+}
+
+impl<'a> CloneArgs<'a> {
+    /// Create a new `CloneArgs` with no flags, and with an exit signal of
+    /// [`Signal::CHILD`].
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            flags: CloneFlags::empty(),
+            exit_signal: Signal::CHILD,
+            cgroup: None,
+            set_tid: &[],
+        }
+    }
+
+    /// Set the `CLONE_*` flags, such as which new namespaces to create for
+    /// the child.
+    #[inline]
+    pub const fn flags(mut self, flags: CloneFlags) -> Self {
+        self.flags = flags;
+        self
+    }
+
+    /// Set the signal sent to the parent when the child exits.
+    ///
+    /// The default is [`Signal::CHILD`].
+    #[inline]
+    pub const fn exit_signal(mut self, exit_signal: Signal) -> Self {
+        self.exit_signal = exit_signal;
+        self
+    }
+
+    /// Set a cgroup directory to place the child in, with
+    /// `CLONE_INTO_CGROUP` (since Linux 5.7).
+    #[doc(alias = "CLONE_INTO_CGROUP")]
+    #[inline]
+    pub const fn cgroup(mut self, cgroup: BorrowedFd<'a>) -> Self {
+        self.cgroup = Some(cgroup);
+        self
+    }
+
+    /// Request specific PIDs for the child, starting with its PID in the
+    /// innermost PID namespace and proceeding outwards (since Linux 5.5).
+    #[inline]
+    pub const fn set_tid(mut self, set_tid: &'a [Pid]) -> Self {
+        self.set_tid = set_tid;
+        self
+    }
+
+    /// `clone3(args)` followed by `execve(path, argv, envp)` in the
+    /// child—Spawns a new process running the program at `path`.
+    ///
+    /// On success, this returns the child's PID, and a pidfd for it. If the
+    /// `execve` fails in the child, the child is reaped and the `execve`
+    /// error is returned.
+    pub fn spawn(&self, path: &CStr, argv: &[&CStr], envp: &[&CStr]) -> io::Result<(Pid, OwnedFd)> {
+        let argv = null_terminated(argv);
+        let envp = null_terminated(envp);
+
+        let mut flags = self.flags.bits() & CloneFlags::all().bits();
+        flags |= u64::from(CLONE_PIDFD | CLONE_VFORK);
+        let cgroup = match self.cgroup {
+            Some(cgroup) => {
+                flags |= CLONE_INTO_CGROUP;
+                cgroup.as_raw_fd() as u64
+            }
+            None => 0,
+        };
+
+        let mut args = clone_args {
+            flags,
+            pidfd: 0,
+            child_tid: 0,
+            parent_tid: 0,
+            exit_signal: self.exit_signal.as_raw() as u64,
+            stack: 0,
+            stack_size: 0,
+            tls: 0,
+            // The kernel rejects a non-null `set_tid` with a zero size.
+            set_tid: if self.set_tid.is_empty() {
+                0
+            } else {
+                self.set_tid.as_ptr() as usize as u64
+            },
+            set_tid_size: self.set_tid.len() as u64,
+            cgroup,
+        };
+
+        // SAFETY: `CloneFlags` doesn't contain any flags which would cause
+        // the child to share memory with the parent, the pointers in `args`
+        // are valid, and `argv` and `envp` are NULL-terminated.
+        let (pid, pidfd, reader) = unsafe {
+            backend::process::syscalls::clone3_spawn(&mut args, path, argv.as_ptr(), envp.as_ptr())?
+        };
+
+        // If the pipe is closed without any data, the `execve` succeeded.
+        let mut buf = [0_u8; size_of::<i32>()];
+        let n = loop {
+            match crate::io::read(&reader, &mut buf) {
+                Ok(n) => break n,
+                Err(io::Errno::INTR) => continue,
+                Err(err) => {
+                    // We don't know whether the `execve` succeeded, so don't
+                    // leave an unreaped child behind.
+                    crate::process::pidfd_send_signal(&pidfd, Signal::KILL).ok();
+                    reap(pidfd.as_fd());
+                    return Err(err);
+                }
+            }
+        };
+        if n == 0 {
+            return Ok((pid, pidfd));
+        }
+
+        // The `execve` failed, so reap the child and report the error.
+        reap(pidfd.as_fd());
+        Err(io::Errno::from_raw_os_error(i32::from_ne_bytes(buf)))
+    }
+}
+
+/// Wait for the child referred to by `pidfd` to exit, and reap it.
+fn reap(pidfd: BorrowedFd<'_>) {
+    // Use `__WALL` so that this works with any exit signal.
+    let options = crate::process::WaitIdOptions::EXITED
+        | crate::process::WaitIdOptions::from_bits_retain(linux_raw_sys::general::__WALL as _);
+    while let Err(io::Errno::INTR) =
+        crate::process::waitid(crate::process::WaitId::PidFd(pidfd), options)
+    {}
+}
+
+impl<'a> Default for CloneArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,88 @@
+//! Executing programs with `execve` and `execveat`.
+#![allow(unsafe_code)]
+
+#[cfg(feature = "fs")]
+use crate::fd::AsFd;
+use crate::ffi::CStr;
+#[cfg(feature = "fs")]
+use crate::fs::AtFlags;
+use crate::{backend, io};
+use alloc::vec::Vec;
+
+/// `execve(path, argv, envp)`—Execute a new program using the current
+/// process.
+///
+/// On success, this doesn't return; the current process is replaced by the
+/// new program. On failure, the error is returned.
+///
+/// `argv` and `envp` are converted into the NULL-terminated arrays that the
+/// kernel expects.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9799919799/functions/execve.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/execve.2.html
+#[inline]
+#[must_use]
+pub fn execve(path: &CStr, argv: &[&CStr], envp: &[&CStr]) -> io::Errno {
+    let argv = null_terminated(argv);
+    let envp = null_terminated(envp);
+
+    // SAFETY: `argv` and `envp` are NULL-terminated arrays of pointers to
+    // NUL-terminated strings.
+    unsafe { backend::process::syscalls::execve(path, argv.as_ptr(), envp.as_ptr()) }
+}
+
+/// `execveat(dirfd, path, argv, envp, flags)`—Execute a new program using
+/// the current process.
+///
+/// This is like [`execve`], but `path` is resolved relative to `dirfd`. With
+/// [`AtFlags::EMPTY_PATH`] and an empty `path`, this executes the file that
+/// `dirfd` refers to, which may be a file opened with `O_PATH` or a
+/// [`memfd_create`] file.
+///
+/// Beware that if `dirfd` has `FD_CLOEXEC` set and the program is a script,
+/// the interpreter won't be able to open it, and this fails with
+/// [`io::Errno::NOENT`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/execveat.2.html
+/// [`memfd_create`]: crate::fs::memfd_create
+#[cfg(feature = "fs")]
+#[cfg_attr(docsrs, doc(cfg(feature = "fs")))]
+#[inline]
+#[must_use]
+pub fn execveat<Fd: AsFd>(
+    dirfd: Fd,
+    path: &CStr,
+    argv: &[&CStr],
+    envp: &[&CStr],
+    flags: AtFlags,
+) -> io::Errno {
+    let argv = null_terminated(argv);
+    let envp = null_terminated(envp);
+
+    // SAFETY: `argv` and `envp` are NULL-terminated arrays of pointers to
+    // NUL-terminated strings.
+    unsafe {
+        backend::process::syscalls::execveat(
+            dirfd.as_fd(),
+            path,
+            argv.as_ptr(),
+            envp.as_ptr(),
+            flags,
+        )
+    }
+}
+
+/// Convert a slice of strings into a NULL-terminated array of pointers.
+pub(super) fn null_terminated(strs: &[&CStr]) -> Vec<*const u8> {
+    strs.iter()
+        .map(|s| s.as_ptr().cast::<u8>())
+        .chain(core::iter::once(core::ptr::null()))
+        .collect()
+}
@@ -34,3 +34,26 @@ pub const EXIT_FAILURE: i32 = backend::c::EXIT_FAILURE;
 /// [`Signal::ABORT`]: crate::process::Signal::ABORT
 #[cfg(not(any(target_os = "espidf", target_os = "wasi")))]
 pub const EXIT_SIGNALED_SIGABRT: i32 = backend::c::EXIT_SIGNALED_SIGABRT;
+
+/// `_exit(status)`—Exit all the threads in the current process' thread
+/// group, without running any destructors.
+///
+/// This is equivalent to `_exit` and `_Exit` in libc. It does not call any
+/// `__cxa_atexit`, `atexit`, or any other destructors, and it doesn't flush
+/// any buffered I/O. Most programs should use [`std::process::exit`]
+/// instead of calling this directly.
+///
+/// # References
+///  - [POSIX `_Exit`]
+///  - [Linux `exit_group`]
+///  - [Linux `_Exit`]
+///
+/// [POSIX `_Exit`]: https://pubs.opengroup.org/onlinepubs/9799919799/functions/_Exit.html
+/// [Linux `exit_group`]: https://man7.org/linux/man-pages/man2/exit_group.2.html
+/// [Linux `_Exit`]: https://man7.org/linux/man-pages/man2/_Exit.2.html
+#[cfg(linux_kernel)]
+#[doc(alias = "_exit", alias = "_Exit")]
+#[inline]
+pub fn exit_group(status: i32) -> ! {
+    backend::process::syscalls::exit_group(status)
+}
@@ -96,3 +96,19 @@ pub fn test_kill_process_group(pid: Pid) -> io::Result<()> {
 pub fn test_kill_current_process_group() -> io::Result<()> {
     backend::process::syscalls::test_kill_current_process_group()
 }
+
+/// `raise(sig)`—Sends a signal to the current thread.
+///
+/// If the signal isn't blocked or ignored and it has a handler, this doesn't
+/// return until the handler has returned.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9799919799/functions/raise.html
+/// [Linux]: https://man7.org/linux/man-pages/man3/raise.3.html
+#[inline]
+pub fn raise(sig: Signal) -> io::Result<()> {
+    backend::process::syscalls::raise(sig)
+}
@@ -4,6 +4,10 @@
 mod chdir;
 #[cfg(not(any(target_os = "fuchsia", target_os = "wasi")))]
 mod chroot;
+#[cfg(all(feature = "alloc", target_os = "linux"))]
+mod clone3;
+#[cfg(all(feature = "alloc", linux_kernel))]
+mod exec;
 mod exit;
 #[cfg(not(any(
     target_os = "emscripten",
@@ -34,6 +38,8 @@ mod prctl;
 mod priority;
 #[cfg(freebsdlike)]
 mod procctl;
+#[cfg(linux_kernel)]
+mod process_vm;
 #[cfg(not(any(
     target_os = "espidf",
     target_os = "fuchsia",
@@ -61,6 +67,10 @@ mod wait;
 pub use chdir::*;
 #[cfg(not(any(target_os = "fuchsia", target_os = "wasi")))]
 pub use chroot::*;
+#[cfg(all(feature = "alloc", target_os = "linux"))]
+pub use clone3::*;
+#[cfg(all(feature = "alloc", linux_kernel))]
+pub use exec::*;
 pub use exit::*;
 #[cfg(not(any(
     target_os = "emscripten",
@@ -90,6 +100,8 @@ pub use prctl::*;
 pub use priority::*;
 #[cfg(freebsdlike)]
 pub use procctl::*;
+#[cfg(linux_kernel)]
+pub use process_vm::*;
 #[cfg(not(any(
     target_os = "espidf",
     target_os = "fuchsia",
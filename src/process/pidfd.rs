@@ -1,7 +1,9 @@
 use crate::fd::OwnedFd;
-use crate::process::{Pid, Signal};
+use crate::ffi::c_void;
+use crate::process::{getpid, getuid, Pid, Signal};
 use crate::{backend, ffi, io};
 use backend::fd::AsFd;
+use linux_raw_sys::general::{siginfo__bindgen_ty_1, siginfo_t, SI_QUEUE};
 
 bitflags::bitflags! {
     /// `PIDFD_*` flags for use with [`pidfd_open`].
@@ -39,5 +41,69 @@ pub fn pidfd_open(pid: Pid, flags: PidfdFlags) -> io::Result<OwnedFd> {
 /// [Linux]: https://man7.org/linux/man-pages/man2/pidfd_send_signal.2.html
 #[inline]
 pub fn pidfd_send_signal<Fd: AsFd>(pidfd: Fd, sig: Signal) -> io::Result<()> {
-    backend::process::syscalls::pidfd_send_signal(pidfd.as_fd(), sig)
+    backend::process::syscalls::pidfd_send_signal(pidfd.as_fd(), sig, None)
+}
+
+/// The payload of a signal sent with [`pidfd_send_signal_with_info`], which
+/// the receiver sees in its `siginfo_t`.
+///
+/// The `si_signo`, `si_pid`, and `si_uid` fields are filled in with the
+/// signal number and the current process' ID and real user ID, as `sigqueue`
+/// does.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[doc(alias = "siginfo_t")]
+pub struct SigqueueInfo {
+    /// `si_code`—Where the signal came from.
+    ///
+    /// Unless the signal is sent to the current process, this must be
+    /// negative, such as `SI_QUEUE`, otherwise the signal isn't sent and
+    /// [`pidfd_send_signal_with_info`] fails with [`io::Errno::PERM`].
+    pub code: i32,
+
+    /// `si_value`—A value to include with the signal.
+    pub value: *mut c_void,
+}
+
+impl SigqueueInfo {
+    /// Constructs a new `SigqueueInfo` with `SI_QUEUE` as its `code`.
+    #[inline]
+    pub const fn new(value: *mut c_void) -> Self {
+        Self {
+            code: SI_QUEUE,
+            value,
+        }
+    }
+}
+
+/// `syscall(SYS_pidfd_send_signal, pidfd, sig, info, 0)`—Send a signal to a
+/// process specified by a file descriptor, with a payload.
+///
+/// If `info` is `None`, this is equivalent to [`pidfd_send_signal`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/pidfd_send_signal.2.html
+#[inline]
+#[doc(alias = "pidfd_send_signal")]
+pub fn pidfd_send_signal_with_info<Fd: AsFd>(
+    pidfd: Fd,
+    sig: Signal,
+    info: Option<&SigqueueInfo>,
+) -> io::Result<()> {
+    let info = info.map(|info| {
+        let mut raw = siginfo_t {
+            __bindgen_anon_1: siginfo__bindgen_ty_1 { _si_pad: [0; 32] },
+        };
+        // Fill in the common fields and the `_rt` fields, which the kernel
+        // uses for signals with a negative `si_code`.
+        let si = &mut raw.__bindgen_anon_1;
+        si.__bindgen_anon_1.si_signo = sig.as_raw();
+        si.__bindgen_anon_1.si_code = info.code;
+        si.__bindgen_anon_1._sifields._rt._pid = getpid().as_raw_nonzero().get();
+        si.__bindgen_anon_1._sifields._rt._uid = getuid().as_raw();
+        si.__bindgen_anon_1._sifields._rt._sigval.sival_ptr = info.value;
+        raw
+    });
+    backend::process::syscalls::pidfd_send_signal(pidfd.as_fd(), sig, info.as_ref())
 }
@@ -0,0 +1,100 @@
+//! The [`process_vm_readv`] and [`process_vm_writev`] functions.
+
+#![allow(unsafe_code)]
+
+use crate::backend;
+use crate::io::{self, IoSlice, IoSliceMut};
+use crate::pid::Pid;
+
+/// `struct iovec`—A range of memory in another process, for use with
+/// [`process_vm_readv`] and [`process_vm_writev`].
+///
+/// Unlike [`IoSlice`] and [`IoSliceMut`], this doesn't refer to memory in
+/// the calling process, so its base is a plain address.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[doc(alias = "iovec")]
+pub struct RemoteIoVec {
+    /// The address of the start of the range in the remote process.
+    pub base: usize,
+
+    /// The length of the range, in bytes.
+    pub len: usize,
+}
+
+impl RemoteIoVec {
+    /// Construct a new `RemoteIoVec` for the `len` bytes at `base` in the
+    /// remote process.
+    #[inline]
+    pub const fn new(base: usize, len: usize) -> Self {
+        Self { base, len }
+    }
+}
+
+/// `process_vm_readv(pid, local, remote, 0)`—Read memory from another
+/// process.
+///
+/// Data is read from the `remote` ranges of process `pid`, in order, into the
+/// `local` buffers, in order. On success, returns the number of bytes read,
+/// which may be less than requested if a remote range is partially or
+/// entirely inaccessible; the transfer stops at the first range which can't
+/// be fully read.
+///
+/// The caller must have permission to `ptrace` process `pid`.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/process_vm_readv.2.html
+#[inline]
+pub fn process_vm_readv(
+    pid: Pid,
+    local: &mut [IoSliceMut<'_>],
+    remote: &[RemoteIoVec],
+) -> io::Result<usize> {
+    backend::process::syscalls::process_vm_readv(pid, local, remote)
+}
+
+/// `process_vm_writev(pid, local, remote, 0)`—Write memory in another
+/// process.
+///
+/// Data is written from the `local` buffers, in order, to the `remote` ranges
+/// of process `pid`, in order. On success, returns the number of bytes
+/// written, which may be less than requested if a remote range is partially
+/// or entirely inaccessible; the transfer stops at the first range which
+/// can't be fully written.
+///
+/// The caller must have permission to `ptrace` process `pid`.
+///
+/// # Safety
+///
+/// If `pid` is the calling process, or a process which shares memory with
+/// it, the `remote` ranges must be memory which it's valid to write to, as if
+/// by [`core::ptr::copy_nonoverlapping`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/process_vm_writev.2.html
+#[inline]
+pub unsafe fn process_vm_writev(
+    pid: Pid,
+    local: &[IoSlice<'_>],
+    remote: &[RemoteIoVec],
+) -> io::Result<usize> {
+    backend::process::syscalls::process_vm_writev(pid, local, remote)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn process_vm_layouts() {
+        use linux_raw_sys::general as c;
+
+        check_renamed_type!(RemoteIoVec, iovec);
+        check_renamed_struct_renamed_field!(RemoteIoVec, iovec, base, iov_base);
+        check_renamed_struct_renamed_field!(RemoteIoVec, iovec, len, iov_len);
+    }
+}
@@ -0,0 +1,602 @@
+//! Linux process tracing.
+//!
+//! A tracer attaches to a tracee with [`seize`]. The tracee then stops at
+//! various points, which the tracer observes with [`waitpid`] and decodes
+//! with [`PtraceStop::from_wait_status`]. While the tracee is stopped, the
+//! tracer can inspect and modify it, and then restart it with [`cont`],
+//! [`syscall`], or [`detach`].
+//!
+//! # Examples
+//!
+//! ```no_run
+//! # fn example(pid: rustix::process::Pid) -> rustix::io::Result<()> {
+//! use rustix::process::{waitpid, WaitOptions};
+//! use rustix::ptrace::{self, get_syscall_info, PtraceOptions, PtraceStop, PtraceSyscallOp};
+//!
+//! // Print the syscall numbers that `pid` makes.
+//! ptrace::seize(pid, PtraceOptions::TRACESYSGOOD | PtraceOptions::EXITKILL)?;
+//! ptrace::interrupt(pid)?;
+//! while let Some((_, status)) = waitpid(Some(pid), WaitOptions::empty())? {
+//!     let signal = match PtraceStop::from_wait_status(status) {
+//!         Some(PtraceStop::Syscall) => {
+//!             if let PtraceSyscallOp::Entry { nr, .. } = get_syscall_info(pid)?.op {
+//!                 println!("syscall {}", nr);
+//!             }
+//!             None
+//!         }
+//!         Some(PtraceStop::Signal(signal)) => Some(signal),
+//!         Some(_) => None,
+//!         None => break,
+//!     };
+//!     ptrace::syscall(pid, signal)?;
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # References
+//!  - [Linux]
+//!
+//! [`waitpid`]: crate::process::waitpid
+//! [Linux]: https://man7.org/linux/man-pages/man2/ptrace.2.html
+
+#![allow(unsafe_code)]
+
+mod regs;
+
+use crate::backend::ptrace::syscalls;
+use crate::ffi::{c_uint, c_ulong, c_void};
+use crate::io;
+use crate::process::{Pid, Signal, WaitStatus};
+use core::mem::{size_of, MaybeUninit};
+use core::ptr::null_mut;
+use linux_raw_sys::general::iovec;
+use linux_raw_sys::ptrace::{self as ptrace, ptrace_syscall_info};
+
+pub use crate::backend::ptrace::types::PtraceOptions;
+#[cfg(any(
+    target_arch = "x86_64",
+    target_arch = "x86",
+    target_arch = "aarch64",
+    target_arch = "arm",
+    target_arch = "riscv64"
+))]
+pub use regs::UserRegs;
+
+/// `NT_PRSTATUS`—The general-purpose register set, for use with
+/// [`get_regset`] and [`set_regset`].
+pub const NT_PRSTATUS: u32 = linux_raw_sys::elf_uapi::NT_PRSTATUS;
+
+/// `NT_PRFPREG`—The floating-point register set, for use with [`get_regset`]
+/// and [`set_regset`].
+pub const NT_PRFPREG: u32 = linux_raw_sys::elf_uapi::NT_PRFPREG;
+
+/// `PTRACE_EVENT_*`—An event reported by a [`PtraceStop::Event`] stop.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[repr(u32)]
+#[non_exhaustive]
+pub enum PtraceEvent {
+    /// `PTRACE_EVENT_FORK`, with [`PtraceOptions::TRACEFORK`]
+    Fork = ptrace::PTRACE_EVENT_FORK,
+
+    /// `PTRACE_EVENT_VFORK`, with [`PtraceOptions::TRACEVFORK`]
+    Vfork = ptrace::PTRACE_EVENT_VFORK,
+
+    /// `PTRACE_EVENT_CLONE`, with [`PtraceOptions::TRACECLONE`]
+    Clone = ptrace::PTRACE_EVENT_CLONE,
+
+    /// `PTRACE_EVENT_EXEC`, with [`PtraceOptions::TRACEEXEC`]
+    Exec = ptrace::PTRACE_EVENT_EXEC,
+
+    /// `PTRACE_EVENT_VFORK_DONE`, with [`PtraceOptions::TRACEVFORKDONE`]
+    VforkDone = ptrace::PTRACE_EVENT_VFORK_DONE,
+
+    /// `PTRACE_EVENT_EXIT`, with [`PtraceOptions::TRACEEXIT`]
+    Exit = ptrace::PTRACE_EVENT_EXIT,
+
+    /// `PTRACE_EVENT_SECCOMP`, with [`PtraceOptions::TRACESECCOMP`]
+    Seccomp = ptrace::PTRACE_EVENT_SECCOMP,
+}
+
+impl PtraceEvent {
+    /// Convert a raw `PTRACE_EVENT_*` value into a `PtraceEvent`, if known.
+    #[inline]
+    pub const fn from_raw(raw: u32) -> Option<Self> {
+        match raw {
+            ptrace::PTRACE_EVENT_FORK => Some(Self::Fork),
+            ptrace::PTRACE_EVENT_VFORK => Some(Self::Vfork),
+            ptrace::PTRACE_EVENT_CLONE => Some(Self::Clone),
+            ptrace::PTRACE_EVENT_EXEC => Some(Self::Exec),
+            ptrace::PTRACE_EVENT_VFORK_DONE => Some(Self::VforkDone),
+            ptrace::PTRACE_EVENT_EXIT => Some(Self::Exit),
+            ptrace::PTRACE_EVENT_SECCOMP => Some(Self::Seccomp),
+            _ => None,
+        }
+    }
+}
+
+/// The kind of stop a tracee is in, as reported by [`waitpid`].
+///
+/// [`waitpid`]: crate::process::waitpid
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum PtraceStop {
+    /// A signal-delivery-stop.
+    ///
+    /// The signal hasn't been delivered yet. To deliver it, pass it to the
+    /// function which restarts the tracee; to suppress it, pass `None`.
+    Signal(Signal),
+
+    /// A syscall-stop, at syscall entry or exit, with
+    /// [`PtraceOptions::TRACESYSGOOD`].
+    ///
+    /// Use [`get_syscall_info`] to find out which.
+    Syscall,
+
+    /// A `PTRACE_EVENT` stop. Use [`get_event_msg`] to get the message
+    /// associated with the event.
+    Event(PtraceEvent),
+
+    /// A `PTRACE_EVENT_STOP` stop, which is either a group-stop caused by
+    /// the given signal, or, with `SIGTRAP`, a stop caused by [`interrupt`].
+    Group(Signal),
+}
+
+impl PtraceStop {
+    /// Decode the status of a stopped tracee.
+    ///
+    /// Returns `None` if the status isn't a stop, or is a `PTRACE_EVENT` stop
+    /// for an unknown event.
+    pub fn from_wait_status(status: WaitStatus) -> Option<Self> {
+        let sig = status.stopping_signal()?;
+        let event = (status.as_raw() as u32 >> 16) & 0xff;
+
+        // SAFETY: The kernel reported a stop with this signal, so it's a
+        // valid non-zero signal number.
+        let signal = |sig: i32| unsafe { Signal::from_raw_unchecked(sig) };
+
+        if sig == linux_raw_sys::general::SIGTRAP as i32 | 0x80 {
+            return Some(Self::Syscall);
+        }
+        match event {
+            0 => Some(Self::Signal(signal(sig))),
+            ptrace::PTRACE_EVENT_STOP => Some(Self::Group(signal(sig))),
+            event => PtraceEvent::from_raw(event).map(Self::Event),
+        }
+    }
+}
+
+/// `struct ptrace_syscall_info`—Information about the syscall a tracee is
+/// stopped at, as returned by [`get_syscall_info`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[doc(alias = "ptrace_syscall_info")]
+#[non_exhaustive]
+pub struct PtraceSyscallInfo {
+    /// The `AUDIT_ARCH_*` value identifying the syscall convention.
+    pub arch: u32,
+
+    /// The instruction pointer.
+    pub instruction_pointer: u64,
+
+    /// The stack pointer.
+    pub stack_pointer: u64,
+
+    /// The kind of stop, and the information specific to it.
+    pub op: PtraceSyscallOp,
+}
+
+/// `PTRACE_SYSCALL_INFO_*`—The kind of stop described by a
+/// [`PtraceSyscallInfo`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum PtraceSyscallOp {
+    /// `PTRACE_SYSCALL_INFO_NONE`—The stop isn't a syscall stop.
+    None,
+
+    /// `PTRACE_SYSCALL_INFO_ENTRY`—A syscall-enter-stop.
+    Entry {
+        /// The syscall number.
+        nr: u64,
+
+        /// The syscall arguments.
+        args: [u64; 6],
+    },
+
+    /// `PTRACE_SYSCALL_INFO_EXIT`—A syscall-exit-stop.
+    Exit {
+        /// The syscall's return value, or the negated error value if
+        /// `is_error` is true.
+        rval: i64,
+
+        /// Whether the syscall failed.
+        is_error: bool,
+    },
+
+    /// `PTRACE_SYSCALL_INFO_SECCOMP`—A [`PtraceEvent::Seccomp`] stop.
+    Seccomp {
+        /// The syscall number.
+        nr: u64,
+
+        /// The syscall arguments.
+        args: [u64; 6],
+
+        /// The data from the `SECCOMP_RET_TRACE` action.
+        ret_data: u32,
+    },
+}
+
+/// `ptrace(PTRACE_SEIZE, pid, 0, options)`—Attach to a process without
+/// stopping it.
+///
+/// Unlike `PTRACE_ATTACH`, this doesn't send `SIGSTOP`, and it enables
+/// [`interrupt`] and [`PtraceStop::Group`] stops.
+///
+/// This function was added in Linux 3.4.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ptrace.2.html
+#[doc(alias = "PTRACE_SEIZE")]
+#[inline]
+pub fn seize(pid: Pid, options: PtraceOptions) -> io::Result<()> {
+    request(ptrace::PTRACE_SEIZE, pid, 0, options.bits() as usize).map(drop)
+}
+
+/// `ptrace(PTRACE_INTERRUPT, pid, 0, 0)`—Stop a tracee attached with
+/// [`seize`].
+///
+/// This function was added in Linux 3.4.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ptrace.2.html
+#[doc(alias = "PTRACE_INTERRUPT")]
+#[inline]
+pub fn interrupt(pid: Pid) -> io::Result<()> {
+    request(ptrace::PTRACE_INTERRUPT, pid, 0, 0).map(drop)
+}
+
+/// `ptrace(PTRACE_CONT, pid, 0, sig)`—Restart a stopped tracee.
+///
+/// If `sig` is `Some`, the signal is delivered to the tracee.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ptrace.2.html
+#[doc(alias = "PTRACE_CONT")]
+#[inline]
+pub fn cont(pid: Pid, sig: Option<Signal>) -> io::Result<()> {
+    request(ptrace::PTRACE_CONT, pid, 0, raw_signal(sig)).map(drop)
+}
+
+/// `ptrace(PTRACE_SYSCALL, pid, 0, sig)`—Restart a stopped tracee, and stop
+/// it at the next syscall entry or exit.
+///
+/// If `sig` is `Some`, the signal is delivered to the tracee.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ptrace.2.html
+#[doc(alias = "PTRACE_SYSCALL")]
+#[inline]
+pub fn syscall(pid: Pid, sig: Option<Signal>) -> io::Result<()> {
+    request(ptrace::PTRACE_SYSCALL, pid, 0, raw_signal(sig)).map(drop)
+}
+
+/// `ptrace(PTRACE_DETACH, pid, 0, sig)`—Restart a stopped tracee, and stop
+/// tracing it.
+///
+/// If `sig` is `Some`, the signal is delivered to the tracee.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ptrace.2.html
+#[doc(alias = "PTRACE_DETACH")]
+#[inline]
+pub fn detach(pid: Pid, sig: Option<Signal>) -> io::Result<()> {
+    request(ptrace::PTRACE_DETACH, pid, 0, raw_signal(sig)).map(drop)
+}
+
+/// `ptrace(PTRACE_SETOPTIONS, pid, 0, options)`—Set the tracing options.
+///
+/// Like most requests, this requires the tracee to be stopped, and fails with
+/// [`io::Errno::SRCH`] otherwise.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ptrace.2.html
+#[doc(alias = "PTRACE_SETOPTIONS")]
+#[inline]
+pub fn set_options(pid: Pid, options: PtraceOptions) -> io::Result<()> {
+    request(ptrace::PTRACE_SETOPTIONS, pid, 0, options.bits() as usize).map(drop)
+}
+
+/// `ptrace(PTRACE_GETEVENTMSG, pid, 0, &msg)`—Get the message associated
+/// with the current [`PtraceStop::Event`] stop.
+///
+/// For fork, vfork, and clone events, this is the ID of the new process or
+/// thread. For exit events, it's the raw wait status. For seccomp events,
+/// it's the data from the `SECCOMP_RET_TRACE` action.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ptrace.2.html
+#[doc(alias = "PTRACE_GETEVENTMSG")]
+#[inline]
+pub fn get_event_msg(pid: Pid) -> io::Result<c_ulong> {
+    let mut msg = MaybeUninit::<c_ulong>::uninit();
+
+    // SAFETY: `PTRACE_GETEVENTMSG` writes an `unsigned long` to `data`.
+    unsafe {
+        syscalls::ptrace(
+            ptrace::PTRACE_GETEVENTMSG,
+            pid,
+            null_mut(),
+            msg.as_mut_ptr().cast(),
+        )?;
+        Ok(msg.assume_init())
+    }
+}
+
+/// `ptrace(PTRACE_PEEKDATA, pid, addr, &word)`—Read a word from the
+/// tracee's memory.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ptrace.2.html
+#[doc(alias = "PTRACE_PEEKDATA", alias = "PTRACE_PEEKTEXT")]
+#[inline]
+pub fn peek_data(pid: Pid, addr: usize) -> io::Result<usize> {
+    let mut word = MaybeUninit::<usize>::uninit();
+
+    // SAFETY: At the syscall level, `PTRACE_PEEKDATA` writes the word to
+    // `data`.
+    unsafe {
+        syscalls::ptrace(
+            ptrace::PTRACE_PEEKDATA,
+            pid,
+            addr as *mut c_void,
+            word.as_mut_ptr().cast(),
+        )?;
+        Ok(word.assume_init())
+    }
+}
+
+/// `ptrace(PTRACE_POKEDATA, pid, addr, word)`—Write a word to the tracee's
+/// memory.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ptrace.2.html
+#[doc(alias = "PTRACE_POKEDATA", alias = "PTRACE_POKETEXT")]
+#[inline]
+pub fn poke_data(pid: Pid, addr: usize, word: usize) -> io::Result<()> {
+    request(ptrace::PTRACE_POKEDATA, pid, addr, word).map(drop)
+}
+
+/// `ptrace(PTRACE_GETREGSET, pid, note_type, &iov)`—Read a register set of
+/// the tracee.
+///
+/// `note_type` is an `NT_*` value such as [`NT_PRSTATUS`]. On success,
+/// returns the number of bytes of `buf` which were written.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ptrace.2.html
+#[doc(alias = "PTRACE_GETREGSET")]
+#[inline]
+pub fn get_regset(pid: Pid, note_type: u32, buf: &mut [u8]) -> io::Result<usize> {
+    let mut iov = iovec {
+        iov_base: buf.as_mut_ptr().cast(),
+        iov_len: buf.len() as _,
+    };
+
+    // SAFETY: `PTRACE_GETREGSET` writes at most `iov_len` bytes to
+    // `iov_base`, and updates `iov_len` to the number of bytes written.
+    unsafe {
+        syscalls::ptrace(
+            ptrace::PTRACE_GETREGSET,
+            pid,
+            note_type as usize as *mut c_void,
+            (&mut iov as *mut iovec).cast(),
+        )?;
+    }
+    Ok(iov.iov_len as usize)
+}
+
+/// `ptrace(PTRACE_SETREGSET, pid, note_type, &iov)`—Write a register set of
+/// the tracee.
+///
+/// `note_type` is an `NT_*` value such as [`NT_PRSTATUS`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ptrace.2.html
+#[doc(alias = "PTRACE_SETREGSET")]
+#[inline]
+pub fn set_regset(pid: Pid, note_type: u32, buf: &[u8]) -> io::Result<()> {
+    let mut iov = iovec {
+        iov_base: buf.as_ptr() as *mut c_void,
+        iov_len: buf.len() as _,
+    };
+
+    // SAFETY: `PTRACE_SETREGSET` reads at most `iov_len` bytes from
+    // `iov_base`.
+    unsafe {
+        syscalls::ptrace(
+            ptrace::PTRACE_SETREGSET,
+            pid,
+            note_type as usize as *mut c_void,
+            (&mut iov as *mut iovec).cast(),
+        )
+        .map(drop)
+    }
+}
+
+/// `ptrace(PTRACE_GETREGSET, pid, NT_PRSTATUS, &iov)`—Read the
+/// general-purpose registers of the tracee.
+///
+/// If the tracee uses a different register layout than the calling process,
+/// such as a 32-bit tracee of a 64-bit tracer, this fails with
+/// [`io::Errno::INVAL`]; use [`get_regset`] instead.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ptrace.2.html
+#[cfg(any(
+    target_arch = "x86_64",
+    target_arch = "x86",
+    target_arch = "aarch64",
+    target_arch = "arm",
+    target_arch = "riscv64"
+))]
+#[doc(alias = "PTRACE_GETREGSET", alias = "PTRACE_GETREGS")]
+#[inline]
+pub fn get_regs(pid: Pid) -> io::Result<UserRegs> {
+    let mut regs = MaybeUninit::<UserRegs>::uninit();
+    let mut iov = iovec {
+        iov_base: regs.as_mut_ptr().cast(),
+        iov_len: size_of::<UserRegs>() as _,
+    };
+
+    // SAFETY: `PTRACE_GETREGSET` writes at most `iov_len` bytes to
+    // `iov_base`, and updates `iov_len` to the number of bytes written.
+    // `UserRegs` consists only of integers, so once all of its bytes are
+    // written, it's initialized.
+    unsafe {
+        syscalls::ptrace(
+            ptrace::PTRACE_GETREGSET,
+            pid,
+            NT_PRSTATUS as usize as *mut c_void,
+            (&mut iov as *mut iovec).cast(),
+        )?;
+        if iov.iov_len as usize != size_of::<UserRegs>() {
+            return Err(io::Errno::INVAL);
+        }
+        Ok(regs.assume_init())
+    }
+}
+
+/// `ptrace(PTRACE_SETREGSET, pid, NT_PRSTATUS, &iov)`—Write the
+/// general-purpose registers of the tracee.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ptrace.2.html
+#[cfg(any(
+    target_arch = "x86_64",
+    target_arch = "x86",
+    target_arch = "aarch64",
+    target_arch = "arm",
+    target_arch = "riscv64"
+))]
+#[doc(alias = "PTRACE_SETREGSET", alias = "PTRACE_SETREGS")]
+#[inline]
+pub fn set_regs(pid: Pid, regs: &UserRegs) -> io::Result<()> {
+    let mut iov = iovec {
+        iov_base: regs as *const UserRegs as *mut c_void,
+        iov_len: size_of::<UserRegs>() as _,
+    };
+
+    // SAFETY: `PTRACE_SETREGSET` reads at most `iov_len` bytes from
+    // `iov_base`.
+    unsafe {
+        syscalls::ptrace(
+            ptrace::PTRACE_SETREGSET,
+            pid,
+            NT_PRSTATUS as usize as *mut c_void,
+            (&mut iov as *mut iovec).cast(),
+        )
+        .map(drop)
+    }
+}
+
+/// `ptrace(PTRACE_GET_SYSCALL_INFO, pid, sizeof(info), &info)`—Get
+/// information about the syscall the tracee is stopped at.
+///
+/// This function was added in Linux 5.3.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ptrace.2.html
+#[doc(alias = "PTRACE_GET_SYSCALL_INFO")]
+#[inline]
+pub fn get_syscall_info(pid: Pid) -> io::Result<PtraceSyscallInfo> {
+    let mut info = MaybeUninit::<ptrace_syscall_info>::zeroed();
+
+    // SAFETY: `PTRACE_GET_SYSCALL_INFO` writes at most `addr` bytes to
+    // `data`, and `info` is zero-initialized, so any bytes it doesn't write
+    // are still initialized.
+    let info = unsafe {
+        syscalls::ptrace(
+            ptrace::PTRACE_GET_SYSCALL_INFO,
+            pid,
+            size_of::<ptrace_syscall_info>() as *mut c_void,
+            info.as_mut_ptr().cast(),
+        )?;
+        info.assume_init()
+    };
+
+    // SAFETY: `op` says which union field the kernel wrote.
+    let op = unsafe {
+        match c_uint::from(info.op) {
+            ptrace::PTRACE_SYSCALL_INFO_ENTRY => {
+                let entry = info.__bindgen_anon_1.entry;
+                PtraceSyscallOp::Entry {
+                    nr: entry.nr,
+                    args: entry.args,
+                }
+            }
+            ptrace::PTRACE_SYSCALL_INFO_EXIT => {
+                let exit = info.__bindgen_anon_1.exit;
+                PtraceSyscallOp::Exit {
+                    rval: exit.rval,
+                    is_error: exit.is_error != 0,
+                }
+            }
+            ptrace::PTRACE_SYSCALL_INFO_SECCOMP => {
+                let seccomp = info.__bindgen_anon_1.seccomp;
+                PtraceSyscallOp::Seccomp {
+                    nr: seccomp.nr,
+                    args: seccomp.args,
+                    ret_data: seccomp.ret_data,
+                }
+            }
+            _ => PtraceSyscallOp::None,
+        }
+    };
+
+    Ok(PtraceSyscallInfo {
+        arch: info.arch,
+        instruction_pointer: info.instruction_pointer,
+        stack_pointer: info.stack_pointer,
+        op,
+    })
+}
+
+/// Make a `ptrace` request whose `addr` and `data` are integers.
+#[inline]
+fn request(request: c_uint, pid: Pid, addr: usize, data: usize) -> io::Result<usize> {
+    // SAFETY: This is only used for requests which don't dereference `addr`
+    // or `data` in the calling process.
+    unsafe { syscalls::ptrace(request, pid, addr as *mut c_void, data as *mut c_void) }
+}
+
+#[inline]
+fn raw_signal(sig: Option<Signal>) -> usize {
+    sig.map_or(0, |sig| sig.as_raw() as usize)
+}
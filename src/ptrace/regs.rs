@@ -0,0 +1,189 @@
+//! The general-purpose register sets read by [`get_regs`] and written by
+//! [`set_regs`].
+//!
+//! These have the layout of the `NT_PRSTATUS` register set, which is the
+//! `user_regs_struct` from `<sys/user.h>` on most architectures.
+//!
+//! [`get_regs`]: crate::ptrace::get_regs
+//! [`set_regs`]: crate::ptrace::set_regs
+
+/// `struct user_regs_struct`—The general-purpose registers of a tracee.
+#[cfg(target_arch = "x86_64")]
+#[repr(C)]
+#[derive(Default, Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[doc(alias = "user_regs_struct")]
+#[allow(missing_docs)]
+pub struct UserRegs {
+    pub r15: u64,
+    pub r14: u64,
+    pub r13: u64,
+    pub r12: u64,
+    pub rbp: u64,
+    pub rbx: u64,
+    pub r11: u64,
+    pub r10: u64,
+    pub r9: u64,
+    pub r8: u64,
+    pub rax: u64,
+    pub rcx: u64,
+    pub rdx: u64,
+    pub rsi: u64,
+    pub rdi: u64,
+    pub orig_rax: u64,
+    pub rip: u64,
+    pub cs: u64,
+    pub eflags: u64,
+    pub rsp: u64,
+    pub ss: u64,
+    pub fs_base: u64,
+    pub gs_base: u64,
+    pub ds: u64,
+    pub es: u64,
+    pub fs: u64,
+    pub gs: u64,
+}
+
+/// `struct user_regs_struct`—The general-purpose registers of a tracee.
+#[cfg(target_arch = "x86")]
+#[repr(C)]
+#[derive(Default, Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[doc(alias = "user_regs_struct")]
+#[allow(missing_docs)]
+pub struct UserRegs {
+    pub ebx: u32,
+    pub ecx: u32,
+    pub edx: u32,
+    pub esi: u32,
+    pub edi: u32,
+    pub ebp: u32,
+    pub eax: u32,
+    pub xds: u32,
+    pub xes: u32,
+    pub xfs: u32,
+    pub xgs: u32,
+    pub orig_eax: u32,
+    pub eip: u32,
+    pub xcs: u32,
+    pub eflags: u32,
+    pub esp: u32,
+    pub xss: u32,
+}
+
+/// `struct user_pt_regs`—The general-purpose registers of a tracee.
+#[cfg(target_arch = "aarch64")]
+#[repr(C)]
+#[derive(Default, Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[doc(alias = "user_pt_regs", alias = "user_regs_struct")]
+pub struct UserRegs {
+    /// `x0` through `x30`.
+    pub regs: [u64; 31],
+
+    /// The stack pointer.
+    pub sp: u64,
+
+    /// The program counter.
+    pub pc: u64,
+
+    /// The processor state.
+    pub pstate: u64,
+}
+
+/// `struct pt_regs`—The general-purpose registers of a tracee.
+#[cfg(target_arch = "arm")]
+#[repr(C)]
+#[derive(Default, Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[doc(alias = "pt_regs", alias = "user_regs")]
+pub struct UserRegs {
+    /// `r0` through `r15`, `cpsr`, and `orig_r0`.
+    pub uregs: [u32; 18],
+}
+
+/// `struct user_regs_struct`—The general-purpose registers of a tracee.
+#[cfg(target_arch = "riscv64")]
+#[repr(C)]
+#[derive(Default, Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[doc(alias = "user_regs_struct")]
+#[allow(missing_docs)]
+pub struct UserRegs {
+    pub pc: u64,
+    pub ra: u64,
+    pub sp: u64,
+    pub gp: u64,
+    pub tp: u64,
+    pub t0: u64,
+    pub t1: u64,
+    pub t2: u64,
+    pub s0: u64,
+    pub s1: u64,
+    pub a0: u64,
+    pub a1: u64,
+    pub a2: u64,
+    pub a3: u64,
+    pub a4: u64,
+    pub a5: u64,
+    pub a6: u64,
+    pub a7: u64,
+    pub s2: u64,
+    pub s3: u64,
+    pub s4: u64,
+    pub s5: u64,
+    pub s6: u64,
+    pub s7: u64,
+    pub s8: u64,
+    pub s9: u64,
+    pub s10: u64,
+    pub s11: u64,
+    pub t3: u64,
+    pub t4: u64,
+    pub t5: u64,
+    pub t6: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(any(
+        target_arch = "x86_64",
+        target_arch = "x86",
+        target_arch = "aarch64",
+        target_arch = "riscv64"
+    ))]
+    #[test]
+    fn ptrace_layouts() {
+        use libc as c;
+
+        check_renamed_type!(UserRegs, user_regs_struct);
+
+        #[cfg(target_arch = "x86_64")]
+        {
+            check_renamed_struct_field!(UserRegs, user_regs_struct, r15);
+            check_renamed_struct_field!(UserRegs, user_regs_struct, rax);
+            check_renamed_struct_field!(UserRegs, user_regs_struct, orig_rax);
+            check_renamed_struct_field!(UserRegs, user_regs_struct, rip);
+            check_renamed_struct_field!(UserRegs, user_regs_struct, rsp);
+            check_renamed_struct_field!(UserRegs, user_regs_struct, gs);
+        }
+        #[cfg(target_arch = "x86")]
+        {
+            check_renamed_struct_field!(UserRegs, user_regs_struct, ebx);
+            check_renamed_struct_field!(UserRegs, user_regs_struct, orig_eax);
+            check_renamed_struct_field!(UserRegs, user_regs_struct, eip);
+            check_renamed_struct_field!(UserRegs, user_regs_struct, xss);
+        }
+        #[cfg(target_arch = "aarch64")]
+        {
+            check_renamed_struct_field!(UserRegs, user_regs_struct, regs);
+            check_renamed_struct_field!(UserRegs, user_regs_struct, sp);
+            check_renamed_struct_field!(UserRegs, user_regs_struct, pc);
+            check_renamed_struct_field!(UserRegs, user_regs_struct, pstate);
+        }
+        #[cfg(target_arch = "riscv64")]
+        {
+            check_renamed_struct_field!(UserRegs, user_regs_struct, pc);
+            check_renamed_struct_field!(UserRegs, user_regs_struct, sp);
+            check_renamed_struct_field!(UserRegs, user_regs_struct, a0);
+            check_renamed_struct_field!(UserRegs, user_regs_struct, t6);
+        }
+    }
+}
@@ -263,7 +263,7 @@ pub unsafe fn exit_thread(status: i32) -> ! {
 #[doc(alias = "_exit", alias = "_Exit")]
 #[inline]
 pub fn exit_group(status: i32) -> ! {
-    backend::runtime::syscalls::exit_group(status)
+    backend::process::syscalls::exit_group(status)
 }
 
 /// `EXIT_SUCCESS` for use with [`exit_group`].
@@ -460,7 +460,7 @@ pub unsafe fn execveat<Fd: AsFd>(
     envp: *const *const u8,
     flags: AtFlags,
 ) -> io::Errno {
-    backend::runtime::syscalls::execveat(dirfd.as_fd(), path, argv, envp, flags)
+    backend::process::syscalls::execveat(dirfd.as_fd(), path, argv, envp, flags)
 }
 
 /// `execve(path.as_c_str(), argv, envp)`—Execute a new command using the
@@ -482,7 +482,7 @@ pub unsafe fn execveat<Fd: AsFd>(
 #[inline]
 #[must_use]
 pub unsafe fn execve(path: &CStr, argv: *const *const u8, envp: *const *const u8) -> io::Errno {
-    backend::runtime::syscalls::execve(path, argv, envp)
+    backend::process::syscalls::execve(path, argv, envp)
 }
 
 /// `sigaction(signal, &new, &old)`—Modify and/or query a signal handler.
@@ -0,0 +1,125 @@
+//! A builder for simple syscall-number filters.
+
+use crate::io;
+use crate::seccomp::{
+    SeccompAction, SockFilter, AUDIT_ARCH, BPF_ABS, BPF_JEQ, BPF_JMP, BPF_K, BPF_LD, BPF_RET,
+    BPF_W, SECCOMP_DATA_ARCH_OFFSET, SECCOMP_DATA_NR_OFFSET,
+};
+use alloc::vec::Vec;
+
+/// A builder for seccomp filters which take actions based on the syscall
+/// number.
+///
+/// The filters built by this first check that the syscall uses the current
+/// architecture's calling convention, as identified by [`AUDIT_ARCH`], and
+/// kill the process with [`SeccompAction::KillProcess`] if not, since a
+/// syscall number means different things under different conventions. On
+/// x86-64, this also kills the process for x32 syscalls.
+///
+/// Rules are checked in the order they're added, and the first one matching
+/// the syscall number is used. If none match, the default action is used.
+///
+/// # Examples
+///
+/// ```
+/// use rustix::io;
+/// use rustix::seccomp::{SeccompAction, SyscallFilter};
+///
+/// // Allow only `read`, `write`, and `exit_group`.
+/// let filter = SyscallFilter::new(SeccompAction::Errno(io::Errno::NOSYS))
+///     .allow(libc::SYS_read as u32)
+///     .allow(libc::SYS_write as u32)
+///     .allow(libc::SYS_exit_group as u32)
+///     .build();
+/// # let _ = filter;
+/// ```
+#[derive(Clone, Debug)]
+pub struct SyscallFilter {
+    default_action: SeccompAction,
+    rules: Vec<(u32, SeccompAction)>,
+}
+
+impl SyscallFilter {
+    /// Create a new `SyscallFilter` which takes `default_action` for any
+    /// syscall without a rule.
+    #[inline]
+    pub const fn new(default_action: SeccompAction) -> Self {
+        Self {
+            default_action,
+            rules: Vec::new(),
+        }
+    }
+
+    /// Take `action` for syscall number `nr`.
+    #[inline]
+    pub fn rule(mut self, nr: u32, action: SeccompAction) -> Self {
+        self.rules.push((nr, action));
+        self
+    }
+
+    /// Allow syscall number `nr`.
+    #[inline]
+    pub fn allow(self, nr: u32) -> Self {
+        self.rule(nr, SeccompAction::Allow)
+    }
+
+    /// Fail syscall number `nr` with `errno`.
+    #[inline]
+    pub fn deny(self, nr: u32, errno: io::Errno) -> Self {
+        self.rule(nr, SeccompAction::Errno(errno))
+    }
+
+    /// Build the filter program, for use with [`set_mode_filter`].
+    ///
+    /// [`set_mode_filter`]: crate::seccomp::set_mode_filter
+    pub fn build(&self) -> Vec<SockFilter> {
+        let kill = SockFilter::stmt(BPF_RET | BPF_K, SeccompAction::KillProcess.as_raw());
+
+        let mut prog = Vec::with_capacity(6 + 2 * self.rules.len());
+
+        // Check the architecture.
+        prog.push(SockFilter::stmt(
+            BPF_LD | BPF_W | BPF_ABS,
+            SECCOMP_DATA_ARCH_OFFSET,
+        ));
+        prog.push(SockFilter::jump(
+            BPF_JMP | BPF_JEQ | BPF_K,
+            AUDIT_ARCH,
+            1,
+            0,
+        ));
+        prog.push(kill);
+
+        // Load the syscall number.
+        prog.push(SockFilter::stmt(
+            BPF_LD | BPF_W | BPF_ABS,
+            SECCOMP_DATA_NR_OFFSET,
+        ));
+
+        // x32 syscalls use `AUDIT_ARCH_X86_64` too, but are distinguished by
+        // having `__X32_SYSCALL_BIT` set.
+        #[cfg(all(target_arch = "x86_64", target_pointer_width = "64"))]
+        {
+            use crate::seccomp::BPF_JGE;
+
+            prog.push(SockFilter::jump(
+                BPF_JMP | BPF_JGE | BPF_K,
+                linux_raw_sys::general::__X32_SYSCALL_BIT,
+                0,
+                1,
+            ));
+            prog.push(kill);
+        }
+
+        for (nr, action) in &self.rules {
+            prog.push(SockFilter::jump(BPF_JMP | BPF_JEQ | BPF_K, *nr, 0, 1));
+            prog.push(SockFilter::stmt(BPF_RET | BPF_K, action.as_raw()));
+        }
+
+        prog.push(SockFilter::stmt(
+            BPF_RET | BPF_K,
+            self.default_action.as_raw(),
+        ));
+        prog
+    }
+}
@@ -0,0 +1,456 @@
+//! `seccomp(SECCOMP_SET_MODE_FILTER, …)` and related operations.
+
+use crate::backend::seccomp::syscalls;
+use crate::fd::OwnedFd;
+use crate::io;
+use crate::pid::Pid;
+use linux_raw_sys::ptrace::{self, sock_filter, sock_fprog};
+
+pub use crate::backend::seccomp::types::SeccompFilterFlags;
+
+/// `struct sock_filter`—A classic BPF instruction.
+///
+/// See [`SockFilter::stmt`] and [`SockFilter::jump`] for the equivalents of
+/// the `BPF_STMT` and `BPF_JUMP` macros, and the `BPF_*` constants in this
+/// module for the opcodes.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[doc(alias = "sock_filter")]
+pub struct SockFilter {
+    /// The opcode.
+    pub code: u16,
+
+    /// For conditional jumps, the offset to jump forward by if the condition
+    /// is true.
+    pub jt: u8,
+
+    /// For conditional jumps, the offset to jump forward by if the condition
+    /// is false.
+    pub jf: u8,
+
+    /// The constant operand.
+    pub k: u32,
+}
+
+impl SockFilter {
+    /// `BPF_STMT(code, k)`—Create a non-jump instruction.
+    #[doc(alias = "BPF_STMT")]
+    #[inline]
+    pub const fn stmt(code: u16, k: u32) -> Self {
+        Self {
+            code,
+            jt: 0,
+            jf: 0,
+            k,
+        }
+    }
+
+    /// `BPF_JUMP(code, k, jt, jf)`—Create a jump instruction.
+    #[doc(alias = "BPF_JUMP")]
+    #[inline]
+    pub const fn jump(code: u16, k: u32, jt: u8, jf: u8) -> Self {
+        Self { code, jt, jf, k }
+    }
+}
+
+/// `BPF_LD`—Instruction class: load into the accumulator.
+pub const BPF_LD: u16 = ptrace::BPF_LD as u16;
+
+/// `BPF_LDX`—Instruction class: load into the index register.
+pub const BPF_LDX: u16 = ptrace::BPF_LDX as u16;
+
+/// `BPF_ST`—Instruction class: store the accumulator to scratch memory.
+pub const BPF_ST: u16 = ptrace::BPF_ST as u16;
+
+/// `BPF_STX`—Instruction class: store the index register to scratch memory.
+pub const BPF_STX: u16 = ptrace::BPF_STX as u16;
+
+/// `BPF_ALU`—Instruction class: arithmetic and logic.
+pub const BPF_ALU: u16 = ptrace::BPF_ALU as u16;
+
+/// `BPF_JMP`—Instruction class: jumps.
+pub const BPF_JMP: u16 = ptrace::BPF_JMP as u16;
+
+/// `BPF_RET`—Instruction class: return.
+pub const BPF_RET: u16 = ptrace::BPF_RET as u16;
+
+/// `BPF_MISC`—Instruction class: miscellaneous.
+pub const BPF_MISC: u16 = ptrace::BPF_MISC as u16;
+
+/// `BPF_W`—Load size: 32-bit word.
+pub const BPF_W: u16 = ptrace::BPF_W as u16;
+
+/// `BPF_H`—Load size: 16-bit half-word.
+pub const BPF_H: u16 = ptrace::BPF_H as u16;
+
+/// `BPF_B`—Load size: byte.
+pub const BPF_B: u16 = ptrace::BPF_B as u16;
+
+/// `BPF_IMM`—Load mode: immediate.
+pub const BPF_IMM: u16 = ptrace::BPF_IMM as u16;
+
+/// `BPF_ABS`—Load mode: absolute offset into the `seccomp_data`.
+pub const BPF_ABS: u16 = ptrace::BPF_ABS as u16;
+
+/// `BPF_IND`—Load mode: offset relative to the index register.
+pub const BPF_IND: u16 = ptrace::BPF_IND as u16;
+
+/// `BPF_MEM`—Load mode: scratch memory.
+pub const BPF_MEM: u16 = ptrace::BPF_MEM as u16;
+
+/// `BPF_LEN`—Load mode: the length of the `seccomp_data`.
+pub const BPF_LEN: u16 = ptrace::BPF_LEN as u16;
+
+/// `BPF_ADD`—ALU operation: addition.
+pub const BPF_ADD: u16 = ptrace::BPF_ADD as u16;
+
+/// `BPF_SUB`—ALU operation: subtraction.
+pub const BPF_SUB: u16 = ptrace::BPF_SUB as u16;
+
+/// `BPF_MUL`—ALU operation: multiplication.
+pub const BPF_MUL: u16 = ptrace::BPF_MUL as u16;
+
+/// `BPF_DIV`—ALU operation: division.
+pub const BPF_DIV: u16 = ptrace::BPF_DIV as u16;
+
+/// `BPF_OR`—ALU operation: bitwise or.
+pub const BPF_OR: u16 = ptrace::BPF_OR as u16;
+
+/// `BPF_AND`—ALU operation: bitwise and.
+pub const BPF_AND: u16 = ptrace::BPF_AND as u16;
+
+/// `BPF_LSH`—ALU operation: left shift.
+pub const BPF_LSH: u16 = ptrace::BPF_LSH as u16;
+
+/// `BPF_RSH`—ALU operation: right shift.
+pub const BPF_RSH: u16 = ptrace::BPF_RSH as u16;
+
+/// `BPF_NEG`—ALU operation: negation.
+pub const BPF_NEG: u16 = ptrace::BPF_NEG as u16;
+
+/// `BPF_MOD`—ALU operation: remainder.
+pub const BPF_MOD: u16 = ptrace::BPF_MOD as u16;
+
+/// `BPF_XOR`—ALU operation: bitwise exclusive or.
+pub const BPF_XOR: u16 = ptrace::BPF_XOR as u16;
+
+/// `BPF_JA`—Jump operation: unconditional.
+pub const BPF_JA: u16 = ptrace::BPF_JA as u16;
+
+/// `BPF_JEQ`—Jump operation: if equal.
+pub const BPF_JEQ: u16 = ptrace::BPF_JEQ as u16;
+
+/// `BPF_JGT`—Jump operation: if greater than.
+pub const BPF_JGT: u16 = ptrace::BPF_JGT as u16;
+
+/// `BPF_JGE`—Jump operation: if greater than or equal.
+pub const BPF_JGE: u16 = ptrace::BPF_JGE as u16;
+
+/// `BPF_JSET`—Jump operation: if any of the bits are set.
+pub const BPF_JSET: u16 = ptrace::BPF_JSET as u16;
+
+/// `BPF_K`—Operand source: the constant `k`.
+pub const BPF_K: u16 = ptrace::BPF_K as u16;
+
+/// `BPF_X`—Operand source: the index register.
+pub const BPF_X: u16 = ptrace::BPF_X as u16;
+
+/// `BPF_A`—Return source: the accumulator.
+pub const BPF_A: u16 = ptrace::BPF_A as u16;
+
+/// The `AUDIT_ARCH_*` value for the current architecture, as found in
+/// `seccomp_data::arch`.
+pub const AUDIT_ARCH: u32 = {
+    use linux_raw_sys::ptrace::*;
+
+    #[cfg(target_arch = "aarch64")]
+    let arch = AUDIT_ARCH_AARCH64;
+    #[cfg(all(target_arch = "arm", target_endian = "little"))]
+    let arch = AUDIT_ARCH_ARM;
+    #[cfg(all(target_arch = "arm", target_endian = "big"))]
+    let arch = AUDIT_ARCH_ARMEB;
+    #[cfg(target_arch = "csky")]
+    let arch = AUDIT_ARCH_CSKY;
+    #[cfg(target_arch = "hexagon")]
+    let arch = AUDIT_ARCH_HEXAGON;
+    #[cfg(target_arch = "loongarch64")]
+    let arch = AUDIT_ARCH_LOONGARCH64;
+    #[cfg(target_arch = "m68k")]
+    let arch = AUDIT_ARCH_M68K;
+    #[cfg(all(
+        any(target_arch = "mips", target_arch = "mips32r6"),
+        target_endian = "little"
+    ))]
+    let arch = AUDIT_ARCH_MIPSEL;
+    #[cfg(all(
+        any(target_arch = "mips", target_arch = "mips32r6"),
+        target_endian = "big"
+    ))]
+    let arch = AUDIT_ARCH_MIPS;
+    #[cfg(all(
+        any(target_arch = "mips64", target_arch = "mips64r6"),
+        target_endian = "little"
+    ))]
+    let arch = AUDIT_ARCH_MIPSEL64;
+    #[cfg(all(
+        any(target_arch = "mips64", target_arch = "mips64r6"),
+        target_endian = "big"
+    ))]
+    let arch = AUDIT_ARCH_MIPS64;
+    #[cfg(target_arch = "powerpc")]
+    let arch = AUDIT_ARCH_PPC;
+    #[cfg(all(target_arch = "powerpc64", target_endian = "little"))]
+    let arch = AUDIT_ARCH_PPC64LE;
+    #[cfg(all(target_arch = "powerpc64", target_endian = "big"))]
+    let arch = AUDIT_ARCH_PPC64;
+    #[cfg(target_arch = "riscv32")]
+    let arch = AUDIT_ARCH_RISCV32;
+    #[cfg(target_arch = "riscv64")]
+    let arch = AUDIT_ARCH_RISCV64;
+    #[cfg(target_arch = "s390x")]
+    let arch = AUDIT_ARCH_S390X;
+    #[cfg(target_arch = "sparc")]
+    let arch = AUDIT_ARCH_SPARC;
+    #[cfg(target_arch = "sparc64")]
+    let arch = AUDIT_ARCH_SPARC64;
+    #[cfg(target_arch = "x86")]
+    let arch = AUDIT_ARCH_I386;
+    // x32 shares `AUDIT_ARCH_X86_64`, and sets `__X32_SYSCALL_BIT` in its
+    // syscall numbers.
+    #[cfg(target_arch = "x86_64")]
+    let arch = AUDIT_ARCH_X86_64;
+
+    arch
+};
+
+/// The offset of the syscall number, `nr`, in `struct seccomp_data`.
+pub const SECCOMP_DATA_NR_OFFSET: u32 = 0;
+
+/// The offset of the `AUDIT_ARCH_*` value, `arch`, in `struct seccomp_data`.
+pub const SECCOMP_DATA_ARCH_OFFSET: u32 = 4;
+
+/// The offset of the instruction pointer, `instruction_pointer`, in
+/// `struct seccomp_data`.
+pub const SECCOMP_DATA_INSTRUCTION_POINTER_OFFSET: u32 = 8;
+
+/// The offset of the syscall arguments, `args`, in `struct seccomp_data`.
+///
+/// The arguments are each 64 bits, and loads are 32 bits, so on
+/// little-endian architectures, the low half of argument `i` is at
+/// `SECCOMP_DATA_ARGS_OFFSET + 8 * i`, and on big-endian architectures it's
+/// at `SECCOMP_DATA_ARGS_OFFSET + 8 * i + 4`.
+pub const SECCOMP_DATA_ARGS_OFFSET: u32 = 16;
+
+/// `SECCOMP_RET_*`—The action to take for a syscall, returned by a filter.
+///
+/// When multiple filters are installed, the action with the highest
+/// precedence is taken; the variants here are listed in order of decreasing
+/// precedence.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum SeccompAction {
+    /// `SECCOMP_RET_KILL_PROCESS`—Kill the process (since Linux 4.14).
+    KillProcess,
+
+    /// `SECCOMP_RET_KILL_THREAD`—Kill the thread.
+    #[doc(alias = "SECCOMP_RET_KILL")]
+    KillThread,
+
+    /// `SECCOMP_RET_TRAP`—Send a `SIGSYS` signal to the thread, with the
+    /// given value in `si_errno`.
+    Trap(u16),
+
+    /// `SECCOMP_RET_ERRNO`—Fail the syscall with the given error.
+    Errno(io::Errno),
+
+    /// `SECCOMP_RET_USER_NOTIF`—Notify the listener file descriptor returned
+    /// by [`set_mode_filter_listener`] (since Linux 5.0).
+    UserNotif,
+
+    /// `SECCOMP_RET_TRACE`—Notify a `ptrace` tracer, with the given value
+    /// available with `PTRACE_GETEVENTMSG`.
+    Trace(u16),
+
+    /// `SECCOMP_RET_LOG`—Log the syscall and allow it (since Linux 4.14).
+    Log,
+
+    /// `SECCOMP_RET_ALLOW`—Allow the syscall.
+    Allow,
+}
+
+impl SeccompAction {
+    /// Return the raw `SECCOMP_RET_*` value, including the data in the low
+    /// 16 bits, for use in a `BPF_RET` instruction.
+    #[inline]
+    pub const fn as_raw(self) -> u32 {
+        match self {
+            Self::KillProcess => ptrace::SECCOMP_RET_KILL_PROCESS,
+            Self::KillThread => ptrace::SECCOMP_RET_KILL_THREAD,
+            Self::Trap(data) => ptrace::SECCOMP_RET_TRAP | data as u32,
+            Self::Errno(errno) => {
+                ptrace::SECCOMP_RET_ERRNO | (errno.raw_os_error() as u32 & ptrace::SECCOMP_RET_DATA)
+            }
+            Self::UserNotif => ptrace::SECCOMP_RET_USER_NOTIF,
+            Self::Trace(data) => ptrace::SECCOMP_RET_TRACE | data as u32,
+            Self::Log => ptrace::SECCOMP_RET_LOG,
+            Self::Allow => ptrace::SECCOMP_RET_ALLOW,
+        }
+    }
+}
+
+/// Convert `filter` into the `sock_fprog` that the kernel expects.
+fn fprog(filter: &[SockFilter]) -> io::Result<sock_fprog> {
+    Ok(sock_fprog {
+        len: filter.len().try_into().map_err(|_| io::Errno::INVAL)?,
+        filter: filter.as_ptr() as *mut sock_filter,
+    })
+}
+
+/// `seccomp(SECCOMP_SET_MODE_FILTER, flags, &prog)`—Install a seccomp
+/// filter for the calling thread.
+///
+/// If `flags` contains [`SeccompFilterFlags::TSYNC`] and another thread
+/// can't be synchronized to the new filter, the filter isn't installed, and
+/// this returns `Ok(Some(tid))` with the ID of that thread. If `flags` also
+/// contains [`SeccompFilterFlags::TSYNC_ESRCH`], this fails with
+/// [`io::Errno::SRCH`] instead. Otherwise, this returns `Ok(None)`.
+///
+/// To use [`SeccompFilterFlags::NEW_LISTENER`], use
+/// [`set_mode_filter_listener`] instead; this function fails with
+/// [`io::Errno::INVAL`] if it's present.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/seccomp.2.html
+#[doc(alias = "seccomp", alias = "SECCOMP_SET_MODE_FILTER")]
+#[inline]
+pub fn set_mode_filter(
+    flags: SeccompFilterFlags,
+    filter: &[SockFilter],
+) -> io::Result<Option<Pid>> {
+    if flags.contains(SeccompFilterFlags::NEW_LISTENER) {
+        return Err(io::Errno::INVAL);
+    }
+
+    // Without `TSYNC_ESRCH`, a thread that couldn't be synchronized is
+    // reported by returning its thread ID.
+    let tid = syscalls::seccomp_set_mode_filter(flags, &fprog(filter)?)?;
+    Ok(Pid::from_raw(tid))
+}
+
+/// `seccomp(SECCOMP_SET_MODE_FILTER, flags | SECCOMP_FILTER_FLAG_NEW_LISTENER,
+/// &prog)`—Install a seccomp filter for the calling thread, and return a
+/// listener file descriptor for its [`SeccompAction::UserNotif`] actions.
+///
+/// If `flags` contains [`SeccompFilterFlags::TSYNC`], it must also contain
+/// [`SeccompFilterFlags::TSYNC_ESRCH`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/seccomp.2.html
+#[doc(alias = "seccomp", alias = "SECCOMP_FILTER_FLAG_NEW_LISTENER")]
+#[inline]
+pub fn set_mode_filter_listener(
+    flags: SeccompFilterFlags,
+    filter: &[SockFilter],
+) -> io::Result<OwnedFd> {
+    syscalls::seccomp_set_mode_filter_listener(
+        flags | SeccompFilterFlags::NEW_LISTENER,
+        &fprog(filter)?,
+    )
+}
+
+/// `seccomp(SECCOMP_GET_ACTION_AVAIL, 0, &action)`—Test whether the kernel
+/// supports the given action.
+///
+/// This function was added in Linux 4.14. The data in `action`, if any, is
+/// ignored.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/seccomp.2.html
+#[doc(alias = "seccomp", alias = "SECCOMP_GET_ACTION_AVAIL")]
+#[inline]
+pub fn get_action_avail(action: SeccompAction) -> io::Result<bool> {
+    let action = action.as_raw() & ptrace::SECCOMP_RET_ACTION_FULL;
+    match syscalls::seccomp_get_action_avail(action) {
+        Ok(()) => Ok(true),
+        Err(io::Errno::OPNOTSUPP) => Ok(false),
+        Err(err) => Err(err),
+    }
+}
+
+/// `struct seccomp_notif_sizes`—The sizes of the structs used with seccomp
+/// user-space notification, as returned by [`get_notif_sizes`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[doc(alias = "seccomp_notif_sizes")]
+#[non_exhaustive]
+pub struct SeccompNotifSizes {
+    /// The size of `struct seccomp_notif`.
+    #[doc(alias = "seccomp_notif")]
+    pub notif: u16,
+
+    /// The size of `struct seccomp_notif_resp`.
+    #[doc(alias = "seccomp_notif_resp")]
+    pub notif_resp: u16,
+
+    /// The size of `struct seccomp_data`.
+    #[doc(alias = "seccomp_data")]
+    pub data: u16,
+}
+
+/// `seccomp(SECCOMP_GET_NOTIF_SIZES, 0, &sizes)`—Query the sizes of the
+/// structs used with seccomp user-space notification.
+///
+/// This function was added in Linux 5.0.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/seccomp.2.html
+#[doc(alias = "seccomp", alias = "SECCOMP_GET_NOTIF_SIZES")]
+#[inline]
+pub fn get_notif_sizes() -> io::Result<SeccompNotifSizes> {
+    let sizes = syscalls::seccomp_get_notif_sizes()?;
+    Ok(SeccompNotifSizes {
+        notif: sizes.seccomp_notif,
+        notif_resp: sizes.seccomp_notif_resp,
+        data: sizes.seccomp_data,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn seccomp_layouts() {
+        use linux_raw_sys::ptrace as c;
+
+        check_renamed_type!(SockFilter, sock_filter);
+        check_renamed_struct_field!(SockFilter, sock_filter, code);
+        check_renamed_struct_field!(SockFilter, sock_filter, jt);
+        check_renamed_struct_field!(SockFilter, sock_filter, jf);
+        check_renamed_struct_field!(SockFilter, sock_filter, k);
+
+        assert_eq!(
+            memoffset::offset_of!(c::seccomp_data, nr),
+            SECCOMP_DATA_NR_OFFSET as usize
+        );
+        assert_eq!(
+            memoffset::offset_of!(c::seccomp_data, arch),
+            SECCOMP_DATA_ARCH_OFFSET as usize
+        );
+        assert_eq!(
+            memoffset::offset_of!(c::seccomp_data, instruction_pointer),
+            SECCOMP_DATA_INSTRUCTION_POINTER_OFFSET as usize
+        );
+        assert_eq!(
+            memoffset::offset_of!(c::seccomp_data, args),
+            SECCOMP_DATA_ARGS_OFFSET as usize
+        );
+    }
+}
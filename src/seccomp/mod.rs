@@ -0,0 +1,48 @@
+//! Linux secure computing filters.
+//!
+//! A seccomp filter is a classic BPF program which is run on every syscall
+//! the thread makes, and which decides whether the syscall is allowed to
+//! proceed. Filters can't be removed once installed, and they're inherited
+//! by child processes and preserved across `execve`.
+//!
+//! Unless the thread has `CAP_SYS_ADMIN` in its user namespace, it must set
+//! the `no_new_privs` attribute, with [`set_no_new_privs`], before installing
+//! a filter.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! # #[cfg(feature = "thread")]
+//! # fn example() -> rustix::io::Result<()> {
+//! use rustix::io;
+//! use rustix::seccomp::{set_mode_filter, SeccompAction, SeccompFilterFlags, SyscallFilter};
+//! use rustix::thread::set_no_new_privs;
+//!
+//! // Make `getppid` fail with `EPERM`, and allow everything else.
+//! let filter = SyscallFilter::new(SeccompAction::Allow)
+//!     .deny(libc::SYS_getppid as u32, io::Errno::PERM)
+//!     .build();
+//!
+//! set_no_new_privs(true)?;
+//! set_mode_filter(SeccompFilterFlags::empty(), &filter)?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # References
+//!  - [Linux]
+//!  - [Linux seccomp filter documentation]
+//!
+//! [`set_no_new_privs`]: https://docs.rs/rustix/*/rustix/thread/fn.set_no_new_privs.html
+//! [Linux]: https://man7.org/linux/man-pages/man2/seccomp.2.html
+//! [Linux seccomp filter documentation]: https://docs.kernel.org/userspace-api/seccomp_filter.html
+
+#[cfg(feature = "alloc")]
+mod builder;
+mod filter;
+mod notify;
+
+#[cfg(feature = "alloc")]
+pub use builder::*;
+pub use filter::*;
+pub use notify::*;
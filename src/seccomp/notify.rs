@@ -0,0 +1,290 @@
+//! Seccomp user-space notification.
+//!
+//! When a filter installed with [`set_mode_filter_listener`] returns
+//! [`SeccompAction::UserNotif`], the syscall is suspended, and a notification
+//! is sent to the listener file descriptor. A supervisor then receives it
+//! with [`notif_recv`], and responds with [`notif_send`].
+//!
+//! [`set_mode_filter_listener`]: crate::seccomp::set_mode_filter_listener
+//! [`SeccompAction::UserNotif`]: crate::seccomp::SeccompAction::UserNotif
+
+#![allow(unsafe_code)]
+
+use crate::fd::{AsFd, AsRawFd as _, RawFd};
+use crate::io::{self, FdFlags};
+use crate::ioctl;
+use core::mem::MaybeUninit;
+use linux_raw_sys::general::O_CLOEXEC;
+use linux_raw_sys::ptrace::{
+    seccomp_notif, seccomp_notif_addfd, seccomp_notif_resp, SECCOMP_ADDFD_FLAG_SETFD,
+};
+
+pub use crate::backend::seccomp::types::{SeccompAddfdFlags, SeccompNotifRespFlags};
+use crate::pid::Pid;
+
+const SECCOMP_IOCTL_NOTIF_RECV: ioctl::Opcode = ioctl::opcode::read_write::<seccomp_notif>(b'!', 0);
+const SECCOMP_IOCTL_NOTIF_SEND: ioctl::Opcode =
+    ioctl::opcode::read_write::<seccomp_notif_resp>(b'!', 1);
+const SECCOMP_IOCTL_NOTIF_ID_VALID: ioctl::Opcode = ioctl::opcode::write::<u64>(b'!', 2);
+const SECCOMP_IOCTL_NOTIF_ADDFD: ioctl::Opcode =
+    ioctl::opcode::write::<seccomp_notif_addfd>(b'!', 3);
+
+/// `struct seccomp_data`—The syscall that a notification is for.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[doc(alias = "seccomp_data")]
+#[non_exhaustive]
+pub struct SeccompData {
+    /// The syscall number.
+    pub nr: i32,
+
+    /// The `AUDIT_ARCH_*` value identifying the syscall convention.
+    pub arch: u32,
+
+    /// The instruction pointer at the time of the syscall.
+    pub instruction_pointer: u64,
+
+    /// The syscall arguments.
+    pub args: [u64; 6],
+}
+
+/// `struct seccomp_notif`—A notification received with [`notif_recv`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[doc(alias = "seccomp_notif")]
+#[non_exhaustive]
+pub struct SeccompNotif {
+    /// The ID of this notification, for use in the response.
+    pub id: u64,
+
+    /// The ID of the thread which made the syscall, or `None` if it isn't
+    /// visible in the supervisor's PID namespace.
+    pub pid: Option<Pid>,
+
+    /// The syscall.
+    pub data: SeccompData,
+}
+
+/// `struct seccomp_notif_resp`—A response to a notification, for use with
+/// [`notif_send`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[doc(alias = "seccomp_notif_resp")]
+#[non_exhaustive]
+pub struct SeccompNotifResp {
+    /// The ID of the notification being responded to.
+    pub id: u64,
+
+    /// The syscall's return value, if `error` is zero.
+    pub val: i64,
+
+    /// The negated error value to fail the syscall with, or zero for
+    /// success.
+    pub error: i32,
+
+    /// Flags.
+    pub flags: SeccompNotifRespFlags,
+}
+
+impl SeccompNotifResp {
+    /// Respond to notification `id` by making the syscall succeed with return
+    /// value `val`, without executing it.
+    #[inline]
+    pub const fn success(id: u64, val: i64) -> Self {
+        Self {
+            id,
+            val,
+            error: 0,
+            flags: SeccompNotifRespFlags::empty(),
+        }
+    }
+
+    /// Respond to notification `id` by making the syscall fail with `errno`,
+    /// without executing it.
+    #[inline]
+    pub const fn error(id: u64, errno: io::Errno) -> Self {
+        Self {
+            id,
+            val: 0,
+            error: -errno.raw_os_error(),
+            flags: SeccompNotifRespFlags::empty(),
+        }
+    }
+
+    /// Respond to notification `id` by letting the kernel execute the
+    /// syscall, with [`SeccompNotifRespFlags::CONTINUE`] (since Linux 5.5).
+    ///
+    /// The supervisor can't rely on this for security, since the target's
+    /// memory may change after the supervisor has inspected the syscall
+    /// arguments.
+    #[inline]
+    pub const fn continue_syscall(id: u64) -> Self {
+        Self {
+            id,
+            val: 0,
+            error: 0,
+            flags: SeccompNotifRespFlags::CONTINUE,
+        }
+    }
+}
+
+/// `ioctl(listener, SECCOMP_IOCTL_NOTIF_RECV, &notif)`—Wait for a
+/// notification.
+///
+/// This blocks until a notification is available. If the target's syscall
+/// is interrupted by a signal before the notification is received, this
+/// fails with [`io::Errno::NOENT`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/seccomp_unotify.2.html
+#[doc(alias = "SECCOMP_IOCTL_NOTIF_RECV")]
+pub fn notif_recv<Fd: AsFd>(listener: Fd) -> io::Result<SeccompNotif> {
+    // The kernel requires the struct to be zeroed.
+    let mut notif = MaybeUninit::<seccomp_notif>::zeroed();
+
+    // SAFETY: `SECCOMP_IOCTL_NOTIF_RECV` writes a `seccomp_notif`.
+    let notif = unsafe {
+        let ctl = ioctl::Updater::<SECCOMP_IOCTL_NOTIF_RECV, _>::new(&mut notif);
+        ioctl::ioctl(listener, ctl)?;
+        notif.assume_init()
+    };
+
+    Ok(SeccompNotif {
+        id: notif.id,
+        pid: Pid::from_raw(notif.pid as _),
+        data: SeccompData {
+            nr: notif.data.nr,
+            arch: notif.data.arch,
+            instruction_pointer: notif.data.instruction_pointer,
+            args: notif.data.args,
+        },
+    })
+}
+
+/// `ioctl(listener, SECCOMP_IOCTL_NOTIF_SEND, &resp)`—Respond to a
+/// notification.
+///
+/// If the target's syscall was interrupted, or the target was killed, since
+/// the notification was received, this fails with [`io::Errno::NOENT`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/seccomp_unotify.2.html
+#[doc(alias = "SECCOMP_IOCTL_NOTIF_SEND")]
+pub fn notif_send<Fd: AsFd>(listener: Fd, resp: &SeccompNotifResp) -> io::Result<()> {
+    let mut resp = seccomp_notif_resp {
+        id: resp.id,
+        val: resp.val,
+        error: resp.error,
+        flags: resp.flags.bits(),
+    };
+
+    // SAFETY: `SECCOMP_IOCTL_NOTIF_SEND` reads a `seccomp_notif_resp`.
+    unsafe {
+        let ctl = ioctl::Updater::<SECCOMP_IOCTL_NOTIF_SEND, _>::new(&mut resp);
+        ioctl::ioctl(listener, ctl)
+    }
+}
+
+/// `ioctl(listener, SECCOMP_IOCTL_NOTIF_ID_VALID, &id)`—Test whether a
+/// notification is still valid.
+///
+/// A notification becomes invalid once it's responded to, or if the target's
+/// syscall is interrupted or the target is killed. Supervisors which read
+/// from the target's memory should check this after reading, to ensure that
+/// the target wasn't replaced by another process with the same PID.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/seccomp_unotify.2.html
+#[doc(alias = "SECCOMP_IOCTL_NOTIF_ID_VALID")]
+pub fn notif_id_valid<Fd: AsFd>(listener: Fd, id: u64) -> io::Result<bool> {
+    // SAFETY: `SECCOMP_IOCTL_NOTIF_ID_VALID` reads a `u64`.
+    let result = unsafe {
+        let ctl = ioctl::Setter::<SECCOMP_IOCTL_NOTIF_ID_VALID, u64>::new(id);
+        ioctl::ioctl(listener, ctl)
+    };
+    match result {
+        Ok(()) => Ok(true),
+        Err(io::Errno::NOENT) => Ok(false),
+        Err(err) => Err(err),
+    }
+}
+
+/// `ioctl(listener, SECCOMP_IOCTL_NOTIF_ADDFD, &addfd)`—Add a file
+/// descriptor to the target of a notification.
+///
+/// This duplicates `fd` into the target's file descriptor table, at
+/// `target_fd` if it's `Some`, replacing any file descriptor already there,
+/// or at the lowest available number otherwise. If `fd_flags` contains
+/// [`FdFlags::CLOEXEC`], the new file descriptor has `FD_CLOEXEC` set.
+///
+/// On success, returns the file descriptor number in the target. With
+/// [`SeccompAddfdFlags::SEND`], this also responds to the notification, with
+/// the file descriptor number as the syscall's return value.
+///
+/// This function was added in Linux 5.9.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/seccomp_unotify.2.html
+#[doc(alias = "SECCOMP_IOCTL_NOTIF_ADDFD", alias = "seccomp_notif_addfd")]
+pub fn add_fd<Listener: AsFd, Fd: AsFd>(
+    listener: Listener,
+    id: u64,
+    fd: Fd,
+    target_fd: Option<RawFd>,
+    fd_flags: FdFlags,
+    flags: SeccompAddfdFlags,
+) -> io::Result<RawFd> {
+    let mut flags = flags.bits();
+    let newfd = match target_fd {
+        Some(target_fd) => {
+            flags |= SECCOMP_ADDFD_FLAG_SETFD;
+            target_fd as u32
+        }
+        None => 0,
+    };
+    let newfd_flags = if fd_flags.contains(FdFlags::CLOEXEC) {
+        O_CLOEXEC
+    } else {
+        0
+    };
+
+    let addfd = seccomp_notif_addfd {
+        id,
+        flags,
+        srcfd: fd.as_fd().as_raw_fd() as u32,
+        newfd,
+        newfd_flags,
+    };
+
+    // SAFETY: `AddFd` implements `SECCOMP_IOCTL_NOTIF_ADDFD`, and `addfd`
+    // refers to a valid file descriptor.
+    unsafe { ioctl::ioctl(listener, AddFd(addfd)) }
+}
+
+struct AddFd(seccomp_notif_addfd);
+
+unsafe impl ioctl::Ioctl for AddFd {
+    type Output = RawFd;
+
+    const IS_MUTATING: bool = true;
+
+    fn opcode(&self) -> ioctl::Opcode {
+        SECCOMP_IOCTL_NOTIF_ADDFD
+    }
+
+    fn as_ptr(&mut self) -> *mut crate::ffi::c_void {
+        (&mut self.0 as *mut seccomp_notif_addfd).cast()
+    }
+
+    unsafe fn output_from_ptr(
+        output: ioctl::IoctlOutput,
+        _: *mut crate::ffi::c_void,
+    ) -> io::Result<Self::Output> {
+        Ok(output as RawFd)
+    }
+}
@@ -0,0 +1,198 @@
+//! System V interprocess communication
+//!
+//! System V shared memory segments, semaphore sets, and message queues are
+//! identified by integer IDs rather than file descriptors. They persist until
+//! they're explicitly removed with [`shmctl_rmid`], [`semctl_rmid`], or
+//! [`msgctl_rmid`], or the system is rebooted.
+//!
+//! New code should generally prefer [`rustix::shm`] and [`rustix::mq`]; this
+//! module is mainly for interoperating with existing programs that use the
+//! System V APIs.
+//!
+//! On some 32-bit architectures, these functions require Linux 5.1 or later.
+//!
+//! # Examples
+//!
+//! ```
+//! use rustix::io;
+//! use rustix::sysv::{shmat, shmctl_rmid, shmctl_stat, shmdt, shmget};
+//! use rustix::sysv::{IpcFlags, Key, Mode, ShmatFlags};
+//! use std::ptr::null_mut;
+//!
+//! # fn example() -> io::Result<()> {
+//! // Create a new segment which only this process knows the ID of.
+//! let shmid = shmget(
+//!     Key::PRIVATE,
+//!     4096,
+//!     IpcFlags::CREATE | IpcFlags::EXCL,
+//!     Mode::RUSR | Mode::WUSR,
+//! )?;
+//!
+//! // Attach it to our address space.
+//! //
+//! // SAFETY: We're attaching the segment at an address the OS picks, so it's
+//! // independent of any existing memory allocations.
+//! let ptr = unsafe { shmat(shmid, null_mut(), ShmatFlags::empty())? };
+//! assert_eq!(shmctl_stat(shmid)?.nattch, 1);
+//!
+//! // Use `ptr`…
+//!
+//! // Detach it, and remove the segment.
+//! //
+//! // SAFETY: We're not using `ptr` anymore.
+//! unsafe { shmdt(ptr)? };
+//! shmctl_rmid(shmid)?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [`rustix::shm`]: crate::shm
+//! [`rustix::mq`]: crate::mq
+
+#![allow(unsafe_code)]
+
+use crate::{io, path};
+
+mod msg;
+mod sem;
+mod shm;
+
+pub use crate::backend::fs::types::Mode;
+pub use crate::backend::sysv::types::{IpcFlags, MsgFlags, SemFlags, ShmatFlags};
+pub use crate::ugid::{Gid, Uid};
+pub use msg::*;
+pub use sem::*;
+pub use shm::*;
+
+/// A System V IPC key, used to name a shared memory segment, semaphore set,
+/// or message queue.
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[doc(alias = "key_t")]
+pub struct Key(i32);
+
+impl Key {
+    /// `IPC_PRIVATE`—A key which always creates a new object.
+    #[doc(alias = "IPC_PRIVATE")]
+    pub const PRIVATE: Self = Self(0);
+
+    /// Converts a raw `key_t` into a `Key`.
+    #[inline]
+    pub const fn from_raw(raw: i32) -> Self {
+        Self(raw)
+    }
+
+    /// Converts a `Key` into a raw `key_t`.
+    #[inline]
+    pub const fn as_raw(self) -> i32 {
+        self.0
+    }
+}
+
+/// `ftok(path, proj_id)`—Derives a System V IPC key from a path.
+///
+/// This uses the same algorithm as glibc and musl, so keys computed here
+/// match keys computed by C programs for the same path and `proj_id`.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9799919799/functions/ftok.html
+/// [Linux]: https://man7.org/linux/man-pages/man3/ftok.3.html
+#[inline]
+pub fn ftok<P: path::Arg>(path: P, proj_id: u8) -> io::Result<Key> {
+    let st = crate::fs::stat(path)?;
+    let key = (st.st_ino as u32 & 0xffff)
+        | ((st.st_dev as u32 & 0xff) << 16)
+        | (u32::from(proj_id) << 24);
+    Ok(Key(key as i32))
+}
+
+/// `struct ipc_perm`—Ownership and permissions of a System V IPC object.
+///
+/// Only `uid`, `gid`, and the permission bits of `mode` can be changed with
+/// [`shmctl_set`], [`semctl_set`], and [`msgctl_set`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[non_exhaustive]
+pub struct IpcPerm {
+    /// The key the object was created with.
+    pub key: Key,
+
+    /// The owner's user ID.
+    pub uid: Uid,
+
+    /// The owner's group ID.
+    pub gid: Gid,
+
+    /// The creator's user ID.
+    pub cuid: Uid,
+
+    /// The creator's group ID.
+    pub cgid: Gid,
+
+    /// The permission bits, plus object-specific status bits such as
+    /// `SHM_DEST` and `SHM_LOCKED`.
+    pub mode: Mode,
+
+    /// The slot usage sequence number.
+    pub seq: u16,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::sysv::types::*;
+
+    #[test]
+    fn sysv_layouts() {
+        use libc as c;
+
+        check_renamed_type!(SemBuf, sembuf);
+        check_renamed_struct_renamed_field!(SemBuf, sembuf, num, sem_num);
+        check_renamed_struct_renamed_field!(SemBuf, sembuf, op, sem_op);
+        check_renamed_struct_renamed_field!(SemBuf, sembuf, flags, sem_flg);
+
+        // On 64-bit platforms, glibc's structs are the kernel's.
+        #[cfg(all(target_env = "gnu", target_pointer_width = "64"))]
+        {
+            check_renamed_type!(ipc64_perm, ipc_perm);
+            check_renamed_type!(shmid64_ds, shmid_ds);
+            check_renamed_type!(semid64_ds, semid_ds);
+            check_renamed_type!(msqid64_ds, msqid_ds);
+            check_renamed_struct_renamed_field!(shmid64_ds, shmid_ds, shm_nattch, shm_nattch);
+            check_renamed_struct_renamed_field!(semid64_ds, semid_ds, sem_nsems, sem_nsems);
+            check_renamed_struct_renamed_field!(msqid64_ds, msqid_ds, msg_lrpid, msg_lrpid);
+        }
+    }
+
+    #[test]
+    fn sysv_constants() {
+        use libc as c;
+
+        assert_eq!(IPC_RMID, c::IPC_RMID);
+        assert_eq!(IPC_SET, c::IPC_SET);
+        assert_eq!(GETPID, c::GETPID);
+        assert_eq!(GETVAL, c::GETVAL);
+        assert_eq!(GETALL, c::GETALL);
+        assert_eq!(GETNCNT, c::GETNCNT);
+        assert_eq!(GETZCNT, c::GETZCNT);
+        assert_eq!(SETVAL, c::SETVAL);
+        assert_eq!(SETALL, c::SETALL);
+
+        assert_eq!(IpcFlags::CREATE.bits(), c::IPC_CREAT as _);
+        assert_eq!(IpcFlags::EXCL.bits(), c::IPC_EXCL as _);
+        assert_eq!(IpcFlags::SHM_HUGETLB.bits(), c::SHM_HUGETLB as _);
+        assert_eq!(IpcFlags::SHM_NORESERVE.bits(), c::SHM_NORESERVE as _);
+        assert_eq!(ShmatFlags::RDONLY.bits(), c::SHM_RDONLY as _);
+        assert_eq!(ShmatFlags::RND.bits(), c::SHM_RND as _);
+        assert_eq!(ShmatFlags::REMAP.bits(), c::SHM_REMAP as _);
+        assert_eq!(ShmatFlags::EXEC.bits(), c::SHM_EXEC as _);
+        assert_eq!(SemFlags::NOWAIT.bits(), c::IPC_NOWAIT as _);
+        assert_eq!(SemFlags::UNDO.bits(), c::SEM_UNDO as _);
+        assert_eq!(MsgFlags::NOWAIT.bits(), c::IPC_NOWAIT as _);
+        assert_eq!(MsgFlags::NOERROR.bits(), c::MSG_NOERROR as _);
+        assert_eq!(MsgFlags::EXCEPT.bits(), c::MSG_EXCEPT as _);
+        assert_eq!(MsgFlags::COPY.bits(), c::MSG_COPY as _);
+    }
+}
@@ -0,0 +1,209 @@
+use crate::ffi::c_long;
+use crate::pid::Pid;
+use crate::sysv::{IpcFlags, IpcPerm, Key, Mode, MsgFlags};
+use crate::{backend, io};
+
+/// A System V message queue identifier.
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct MsgId(i32);
+
+impl MsgId {
+    /// Converts a raw message queue identifier into a `MsgId`.
+    #[inline]
+    pub const fn from_raw(raw: i32) -> Self {
+        Self(raw)
+    }
+
+    /// Converts a `MsgId` into a raw message queue identifier.
+    #[inline]
+    pub const fn as_raw(self) -> i32 {
+        self.0
+    }
+}
+
+/// `struct msgbuf`—A message for use with [`msgsnd`] and [`msgrcv`], with
+/// room for up to `N` bytes of text.
+#[repr(C)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[doc(alias = "msgbuf")]
+pub struct MsgBuf<const N: usize> {
+    /// The message type, which must be positive.
+    pub mtype: c_long,
+
+    /// The message text.
+    pub mtext: [u8; N],
+}
+
+impl<const N: usize> MsgBuf<N> {
+    /// Constructs a new `MsgBuf` with the given type and zeroed text.
+    #[inline]
+    pub const fn new(mtype: c_long) -> Self {
+        Self {
+            mtype,
+            mtext: [0; N],
+        }
+    }
+}
+
+/// `struct msqid_ds`—The status of a System V message queue.
+///
+/// Times are in seconds since the Unix epoch, and are 0 if the event hasn't
+/// happened.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[non_exhaustive]
+pub struct MsqidDs {
+    /// Ownership and permissions.
+    pub perm: IpcPerm,
+
+    /// The time of the last [`msgsnd`].
+    pub stime: i64,
+
+    /// The time of the last [`msgrcv`].
+    pub rtime: i64,
+
+    /// The time of the last change by [`msgctl_set`], or creation.
+    pub ctime: i64,
+
+    /// The number of bytes of text currently in the queue.
+    pub cbytes: usize,
+
+    /// The number of messages currently in the queue.
+    pub qnum: usize,
+
+    /// The maximum number of bytes of text allowed in the queue.
+    pub qbytes: usize,
+
+    /// The process that performed the last [`msgsnd`], if any.
+    pub lspid: Option<Pid>,
+
+    /// The process that performed the last [`msgrcv`], if any.
+    pub lrpid: Option<Pid>,
+}
+
+/// `msgget(key, flags | mode)`—Gets or creates a System V message queue.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9799919799/functions/msgget.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/msgget.2.html
+#[inline]
+pub fn msgget(key: Key, flags: IpcFlags, mode: Mode) -> io::Result<MsgId> {
+    backend::sysv::syscalls::msgget(key, flags, mode)
+}
+
+/// `msgsnd(msqid, msg, len, flags)`—Sends a message to a System V message
+/// queue.
+///
+/// The first `len` bytes of `msg.mtext` are sent. If `len` is greater than
+/// `N`, this fails with [`io::Errno::INVAL`].
+///
+/// If the queue is full, this blocks until there's room, unless `flags`
+/// contains [`MsgFlags::NOWAIT`], in which case it fails with
+/// [`io::Errno::AGAIN`].
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9799919799/functions/msgsnd.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/msgsnd.2.html
+#[inline]
+pub fn msgsnd<const N: usize>(
+    msqid: MsgId,
+    msg: &MsgBuf<N>,
+    len: usize,
+    flags: MsgFlags,
+) -> io::Result<()> {
+    if len > N {
+        return Err(io::Errno::INVAL);
+    }
+    // SAFETY: `msg` is a `struct msgbuf` with at least `len` bytes of text.
+    unsafe { backend::sysv::syscalls::msgsnd(msqid, (msg as *const MsgBuf<N>).cast(), len, flags) }
+}
+
+/// `msgrcv(msqid, msg, N, msgtyp, flags)`—Receives a message from a System V
+/// message queue.
+///
+/// If `msgtyp` is 0, the first message in the queue is received. If it's
+/// positive, the first message of that type is received, or with
+/// [`MsgFlags::EXCEPT`], the first message not of that type. If it's
+/// negative, the first message with the lowest type less than or equal to
+/// its absolute value is received.
+///
+/// On success, returns the number of bytes of text received into
+/// `msg.mtext`.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9799919799/functions/msgrcv.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/msgrcv.2.html
+#[inline]
+pub fn msgrcv<const N: usize>(
+    msqid: MsgId,
+    msg: &mut MsgBuf<N>,
+    msgtyp: c_long,
+    flags: MsgFlags,
+) -> io::Result<usize> {
+    // SAFETY: `msg` is a `struct msgbuf` with room for `N` bytes of text.
+    unsafe {
+        backend::sysv::syscalls::msgrcv(msqid, (msg as *mut MsgBuf<N>).cast(), N, msgtyp, flags)
+    }
+}
+
+/// `msgctl(msqid, IPC_STAT, &mut buf)`—Returns the status of a System V
+/// message queue.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9799919799/functions/msgctl.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/msgctl.2.html
+#[inline]
+#[doc(alias = "msgctl")]
+#[doc(alias = "IPC_STAT")]
+pub fn msgctl_stat(msqid: MsgId) -> io::Result<MsqidDs> {
+    backend::sysv::syscalls::msgctl_stat(msqid)
+}
+
+/// `msgctl(msqid, IPC_SET, &buf)`—Sets the owner, permissions, and size
+/// limit of a System V message queue.
+///
+/// Only `buf.perm.uid`, `buf.perm.gid`, the permission bits of
+/// `buf.perm.mode`, and `buf.qbytes` are used.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9799919799/functions/msgctl.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/msgctl.2.html
+#[inline]
+#[doc(alias = "msgctl")]
+#[doc(alias = "IPC_SET")]
+pub fn msgctl_set(msqid: MsgId, buf: &MsqidDs) -> io::Result<()> {
+    backend::sysv::syscalls::msgctl_set(msqid, buf)
+}
+
+/// `msgctl(msqid, IPC_RMID, NULL)`—Removes a System V message queue.
+///
+/// Any processes blocked in [`msgsnd`] or [`msgrcv`] on the queue are woken,
+/// and fail with [`io::Errno::IDRM`].
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9799919799/functions/msgctl.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/msgctl.2.html
+#[inline]
+#[doc(alias = "msgctl")]
+#[doc(alias = "IPC_RMID")]
+pub fn msgctl_rmid(msqid: MsgId) -> io::Result<()> {
+    backend::sysv::syscalls::msgctl_rmid(msqid)
+}
@@ -0,0 +1,299 @@
+use crate::pid::Pid;
+use crate::sysv::{IpcFlags, IpcPerm, Key, Mode, SemFlags};
+use crate::timespec::Timespec;
+use crate::{backend, io};
+
+/// A System V semaphore set identifier.
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct SemId(i32);
+
+impl SemId {
+    /// Converts a raw semaphore set identifier into a `SemId`.
+    #[inline]
+    pub const fn from_raw(raw: i32) -> Self {
+        Self(raw)
+    }
+
+    /// Converts a `SemId` into a raw semaphore set identifier.
+    #[inline]
+    pub const fn as_raw(self) -> i32 {
+        self.0
+    }
+}
+
+/// `struct sembuf`—An operation on one semaphore in a set, for use with
+/// [`semop`] and [`semtimedop`].
+#[repr(C)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[doc(alias = "sembuf")]
+pub struct SemBuf {
+    /// The index of the semaphore in the set.
+    pub num: u16,
+
+    /// The operation: a positive value is added to the semaphore, a negative
+    /// value waits until it can be subtracted, and zero waits until the
+    /// semaphore is zero.
+    pub op: i16,
+
+    /// Flags for the operation.
+    pub flags: SemFlags,
+}
+
+impl SemBuf {
+    /// Constructs a new `SemBuf`.
+    #[inline]
+    pub const fn new(num: u16, op: i16, flags: SemFlags) -> Self {
+        Self { num, op, flags }
+    }
+}
+
+/// `struct semid_ds`—The status of a System V semaphore set.
+///
+/// Times are in seconds since the Unix epoch, and are 0 if the event hasn't
+/// happened.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[non_exhaustive]
+pub struct SemidDs {
+    /// Ownership and permissions.
+    pub perm: IpcPerm,
+
+    /// The time of the last [`semop`].
+    pub otime: i64,
+
+    /// The time of the last change by [`semctl_set`], or creation.
+    pub ctime: i64,
+
+    /// The number of semaphores in the set.
+    pub nsems: usize,
+}
+
+/// `semget(key, nsems, flags | mode)`—Gets or creates a System V semaphore
+/// set.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9799919799/functions/semget.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/semget.2.html
+#[inline]
+pub fn semget(key: Key, nsems: usize, flags: IpcFlags, mode: Mode) -> io::Result<SemId> {
+    let nsems = nsems.try_into().map_err(|_| io::Errno::INVAL)?;
+    backend::sysv::syscalls::semget(key, nsems, flags, mode)
+}
+
+/// `semop(semid, ops)`—Performs operations on a System V semaphore set.
+///
+/// The operations are performed atomically: either all of them are
+/// performed, or none are.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9799919799/functions/semop.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/semop.2.html
+#[inline]
+pub fn semop(semid: SemId, ops: &[SemBuf]) -> io::Result<()> {
+    backend::sysv::syscalls::semop(semid, ops)
+}
+
+/// `semtimedop(semid, ops, timeout)`—Performs operations on a System V
+/// semaphore set, with a timeout.
+///
+/// `timeout` is a relative time. If the operations can't be performed before
+/// it expires, this fails with [`io::Errno::AGAIN`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/semtimedop.2.html
+#[inline]
+pub fn semtimedop(semid: SemId, ops: &[SemBuf], timeout: &Timespec) -> io::Result<()> {
+    backend::sysv::syscalls::semtimedop(semid, ops, timeout)
+}
+
+/// `semctl(semid, 0, IPC_STAT, &mut buf)`—Returns the status of a System V
+/// semaphore set.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9799919799/functions/semctl.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/semctl.2.html
+#[inline]
+#[doc(alias = "semctl")]
+#[doc(alias = "IPC_STAT")]
+pub fn semctl_stat(semid: SemId) -> io::Result<SemidDs> {
+    backend::sysv::syscalls::semctl_stat(semid)
+}
+
+/// `semctl(semid, 0, IPC_SET, &buf)`—Sets the owner and permissions of a
+/// System V semaphore set.
+///
+/// Only `buf.perm.uid`, `buf.perm.gid`, and the permission bits of
+/// `buf.perm.mode` are used.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9799919799/functions/semctl.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/semctl.2.html
+#[inline]
+#[doc(alias = "semctl")]
+#[doc(alias = "IPC_SET")]
+pub fn semctl_set(semid: SemId, buf: &SemidDs) -> io::Result<()> {
+    backend::sysv::syscalls::semctl_set(semid, buf)
+}
+
+/// `semctl(semid, 0, IPC_RMID)`—Removes a System V semaphore set.
+///
+/// Any processes blocked in [`semop`] on the set are woken, and fail with
+/// [`io::Errno::IDRM`].
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9799919799/functions/semctl.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/semctl.2.html
+#[inline]
+#[doc(alias = "semctl")]
+#[doc(alias = "IPC_RMID")]
+pub fn semctl_rmid(semid: SemId) -> io::Result<()> {
+    backend::sysv::syscalls::semctl_rmid(semid)
+}
+
+/// `semctl(semid, semnum, GETVAL)`—Returns the value of a semaphore.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9799919799/functions/semctl.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/semctl.2.html
+#[inline]
+#[doc(alias = "semctl")]
+#[doc(alias = "GETVAL")]
+pub fn semctl_getval(semid: SemId, semnum: u16) -> io::Result<i32> {
+    backend::sysv::syscalls::semctl_get(semid, semnum, backend::sysv::types::GETVAL)
+}
+
+/// `semctl(semid, semnum, SETVAL, val)`—Sets the value of a semaphore.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9799919799/functions/semctl.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/semctl.2.html
+#[inline]
+#[doc(alias = "semctl")]
+#[doc(alias = "SETVAL")]
+pub fn semctl_setval(semid: SemId, semnum: u16, val: i32) -> io::Result<()> {
+    backend::sysv::syscalls::semctl_setval(semid, semnum, val)
+}
+
+/// `semctl(semid, 0, GETALL, vals)`—Returns the values of all the semaphores
+/// in a set.
+///
+/// `vals.len()` must be equal to the number of semaphores in the set,
+/// otherwise this fails with [`io::Errno::INVAL`].
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9799919799/functions/semctl.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/semctl.2.html
+#[inline]
+#[doc(alias = "semctl")]
+#[doc(alias = "GETALL")]
+pub fn semctl_getall(semid: SemId, vals: &mut [u16]) -> io::Result<()> {
+    // The kernel writes one value for each semaphore in the set, so check
+    // that `vals` is big enough. The number of semaphores is fixed when the
+    // set is created, so this can't change before the `GETALL`.
+    if semctl_stat(semid)?.nsems != vals.len() {
+        return Err(io::Errno::INVAL);
+    }
+    // SAFETY: We checked that `vals` has one element for each semaphore.
+    unsafe { backend::sysv::syscalls::semctl_getall(semid, vals.as_mut_ptr()) }
+}
+
+/// `semctl(semid, 0, SETALL, vals)`—Sets the values of all the semaphores in
+/// a set.
+///
+/// `vals.len()` must be equal to the number of semaphores in the set,
+/// otherwise this fails with [`io::Errno::INVAL`].
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9799919799/functions/semctl.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/semctl.2.html
+#[inline]
+#[doc(alias = "semctl")]
+#[doc(alias = "SETALL")]
+pub fn semctl_setall(semid: SemId, vals: &[u16]) -> io::Result<()> {
+    // As in `semctl_getall`, the kernel reads one value for each semaphore.
+    if semctl_stat(semid)?.nsems != vals.len() {
+        return Err(io::Errno::INVAL);
+    }
+    // SAFETY: We checked that `vals` has one element for each semaphore.
+    unsafe { backend::sysv::syscalls::semctl_setall(semid, vals.as_ptr()) }
+}
+
+/// `semctl(semid, semnum, GETPID)`—Returns the process that last performed
+/// an operation on a semaphore, if any.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9799919799/functions/semctl.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/semctl.2.html
+#[inline]
+#[doc(alias = "semctl")]
+#[doc(alias = "GETPID")]
+pub fn semctl_getpid(semid: SemId, semnum: u16) -> io::Result<Option<Pid>> {
+    let pid = backend::sysv::syscalls::semctl_get(semid, semnum, backend::sysv::types::GETPID)?;
+    Ok(Pid::from_raw(pid))
+}
+
+/// `semctl(semid, semnum, GETNCNT)`—Returns the number of processes waiting
+/// for a semaphore to increase.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9799919799/functions/semctl.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/semctl.2.html
+#[inline]
+#[doc(alias = "semctl")]
+#[doc(alias = "GETNCNT")]
+pub fn semctl_getncnt(semid: SemId, semnum: u16) -> io::Result<usize> {
+    let n = backend::sysv::syscalls::semctl_get(semid, semnum, backend::sysv::types::GETNCNT)?;
+    Ok(n as usize)
+}
+
+/// `semctl(semid, semnum, GETZCNT)`—Returns the number of processes waiting
+/// for a semaphore to become zero.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9799919799/functions/semctl.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/semctl.2.html
+#[inline]
+#[doc(alias = "semctl")]
+#[doc(alias = "GETZCNT")]
+pub fn semctl_getzcnt(semid: SemId, semnum: u16) -> io::Result<usize> {
+    let n = backend::sysv::syscalls::semctl_get(semid, semnum, backend::sysv::types::GETZCNT)?;
+    Ok(n as usize)
+}
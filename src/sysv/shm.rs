@@ -0,0 +1,164 @@
+use crate::ffi::c_void;
+use crate::pid::Pid;
+use crate::sysv::{IpcFlags, IpcPerm, Key, Mode, ShmatFlags};
+use crate::{backend, io};
+
+/// A System V shared memory segment identifier.
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct ShmId(i32);
+
+impl ShmId {
+    /// Converts a raw shared memory segment identifier into a `ShmId`.
+    #[inline]
+    pub const fn from_raw(raw: i32) -> Self {
+        Self(raw)
+    }
+
+    /// Converts a `ShmId` into a raw shared memory segment identifier.
+    #[inline]
+    pub const fn as_raw(self) -> i32 {
+        self.0
+    }
+}
+
+/// `struct shmid_ds`—The status of a System V shared memory segment.
+///
+/// Times are in seconds since the Unix epoch, and are 0 if the event hasn't
+/// happened.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[non_exhaustive]
+pub struct ShmidDs {
+    /// Ownership and permissions.
+    pub perm: IpcPerm,
+
+    /// The size of the segment in bytes.
+    pub segsz: usize,
+
+    /// The time of the last [`shmat`].
+    pub atime: i64,
+
+    /// The time of the last [`shmdt`].
+    pub dtime: i64,
+
+    /// The time of the last change by [`shmctl_set`], or creation.
+    pub ctime: i64,
+
+    /// The process that created the segment, if it's visible in the current
+    /// PID namespace.
+    pub cpid: Option<Pid>,
+
+    /// The process that last attached or detached the segment, if any.
+    pub lpid: Option<Pid>,
+
+    /// The number of current attaches.
+    pub nattch: usize,
+}
+
+/// `shmget(key, size, flags | mode)`—Gets or creates a System V shared
+/// memory segment.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9799919799/functions/shmget.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/shmget.2.html
+#[inline]
+pub fn shmget(key: Key, size: usize, flags: IpcFlags, mode: Mode) -> io::Result<ShmId> {
+    backend::sysv::syscalls::shmget(key, size, flags, mode)
+}
+
+/// `shmat(shmid, addr, flags)`—Attaches a System V shared memory segment to
+/// the address space of the calling process.
+///
+/// If `addr` is null, the OS chooses the address.
+///
+/// # Safety
+///
+/// If `addr` is non-null, and `flags` contains [`ShmatFlags::REMAP`], any
+/// existing mappings in the range are replaced. The contents of the segment
+/// may be modified by other processes at any time.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9799919799/functions/shmat.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/shmat.2.html
+#[inline]
+pub unsafe fn shmat(shmid: ShmId, addr: *mut c_void, flags: ShmatFlags) -> io::Result<*mut c_void> {
+    backend::sysv::syscalls::shmat(shmid, addr, flags)
+}
+
+/// `shmdt(addr)`—Detaches a System V shared memory segment from the address
+/// space of the calling process.
+///
+/// # Safety
+///
+/// `addr` must be an address returned from [`shmat`] which hasn't already
+/// been detached, and nothing may use the memory after it's detached.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9799919799/functions/shmdt.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/shmdt.2.html
+#[inline]
+pub unsafe fn shmdt(addr: *mut c_void) -> io::Result<()> {
+    backend::sysv::syscalls::shmdt(addr)
+}
+
+/// `shmctl(shmid, IPC_STAT, &mut buf)`—Returns the status of a System V
+/// shared memory segment.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9799919799/functions/shmctl.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/shmctl.2.html
+#[inline]
+#[doc(alias = "shmctl")]
+#[doc(alias = "IPC_STAT")]
+pub fn shmctl_stat(shmid: ShmId) -> io::Result<ShmidDs> {
+    backend::sysv::syscalls::shmctl_stat(shmid)
+}
+
+/// `shmctl(shmid, IPC_SET, &buf)`—Sets the owner and permissions of a
+/// System V shared memory segment.
+///
+/// Only `buf.perm.uid`, `buf.perm.gid`, and the permission bits of
+/// `buf.perm.mode` are used.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9799919799/functions/shmctl.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/shmctl.2.html
+#[inline]
+#[doc(alias = "shmctl")]
+#[doc(alias = "IPC_SET")]
+pub fn shmctl_set(shmid: ShmId, buf: &ShmidDs) -> io::Result<()> {
+    backend::sysv::syscalls::shmctl_set(shmid, buf)
+}
+
+/// `shmctl(shmid, IPC_RMID, NULL)`—Marks a System V shared memory segment to
+/// be removed.
+///
+/// The segment is removed once the last process detaches it.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9799919799/functions/shmctl.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/shmctl.2.html
+#[inline]
+#[doc(alias = "shmctl")]
+#[doc(alias = "IPC_RMID")]
+pub fn shmctl_rmid(shmid: ShmId) -> io::Result<()> {
+    backend::sysv::syscalls::shmctl_rmid(shmid)
+}
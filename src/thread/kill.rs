@@ -0,0 +1,19 @@
+use crate::thread::Pid;
+use crate::{backend, io};
+
+pub use crate::signal::Signal;
+
+/// `tgkill(pid, tid, sig)`—Sends a signal to a thread.
+///
+/// `tid` must be a thread in the thread group `pid`, otherwise this fails
+/// with [`io::Errno::SRCH`]. This guards against signaling an unrelated
+/// thread if the thread `tid` has exited and its ID has been reused.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/tgkill.2.html
+#[inline]
+pub fn tgkill(pid: Pid, tid: Pid, sig: Signal) -> io::Result<()> {
+    backend::thread::syscalls::tgkill(pid, tid, sig)
+}
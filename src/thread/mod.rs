@@ -7,6 +7,8 @@ pub mod futex;
 #[cfg(linux_kernel)]
 mod id;
 #[cfg(linux_kernel)]
+mod kill;
+#[cfg(linux_kernel)]
 mod libcap;
 #[cfg(linux_kernel)]
 mod membarrier;
@@ -14,6 +16,8 @@ mod membarrier;
 mod prctl;
 #[cfg(any(freebsdlike, linux_kernel, target_os = "fuchsia"))]
 mod sched;
+#[cfg(linux_kernel)]
+mod sched_attr;
 mod sched_yield;
 #[cfg(linux_kernel)]
 mod setns;
@@ -23,6 +27,8 @@ pub use clock::*;
 #[cfg(linux_kernel)]
 pub use id::*;
 #[cfg(linux_kernel)]
+pub use kill::*;
+#[cfg(linux_kernel)]
 // #[expect(deprecated, reason = "CapabilityFlags is deprecated")]
 #[allow(deprecated)]
 pub use libcap::{capabilities, set_capabilities, CapabilityFlags, CapabilitySet, CapabilitySets};
@@ -32,6 +38,13 @@ pub use membarrier::*;
 pub use prctl::*;
 #[cfg(any(freebsdlike, linux_kernel, target_os = "fuchsia"))]
 pub use sched::*;
+#[cfg(linux_kernel)]
+pub(crate) use sched_attr::RawSchedAttr;
+#[cfg(linux_kernel)]
+pub use sched_attr::{
+    sched_get_priority_max, sched_get_priority_min, sched_getattr, sched_getparam, sched_setattr,
+    sched_setscheduler, SchedAttr, SchedFlags, SchedPolicy,
+};
 pub use sched_yield::sched_yield;
 #[cfg(linux_kernel)]
 pub use setns::*;
@@ -0,0 +1,196 @@
+//! Scheduling policy and attributes.
+
+use crate::pid::Pid;
+use crate::{backend, io};
+
+pub use backend::thread::types::{SchedFlags, SchedPolicy};
+
+/// `struct sched_attr`—A thread's scheduling policy and attributes, for use
+/// with [`sched_setattr`] and [`sched_getattr`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/sched_setattr.2.html
+#[derive(Default, Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[doc(alias = "sched_attr")]
+pub struct SchedAttr {
+    /// The scheduling policy.
+    pub policy: SchedPolicy,
+
+    /// Flags.
+    pub flags: SchedFlags,
+
+    /// The nice value, for [`SchedPolicy::Other`] and [`SchedPolicy::Batch`].
+    pub nice: i32,
+
+    /// The static priority, for [`SchedPolicy::Fifo`] and [`SchedPolicy::Rr`].
+    pub priority: u32,
+
+    /// The runtime, in nanoseconds, for [`SchedPolicy::Deadline`].
+    pub runtime: u64,
+
+    /// The relative deadline, in nanoseconds, for [`SchedPolicy::Deadline`].
+    pub deadline: u64,
+
+    /// The period, in nanoseconds, for [`SchedPolicy::Deadline`].
+    pub period: u64,
+
+    /// The minimum utilization clamp, with [`SchedFlags::UTIL_CLAMP_MIN`]
+    /// (since Linux 5.3).
+    pub util_min: u32,
+
+    /// The maximum utilization clamp, with [`SchedFlags::UTIL_CLAMP_MAX`]
+    /// (since Linux 5.3).
+    pub util_max: u32,
+}
+
+/// The kernel's `struct sched_attr`, which isn't in `linux-raw-sys`.
+#[repr(C)]
+#[derive(Default)]
+pub(crate) struct RawSchedAttr {
+    pub(crate) size: u32,
+    pub(crate) sched_policy: u32,
+    pub(crate) sched_flags: u64,
+    pub(crate) sched_nice: i32,
+    pub(crate) sched_priority: u32,
+    pub(crate) sched_runtime: u64,
+    pub(crate) sched_deadline: u64,
+    pub(crate) sched_period: u64,
+    pub(crate) sched_util_min: u32,
+    pub(crate) sched_util_max: u32,
+}
+
+/// `sched_setattr(pid, &attr, 0)`—Set a thread's scheduling policy and
+/// attributes.
+///
+/// `pid` is the thread ID to update. If `pid` is `None`, then the current
+/// thread is updated.
+///
+/// Real-time policies, and lowering the nice value, require
+/// `CAP_SYS_NICE` or a suitable `RLIMIT_RTPRIO` or `RLIMIT_NICE` limit.
+///
+/// This function was added in Linux 3.14.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/sched_setattr.2.html
+#[inline]
+pub fn sched_setattr(pid: Option<Pid>, attr: &SchedAttr) -> io::Result<()> {
+    let raw = RawSchedAttr {
+        size: core::mem::size_of::<RawSchedAttr>() as u32,
+        sched_policy: attr.policy as u32,
+        sched_flags: attr.flags.bits(),
+        sched_nice: attr.nice,
+        sched_priority: attr.priority,
+        sched_runtime: attr.runtime,
+        sched_deadline: attr.deadline,
+        sched_period: attr.period,
+        sched_util_min: attr.util_min,
+        sched_util_max: attr.util_max,
+    };
+    backend::thread::syscalls::sched_setattr(pid, &raw)
+}
+
+/// `sched_getattr(pid, &attr, sizeof(attr), 0)`—Get a thread's scheduling
+/// policy and attributes.
+///
+/// `pid` is the thread ID to query. If `pid` is `None`, then the current
+/// thread is queried.
+///
+/// If the thread's policy isn't one that [`SchedPolicy`] knows about, this
+/// fails with [`io::Errno::NOTSUP`].
+///
+/// This function was added in Linux 3.14.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/sched_getattr.2.html
+#[inline]
+pub fn sched_getattr(pid: Option<Pid>) -> io::Result<SchedAttr> {
+    let mut raw = RawSchedAttr::default();
+    backend::thread::syscalls::sched_getattr(pid, &mut raw)?;
+    Ok(SchedAttr {
+        policy: SchedPolicy::from_raw(raw.sched_policy).ok_or(io::Errno::NOTSUP)?,
+        flags: SchedFlags::from_bits_retain(raw.sched_flags),
+        nice: raw.sched_nice,
+        priority: raw.sched_priority,
+        runtime: raw.sched_runtime,
+        deadline: raw.sched_deadline,
+        period: raw.sched_period,
+        util_min: raw.sched_util_min,
+        util_max: raw.sched_util_max,
+    })
+}
+
+/// `sched_setscheduler(pid, policy, &param)`—Set a thread's scheduling
+/// policy and static priority.
+///
+/// `pid` is the thread ID to update. If `pid` is `None`, then the current
+/// thread is updated. `priority` must be zero unless `policy` is
+/// [`SchedPolicy::Fifo`] or [`SchedPolicy::Rr`]. [`SchedPolicy::Deadline`]
+/// can only be set with [`sched_setattr`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/sched_setscheduler.2.html
+#[doc(alias = "sched_param")]
+#[inline]
+pub fn sched_setscheduler(pid: Option<Pid>, policy: SchedPolicy, priority: i32) -> io::Result<()> {
+    backend::thread::syscalls::sched_setscheduler(pid, policy, priority)
+}
+
+/// `sched_getparam(pid, &param)`—Get a thread's static priority.
+///
+/// `pid` is the thread ID to query. If `pid` is `None`, then the current
+/// thread is queried.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/sched_getparam.2.html
+#[doc(alias = "sched_param")]
+#[inline]
+pub fn sched_getparam(pid: Option<Pid>) -> io::Result<i32> {
+    backend::thread::syscalls::sched_getparam(pid)
+}
+
+/// `sched_get_priority_min(policy)`—Get the minimum static priority for a
+/// scheduling policy.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/sched_get_priority_min.2.html
+#[inline]
+pub fn sched_get_priority_min(policy: SchedPolicy) -> io::Result<i32> {
+    backend::thread::syscalls::sched_get_priority_min(policy)
+}
+
+/// `sched_get_priority_max(policy)`—Get the maximum static priority for a
+/// scheduling policy.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/sched_get_priority_max.2.html
+#[inline]
+pub fn sched_get_priority_max(policy: SchedPolicy) -> io::Result<i32> {
+    backend::thread::syscalls::sched_get_priority_max(policy)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sched_attr_layouts() {
+        // `SCHED_ATTR_SIZE_VER1`, which includes the utilization clamps.
+        assert_eq!(core::mem::size_of::<RawSchedAttr>(), 56);
+        assert_eq!(memoffset::offset_of!(RawSchedAttr, sched_runtime), 24);
+        assert_eq!(memoffset::offset_of!(RawSchedAttr, sched_util_min), 48);
+    }
+}
@@ -18,6 +18,8 @@ mod poll;
 mod port;
 #[cfg(any(bsd, linux_kernel, windows, target_os = "wasi"))]
 mod select;
+#[cfg(linux_kernel)]
+mod signalfd;
 
 #[cfg(windows)]
 mod windows {
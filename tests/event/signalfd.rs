@@ -0,0 +1,80 @@
+use rustix::buffer::spare_capacity;
+use rustix::event::{
+    epoll, signalfd_read, signalfd_set_mask, signalfd_with, SigSet, Signal, SignalfdFlags,
+};
+use rustix::io;
+
+/// Block `sig` in the current thread, so that it's queued for a signalfd.
+fn block(sig: Signal) {
+    unsafe {
+        let mut set = std::mem::zeroed::<libc::sigset_t>();
+        libc::sigemptyset(&mut set);
+        libc::sigaddset(&mut set, sig.as_raw());
+        assert_eq!(
+            libc::pthread_sigmask(libc::SIG_BLOCK, &set, std::ptr::null_mut()),
+            0
+        );
+    }
+}
+
+/// Send `sig` to the current thread.
+fn raise(sig: Signal) {
+    unsafe {
+        assert_eq!(libc::pthread_kill(libc::pthread_self(), sig.as_raw()), 0);
+    }
+}
+
+#[test]
+fn test_signalfd_epoll() {
+    let mut mask = SigSet::empty();
+    mask.insert(Signal::USR1);
+    block(Signal::USR1);
+
+    let sfd =
+        unsafe { signalfd_with(&mask, SignalfdFlags::CLOEXEC | SignalfdFlags::NONBLOCK) }.unwrap();
+
+    assert_eq!(signalfd_read(&sfd).unwrap_err(), io::Errno::AGAIN);
+
+    let epoll = epoll::create(epoll::CreateFlags::CLOEXEC).unwrap();
+    epoll::add(
+        &epoll,
+        &sfd,
+        epoll::EventData::new_u64(1),
+        epoll::EventFlags::IN,
+    )
+    .unwrap();
+
+    raise(Signal::USR1);
+
+    let mut events = Vec::with_capacity(1);
+    let n = epoll::wait(&epoll, spare_capacity(&mut events), None).unwrap();
+    assert_eq!(n, 1);
+    assert_eq!(events[0].data.u64(), 1);
+
+    let info = signalfd_read(&sfd).unwrap();
+    assert_eq!(info.signal(), Some(Signal::USR1));
+    assert_eq!(info.signo(), Signal::USR1.as_raw());
+    assert_eq!(info.pid(), std::process::id());
+
+    assert_eq!(signalfd_read(&sfd).unwrap_err(), io::Errno::AGAIN);
+}
+
+#[test]
+fn test_signalfd_set_mask() {
+    let mut mask = SigSet::empty();
+    mask.insert(Signal::USR2);
+    block(Signal::USR2);
+    block(Signal::WINCH);
+
+    let sfd =
+        unsafe { signalfd_with(&mask, SignalfdFlags::CLOEXEC | SignalfdFlags::NONBLOCK) }.unwrap();
+
+    mask.clear();
+    mask.insert(Signal::WINCH);
+    unsafe { signalfd_set_mask(&sfd, &mask) }.unwrap();
+
+    raise(Signal::WINCH);
+
+    let info = signalfd_read(&sfd).unwrap();
+    assert_eq!(info.signal(), Some(Signal::WINCH));
+}
@@ -0,0 +1,38 @@
+use core::num::NonZeroU64;
+use rustix::fs::{cachestat, openat, Mode, OFlags, CWD};
+use rustix::io::{self, write};
+
+#[test]
+fn test_cachestat() {
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(CWD, tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    let file = openat(
+        &dir,
+        "file",
+        OFlags::RDWR | OFlags::CREATE | OFlags::TRUNC,
+        Mode::RUSR | Mode::WUSR,
+    )
+    .unwrap();
+
+    let page_size = rustix::param::page_size();
+    write(&file, &vec![1_u8; page_size * 4]).unwrap();
+
+    let stat = match cachestat(&file, 0, None) {
+        Ok(stat) => stat,
+        // `cachestat` requires Linux 6.5.
+        Err(io::Errno::NOSYS) => return,
+        Err(err) => panic!("{:?}", err),
+    };
+    // The pages we just wrote are in the page cache.
+    assert_eq!(stat.cached, 4);
+    assert!(stat.dirty <= stat.cached);
+
+    // Query just the second page.
+    let len = NonZeroU64::new(page_size as u64);
+    let stat = cachestat(&file, page_size as u64, len).unwrap();
+    assert_eq!(stat.cached, 1);
+
+    // There's nothing cached past the end of the file.
+    let stat = cachestat(&file, page_size as u64 * 8, None).unwrap();
+    assert_eq!(stat.cached, 0);
+}
@@ -57,3 +57,43 @@ fn test_chmodat() {
     let reverted = statat(&dir, "file", AtFlags::empty()).unwrap();
     assert_ne!(reverted.st_mode as u64 & libc::S_IRWXU as u64, 0);
 }
+
+#[cfg(linux_kernel)]
+#[test]
+fn test_chmodat_with() {
+    use rustix::fs::{
+        chmodat_with, openat, statat, symlinkat, AtFlags, FileType, Mode, OFlags, CWD,
+    };
+
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(CWD, tmp.path(), OFlags::RDONLY, Mode::RWXU).unwrap();
+
+    let _ = openat(&dir, "file", OFlags::CREATE | OFlags::WRONLY, Mode::RWXU).unwrap();
+    symlinkat("file", &dir, "link").unwrap();
+
+    match chmodat_with(&dir, "file", Mode::RUSR, AtFlags::SYMLINK_NOFOLLOW) {
+        Ok(()) => (),
+        // `fchmodat2` requires Linux 6.6.
+        Err(rustix::io::Errno::NOSYS) => return,
+        Err(err) => panic!("{:?}", err),
+    }
+    let after = statat(&dir, "file", AtFlags::empty()).unwrap();
+    assert_eq!(after.st_mode as u64 & 0o777, libc::S_IRUSR as u64);
+
+    // Changing the permissions of the symlink itself isn't supported, and
+    // the target is left alone.
+    assert_eq!(
+        chmodat_with(&dir, "link", Mode::RWXU, AtFlags::SYMLINK_NOFOLLOW),
+        Err(rustix::io::Errno::OPNOTSUPP)
+    );
+    let link = statat(&dir, "link", AtFlags::SYMLINK_NOFOLLOW).unwrap();
+    assert_eq!(FileType::from_raw_mode(link.st_mode), FileType::Symlink);
+    let after = statat(&dir, "file", AtFlags::empty()).unwrap();
+    assert_eq!(after.st_mode as u64 & 0o777, libc::S_IRUSR as u64);
+
+    // With `AT_EMPTY_PATH`, change the permissions of `dirfd` itself.
+    let file = openat(&dir, "file", OFlags::PATH, Mode::empty()).unwrap();
+    chmodat_with(&file, "", Mode::RWXU, AtFlags::EMPTY_PATH).unwrap();
+    let reverted = statat(&dir, "file", AtFlags::empty()).unwrap();
+    assert_eq!(reverted.st_mode as u64 & 0o777, libc::S_IRWXU as u64);
+}
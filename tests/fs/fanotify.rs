@@ -0,0 +1,106 @@
+use rustix::fs::fanotify::{self, EventMask, Info, InitFlags, MarkFlags};
+use rustix::fs::{openat, Mode, OFlags, CWD};
+use rustix::io::Errno;
+use std::fs::File;
+use std::mem::MaybeUninit;
+
+#[test]
+fn test_fanotify_fid() {
+    // Unprivileged users may use fanotify since Linux 5.13, but only when
+    // reporting file handles instead of file descriptors.
+    let fanotify = match fanotify::init(
+        InitFlags::CLOEXEC | InitFlags::NONBLOCK | InitFlags::REPORT_DFID_NAME,
+        OFlags::RDONLY,
+    ) {
+        Err(Errno::PERM | Errno::NOSYS | Errno::INVAL) => return,
+        r => r.unwrap(),
+    };
+
+    let dir = tempfile::tempdir().unwrap();
+    fanotify::mark(
+        &fanotify,
+        MarkFlags::ADD,
+        EventMask::CREATE | EventMask::EVENT_ON_CHILD,
+        CWD,
+        dir.path(),
+    )
+    .unwrap();
+
+    File::create(dir.path().join("foo")).unwrap();
+
+    let mut buf = [MaybeUninit::uninit(); 512];
+    let mut reader = fanotify::Reader::new(&fanotify, &mut buf);
+    let event = reader.next().unwrap();
+    assert_eq!(event.mask(), EventMask::CREATE);
+    assert!(event.fd().is_none());
+    assert_eq!(event.pid().unwrap(), rustix::process::getpid());
+
+    let mut infos = event.info();
+    match infos.next().unwrap() {
+        Info::DfidName(fid, name) => {
+            assert!(!fid.handle().is_empty());
+            assert_eq!(name.to_bytes(), b"foo");
+        }
+        info => panic!("unexpected info record {info:?}"),
+    }
+    assert!(infos.next().is_none());
+    drop(infos);
+    drop(event);
+
+    assert!(reader.is_buffer_empty());
+    assert_eq!(reader.next().unwrap_err(), Errno::WOULDBLOCK);
+}
+
+#[test]
+fn test_fanotify_fd() {
+    // Reporting file descriptors requires `CAP_SYS_ADMIN`.
+    let fanotify = match fanotify::init(
+        InitFlags::CLOEXEC | InitFlags::NONBLOCK,
+        OFlags::RDONLY | OFlags::CLOEXEC,
+    ) {
+        Err(Errno::PERM | Errno::NOSYS) => return,
+        r => r.unwrap(),
+    };
+
+    let dir = tempfile::tempdir().unwrap();
+    let dirfd = openat(
+        CWD,
+        dir.path(),
+        OFlags::RDONLY | OFlags::CLOEXEC,
+        Mode::empty(),
+    )
+    .unwrap();
+    fanotify::mark_fd(
+        &fanotify,
+        MarkFlags::ADD,
+        EventMask::CLOSE_WRITE | EventMask::EVENT_ON_CHILD,
+        &dirfd,
+    )
+    .unwrap();
+
+    std::fs::write(dir.path().join("foo"), b"hello").unwrap();
+
+    let mut buf = [MaybeUninit::uninit(); 512];
+    let mut reader = fanotify::Reader::new(&fanotify, &mut buf);
+    let mut event = reader.next().unwrap();
+    assert_eq!(event.mask(), EventMask::CLOSE_WRITE);
+    assert!(event.info().next().is_none());
+
+    let fd = event.take_fd().unwrap();
+    assert!(event.fd().is_none());
+    let mut contents = [0_u8; 8];
+    assert_eq!(rustix::io::read(&fd, &mut contents).unwrap(), 5);
+    assert_eq!(&contents[..5], b"hello");
+    drop(event);
+
+    // Removing the mark stops further events.
+    fanotify::mark_fd(
+        &fanotify,
+        MarkFlags::REMOVE,
+        EventMask::CLOSE_WRITE | EventMask::EVENT_ON_CHILD,
+        &dirfd,
+    )
+    .unwrap();
+    std::fs::write(dir.path().join("bar"), b"world").unwrap();
+    assert_eq!(reader.next().unwrap_err(), Errno::WOULDBLOCK);
+}
@@ -4,9 +4,14 @@
 #![cfg(not(windows))]
 #![cfg_attr(core_c_str, feature(core_c_str))]
 
+#[cfg(linux_kernel)]
+#[cfg(feature = "param")]
+mod cachestat;
 mod chmodat;
 #[cfg(not(target_os = "redox"))]
 mod dir;
+#[cfg(target_os = "linux")]
+mod fanotify;
 mod fcntl;
 #[cfg(not(any(
     target_os = "emscripten",
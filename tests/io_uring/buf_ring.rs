@@ -0,0 +1,101 @@
+use crate::new_ring;
+use rustix::io::Errno;
+use rustix::io_uring::{io_uring_cqe, BufRing, IoringCqeFlags, Ring, Sqe};
+use rustix::net::{socketpair, AddressFamily, RecvFlags, SendFlags, SocketFlags, SocketType};
+
+/// Send messages through a multishot receive selecting buffers from `bufs`,
+/// recycling each buffer after checking its contents.
+fn exercise(ring: &mut Ring, bufs: &mut BufRing) {
+    let (a, b) = socketpair(
+        AddressFamily::UNIX,
+        SocketType::SEQPACKET,
+        SocketFlags::CLOEXEC,
+        None,
+    )
+    .unwrap();
+
+    unsafe {
+        ring.push(
+            Sqe::recv_multishot(&b, RecvFlags::empty(), bufs.bgid())
+                .user_data(1)
+                .as_raw(),
+        )
+        .unwrap();
+    }
+    ring.submit().unwrap();
+
+    // Send more messages than there are buffers, so that the ring wraps.
+    for i in 0..usize::from(bufs.entries()) * 3 {
+        let msg = format!("message {}", i);
+        rustix::net::send(&a, msg.as_bytes(), SendFlags::empty()).unwrap();
+
+        ring.submit_and_wait(1).unwrap();
+        let cqe = ring.completions().next().unwrap();
+        assert_eq!(cqe.user_data.u64_(), 1);
+        assert_eq!(cqe.res, msg.len() as i32);
+        let bid = BufRing::buffer_id(&cqe).unwrap();
+        assert!(bid < bufs.entries());
+        assert_eq!(unsafe { bufs.buffer(bid, msg.len()) }, msg.as_bytes());
+        bufs.recycle(bid);
+    }
+
+    // End the multishot receive.
+    drop(a);
+    loop {
+        ring.submit_and_wait(1).unwrap();
+        let cqe = ring.completions().next().unwrap();
+        if !cqe.flags.contains(IoringCqeFlags::MORE) {
+            break;
+        }
+    }
+}
+
+#[test]
+fn test_buf_ring() {
+    let Some(mut ring) = new_ring() else {
+        return;
+    };
+    let mut bufs = match BufRing::new(&ring, 7, 4, 64) {
+        Ok(bufs) => bufs,
+        Err(Errno::INVAL) => return,
+        Err(err) => panic!("{:?}", err),
+    };
+    assert_eq!(bufs.bgid(), 7);
+    assert_eq!(bufs.entries(), 4);
+    assert_eq!(bufs.buf_len(), 64);
+    exercise(&mut ring, &mut bufs);
+
+    // The group ID is taken until the ring is dropped.
+    assert_eq!(BufRing::new(&ring, 7, 4, 64).unwrap_err(), Errno::EXIST);
+    drop(bufs);
+    BufRing::new(&ring, 7, 4, 64).unwrap();
+}
+
+#[test]
+fn test_buf_ring_mapped() {
+    let Some(mut ring) = new_ring() else {
+        return;
+    };
+    let mut bufs = match BufRing::new_mapped(&ring, 3, 8, 32) {
+        Ok(bufs) => bufs,
+        Err(Errno::INVAL) => return,
+        Err(err) => panic!("{:?}", err),
+    };
+    exercise(&mut ring, &mut bufs);
+}
+
+#[test]
+fn test_buf_ring_invalid() {
+    let Some(ring) = new_ring() else {
+        return;
+    };
+    assert_eq!(BufRing::new(&ring, 0, 3, 64).unwrap_err(), Errno::INVAL);
+    assert_eq!(BufRing::new(&ring, 0, 0, 64).unwrap_err(), Errno::INVAL);
+    assert_eq!(BufRing::new(&ring, 0, 4, 0).unwrap_err(), Errno::INVAL);
+}
+
+#[test]
+fn test_buf_ring_no_buffer() {
+    let cqe = io_uring_cqe::default();
+    assert_eq!(BufRing::buffer_id(&cqe), None);
+}
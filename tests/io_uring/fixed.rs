@@ -0,0 +1,144 @@
+use crate::{new_ring, run};
+use rustix::fd::{AsFd, AsRawFd, FromRawFd, OwnedFd};
+use rustix::io::{Errno, IoSliceMut};
+use rustix::io_uring::{
+    FixedBuffer, FixedBuffers, FixedFile, FixedFiles, IoringFixedFdFlags, Sqe,
+    IORING_REGISTER_FILES_SKIP,
+};
+
+#[test]
+fn test_fixed_files() {
+    let Some(mut ring) = new_ring() else {
+        return;
+    };
+    let files = match FixedFiles::new(&ring, 8) {
+        Ok(files) => files,
+        Err(Errno::INVAL) => return,
+        Err(err) => panic!("{:?}", err),
+    };
+    assert_eq!(files.len(), 8);
+    assert!(!files.is_empty());
+
+    let (reader, writer) = rustix::pipe::pipe().unwrap();
+    assert_eq!(
+        files
+            .update(
+                FixedFile(2),
+                &[writer.as_fd(), IORING_REGISTER_FILES_SKIP, reader.as_fd()]
+            )
+            .unwrap(),
+        3
+    );
+
+    // The table holds its own reference to the pipe.
+    drop(writer);
+    let cqe = run(&mut ring, Sqe::write(FixedFile(2), b"fixed", u64::MAX));
+    assert_eq!(cqe.res, 5);
+    let mut buf = [0_u8; 5];
+    let cqe = run(&mut ring, Sqe::read(FixedFile(4), &mut buf, u64::MAX));
+    assert_eq!(cqe.res, 5);
+    assert_eq!(&buf, b"fixed");
+
+    // Skipped and removed slots are empty.
+    let cqe = run(&mut ring, Sqe::write(FixedFile(3), b"x", u64::MAX));
+    assert_eq!(cqe.res, -Errno::BADF.raw_os_error());
+    files.remove(FixedFile(2)).unwrap();
+    let cqe = run(&mut ring, Sqe::write(FixedFile(2), b"x", u64::MAX));
+    assert_eq!(cqe.res, -Errno::BADF.raw_os_error());
+
+    assert_eq!(
+        files.update(FixedFile(8), &[reader.as_fd()]),
+        Err(Errno::INVAL)
+    );
+}
+
+#[test]
+fn test_fixed_files_alloc() {
+    let Some(mut ring) = new_ring() else {
+        return;
+    };
+    let files = match FixedFiles::new(&ring, 8) {
+        Ok(files) => files,
+        Err(Errno::INVAL) => return,
+        Err(err) => panic!("{:?}", err),
+    };
+    match files.set_alloc_range(5..7) {
+        Ok(()) => {}
+        Err(Errno::INVAL) => return,
+        Err(err) => panic!("{:?}", err),
+    }
+    assert_eq!(
+        files.set_alloc_range(std::ops::Range { start: 7, end: 5 }),
+        Err(Errno::INVAL)
+    );
+
+    let (reader, writer) = rustix::pipe::pipe().unwrap();
+    let mut fds = [reader.as_raw_fd(), writer.as_raw_fd()];
+    let cqe = run(&mut ring, files.alloc(&mut fds));
+    assert_eq!(cqe.res, 2);
+    fds.sort_unstable();
+    assert_eq!(fds, [5, 6]);
+
+    // The range is full.
+    let mut fds = [reader.as_raw_fd()];
+    let cqe = run(&mut ring, files.alloc(&mut fds));
+    assert_eq!(cqe.res, -Errno::NFILE.raw_os_error());
+
+    // Install a registered file as a regular file descriptor.
+    let cqe = run(
+        &mut ring,
+        Sqe::fixed_fd_install(FixedFile(5), IoringFixedFdFlags::empty()),
+    );
+    if cqe.res == -Errno::INVAL.raw_os_error() {
+        return;
+    }
+    assert!(cqe.res >= 0, "{}", cqe.res);
+    let installed = unsafe { OwnedFd::from_raw_fd(cqe.res) };
+    assert_eq!(
+        rustix::fs::fstat(&installed).unwrap().st_ino,
+        rustix::fs::fstat(&reader).unwrap().st_ino
+    );
+}
+
+#[test]
+fn test_fixed_buffers() {
+    let Some(mut ring) = new_ring() else {
+        return;
+    };
+    let mut a = *b"registered";
+    let mut b = [0_u8; 16];
+    let mut bufs = [IoSliceMut::new(&mut a), IoSliceMut::new(&mut b)];
+    let fixed = match FixedBuffers::new(&ring, &bufs) {
+        Ok(fixed) => fixed,
+        Err(Errno::INVAL) => return,
+        Err(err) => panic!("{:?}", err),
+    };
+    assert_eq!(fixed.len(), 2);
+    assert_eq!(
+        fixed.iter().collect::<Vec<_>>(),
+        [FixedBuffer(0), FixedBuffer(1)]
+    );
+    assert_eq!(fixed.get(2), None);
+    let (a_index, b_index) = (fixed.get(0).unwrap(), fixed.get(1).unwrap());
+
+    let (reader, writer) = rustix::pipe::pipe().unwrap();
+    let cqe = run(
+        &mut ring,
+        Sqe::write_fixed(&writer, &bufs[0][..], u64::MAX, a_index),
+    );
+    assert_eq!(cqe.res, 10);
+    let cqe = run(
+        &mut ring,
+        Sqe::read_fixed(&reader, &mut bufs[1][4..], u64::MAX, b_index),
+    );
+    assert_eq!(cqe.res, 10);
+    assert_eq!(&bufs[1][4..14], b"registered");
+
+    // A buffer outside of the registered one is rejected.
+    let mut other = [0_u8; 4];
+    let cqe = run(
+        &mut ring,
+        Sqe::read_fixed(&reader, &mut other, u64::MAX, a_index),
+    );
+    assert_eq!(cqe.res, -Errno::FAULT.raw_os_error());
+}
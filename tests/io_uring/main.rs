@@ -2,6 +2,59 @@
 
 #![cfg(feature = "io_uring")]
 
+#[cfg(linux_kernel)]
+#[cfg(feature = "mm")]
+mod buf_ring;
+#[cfg(linux_kernel)]
+#[cfg(feature = "mm")]
+mod fixed;
+#[cfg(linux_kernel)]
+#[cfg(feature = "mm")]
+mod probe;
 #[cfg(linux_kernel)]
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 mod register;
+#[cfg(linux_kernel)]
+#[cfg(feature = "mm")]
+mod restrictions;
+#[cfg(linux_kernel)]
+#[cfg(feature = "mm")]
+mod ring;
+#[cfg(linux_kernel)]
+#[cfg(feature = "mm")]
+mod sqe;
+
+#[cfg(linux_kernel)]
+#[cfg(feature = "mm")]
+use {
+    rustix::io::Errno,
+    rustix::io_uring::{io_uring_cqe, io_uring_params, Ring, Sqe},
+};
+
+/// Create a ring, or return `None` if io_uring isn't supported here.
+#[cfg(linux_kernel)]
+#[cfg(feature = "mm")]
+fn new_ring() -> Option<Ring> {
+    new_ring_with_params(io_uring_params::default())
+}
+
+/// Create a ring with `params`, or return `None` if io_uring isn't supported
+/// here.
+#[cfg(linux_kernel)]
+#[cfg(feature = "mm")]
+fn new_ring_with_params(params: io_uring_params) -> Option<Ring> {
+    match Ring::with_params(16, params) {
+        Ok(ring) => Some(ring),
+        Err(Errno::NOSYS | Errno::PERM | Errno::ACCESS) => None,
+        Err(err) => panic!("{:?}", err),
+    }
+}
+
+/// Submit one entry and return its completion.
+#[cfg(linux_kernel)]
+#[cfg(feature = "mm")]
+fn run(ring: &mut Ring, sqe: Sqe<'_>) -> io_uring_cqe {
+    unsafe { ring.push(sqe.as_raw()).unwrap() };
+    ring.submit_and_wait(1).unwrap();
+    ring.completions().next().unwrap()
+}
@@ -0,0 +1,31 @@
+use rustix::io::Errno;
+use rustix::io_uring::{probe, IoringOp, IoringOpFlags, Ring};
+
+#[test]
+fn test_probe() {
+    let ring = match Ring::new(4) {
+        Ok(ring) => ring,
+        Err(Errno::NOSYS | Errno::PERM | Errno::ACCESS) => return,
+        Err(err) => panic!("{:?}", err),
+    };
+    let probe = match probe(&ring) {
+        Ok(probe) => probe,
+        Err(Errno::INVAL) => return,
+        Err(err) => panic!("{:?}", err),
+    };
+
+    // These have been supported since before probing was added.
+    assert!(probe.is_supported(IoringOp::Nop));
+    assert!(probe.is_supported(IoringOp::Readv));
+    assert!(probe.is_supported(IoringOp::Writev));
+    assert!(probe.last_op() >= IoringOp::Openat as u8);
+    assert!(probe
+        .op_flags(IoringOp::Nop)
+        .contains(IoringOpFlags::SUPPORTED));
+}
+
+#[test]
+fn test_probe_not_a_ring() {
+    let file = std::fs::File::open("Cargo.toml").unwrap();
+    assert!(probe(&file).is_err());
+}
@@ -0,0 +1,98 @@
+use rustix::fd::AsFd;
+use rustix::io::Errno;
+use rustix::io_uring::{
+    io_uring_params, io_uring_register, IoringOp, IoringRegisterOp, IoringSetupFlags,
+    IoringSqeFlags, Restrictions, Ring, Sqe,
+};
+
+fn new_disabled_ring() -> Option<Ring> {
+    let mut params = io_uring_params::default();
+    params.flags = IoringSetupFlags::R_DISABLED;
+    match Ring::with_params(8, params) {
+        Ok(ring) => Some(ring),
+        Err(Errno::NOSYS | Errno::PERM | Errno::ACCESS | Errno::INVAL) => None,
+        Err(err) => panic!("{:?}", err),
+    }
+}
+
+#[test]
+fn test_restrictions() {
+    let Some(mut ring) = new_disabled_ring() else {
+        return;
+    };
+
+    // A disabled ring can't be used yet.
+    unsafe { ring.push(Sqe::nop().as_raw()).unwrap() };
+    assert_eq!(ring.submit(), Err(Errno::BADFD));
+
+    Restrictions::new()
+        .allow_op(IoringOp::Nop)
+        .allow_sqe_flags(IoringSqeFlags::IO_LINK)
+        .allow_register_op(IoringRegisterOp::RegisterProbe)
+        .apply(&ring)
+        .unwrap();
+
+    ring.submit_and_wait(1).unwrap();
+    assert_eq!(ring.completions().next().unwrap().res, 0);
+
+    // An allowed operation with an allowed flag.
+    unsafe {
+        ring.push(Sqe::nop().flags(IoringSqeFlags::IO_LINK).as_raw())
+            .unwrap();
+        ring.push(Sqe::nop().as_raw()).unwrap();
+    }
+    ring.submit_and_wait(2).unwrap();
+    assert!(ring.completions().all(|cqe| cqe.res == 0));
+
+    // An allowed operation with a flag which isn't allowed.
+    unsafe {
+        ring.push(Sqe::nop().flags(IoringSqeFlags::ASYNC).as_raw())
+            .unwrap();
+    }
+    ring.submit_and_wait(1).unwrap();
+    assert_eq!(
+        ring.completions().next().unwrap().res,
+        -Errno::ACCESS.raw_os_error()
+    );
+
+    // An operation which isn't allowed.
+    let (reader, _writer) = rustix::pipe::pipe().unwrap();
+    let mut buf = [0_u8; 4];
+    unsafe {
+        ring.push(Sqe::read(&reader, &mut buf, u64::MAX).as_raw())
+            .unwrap();
+    }
+    ring.submit_and_wait(1).unwrap();
+    assert_eq!(
+        ring.completions().next().unwrap().res,
+        -Errno::ACCESS.raw_os_error()
+    );
+
+    // Register operations are restricted too.
+    rustix::io_uring::probe(&ring).unwrap();
+    let fds = [reader.as_fd()];
+    assert_eq!(
+        unsafe {
+            io_uring_register(
+                &ring,
+                IoringRegisterOp::RegisterFiles,
+                fds.as_ptr().cast(),
+                1,
+            )
+        },
+        Err(Errno::ACCESS)
+    );
+}
+
+#[test]
+fn test_restrictions_enabled_ring() {
+    let ring = match Ring::new(4) {
+        Ok(ring) => ring,
+        Err(Errno::NOSYS | Errno::PERM | Errno::ACCESS) => return,
+        Err(err) => panic!("{:?}", err),
+    };
+    assert_eq!(
+        Restrictions::new().allow_op(IoringOp::Nop).apply(&ring),
+        Err(Errno::BADFD)
+    );
+}
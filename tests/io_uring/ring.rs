@@ -0,0 +1,147 @@
+use crate::{new_ring, new_ring_with_params, run};
+use rustix::io::Errno;
+use rustix::io_uring::{io_uring_params, IoringSetupFlags, Ring, Sqe};
+
+fn check_nops(ring: &mut Ring) {
+    for user_data in 0..4 {
+        unsafe { ring.push(Sqe::nop().user_data(user_data).as_raw()).unwrap() };
+    }
+    assert_eq!(ring.submit_and_wait(4).unwrap(), 4);
+
+    let mut seen = ring
+        .completions()
+        .map(|cqe| {
+            assert_eq!(cqe.res, 0);
+            cqe.user_data.u64_()
+        })
+        .collect::<Vec<_>>();
+    seen.sort_unstable();
+    assert_eq!(seen, [0, 1, 2, 3]);
+    assert_eq!(ring.completions().len(), 0);
+}
+
+fn check_write_read(ring: &mut Ring) {
+    let file = tempfile::tempfile().unwrap();
+    let data = b"hello, io_uring";
+
+    let cqe = run(ring, Sqe::write(&file, data, 0).user_data(1));
+    assert_eq!(cqe.user_data.u64_(), 1);
+    assert_eq!(cqe.res, data.len() as i32);
+
+    let mut buf = [0_u8; 32];
+    let cqe = run(ring, Sqe::read(&file, &mut buf, 0).user_data(2));
+    assert_eq!(cqe.user_data.u64_(), 2);
+    assert_eq!(cqe.res, data.len() as i32);
+    assert_eq!(&buf[..data.len()], data);
+}
+
+#[test]
+fn test_ring() {
+    let Some(mut ring) = new_ring() else {
+        return;
+    };
+    assert!(ring.params().sq_entries >= 8);
+    check_nops(&mut ring);
+    check_write_read(&mut ring);
+}
+
+#[test]
+fn test_ring_full() {
+    let Some(mut ring) = new_ring() else {
+        return;
+    };
+    let entries = ring.params().sq_entries;
+    for user_data in 0..entries {
+        unsafe {
+            ring.push(Sqe::nop().user_data(user_data.into()).as_raw())
+                .unwrap()
+        };
+    }
+    assert_eq!(ring.sq_space_left(), 0);
+    assert_eq!(unsafe { ring.push(Sqe::nop().as_raw()) }, Err(Errno::AGAIN));
+
+    assert_eq!(ring.submit_and_wait(entries).unwrap(), entries);
+    assert_eq!(ring.completions().count(), entries as usize);
+    assert_eq!(ring.sq_space_left(), entries);
+}
+
+#[test]
+fn test_ring_cqsize() {
+    let mut params = io_uring_params::default();
+    params.flags = IoringSetupFlags::CQSIZE;
+    params.cq_entries = 64;
+    let Some(mut ring) = new_ring_with_params(params) else {
+        return;
+    };
+    assert_eq!(ring.params().cq_entries, 64);
+    check_nops(&mut ring);
+    check_write_read(&mut ring);
+}
+
+#[test]
+fn test_ring_sqpoll() {
+    let mut params = io_uring_params::default();
+    params.flags = IoringSetupFlags::SQPOLL;
+    params.sq_thread_idle = 10;
+    let Some(mut ring) = new_ring_with_params(params) else {
+        return;
+    };
+    check_nops(&mut ring);
+
+    // Let the polling thread go idle, so that submitting has to wake it.
+    std::thread::sleep(std::time::Duration::from_millis(50));
+    check_write_read(&mut ring);
+}
+
+#[test]
+fn test_ring_no_mmap() {
+    let mut params = io_uring_params::default();
+    params.flags = IoringSetupFlags::NO_MMAP;
+    let Some(mut ring) = new_ring_with_params(params) else {
+        return;
+    };
+    check_nops(&mut ring);
+    check_write_read(&mut ring);
+}
+
+#[test]
+fn test_ring_cq_overflow() {
+    let Some(mut ring) = new_ring() else {
+        return;
+    };
+    let sq_entries = ring.params().sq_entries;
+    let cq_entries = ring.params().cq_entries;
+
+    // Submit more entries than the completion queue can hold, without
+    // consuming any completions.
+    let total = cq_entries + sq_entries;
+    for user_data in 0..total {
+        unsafe {
+            ring.push(Sqe::nop().user_data(user_data.into()).as_raw())
+                .unwrap()
+        };
+        if ring.sq_space_left() == 0 {
+            ring.submit().unwrap();
+        }
+    }
+    ring.submit().unwrap();
+    assert_eq!(ring.completions().count(), cq_entries as usize);
+
+    if ring.cq_has_overflow() {
+        // Submitting nothing moves the overflowed completions into the
+        // completion queue.
+        ring.submit().unwrap();
+        assert!(!ring.cq_has_overflow());
+        assert_eq!(ring.completions().count(), sq_entries as usize);
+        assert_eq!(ring.cq_overflow(), 0);
+    } else {
+        assert_eq!(ring.cq_overflow(), sq_entries);
+    }
+}
+
+#[test]
+fn test_ring_unsupported_flags() {
+    let mut params = io_uring_params::default();
+    params.flags = IoringSetupFlags::SQE128;
+    assert_eq!(Ring::with_params(8, params).unwrap_err(), Errno::INVAL);
+}
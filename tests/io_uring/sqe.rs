@@ -0,0 +1,319 @@
+use crate::{new_ring, run};
+use rustix::fd::{AsFd, OwnedFd};
+use rustix::fs::{openat, Mode, OFlags, CWD};
+use rustix::io::{Errno, IoSlice, IoSliceMut, ReadWriteFlags};
+use rustix::io_uring::{
+    IoringAsyncCancelFlags, IoringCqeFlags, IoringFsyncFlags, IoringSqeFlags, IoringTimeoutFlags,
+    Ring, SpliceFlags, Sqe, Statx, StatxFlags, Timespec, IORING_CQE_BUFFER_SHIFT,
+};
+use rustix::net::{socketpair, AddressFamily, RecvFlags, SendFlags, SocketFlags, SocketType};
+use std::ffi::CString;
+use std::mem::MaybeUninit;
+
+/// Submit the pushed entries and wait for `n` completions, returning their
+/// user data and results sorted by user data.
+fn wait_all(ring: &mut Ring, n: usize) -> Vec<(u64, i32)> {
+    let mut cqes = Vec::new();
+    while cqes.len() < n {
+        ring.submit_and_wait(1).unwrap();
+        cqes.extend(
+            ring.completions()
+                .map(|cqe| (cqe.user_data.u64_(), cqe.res)),
+        );
+    }
+    cqes.sort_unstable();
+    cqes
+}
+
+#[test]
+fn test_sqe_files() {
+    let Some(mut ring) = new_ring() else {
+        return;
+    };
+    let dir = tempfile::tempdir().unwrap();
+    let dirfd = openat(
+        CWD,
+        dir.path(),
+        OFlags::RDONLY | OFlags::CLOEXEC,
+        Mode::empty(),
+    )
+    .unwrap();
+
+    let name = CString::new("file").unwrap();
+    let cqe = run(
+        &mut ring,
+        Sqe::openat(
+            dirfd.as_fd(),
+            &name,
+            OFlags::RDWR | OFlags::CREATE | OFlags::CLOEXEC,
+            Mode::RUSR | Mode::WUSR,
+        ),
+    );
+    assert!(cqe.res >= 0, "{}", cqe.res);
+    let file = unsafe { <OwnedFd as rustix::fd::FromRawFd>::from_raw_fd(cqe.res) };
+
+    let data = b"hello, world";
+    let cqe = run(&mut ring, Sqe::write(&file, data, 0).user_data(1));
+    assert_eq!(cqe.user_data.u64_(), 1);
+    assert_eq!(cqe.res, data.len() as i32);
+
+    let cqe = run(&mut ring, Sqe::fsync(&file, IoringFsyncFlags::DATASYNC));
+    assert_eq!(cqe.res, 0);
+
+    let mut a = [0_u8; 5];
+    let mut b = [0_u8; 7];
+    let mut bufs = [IoSliceMut::new(&mut a), IoSliceMut::new(&mut b)];
+    let cqe = run(
+        &mut ring,
+        Sqe::readv(&file, &mut bufs, 0, ReadWriteFlags::empty()),
+    );
+    assert_eq!(cqe.res, data.len() as i32);
+    assert_eq!(&a, b"hello");
+    assert_eq!(&b, b", world");
+
+    let bufs = [IoSlice::new(b"HELLO")];
+    let cqe = run(
+        &mut ring,
+        Sqe::writev(&file, &bufs, 0, ReadWriteFlags::empty()),
+    );
+    assert_eq!(cqe.res, 5);
+
+    let mut statx = MaybeUninit::<Statx>::uninit();
+    let cqe = run(
+        &mut ring,
+        Sqe::statx(
+            dirfd.as_fd(),
+            &name,
+            rustix::fs::AtFlags::empty(),
+            StatxFlags::SIZE,
+            &mut statx,
+        ),
+    );
+    assert_eq!(cqe.res, 0);
+    assert_eq!(unsafe { statx.assume_init() }.stx_size, data.len() as u64);
+
+    let cqe = run(&mut ring, Sqe::ftruncate(&file, 5));
+    if cqe.res != -Errno::INVAL.raw_os_error() {
+        assert_eq!(cqe.res, 0);
+        assert_eq!(rustix::fs::fstat(&file).unwrap().st_size, 5);
+    }
+
+    let mut buf = [0_u8; 16];
+    let cqe = run(&mut ring, Sqe::read(&file, &mut buf, 0));
+    assert!(cqe.res == 5 || cqe.res == data.len() as i32);
+    assert_eq!(&buf[..5], b"HELLO");
+
+    let cqe = run(&mut ring, Sqe::close(file));
+    assert_eq!(cqe.res, 0);
+
+    let subdir = CString::new("subdir").unwrap();
+    let renamed = CString::new("renamed").unwrap();
+    let link = CString::new("link").unwrap();
+    let cqe = run(&mut ring, Sqe::mkdirat(dirfd.as_fd(), &subdir, Mode::RWXU));
+    assert_eq!(cqe.res, 0);
+    let cqe = run(
+        &mut ring,
+        Sqe::renameat(
+            dirfd.as_fd(),
+            &name,
+            dirfd.as_fd(),
+            &renamed,
+            rustix::fs::RenameFlags::empty(),
+        ),
+    );
+    assert_eq!(cqe.res, 0);
+    let cqe = run(&mut ring, Sqe::symlinkat(&renamed, dirfd.as_fd(), &link));
+    assert_eq!(cqe.res, 0);
+    assert_eq!(
+        rustix::fs::readlinkat(&dirfd, "link", Vec::new())
+            .unwrap()
+            .as_bytes(),
+        b"renamed"
+    );
+    let cqe = run(
+        &mut ring,
+        Sqe::unlinkat(dirfd.as_fd(), &subdir, rustix::fs::AtFlags::REMOVEDIR),
+    );
+    assert_eq!(cqe.res, 0);
+    let cqe = run(
+        &mut ring,
+        Sqe::unlinkat(dirfd.as_fd(), &link, rustix::fs::AtFlags::empty()),
+    );
+    assert_eq!(cqe.res, 0);
+}
+
+#[test]
+fn test_sqe_timeouts() {
+    let Some(mut ring) = new_ring() else {
+        return;
+    };
+    let ts = Timespec {
+        tv_sec: 0,
+        tv_nsec: 1_000_000,
+    };
+    let cqe = run(&mut ring, Sqe::timeout(&ts, 0, IoringTimeoutFlags::empty()));
+    assert_eq!(cqe.res, -Errno::TIME.raw_os_error());
+
+    // A long timeout, canceled by user data.
+    let long = Timespec {
+        tv_sec: 60,
+        tv_nsec: 0,
+    };
+    unsafe {
+        ring.push(
+            Sqe::timeout(&long, 0, IoringTimeoutFlags::empty())
+                .user_data(7)
+                .as_raw(),
+        )
+        .unwrap();
+        ring.push(Sqe::timeout_remove(7).user_data(8).as_raw())
+            .unwrap();
+    }
+    assert_eq!(
+        wait_all(&mut ring, 2),
+        [(7, -Errno::CANCELED.raw_os_error()), (8, 0)]
+    );
+
+    // A read from an empty pipe, canceled by a linked timeout.
+    let (reader, _writer) = rustix::pipe::pipe().unwrap();
+    let mut buf = [0_u8; 4];
+    unsafe {
+        ring.push(
+            Sqe::read(&reader, &mut buf, u64::MAX)
+                .flags(IoringSqeFlags::IO_LINK)
+                .user_data(1)
+                .as_raw(),
+        )
+        .unwrap();
+        ring.push(
+            Sqe::link_timeout(&ts, IoringTimeoutFlags::empty())
+                .user_data(2)
+                .as_raw(),
+        )
+        .unwrap();
+    }
+    assert_eq!(
+        wait_all(&mut ring, 2),
+        [
+            (1, -Errno::CANCELED.raw_os_error()),
+            (2, -Errno::TIME.raw_os_error())
+        ]
+    );
+}
+
+#[test]
+fn test_sqe_pipes() {
+    let Some(mut ring) = new_ring() else {
+        return;
+    };
+    let (reader, writer) = rustix::pipe::pipe().unwrap();
+
+    // A poll which is satisfied by a later write.
+    unsafe {
+        ring.push(
+            Sqe::poll_add(&reader, rustix::event::PollFlags::IN)
+                .user_data(1)
+                .as_raw(),
+        )
+        .unwrap();
+        ring.push(Sqe::write(&writer, b"data", u64::MAX).user_data(2).as_raw())
+            .unwrap();
+    }
+    let cqes = wait_all(&mut ring, 2);
+    assert_eq!(cqes[0].0, 1);
+    assert!(rustix::event::PollFlags::from_bits_truncate(cqes[0].1 as _)
+        .contains(rustix::event::PollFlags::IN));
+    assert_eq!(cqes[1], (2, 4));
+
+    // Splice the data into another pipe, then read it out.
+    let (reader2, writer2) = rustix::pipe::pipe().unwrap();
+    let cqe = run(
+        &mut ring,
+        Sqe::splice(&reader, None, &writer2, None, 4, SpliceFlags::empty()),
+    );
+    assert_eq!(cqe.res, 4);
+    let mut buf = [0_u8; 4];
+    let cqe = run(&mut ring, Sqe::read(&reader2, &mut buf, u64::MAX));
+    assert_eq!(cqe.res, 4);
+    assert_eq!(&buf, b"data");
+
+    // Cancel a pending read by file descriptor.
+    let mut buf = [0_u8; 4];
+    unsafe {
+        ring.push(Sqe::read(&reader, &mut buf, u64::MAX).user_data(3).as_raw())
+            .unwrap();
+    }
+    ring.submit().unwrap();
+    unsafe {
+        ring.push(
+            Sqe::async_cancel_fd(&reader, IoringAsyncCancelFlags::empty())
+                .user_data(4)
+                .as_raw(),
+        )
+        .unwrap();
+    }
+    assert_eq!(
+        wait_all(&mut ring, 2),
+        [(3, -Errno::CANCELED.raw_os_error()), (4, 0)]
+    );
+}
+
+#[test]
+fn test_sqe_sockets() {
+    let Some(mut ring) = new_ring() else {
+        return;
+    };
+    let (a, b) = socketpair(
+        AddressFamily::UNIX,
+        SocketType::STREAM,
+        SocketFlags::CLOEXEC,
+        None,
+    )
+    .unwrap();
+
+    let cqe = run(&mut ring, Sqe::send(&a, b"ping", SendFlags::empty()));
+    assert_eq!(cqe.res, 4);
+    let mut buf = [0_u8; 8];
+    let cqe = run(&mut ring, Sqe::recv(&b, &mut buf, RecvFlags::empty()));
+    assert_eq!(cqe.res, 4);
+    assert_eq!(&buf[..4], b"ping");
+
+    // Receive into a provided buffer.
+    let mut pool = [0_u8; 64];
+    let cqe = run(&mut ring, Sqe::provide_buffers(&mut pool, 16, 3, 10));
+    assert_eq!(cqe.res, 0);
+    let cqe = run(&mut ring, Sqe::send(&a, b"pong", SendFlags::empty()));
+    assert_eq!(cqe.res, 4);
+    let cqe = run(
+        &mut ring,
+        Sqe::recv_multishot(&b, RecvFlags::empty(), 3).user_data(5),
+    );
+    assert_eq!(cqe.res, 4);
+    assert!(cqe.flags.contains(IoringCqeFlags::BUFFER));
+    let bid = (cqe.flags.bits() >> IORING_CQE_BUFFER_SHIFT) as usize;
+    assert!((10..14).contains(&bid));
+    assert_eq!(&pool[(bid - 10) * 16..][..4], b"pong");
+
+    // The multishot receive ends with EOF once the peer shuts down.
+    unsafe {
+        ring.push(
+            Sqe::shutdown(&a, rustix::net::Shutdown::Both)
+                .user_data(6)
+                .as_raw(),
+        )
+        .unwrap();
+    }
+    let mut pending = 2;
+    while pending > 0 {
+        ring.submit_and_wait(1).unwrap();
+        for cqe in ring.completions() {
+            assert_eq!(cqe.res, 0);
+            if !cqe.flags.contains(IoringCqeFlags::MORE) {
+                pending -= 1;
+            }
+        }
+    }
+
+    let cqe = run(&mut ring, Sqe::remove_buffers(4, 3));
+    assert!(cqe.res >= 0, "{}", cqe.res);
+}
@@ -0,0 +1,68 @@
+//! Tests for [`rustix::landlock`].
+
+#![cfg(feature = "landlock")]
+#![cfg(linux_kernel)]
+
+#[cfg(all(feature = "fs", feature = "thread"))]
+mod restrict;
+
+use rustix::io;
+use rustix::landlock::{
+    abi_version, add_rule_net_port, create_ruleset, AccessFs, AccessNet, RulesetAttr,
+};
+
+/// Return the Landlock ABI version, or `None` if Landlock isn't available.
+fn landlock_abi() -> Option<u32> {
+    match abi_version() {
+        Ok(version) => Some(version),
+        Err(io::Errno::NOSYS) | Err(io::Errno::OPNOTSUPP) => None,
+        Err(err) => panic!("{:?}", err),
+    }
+}
+
+#[test]
+fn test_abi_version() {
+    if let Some(version) = landlock_abi() {
+        assert!(version >= 1);
+    }
+}
+
+#[test]
+fn test_empty_ruleset() {
+    if landlock_abi().is_none() {
+        return;
+    }
+
+    // A ruleset must handle at least one access right.
+    assert_eq!(
+        create_ruleset(&RulesetAttr::default()).unwrap_err(),
+        io::Errno::NOMSG
+    );
+    assert!(create_ruleset(&RulesetAttr {
+        handled_access_fs: AccessFs::READ_FILE,
+        ..RulesetAttr::default()
+    })
+    .is_ok());
+}
+
+#[test]
+fn test_net_port_rule() {
+    // Network rules require ABI version 4.
+    match landlock_abi() {
+        Some(version) if version >= 4 => {}
+        _ => return,
+    }
+
+    let ruleset = create_ruleset(&RulesetAttr {
+        handled_access_net: AccessNet::BIND_TCP,
+        ..RulesetAttr::default()
+    })
+    .unwrap();
+    add_rule_net_port(&ruleset, 8080, AccessNet::BIND_TCP).unwrap();
+
+    // Accesses which the ruleset doesn't handle can't be allowed.
+    assert_eq!(
+        add_rule_net_port(&ruleset, 8080, AccessNet::CONNECT_TCP),
+        Err(io::Errno::INVAL)
+    );
+}
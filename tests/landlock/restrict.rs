@@ -0,0 +1,49 @@
+use rustix::fs::{openat, Mode, OFlags, CWD};
+use rustix::io;
+use rustix::landlock::{
+    add_rule_path_beneath, create_ruleset, restrict_self, AccessFs, RestrictSelfFlags, RulesetAttr,
+};
+
+/// Call `f` in a new thread, and return its result. Landlock restrictions
+/// can't be removed, but they only apply to the thread which enforces them,
+/// so they're enforced in a thread which exits afterwards.
+fn in_thread(f: impl FnOnce() -> bool + Send) -> bool {
+    std::thread::scope(|scope| scope.spawn(f).join().unwrap_or(false))
+}
+
+#[test]
+fn test_restrict_self_openat() {
+    if super::landlock_abi().is_none() {
+        return;
+    }
+
+    let inside = tempfile::tempdir().unwrap();
+    let outside = tempfile::tempdir().unwrap();
+    std::fs::write(inside.path().join("file"), b"inside").unwrap();
+    std::fs::write(outside.path().join("file"), b"outside").unwrap();
+
+    let access = AccessFs::READ_FILE | AccessFs::WRITE_FILE | AccessFs::READ_DIR;
+    let ruleset = create_ruleset(&RulesetAttr {
+        handled_access_fs: access,
+        ..RulesetAttr::default()
+    })
+    .unwrap();
+    let dir = openat(
+        CWD,
+        inside.path(),
+        OFlags::PATH | OFlags::DIRECTORY | OFlags::CLOEXEC,
+        Mode::empty(),
+    )
+    .unwrap();
+    add_rule_path_beneath(&ruleset, &dir, access).unwrap();
+
+    assert!(in_thread(|| {
+        rustix::thread::set_no_new_privs(true).unwrap();
+        restrict_self(&ruleset, RestrictSelfFlags::empty()).unwrap();
+
+        let oflags = OFlags::RDONLY | OFlags::CLOEXEC;
+        openat(CWD, inside.path().join("file"), oflags, Mode::empty()).unwrap();
+        openat(CWD, outside.path().join("file"), oflags, Mode::empty()).unwrap_err()
+            == io::Errno::ACCESS
+    }));
+}
@@ -3,9 +3,23 @@
 #![cfg(feature = "mm")]
 #![cfg(not(target_os = "espidf"))]
 
+#[cfg(linux_kernel)]
+#[cfg(feature = "fs")]
+#[cfg(any(
+    target_arch = "aarch64",
+    target_arch = "riscv64",
+    target_arch = "s390x",
+    target_arch = "x86",
+    target_arch = "x86_64"
+))]
+mod memfd_secret;
+#[cfg(linux_kernel)]
+mod mempolicy;
 #[cfg(not(any(windows, target_os = "wasi")))]
 mod mlock;
 #[cfg(not(any(windows, target_os = "wasi")))]
 mod mmap;
+#[cfg(linux_kernel)]
+mod pkey;
 #[cfg(not(any(windows, target_os = "wasi")))]
 mod prot;
@@ -0,0 +1,42 @@
+use rustix::fs::ftruncate;
+use rustix::io::Errno;
+use rustix::mm::{memfd_secret, mmap, munmap, MapFlags, MemfdSecretFlags, ProtFlags};
+use std::ptr::null_mut;
+
+#[test]
+fn test_memfd_secret() {
+    let fd = match memfd_secret(MemfdSecretFlags::CLOEXEC) {
+        Ok(fd) => fd,
+        // The kernel doesn't support `memfd_secret`, or it isn't enabled.
+        Err(Errno::NOSYS) => return,
+        Err(err) => panic!("{:?}", err),
+    };
+    ftruncate(&fd, 4096).unwrap();
+
+    unsafe {
+        let addr = match mmap(
+            null_mut(),
+            4096,
+            ProtFlags::READ | ProtFlags::WRITE,
+            MapFlags::SHARED,
+            &fd,
+            0,
+        ) {
+            Ok(addr) => addr,
+            // Secret memory is locked, so it counts against `RLIMIT_MEMLOCK`.
+            Err(Errno::AGAIN) | Err(Errno::NOMEM) => return,
+            Err(err) => panic!("{:?}", err),
+        };
+
+        let secret = addr.cast::<u8>();
+        secret.write(0x5a);
+        assert_eq!(secret.read(), 0x5a);
+
+        munmap(addr, 4096).unwrap();
+    }
+
+    assert_eq!(
+        memfd_secret(MemfdSecretFlags::from_bits_retain(!0)).unwrap_err(),
+        Errno::INVAL
+    );
+}
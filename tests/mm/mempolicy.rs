@@ -0,0 +1,225 @@
+//! Tests for the NUMA memory policy APIs.
+//!
+//! These are written to pass on single-node machines, which are the common
+//! case for test environments, so they only bind memory to the first node the
+//! thread is allowed to use.
+
+use rustix::io::Errno;
+use rustix::mm::{
+    get_mempolicy, get_mempolicy_addr, get_mempolicy_node, get_mems_allowed, mbind, migrate_pages,
+    mmap_anonymous, move_pages, munmap, set_mempolicy, MapFlags, MbindFlags, MemPolicyFlags,
+    MemPolicyMode, MovePagesFlags, NodeMask, PageStatus, ProtFlags,
+};
+use std::ffi::c_void;
+use std::ptr::null_mut;
+
+/// Returns the first node the current thread may allocate on, or `None` if
+/// the kernel was built without NUMA support.
+fn first_node() -> Option<usize> {
+    match get_mems_allowed() {
+        Ok(nodes) => Some((0..NodeMask::MAX_NODE).find(|i| nodes.is_set(*i)).unwrap()),
+        Err(Errno::NOSYS) => None,
+        Err(err) => panic!("{:?}", err),
+    }
+}
+
+#[test]
+fn test_node_mask() {
+    let mut nodes = NodeMask::new();
+    assert_eq!(nodes.count(), 0);
+    assert_eq!(format!("{:?}", nodes), "NodeMask { }");
+
+    nodes.set(0);
+    nodes.set(3);
+    nodes.set(NodeMask::MAX_NODE - 1);
+    assert!(nodes.is_set(0));
+    assert!(!nodes.is_set(1));
+    assert!(nodes.is_set(3));
+    assert!(nodes.is_set(NodeMask::MAX_NODE - 1));
+    assert_eq!(nodes.count(), 3);
+    assert_eq!(
+        format!("{:?}", nodes),
+        "NodeMask { node0, node3, node1023 }"
+    );
+
+    nodes.unset(3);
+    assert!(!nodes.is_set(3));
+    assert_eq!(nodes.count(), 2);
+    assert_ne!(nodes, NodeMask::default());
+
+    // Node ids past the end of the mask are ignored.
+    nodes.set(NodeMask::MAX_NODE);
+    nodes.set(usize::MAX);
+    assert!(!nodes.is_set(NodeMask::MAX_NODE));
+    assert!(!nodes.is_set(usize::MAX));
+    assert_eq!(nodes.count(), 2);
+    nodes.unset(NodeMask::MAX_NODE);
+
+    nodes.clear();
+    assert_eq!(nodes, NodeMask::default());
+}
+
+#[test]
+fn test_set_mempolicy() {
+    let Some(node) = first_node() else { return };
+
+    // Test threads start with the default policy.
+    let policy = get_mempolicy().unwrap();
+    assert_eq!(policy.mode, MemPolicyMode::Default);
+    assert_eq!(policy.flags, MemPolicyFlags::empty());
+    assert_eq!(policy.nodes, NodeMask::new());
+
+    let mut nodes = NodeMask::new();
+    nodes.set(node);
+    set_mempolicy(MemPolicyMode::Bind, MemPolicyFlags::STATIC_NODES, &nodes).unwrap();
+    let policy = get_mempolicy().unwrap();
+    assert_eq!(policy.mode, MemPolicyMode::Bind);
+    assert_eq!(policy.flags, MemPolicyFlags::STATIC_NODES);
+    assert_eq!(policy.nodes, nodes);
+
+    // Binding to no nodes, or only to nodes that don't exist, is an error.
+    assert_eq!(
+        set_mempolicy(
+            MemPolicyMode::Bind,
+            MemPolicyFlags::empty(),
+            &NodeMask::new()
+        ),
+        Err(Errno::INVAL)
+    );
+    let mut missing = NodeMask::new();
+    missing.set(NodeMask::MAX_NODE - 1);
+    assert_eq!(
+        set_mempolicy(MemPolicyMode::Bind, MemPolicyFlags::empty(), &missing),
+        Err(Errno::INVAL)
+    );
+
+    // The default policy doesn't take any nodes.
+    assert_eq!(
+        set_mempolicy(MemPolicyMode::Default, MemPolicyFlags::empty(), &nodes),
+        Err(Errno::INVAL)
+    );
+    set_mempolicy(
+        MemPolicyMode::Default,
+        MemPolicyFlags::empty(),
+        &NodeMask::new(),
+    )
+    .unwrap();
+    assert_eq!(get_mempolicy().unwrap().mode, MemPolicyMode::Default);
+}
+
+#[cfg(feature = "param")]
+#[test]
+fn test_mbind() {
+    let Some(node) = first_node() else { return };
+
+    let mut nodes = NodeMask::new();
+    nodes.set(node);
+
+    unsafe {
+        let len = 2 * rustix::param::page_size();
+        let addr = mmap_anonymous(
+            null_mut(),
+            len,
+            ProtFlags::READ | ProtFlags::WRITE,
+            MapFlags::PRIVATE,
+        )
+        .unwrap();
+
+        mbind(
+            addr,
+            len,
+            MemPolicyMode::Bind,
+            MemPolicyFlags::empty(),
+            &nodes,
+            MbindFlags::STRICT | MbindFlags::MOVE,
+        )
+        .unwrap();
+        let policy = get_mempolicy_addr(addr).unwrap();
+        assert_eq!(policy.mode, MemPolicyMode::Bind);
+        assert_eq!(policy.nodes, nodes);
+
+        addr.cast::<u8>().write(1);
+        assert_eq!(get_mempolicy_node(addr).unwrap(), node as u32);
+
+        // The rest of the address space still uses the thread's policy.
+        let other = [0_u8; 1];
+        let policy = get_mempolicy_addr(other.as_ptr().cast::<c_void>()).unwrap();
+        assert_eq!(policy.mode, MemPolicyMode::Default);
+
+        munmap(addr, len).unwrap();
+    }
+}
+
+#[cfg(feature = "param")]
+#[test]
+fn test_move_pages() {
+    let Some(node) = first_node() else { return };
+
+    unsafe {
+        let page_size = rustix::param::page_size();
+        let addr = mmap_anonymous(
+            null_mut(),
+            2 * page_size,
+            ProtFlags::READ | ProtFlags::WRITE,
+            MapFlags::PRIVATE,
+        )
+        .unwrap();
+
+        // Only the first page is touched, so only it is allocated.
+        addr.cast::<u8>().write(1);
+        let pages = [
+            addr.cast_const(),
+            addr.cast::<u8>()
+                .add(page_size)
+                .cast::<c_void>()
+                .cast_const(),
+        ];
+
+        let mut status = [PageStatus::new(); 2];
+        assert_eq!(
+            move_pages(None, &pages, None, &mut status, MovePagesFlags::empty()),
+            Ok(0)
+        );
+        assert_eq!(status[0].node(), Ok(node as u32));
+        assert_eq!(status[1].node(), Err(Errno::NOENT));
+
+        // Moving a page to the node it's already on succeeds.
+        let nodes = [node as i32; 2];
+        let mut status = [PageStatus::new(); 2];
+        move_pages(
+            None,
+            &pages,
+            Some(&nodes),
+            &mut status,
+            MovePagesFlags::MOVE,
+        )
+        .unwrap();
+        assert_eq!(status[0].node(), Ok(node as u32));
+
+        // The slices must all have the same length.
+        let mut status = [PageStatus::new(); 1];
+        assert_eq!(
+            move_pages(None, &pages, None, &mut status, MovePagesFlags::empty()),
+            Err(Errno::INVAL)
+        );
+
+        munmap(addr, 2 * page_size).unwrap();
+    }
+}
+
+#[test]
+fn test_migrate_pages() {
+    let Some(node) = first_node() else { return };
+
+    let mut nodes = NodeMask::new();
+    nodes.set(node);
+    let pid = rustix::mm::Pid::from_raw(std::process::id() as _);
+    for pid in [None, pid] {
+        match migrate_pages(pid, &nodes, &nodes) {
+            Ok(not_moved) => assert_eq!(not_moved, 0),
+            // Seccomp policies in some test environments reject this.
+            Err(Errno::PERM) => {}
+            Err(err) => panic!("{:?}", err),
+        }
+    }
+}
@@ -187,3 +187,38 @@ fn test_mremap() {
         munmap(addr.offset(4096), 4096).unwrap();
     }
 }
+
+#[cfg(linux_kernel)]
+#[test]
+fn test_mseal() {
+    use rustix::io::Errno;
+    use rustix::mm::{mmap_anonymous, mprotect, mseal, munmap, MapFlags, MprotectFlags, ProtFlags};
+    use std::ptr::null_mut;
+
+    unsafe {
+        let addr = mmap_anonymous(null_mut(), 8192, ProtFlags::READ, MapFlags::PRIVATE).unwrap();
+
+        match mseal(addr, 4096) {
+            Ok(()) => {}
+            Err(Errno::NOSYS) => {
+                munmap(addr, 8192).unwrap();
+                return;
+            }
+            Err(err) => panic!("{:?}", err),
+        }
+
+        // The sealed page can't be changed or unmapped.
+        assert_eq!(
+            mprotect(addr, 4096, MprotectFlags::READ | MprotectFlags::WRITE),
+            Err(Errno::PERM)
+        );
+        assert_eq!(munmap(addr, 4096), Err(Errno::PERM));
+
+        // The unsealed page can.
+        mprotect(addr.add(4096), 4096, MprotectFlags::WRITE).unwrap();
+        munmap(addr.add(4096), 4096).unwrap();
+
+        // Unaligned addresses are rejected.
+        assert_eq!(mseal(addr.add(1), 4095), Err(Errno::INVAL));
+    }
+}
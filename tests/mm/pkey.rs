@@ -0,0 +1,90 @@
+use rustix::io;
+use rustix::mm::{pkey_alloc, pkey_free, PkeyAccessRights};
+
+/// Protection keys are a process-wide resource, so do everything in one
+/// test, to avoid racing with other tests.
+#[test]
+fn test_pkey() {
+    let pkey = match pkey_alloc(PkeyAccessRights::empty()) {
+        Ok(pkey) => pkey,
+        // The CPU or kernel doesn't support protection keys.
+        Err(io::Errno::NOSPC) | Err(io::Errno::INVAL) | Err(io::Errno::NOSYS) => {
+            #[cfg(all(linux_raw, target_arch = "x86_64"))]
+            {
+                assert_eq!(rustix::mm::pkey_get(0), Err(io::Errno::INVAL));
+                assert_eq!(
+                    unsafe { rustix::mm::pkey_set(0, PkeyAccessRights::empty()) },
+                    Err(io::Errno::INVAL)
+                );
+            }
+            return;
+        }
+        Err(err) => panic!("{:?}", err),
+    };
+
+    test_pkey_mprotect(pkey);
+
+    pkey_free(pkey).unwrap();
+
+    // Allocate keys until they run out.
+    let mut pkeys = Vec::new();
+    let err = loop {
+        match pkey_alloc(PkeyAccessRights::DISABLE_WRITE) {
+            Ok(pkey) => pkeys.push(pkey),
+            Err(err) => break err,
+        }
+    };
+    assert_eq!(err, io::Errno::NOSPC);
+    assert!(!pkeys.is_empty());
+    for pkey in pkeys {
+        pkey_free(pkey).unwrap();
+    }
+
+    assert_eq!(
+        pkey_alloc(PkeyAccessRights::from_bits_retain(0x100)),
+        Err(io::Errno::INVAL)
+    );
+}
+
+fn test_pkey_mprotect(pkey: u32) {
+    use core::ptr::null_mut;
+    use rustix::mm::{mmap_anonymous, munmap, pkey_mprotect, MapFlags, MprotectFlags, ProtFlags};
+
+    unsafe {
+        let len = 4096;
+        let addr = mmap_anonymous(
+            null_mut(),
+            len,
+            ProtFlags::READ | ProtFlags::WRITE,
+            MapFlags::PRIVATE,
+        )
+        .unwrap();
+        pkey_mprotect(addr, len, MprotectFlags::READ | MprotectFlags::WRITE, pkey).unwrap();
+        addr.cast::<u8>().write(1);
+
+        #[cfg(all(linux_raw, target_arch = "x86_64"))]
+        {
+            use rustix::mm::{pkey_get, pkey_set};
+
+            assert_eq!(pkey_get(pkey), Ok(PkeyAccessRights::empty()));
+            pkey_set(pkey, PkeyAccessRights::DISABLE_WRITE).unwrap();
+            assert_eq!(pkey_get(pkey), Ok(PkeyAccessRights::DISABLE_WRITE));
+
+            // Reads are still allowed.
+            assert_eq!(addr.cast::<u8>().read_volatile(), 1);
+
+            pkey_set(pkey, PkeyAccessRights::empty()).unwrap();
+            addr.cast::<u8>().write_volatile(2);
+
+            assert_eq!(pkey_get(16), Err(io::Errno::INVAL));
+            assert_eq!(
+                pkey_set(pkey, PkeyAccessRights::from_bits_retain(0x4)),
+                Err(io::Errno::INVAL)
+            );
+        }
+
+        // Reassociate the pages with the default key before freeing `pkey`.
+        pkey_mprotect(addr, len, MprotectFlags::READ | MprotectFlags::WRITE, 0).unwrap();
+        munmap(addr, len).unwrap();
+    }
+}
@@ -1,7 +1,52 @@
 //! Tests for [`rustix::mount`].
+//!
+//! Most of the `mount` functions require elevated privileges, so the tests
+//! here run in their own user and mount namespaces, in which they have
+//! `CAP_SYS_ADMIN`. A process can only enter a new user namespace while it's
+//! single-threaded, so this doesn't use the libtest harness, and `main` runs
+//! the tests itself.
 
-#![cfg(feature = "mount")]
-#![cfg(linux_kernel)]
+#[cfg(all(linux_kernel, feature = "mount", feature = "fs", feature = "thread"))]
+mod mount_setattr;
 
-// At this time, we have no tests for the `mount` functions, because they
-// all require elevated privileges.
+#[cfg(all(linux_kernel, feature = "mount", feature = "fs", feature = "thread"))]
+fn main() {
+    use rustix::thread::{unshare_unsafe, UnshareFlags};
+
+    let uid = unsafe { libc::geteuid() };
+    let gid = unsafe { libc::getegid() };
+
+    // SAFETY: The process is still single-threaded, and this doesn't
+    // unshare its file descriptor table.
+    if let Err(err) = unsafe { unshare_unsafe(UnshareFlags::NEWUSER | UnshareFlags::NEWNS) } {
+        // User namespaces may be disabled or forbidden by seccomp.
+        println!("skipping the mount tests: {:?}", err);
+        return;
+    }
+
+    // Map root in the new user namespace to our own IDs, so that the tests
+    // can create nested user namespaces with mappings of their own.
+    std::fs::write("/proc/self/uid_map", format!("0 {} 1", uid)).unwrap();
+    std::fs::write("/proc/self/setgroups", "deny").unwrap();
+    std::fs::write("/proc/self/gid_map", format!("0 {} 1", gid)).unwrap();
+
+    let tests: &[(&str, fn())] = &[
+        (
+            "mount_setattr::test_mount_setattr_detached",
+            mount_setattr::test_mount_setattr_detached,
+        ),
+        #[cfg(feature = "process")]
+        (
+            "mount_setattr::test_mount_setattr_idmap",
+            mount_setattr::test_mount_setattr_idmap,
+        ),
+    ];
+    for (name, test) in tests {
+        println!("test {} ...", name);
+        test();
+    }
+    println!("test result: ok. {} passed", tests.len());
+}
+
+#[cfg(not(all(linux_kernel, feature = "mount", feature = "fs", feature = "thread")))]
+fn main() {}
@@ -0,0 +1,124 @@
+//! Tests for `mount_setattr` and `open_tree_attr`.
+
+use rustix::fs::{fstatvfs, StatVfsMountFlags, CWD};
+use rustix::io;
+use rustix::mount::{
+    mount_setattr, open_tree, open_tree_attr, MountAttr, MountAttrFlags, MountPropagationFlags,
+    MountSetattrFlags, OpenTreeFlags,
+};
+
+pub(crate) fn test_mount_setattr_detached() {
+    let dir = tempfile::tempdir().unwrap();
+    let tree = open_tree(
+        CWD,
+        dir.path(),
+        OpenTreeFlags::OPEN_TREE_CLONE | OpenTreeFlags::OPEN_TREE_CLOEXEC,
+    )
+    .unwrap();
+    assert!(!fstatvfs(&tree)
+        .unwrap()
+        .f_flag
+        .contains(StatVfsMountFlags::RDONLY));
+
+    let attr = MountAttr {
+        attr_set: MountAttrFlags::MOUNT_ATTR_RDONLY | MountAttrFlags::MOUNT_ATTR_NOSUID,
+        propagation: MountPropagationFlags::PRIVATE,
+        ..MountAttr::default()
+    };
+    mount_setattr(
+        &tree,
+        "",
+        MountSetattrFlags::AT_EMPTY_PATH | MountSetattrFlags::AT_RECURSIVE,
+        &attr,
+    )
+    .unwrap();
+    let flags = fstatvfs(&tree).unwrap().f_flag;
+    assert!(flags.contains(StatVfsMountFlags::RDONLY | StatVfsMountFlags::NOSUID));
+
+    // An idmapped mount needs a `userns_fd`.
+    let attr = MountAttr {
+        attr_set: MountAttrFlags::MOUNT_ATTR_IDMAP,
+        ..MountAttr::default()
+    };
+    assert_eq!(
+        mount_setattr(&tree, "", MountSetattrFlags::AT_EMPTY_PATH, &attr),
+        Err(io::Errno::INVAL)
+    );
+
+    // Clone the detached tree, clearing `MOUNT_ATTR_RDONLY` in the clone.
+    let attr = MountAttr {
+        attr_clr: MountAttrFlags::MOUNT_ATTR_RDONLY,
+        ..MountAttr::default()
+    };
+    let clone = match open_tree_attr(
+        &tree,
+        "",
+        OpenTreeFlags::OPEN_TREE_CLONE
+            | OpenTreeFlags::OPEN_TREE_CLOEXEC
+            | OpenTreeFlags::AT_EMPTY_PATH,
+        Some(&attr),
+    ) {
+        Ok(clone) => clone,
+        // `open_tree_attr` requires Linux 6.15.
+        Err(io::Errno::NOSYS) => return,
+        Err(err) => panic!("{:?}", err),
+    };
+    let flags = fstatvfs(&clone).unwrap().f_flag;
+    assert!(!flags.contains(StatVfsMountFlags::RDONLY));
+    assert!(flags.contains(StatVfsMountFlags::NOSUID));
+
+    // The original is unchanged.
+    let flags = fstatvfs(&tree).unwrap().f_flag;
+    assert!(flags.contains(StatVfsMountFlags::RDONLY));
+}
+
+/// Create an idmapped mount of a tmpfs, using a user namespace which maps
+/// root to root in our own user namespace.
+#[cfg(feature = "process")]
+pub(crate) fn test_mount_setattr_idmap() {
+    use rustix::cstr;
+    use rustix::fd::AsFd;
+    use rustix::mount::{fsconfig_create, fsmount, fsopen, FsMountFlags, FsOpenFlags};
+    use rustix::process::{
+        pidfd_send_signal, waitid, CloneArgs, CloneFlags, Signal, WaitId, WaitIdOptions,
+    };
+
+    // Spawn a process in a new user namespace to get hold of it.
+    let (pid, pidfd) = match CloneArgs::new()
+        .flags(CloneFlags::NEWUSER | CloneFlags::CLEAR_SIGHAND)
+        .spawn(cstr!("/bin/sleep"), &[cstr!("sleep"), cstr!("60")], &[])
+    {
+        Ok(child) => child,
+        Err(io::Errno::NOENT) => return,
+        Err(err) => panic!("{:?}", err),
+    };
+    let proc = format!("/proc/{}", pid.as_raw_nonzero());
+    std::fs::write(format!("{}/uid_map", proc), "0 0 1").unwrap();
+    std::fs::write(format!("{}/gid_map", proc), "0 0 1").unwrap();
+    let userns = std::fs::File::open(format!("{}/ns/user", proc)).unwrap();
+    pidfd_send_signal(&pidfd, Signal::KILL).unwrap();
+    waitid(WaitId::PidFd(pidfd.as_fd()), WaitIdOptions::EXITED).unwrap();
+
+    // Our user namespace owns the tmpfs, and the mount is detached, so we
+    // may idmap it.
+    let fs = fsopen("tmpfs", FsOpenFlags::FSOPEN_CLOEXEC).unwrap();
+    fsconfig_create(&fs).unwrap();
+    let mnt = fsmount(&fs, FsMountFlags::FSMOUNT_CLOEXEC, MountAttrFlags::empty()).unwrap();
+    let attr = MountAttr {
+        attr_set: MountAttrFlags::MOUNT_ATTR_IDMAP,
+        userns_fd: Some(userns.as_fd()),
+        ..MountAttr::default()
+    };
+    match mount_setattr(&mnt, "", MountSetattrFlags::AT_EMPTY_PATH, &attr) {
+        Ok(()) => {}
+        // tmpfs supports idmapped mounts since Linux 6.3.
+        Err(io::Errno::INVAL) => return,
+        Err(err) => panic!("{:?}", err),
+    }
+
+    // A mount's idmapping can't be changed once it's set.
+    assert_eq!(
+        mount_setattr(&mnt, "", MountSetattrFlags::AT_EMPTY_PATH, &attr),
+        Err(io::Errno::PERM)
+    );
+}
@@ -0,0 +1,172 @@
+use rustix::fs::Mode;
+use rustix::io::Errno;
+use rustix::mq::{self, MqAttr, Notification, OFlags};
+use rustix::time::{clock_gettime, ClockId, Timespec};
+
+/// Returns a queue name that's unique to this process and test.
+fn name(test: &str) -> String {
+    format!("/rustix-mq-{}-{}", test, std::process::id())
+}
+
+#[test]
+fn test_mq_send_receive() {
+    let name = name("send-receive");
+    let mqdes = mq::open(
+        &name,
+        OFlags::CREATE | OFlags::EXCL | OFlags::RDWR,
+        Mode::RUSR | Mode::WUSR,
+        Some(&MqAttr {
+            flags: OFlags::empty(),
+            maxmsg: 4,
+            msgsize: 16,
+            curmsgs: 0,
+        }),
+    )
+    .unwrap();
+
+    // The name now exists.
+    assert_eq!(
+        mq::open(
+            &name,
+            OFlags::CREATE | OFlags::EXCL | OFlags::RDWR,
+            Mode::RUSR | Mode::WUSR,
+            None
+        )
+        .unwrap_err(),
+        Errno::EXIST
+    );
+
+    let attr = mq::getattr(&mqdes).unwrap();
+    assert_eq!(attr.maxmsg, 4);
+    assert_eq!(attr.msgsize, 16);
+    assert_eq!(attr.curmsgs, 0);
+
+    mq::send(&mqdes, b"low", 1).unwrap();
+    mq::send(&mqdes, b"high", 5).unwrap();
+    mq::send(&mqdes, b"low again", 1).unwrap();
+    assert_eq!(mq::getattr(&mqdes).unwrap().curmsgs, 3);
+
+    // Messages that don't fit are rejected.
+    assert_eq!(mq::send(&mqdes, &[0; 17], 0), Err(Errno::MSGSIZE));
+
+    // Receive buffers must be at least as large as `msgsize`.
+    let mut small = [0_u8; 8];
+    assert_eq!(mq::receive(&mqdes, &mut small), Err(Errno::MSGSIZE));
+
+    // The highest priority message comes first, and messages of equal
+    // priority come in the order they were sent.
+    let mut buf = [0_u8; 16];
+    let (len, priority) = mq::receive(&mqdes, &mut buf).unwrap();
+    assert_eq!((&buf[..len], priority), (&b"high"[..], 5));
+    let (len, priority) = mq::receive(&mqdes, &mut buf).unwrap();
+    assert_eq!((&buf[..len], priority), (&b"low"[..], 1));
+    let (len, priority) = mq::receive(&mqdes, &mut buf).unwrap();
+    assert_eq!((&buf[..len], priority), (&b"low again"[..], 1));
+
+    mq::unlink(&name).unwrap();
+    assert_eq!(mq::unlink(&name), Err(Errno::NOENT));
+}
+
+#[test]
+fn test_mq_nonblock() {
+    let name = name("nonblock");
+    let mqdes = mq::open(
+        &name,
+        OFlags::CREATE | OFlags::EXCL | OFlags::RDWR,
+        Mode::RUSR | Mode::WUSR,
+        Some(&MqAttr {
+            flags: OFlags::empty(),
+            maxmsg: 1,
+            msgsize: 8,
+            curmsgs: 0,
+        }),
+    )
+    .unwrap();
+    mq::unlink(&name).unwrap();
+
+    // Only `NONBLOCK` can be changed with `setattr`; the other fields are
+    // ignored.
+    let mut attr = mq::getattr(&mqdes).unwrap();
+    assert!(!attr.flags.contains(OFlags::NONBLOCK));
+    attr.flags = OFlags::NONBLOCK;
+    attr.maxmsg = 100;
+    let old = mq::setattr(&mqdes, &attr).unwrap();
+    assert_eq!(old.flags, OFlags::empty());
+    let attr = mq::getattr(&mqdes).unwrap();
+    assert_eq!(attr.flags, OFlags::NONBLOCK);
+    assert_eq!(attr.maxmsg, 1);
+
+    let mut buf = [0_u8; 8];
+    assert_eq!(mq::receive(&mqdes, &mut buf), Err(Errno::AGAIN));
+    mq::send(&mqdes, b"full", 0).unwrap();
+    assert_eq!(mq::send(&mqdes, b"full", 0), Err(Errno::AGAIN));
+    let (len, _priority) = mq::receive(&mqdes, &mut buf).unwrap();
+    assert_eq!(&buf[..len], b"full");
+}
+
+#[test]
+fn test_mq_timed() {
+    let name = name("timed");
+    let mqdes = mq::open(
+        &name,
+        OFlags::CREATE | OFlags::EXCL | OFlags::RDWR,
+        Mode::RUSR | Mode::WUSR,
+        Some(&MqAttr {
+            flags: OFlags::empty(),
+            maxmsg: 1,
+            msgsize: 8,
+            curmsgs: 0,
+        }),
+    )
+    .unwrap();
+    mq::unlink(&name).unwrap();
+
+    // A timeout that has already passed fails immediately instead of
+    // blocking.
+    let now = clock_gettime(ClockId::Realtime);
+    let past = Timespec {
+        tv_sec: now.tv_sec - 1,
+        tv_nsec: 0,
+    };
+    let mut buf = [0_u8; 8];
+    assert_eq!(
+        mq::timedreceive(&mqdes, &mut buf, &past),
+        Err(Errno::TIMEDOUT)
+    );
+
+    mq::timedsend(&mqdes, b"timed", 3, &past).unwrap();
+    assert_eq!(
+        mq::timedsend(&mqdes, b"timed", 3, &past),
+        Err(Errno::TIMEDOUT)
+    );
+
+    let (len, priority) = mq::timedreceive(&mqdes, &mut buf, &past).unwrap();
+    assert_eq!((&buf[..len], priority), (&b"timed"[..], 3));
+}
+
+#[test]
+fn test_mq_notify() {
+    let name = name("notify");
+    let mqdes = mq::open(
+        &name,
+        OFlags::CREATE | OFlags::EXCL | OFlags::RDWR,
+        Mode::RUSR | Mode::WUSR,
+        None,
+    )
+    .unwrap();
+    mq::unlink(&name).unwrap();
+
+    // Register for a notification that doesn't deliver anything. Only one
+    // registration is allowed at a time, until it's removed.
+    mq::notify(&mqdes, Some(Notification::None)).unwrap();
+    assert_eq!(
+        mq::notify(&mqdes, Some(Notification::None)),
+        Err(Errno::BUSY)
+    );
+    mq::notify(&mqdes, None).unwrap();
+    mq::notify(&mqdes, Some(Notification::None)).unwrap();
+    mq::notify(&mqdes, None).unwrap();
+
+    // Unregistering when nothing is registered is fine too.
+    mq::notify(&mqdes, None).unwrap();
+}
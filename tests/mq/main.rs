@@ -0,0 +1,6 @@
+//! Tests for [`rustix::mq`].
+
+#![cfg(feature = "mq")]
+#![cfg(any(linux_kernel, target_os = "dragonfly", target_os = "netbsd"))]
+
+mod basic;
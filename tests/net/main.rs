@@ -16,6 +16,8 @@ mod netlink;
 mod poll;
 #[cfg(unix)]
 mod recv_trunc;
+#[cfg(target_os = "linux")]
+mod recvmmsg;
 mod sockopt;
 #[cfg(unix)]
 mod unix;
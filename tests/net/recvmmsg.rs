@@ -0,0 +1,149 @@
+use rustix::fd::AsFd as _;
+use rustix::io::{IoSlice, IoSliceMut};
+use rustix::net::{
+    bind, getsockname, recvmmsg, sendmsg, sendto, socket, socketpair, AddressFamily, Ipv4Addr,
+    RecvAncillaryBuffer, RecvAncillaryMessage, RecvFlags, RecvMMsgHdr, ReturnFlags,
+    SendAncillaryBuffer, SendAncillaryMessage, SendFlags, SocketAddrAny, SocketAddrV4, SocketFlags,
+    SocketType, Timespec,
+};
+use std::mem::MaybeUninit;
+
+#[test]
+fn test_recvmmsg_udp() {
+    crate::init();
+
+    let receiver = socket(AddressFamily::INET, SocketType::DGRAM, None).unwrap();
+    bind(&receiver, &SocketAddrV4::new(Ipv4Addr::LOCALHOST, 0)).unwrap();
+    let receiver_addr = getsockname(&receiver).unwrap();
+
+    let sender = socket(AddressFamily::INET, SocketType::DGRAM, None).unwrap();
+    bind(&sender, &SocketAddrV4::new(Ipv4Addr::LOCALHOST, 0)).unwrap();
+    let sender_addr = getsockname(&sender).unwrap();
+
+    for data in [&b"hello"[..], b"world!", b"x"] {
+        sendto(&sender, data, SendFlags::empty(), &receiver_addr).unwrap();
+    }
+
+    let mut bufs = [[0_u8; 16]; 4];
+    let mut addrs = [MaybeUninit::uninit(); 4];
+    let mut iovs: Vec<_> = bufs.iter_mut().map(|buf| [IoSliceMut::new(buf)]).collect();
+    let mut controls: [RecvAncillaryBuffer<'_>; 4] = Default::default();
+    let mut msgs: Vec<_> = addrs
+        .iter_mut()
+        .zip(&mut iovs)
+        .zip(&mut controls)
+        .map(|((addr, iov), control)| RecvMMsgHdr::new_with_addr(addr, iov, control))
+        .collect();
+
+    let timeout = Timespec {
+        tv_sec: 1,
+        tv_nsec: 0,
+    };
+    let n = recvmmsg(&receiver, &mut msgs, RecvFlags::WAITFORONE, Some(&timeout)).unwrap();
+    assert_eq!(n, 3);
+
+    let expected: [&[u8]; 3] = [b"hello", b"world!", b"x"];
+    for (msg, expected) in msgs.iter().zip(expected) {
+        assert!(msg.is_received());
+        assert_eq!(msg.bytes_received(), expected.len());
+        assert_eq!(msg.flags(), ReturnFlags::empty());
+        let addr: SocketAddrAny = msg.address().unwrap();
+        assert_eq!(
+            SocketAddrV4::try_from(addr).unwrap(),
+            SocketAddrV4::try_from(sender_addr.clone()).unwrap()
+        );
+    }
+    assert!(!msgs[3].is_received());
+    assert_eq!(msgs[3].bytes_received(), 0);
+    assert!(msgs[3].address().is_none());
+
+    // Headers which have already received a message can't be reused.
+    assert_eq!(
+        recvmmsg(&receiver, &mut msgs, RecvFlags::DONTWAIT, None),
+        Err(rustix::io::Errno::INVAL)
+    );
+
+    // The unused header can still receive, and there's nothing to receive.
+    assert_eq!(
+        recvmmsg(&receiver, &mut msgs[3..], RecvFlags::DONTWAIT, None),
+        Err(rustix::io::Errno::AGAIN)
+    );
+
+    drop(msgs);
+    drop(iovs);
+    assert_eq!(&bufs[0][..5], b"hello");
+    assert_eq!(&bufs[1][..6], b"world!");
+    assert_eq!(&bufs[2][..1], b"x");
+}
+
+#[cfg(feature = "pipe")]
+#[test]
+fn test_recvmmsg_scm_rights() {
+    crate::init();
+
+    use rustix::io::{read, write};
+    use rustix::pipe::pipe;
+
+    let (send_sock, recv_sock) = socketpair(
+        AddressFamily::UNIX,
+        SocketType::DGRAM,
+        SocketFlags::CLOEXEC,
+        None,
+    )
+    .unwrap();
+
+    let (read_end, write_end) = pipe().unwrap();
+
+    for data in [&b"first"[..], b"second"] {
+        let fds = [read_end.as_fd()];
+        let mut space = [MaybeUninit::uninit(); rustix::cmsg_space!(ScmRights(1))];
+        let mut cmsg_buffer = SendAncillaryBuffer::new(&mut space);
+        assert!(cmsg_buffer.push(SendAncillaryMessage::ScmRights(&fds)));
+        sendmsg(
+            &send_sock,
+            &[IoSlice::new(data)],
+            &mut cmsg_buffer,
+            SendFlags::empty(),
+        )
+        .unwrap();
+    }
+    drop(read_end);
+
+    let (mut buf0, mut buf1) = ([0_u8; 16], [0_u8; 16]);
+    let mut space0 = [MaybeUninit::uninit(); rustix::cmsg_space!(ScmRights(1))];
+    let mut space1 = [MaybeUninit::uninit(); rustix::cmsg_space!(ScmRights(1))];
+    let mut iov0 = [IoSliceMut::new(&mut buf0)];
+    let mut iov1 = [IoSliceMut::new(&mut buf1)];
+    let mut control0 = RecvAncillaryBuffer::new(&mut space0);
+    let mut control1 = RecvAncillaryBuffer::new(&mut space1);
+    let mut msgs = [
+        RecvMMsgHdr::new(&mut iov0, &mut control0),
+        RecvMMsgHdr::new(&mut iov1, &mut control1),
+    ];
+
+    let n = recvmmsg(&recv_sock, &mut msgs, RecvFlags::CMSG_CLOEXEC, None).unwrap();
+    assert_eq!(n, 2);
+
+    let mut received = Vec::new();
+    for msg in &mut msgs {
+        assert!(msg.address().is_none());
+        let mut control = msg.take_control();
+        match control.drain().next().unwrap() {
+            RecvAncillaryMessage::ScmRights(mut fds) => received.push(fds.next().unwrap()),
+            _ => panic!("unexpected ancillary message"),
+        }
+        drop(control);
+
+        // The ancillary messages can only be taken once.
+        assert!(msg.take_control().drain().next().is_none());
+    }
+    assert_eq!(msgs[0].bytes_received(), 5);
+    assert_eq!(msgs[1].bytes_received(), 6);
+
+    // Both received file descriptors refer to the pipe's read end.
+    write(&write_end, b"pipe").unwrap();
+    let mut buf = [0_u8; 4];
+    assert_eq!(read(&received[1], &mut buf).unwrap(), 4);
+    assert_eq!(&buf, b"pipe");
+    drop(received);
+}
@@ -0,0 +1,60 @@
+//! Tests for `CloneArgs`.
+
+use rustix::cstr;
+use rustix::fd::AsFd as _;
+use rustix::io;
+use rustix::process::{waitid, CloneArgs, CloneFlags, WaitId, WaitIdOptions};
+use serial_test::serial;
+
+#[test]
+#[serial]
+fn test_clone3_spawn() {
+    let (pid, pidfd) = match CloneArgs::new().spawn(
+        cstr!("/bin/sh"),
+        &[cstr!("sh"), cstr!("-c"), cstr!("exit $CODE")],
+        &[cstr!("CODE=3")],
+    ) {
+        Ok(child) => child,
+        // `clone3` may be unavailable or forbidden by seccomp.
+        Err(io::Errno::NOSYS) | Err(io::Errno::PERM) => return,
+        Err(err) => panic!("{:?}", err),
+    };
+
+    let status = waitid(WaitId::PidFd(pidfd.as_fd()), WaitIdOptions::EXITED)
+        .unwrap()
+        .unwrap();
+    assert!(status.exited());
+    assert_eq!(status.exit_status(), Some(3));
+    assert!(pid.as_raw_nonzero().get() > 0);
+}
+
+#[test]
+#[serial]
+fn test_clone3_spawn_clear_sighand() {
+    let (_pid, pidfd) = match CloneArgs::new().flags(CloneFlags::CLEAR_SIGHAND).spawn(
+        cstr!("/bin/sh"),
+        &[cstr!("sh"), cstr!("-c"), cstr!("true")],
+        &[],
+    ) {
+        Ok(child) => child,
+        // `CLONE_CLEAR_SIGHAND` requires Linux 5.5.
+        Err(io::Errno::NOSYS) | Err(io::Errno::PERM) | Err(io::Errno::INVAL) => return,
+        Err(err) => panic!("{:?}", err),
+    };
+
+    let status = waitid(WaitId::PidFd(pidfd.as_fd()), WaitIdOptions::EXITED)
+        .unwrap()
+        .unwrap();
+    assert_eq!(status.exit_status(), Some(0));
+}
+
+#[test]
+#[serial]
+fn test_clone3_spawn_exec_failure() {
+    match CloneArgs::new().spawn(cstr!("/no/such/program"), &[cstr!("program")], &[]) {
+        Err(io::Errno::NOENT) => {}
+        Err(io::Errno::NOSYS) | Err(io::Errno::PERM) => return,
+        Ok(_) => panic!("spawned a nonexistent program"),
+        Err(err) => panic!("{:?}", err),
+    }
+}
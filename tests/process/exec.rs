@@ -0,0 +1,57 @@
+use rustix::cstr;
+use rustix::io;
+use rustix::process;
+
+#[test]
+fn test_execve_noent() {
+    let err = process::execve(cstr!("/no/such/file"), &[cstr!("file")], &[]);
+    assert_eq!(err, io::Errno::NOENT);
+}
+
+#[test]
+fn test_exit_group() {
+    if crate::is_child() {
+        process::exit_group(42);
+    }
+
+    let status = crate::child_command("exec::test_exit_group")
+        .status()
+        .unwrap();
+    assert_eq!(status.code(), Some(42));
+}
+
+#[cfg(feature = "fs")]
+#[test]
+fn test_execveat_memfd() {
+    use rustix::fs::{memfd_create, AtFlags, MemfdFlags};
+
+    let Ok(program) = std::fs::read("/bin/true") else {
+        return;
+    };
+
+    if crate::is_child() {
+        let memfd = memfd_create("true", MemfdFlags::CLOEXEC).unwrap();
+        let mut buf = &program[..];
+        while !buf.is_empty() {
+            let n = io::write(&memfd, buf).unwrap();
+            buf = &buf[n..];
+        }
+
+        let err = process::execveat(
+            &memfd,
+            cstr!(""),
+            &[cstr!("true")],
+            &[],
+            AtFlags::EMPTY_PATH,
+        );
+        process::exit_group(if err == io::Errno::NOSYS { 77 } else { 127 });
+    }
+
+    let status = crate::child_command("exec::test_execveat_memfd")
+        .status()
+        .unwrap();
+    // Skip if `execveat` isn't available.
+    if status.code() != Some(77) {
+        assert_eq!(status.code(), Some(0));
+    }
+}
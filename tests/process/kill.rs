@@ -0,0 +1,7 @@
+use rustix::process::{raise, Signal};
+
+#[test]
+fn test_raise() {
+    // `SIGWINCH` is ignored by default.
+    raise(Signal::WINCH).unwrap();
+}
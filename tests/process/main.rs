@@ -4,6 +4,10 @@
 #![cfg(not(windows))]
 #![cfg_attr(core_c_str, feature(core_c_str))]
 
+#[cfg(target_os = "linux")]
+mod clone3;
+#[cfg(linux_kernel)]
+mod exec;
 #[cfg(not(any(
     target_os = "emscripten",
     target_os = "espidf",
@@ -16,6 +20,8 @@
 mod fcntl_getlk;
 #[cfg(not(target_os = "wasi"))] // WASI doesn't have get[gpu]id.
 mod id;
+#[cfg(not(any(target_os = "espidf", target_os = "wasi")))]
+mod kill;
 #[cfg(target_os = "linux")]
 mod pidfd;
 #[cfg(linux_kernel)]
@@ -24,6 +30,8 @@ mod prctl;
 mod priority;
 #[cfg(freebsdlike)]
 mod procctl;
+#[cfg(linux_kernel)]
+mod process_vm;
 #[cfg(not(any(target_os = "fuchsia", target_os = "redox", target_os = "wasi")))]
 mod rlimit;
 #[cfg(not(target_os = "wasi"))] // WASI doesn't have umask.
@@ -33,3 +41,30 @@ mod wait;
 #[cfg(not(any(target_os = "redox", target_os = "wasi")))]
 #[cfg(feature = "fs")]
 mod working_directory;
+
+/// The environment variable which tells a re-executed test binary that it's
+/// running the child half of a test.
+#[cfg(linux_kernel)]
+const CHILD_ENV: &str = "RUSTIX_TEST_CHILD";
+
+/// Test whether this process is running the child half of a test, for a
+/// parent which started it with [`child_command`].
+#[cfg(linux_kernel)]
+fn is_child() -> bool {
+    std::env::var_os(CHILD_ENV).is_some()
+}
+
+/// Return a `Command` which re-executes this test binary to run only the test
+/// `name`, in which [`is_child`] returns `true`.
+///
+/// Tests which need a child process use this rather than `fork`, which isn't
+/// safe in the multithreaded test harness.
+#[cfg(linux_kernel)]
+fn child_command(name: &str) -> std::process::Command {
+    let mut command = std::process::Command::new(std::env::current_exe().unwrap());
+    command
+        .args([name, "--exact", "--quiet", "--test-threads=1"])
+        .env(CHILD_ENV, "1")
+        .stdout(std::process::Stdio::null());
+    command
+}
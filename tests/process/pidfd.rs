@@ -251,3 +251,73 @@ fn test_pidfd_poll() {
     assert_eq!(status.exit_status(), Some(0));
     assert_eq!(status.terminating_signal(), None);
 }
+
+#[test]
+#[serial]
+fn test_pidfd_send_signal_with_info() {
+    use std::mem::MaybeUninit;
+    use std::os::unix::process::CommandExt as _;
+
+    let mut set = MaybeUninit::<libc::sigset_t>::uninit();
+    let set = unsafe {
+        libc::sigemptyset(set.as_mut_ptr());
+        libc::sigaddset(set.as_mut_ptr(), libc::SIGUSR1);
+        set.assume_init()
+    };
+
+    if crate::is_child() {
+        let mut info = MaybeUninit::<libc::siginfo_t>::uninit();
+        let ok = unsafe {
+            libc::sigwaitinfo(&set, info.as_mut_ptr()) == libc::SIGUSR1 && {
+                let info = info.assume_init();
+                info.si_code == libc::SI_QUEUE
+                    && info.si_pid() == libc::getppid()
+                    && info.si_value().sival_ptr as usize == 42
+            }
+        };
+        process::exit_group(if ok { 0 } else { 1 });
+    }
+
+    // Start the child with `SIGUSR1` blocked, so that all of its threads
+    // inherit the mask, and it can wait for the signal with `sigwaitinfo`.
+    let mut command = crate::child_command("pidfd::test_pidfd_send_signal_with_info");
+    // SAFETY: `pthread_sigmask` is async-signal-safe.
+    unsafe {
+        command.pre_exec(move || {
+            libc::pthread_sigmask(libc::SIG_BLOCK, &set, std::ptr::null_mut());
+            Ok(())
+        });
+    }
+    let pid = process::Pid::from_raw(command.spawn().unwrap().id() as _).unwrap();
+    let pidfd = match process::pidfd_open(pid, process::PidfdFlags::empty()) {
+        Ok(pidfd) => pidfd,
+        Err(io::Errno::NOSYS) => {
+            // The kernel does not support pidfds.
+            process::kill_process(pid, process::Signal::KILL).unwrap();
+            process::waitpid(Some(pid), process::WaitOptions::empty()).unwrap();
+            return;
+        }
+        Err(e) => panic!("failed to open pidfd: {}", e),
+    };
+
+    // Only negative `si_code` values may be sent to other processes.
+    let info = process::SigqueueInfo {
+        code: 0,
+        value: std::ptr::null_mut(),
+    };
+    assert_eq!(
+        process::pidfd_send_signal_with_info(&pidfd, process::Signal::USR1, Some(&info)),
+        Err(io::Errno::PERM)
+    );
+
+    let info = process::SigqueueInfo::new(42 as *mut _);
+    process::pidfd_send_signal_with_info(&pidfd, process::Signal::USR1, Some(&info)).unwrap();
+
+    let status = process::waitid(
+        process::WaitId::PidFd(pidfd.as_fd()),
+        process::WaitIdOptions::EXITED,
+    )
+    .expect("failed to wait")
+    .unwrap();
+    assert_eq!(status.exit_status(), Some(0));
+}
@@ -0,0 +1,77 @@
+use rustix::io::{self, IoSlice, IoSliceMut};
+use rustix::process::{self, process_vm_readv, process_vm_writev, RemoteIoVec};
+use std::io::{Read as _, Write as _};
+use std::process::Stdio;
+
+const ADDR_LEN: usize = core::mem::size_of::<usize>();
+
+#[test]
+fn test_process_vm_readv_writev() {
+    if crate::is_child() {
+        // Send the address of a buffer on the stack to the parent, wait for
+        // the parent to write to it, and check what it wrote.
+        let mut data = *b"0123456789abcdef";
+        let addr = data.as_mut_ptr() as usize;
+        std::io::stderr().write_all(&addr.to_ne_bytes()).unwrap();
+        std::io::stdin().read_exact(&mut [0_u8]).unwrap();
+        let ok = unsafe { core::ptr::read_volatile(&data) } == *b"ABCDEFGH89abcdef";
+        process::exit_group(if ok { 0 } else { 1 });
+    }
+
+    let mut child = crate::child_command("process_vm::test_process_vm_readv_writev")
+        .stdin(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    let pid = process::Pid::from_raw(child.id() as _).unwrap();
+
+    let mut addr = [0_u8; ADDR_LEN];
+    child.stderr.take().unwrap().read_exact(&mut addr).unwrap();
+    let addr = usize::from_ne_bytes(addr);
+
+    // Read the whole buffer, scattered into two local buffers.
+    let mut head = [0_u8; 4];
+    let mut tail = [0_u8; 12];
+    let n = process_vm_readv(
+        pid,
+        &mut [IoSliceMut::new(&mut head), IoSliceMut::new(&mut tail)],
+        &[RemoteIoVec::new(addr, 16)],
+    )
+    .unwrap();
+    assert_eq!(n, 16);
+    assert_eq!(&head, b"0123");
+    assert_eq!(&tail, b"456789abcdef");
+
+    // A transfer stops at the first inaccessible remote range.
+    let mut buf = [0_u8; 16];
+    let n = process_vm_readv(
+        pid,
+        &mut [IoSliceMut::new(&mut buf)],
+        &[RemoteIoVec::new(addr, 8), RemoteIoVec::new(0, 8)],
+    )
+    .unwrap();
+    assert_eq!(n, 8);
+    assert_eq!(&buf[..8], b"01234567");
+    assert_eq!(
+        process_vm_readv(
+            pid,
+            &mut [IoSliceMut::new(&mut buf)],
+            &[RemoteIoVec::new(0, 8)]
+        ),
+        Err(io::Errno::FAULT)
+    );
+
+    // SAFETY: The child doesn't share memory with this process.
+    let n = unsafe {
+        process_vm_writev(
+            pid,
+            &[IoSlice::new(b"ABCDEFGH")],
+            &[RemoteIoVec::new(addr, 8)],
+        )
+    }
+    .unwrap();
+    assert_eq!(n, 8);
+
+    child.stdin.take().unwrap().write_all(b"x").unwrap();
+    assert!(child.wait().unwrap().success());
+}
@@ -0,0 +1,30 @@
+//! Tests for [`rustix::ptrace`].
+//!
+//! The tracees are new instances of this test binary, which must be
+//! single-threaded so that each is traced as a whole by its PID. So this
+//! doesn't use the libtest harness, and `main` either runs the tracee or runs
+//! the tests itself.
+
+#[cfg(all(linux_kernel, feature = "ptrace"))]
+mod tracer;
+
+#[cfg(all(linux_kernel, feature = "ptrace"))]
+fn main() {
+    if std::env::var_os(tracer::TRACEE_ENV).is_some() {
+        tracer::tracee();
+    }
+
+    let tests: &[(&str, fn())] = &[
+        ("tracer::test_peek_poke", tracer::test_peek_poke),
+        ("tracer::test_syscall_stops", tracer::test_syscall_stops),
+        ("tracer::test_signal_delivery", tracer::test_signal_delivery),
+    ];
+    for (name, test) in tests {
+        println!("test {} ...", name);
+        test();
+    }
+    println!("test result: ok. {} passed", tests.len());
+}
+
+#[cfg(not(all(linux_kernel, feature = "ptrace")))]
+fn main() {}
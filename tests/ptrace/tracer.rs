@@ -0,0 +1,143 @@
+//! The tracer and tracee halves of the tests.
+
+use rustix::process::{waitpid, Pid, Signal, WaitOptions, WaitStatus};
+use rustix::ptrace::{
+    self, get_event_msg, get_syscall_info, peek_data, poke_data, PtraceEvent, PtraceOptions,
+    PtraceStop, PtraceSyscallOp,
+};
+use std::io::{Read as _, Write as _};
+use std::process::{Command, Stdio};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+const MAGIC: usize = 0x5eed_cafe;
+
+/// The environment variable which tells a re-executed test binary to be a
+/// tracee.
+pub(crate) const TRACEE_ENV: &str = "RUSTIX_TEST_TRACEE";
+
+/// The tracer pokes `MAGIC` into the tracee's copy of this to tell it to
+/// exit.
+static WORD: AtomicUsize = AtomicUsize::new(0);
+
+/// Write the address of `WORD` to stdout, and then make syscalls until
+/// `WORD` is `MAGIC`, and then exit with status 0.
+pub(crate) fn tracee() -> ! {
+    let addr = &WORD as *const AtomicUsize as usize;
+    let mut stdout = std::io::stdout();
+    stdout.write_all(&addr.to_ne_bytes()).unwrap();
+    stdout.flush().unwrap();
+    for _ in 0..10_000 {
+        if WORD.load(Ordering::SeqCst) == MAGIC {
+            std::process::exit(0);
+        }
+        std::thread::sleep(std::time::Duration::from_millis(1));
+    }
+    std::process::exit(1);
+}
+
+/// Start a new instance of this test binary as a tracee, and return its PID
+/// and the address of its `WORD`.
+///
+/// The tests reap the tracee with `waitpid`, rather than with `Child::wait`.
+#[allow(clippy::zombie_processes)]
+fn spawn_tracee() -> (Pid, usize) {
+    let child = Command::new(std::env::current_exe().unwrap())
+        .env(TRACEE_ENV, "1")
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    let pid = Pid::from_raw(child.id() as _).unwrap();
+    let mut addr = [0_u8; core::mem::size_of::<usize>()];
+    child.stdout.unwrap().read_exact(&mut addr).unwrap();
+    (pid, usize::from_ne_bytes(addr))
+}
+
+fn wait_stop(pid: Pid) -> PtraceStop {
+    let (_, status) = waitpid(Some(pid), WaitOptions::empty()).unwrap().unwrap();
+    PtraceStop::from_wait_status(status).unwrap()
+}
+
+fn wait_exit(pid: Pid) -> WaitStatus {
+    let (_, status) = waitpid(Some(pid), WaitOptions::empty()).unwrap().unwrap();
+    assert_eq!(PtraceStop::from_wait_status(status), None);
+    status
+}
+
+pub(crate) fn test_peek_poke() {
+    let (pid, addr) = spawn_tracee();
+    ptrace::seize(pid, PtraceOptions::EXITKILL | PtraceOptions::TRACEEXIT).unwrap();
+    ptrace::interrupt(pid).unwrap();
+    assert_eq!(wait_stop(pid), PtraceStop::Group(Signal::TRAP));
+
+    #[cfg(any(
+        target_arch = "x86_64",
+        target_arch = "x86",
+        target_arch = "aarch64",
+        target_arch = "arm",
+        target_arch = "riscv64"
+    ))]
+    ptrace::get_regs(pid).unwrap();
+
+    assert_eq!(peek_data(pid, addr).unwrap(), 0);
+    poke_data(pid, addr, MAGIC).unwrap();
+    assert_eq!(peek_data(pid, addr).unwrap(), MAGIC);
+    ptrace::cont(pid, None).unwrap();
+
+    // With `TRACEEXIT`, the tracee stops before exiting, and the event
+    // message is its wait status.
+    assert_eq!(wait_stop(pid), PtraceStop::Event(PtraceEvent::Exit));
+    assert_eq!(get_event_msg(pid).unwrap(), 0);
+    ptrace::cont(pid, None).unwrap();
+
+    assert_eq!(wait_exit(pid).exit_status(), Some(0));
+}
+
+pub(crate) fn test_syscall_stops() {
+    let (pid, addr) = spawn_tracee();
+    ptrace::seize(pid, PtraceOptions::EXITKILL).unwrap();
+    ptrace::interrupt(pid).unwrap();
+    assert_eq!(wait_stop(pid), PtraceStop::Group(Signal::TRAP));
+    ptrace::set_options(pid, PtraceOptions::EXITKILL | PtraceOptions::TRACESYSGOOD).unwrap();
+
+    // Skip to the next syscall entry. The tracee may have been interrupted
+    // during a syscall, in which case the first stop is its exit.
+    let nr = loop {
+        ptrace::syscall(pid, None).unwrap();
+        assert_eq!(wait_stop(pid), PtraceStop::Syscall);
+        let info = match get_syscall_info(pid) {
+            Ok(info) => info,
+            // `PTRACE_GET_SYSCALL_INFO` requires Linux 5.3.
+            Err(rustix::io::Errno::IO) => break None,
+            Err(err) => panic!("{:?}", err),
+        };
+        assert_ne!(info.arch, 0);
+        assert_ne!(info.instruction_pointer, 0);
+        if let PtraceSyscallOp::Entry { nr, .. } = info.op {
+            break Some(nr);
+        }
+    };
+
+    if let Some(nr) = nr {
+        ptrace::syscall(pid, None).unwrap();
+        assert_eq!(wait_stop(pid), PtraceStop::Syscall);
+        match get_syscall_info(pid).unwrap().op {
+            PtraceSyscallOp::Exit { .. } => {}
+            op => panic!("expected a syscall exit after syscall {}: {:?}", nr, op),
+        }
+    }
+
+    poke_data(pid, addr, MAGIC).unwrap();
+    ptrace::detach(pid, None).unwrap();
+    assert_eq!(wait_exit(pid).exit_status(), Some(0));
+}
+
+pub(crate) fn test_signal_delivery() {
+    let (pid, _) = spawn_tracee();
+    ptrace::seize(pid, PtraceOptions::EXITKILL).unwrap();
+    rustix::process::kill_process(pid, Signal::USR1).unwrap();
+    assert_eq!(wait_stop(pid), PtraceStop::Signal(Signal::USR1));
+
+    // Deliver the signal, which terminates the tracee.
+    ptrace::cont(pid, Some(Signal::USR1)).unwrap();
+    assert_eq!(wait_exit(pid).terminating_signal(), Some(libc::SIGUSR1));
+}
@@ -0,0 +1,107 @@
+use rustix::io;
+use rustix::seccomp::{
+    get_action_avail, get_notif_sizes, set_mode_filter, SeccompAction, SeccompFilterFlags,
+    SockFilter, SyscallFilter, BPF_K, BPF_RET,
+};
+
+/// Call `f` in a new thread, and return its result. Seccomp filters can't
+/// be removed, but without `TSYNC` they only apply to the thread which
+/// installs them, so they're installed in a thread which exits afterwards.
+fn in_thread(f: impl FnOnce() -> bool + Send) -> bool {
+    std::thread::scope(|scope| scope.spawn(f).join().unwrap_or(false))
+}
+
+fn set_no_new_privs() -> bool {
+    unsafe { libc::prctl(libc::PR_SET_NO_NEW_PRIVS, 1, 0, 0, 0) == 0 }
+}
+
+#[test]
+fn test_get_action_avail() {
+    assert!(get_action_avail(SeccompAction::Allow).unwrap());
+    assert!(get_action_avail(SeccompAction::KillThread).unwrap());
+    assert!(get_action_avail(SeccompAction::Errno(io::Errno::PERM)).unwrap());
+    assert!(get_action_avail(SeccompAction::Trace(7)).unwrap());
+}
+
+#[test]
+fn test_get_notif_sizes() {
+    let sizes = match get_notif_sizes() {
+        Ok(sizes) => sizes,
+        // `SECCOMP_GET_NOTIF_SIZES` requires Linux 5.0.
+        Err(io::Errno::INVAL) => return,
+        Err(err) => panic!("{:?}", err),
+    };
+    assert_eq!(usize::from(sizes.data), 64);
+    assert!(usize::from(sizes.notif) >= 16 + usize::from(sizes.data));
+    assert!(sizes.notif_resp >= 24);
+}
+
+#[test]
+fn test_new_listener_rejected() {
+    let filter = [SockFilter::stmt(
+        BPF_RET | BPF_K,
+        SeccompAction::Allow.as_raw(),
+    )];
+    assert_eq!(
+        set_mode_filter(SeccompFilterFlags::NEW_LISTENER, &filter),
+        Err(io::Errno::INVAL)
+    );
+}
+
+#[test]
+fn test_syscall_filter() {
+    let filter = SyscallFilter::new(SeccompAction::Allow)
+        .deny(libc::SYS_getppid as u32, io::Errno::ACCESS)
+        .build();
+
+    assert!(in_thread(|| {
+        if !set_no_new_privs() {
+            return false;
+        }
+        if set_mode_filter(SeccompFilterFlags::empty(), &filter).is_err() {
+            return false;
+        }
+
+        // `getppid` is denied, and `getpid` is still allowed.
+        let r = unsafe { libc::syscall(libc::SYS_getppid) };
+        let errno = std::io::Error::last_os_error().raw_os_error();
+        r == -1 && errno == Some(libc::EACCES) && unsafe { libc::syscall(libc::SYS_getpid) } > 0
+    }));
+}
+
+#[test]
+fn test_tsync_conflict() {
+    let filter = [SockFilter::stmt(
+        BPF_RET | BPF_K,
+        SeccompAction::Allow.as_raw(),
+    )];
+    let installed = std::sync::Barrier::new(2);
+    let done = std::sync::Barrier::new(2);
+
+    std::thread::scope(|scope| {
+        // Install a filter in one thread, which the other thread's filter
+        // won't descend from, so `TSYNC` can't synchronize it.
+        let other = scope.spawn(|| {
+            let ok =
+                set_no_new_privs() && set_mode_filter(SeccompFilterFlags::empty(), &filter).is_ok();
+            installed.wait();
+            done.wait();
+            ok
+        });
+
+        installed.wait();
+        let result = scope
+            .spawn(|| {
+                set_no_new_privs()
+                    && set_mode_filter(SeccompFilterFlags::TSYNC, &filter)
+                        .unwrap()
+                        .is_some()
+            })
+            .join();
+        done.wait();
+
+        if other.join().unwrap() {
+            assert!(result.unwrap());
+        }
+    });
+}
@@ -0,0 +1,8 @@
+//! Tests for [`rustix::seccomp`].
+
+#![cfg(feature = "seccomp")]
+#![cfg(linux_kernel)]
+
+mod filter;
+#[cfg(feature = "pipe")]
+mod notify;
@@ -0,0 +1,87 @@
+use rustix::fd::OwnedFd;
+use rustix::io::{self, FdFlags};
+use rustix::seccomp::{
+    add_fd, notif_id_valid, notif_recv, notif_send, set_mode_filter_listener, SeccompAction,
+    SeccompAddfdFlags, SeccompFilterFlags, SeccompNotifResp, SyscallFilter,
+};
+use std::sync::mpsc;
+
+/// Supervise the child's `getppid` calls: the first returns a fixed value,
+/// the second returns a new file descriptor, and the third is executed.
+fn supervise(listener: OwnedFd, fd: OwnedFd) -> bool {
+    let notif = notif_recv(&listener).unwrap();
+    if notif.data.nr != libc::SYS_getppid as i32 || notif.pid.is_none() {
+        return false;
+    }
+    if !notif_id_valid(&listener, notif.id).unwrap() {
+        return false;
+    }
+    notif_send(&listener, &SeccompNotifResp::success(notif.id, 4242)).unwrap();
+    if notif_id_valid(&listener, notif.id).unwrap() {
+        return false;
+    }
+
+    let notif = notif_recv(&listener).unwrap();
+    match add_fd(
+        &listener,
+        notif.id,
+        &fd,
+        None,
+        FdFlags::CLOEXEC,
+        SeccompAddfdFlags::SEND,
+    ) {
+        Ok(_) => {}
+        // `SECCOMP_ADDFD_FLAG_SEND` requires Linux 5.14.
+        Err(io::Errno::INVAL) => notif_send(
+            &listener,
+            &SeccompNotifResp::error(notif.id, io::Errno::NOSYS),
+        )
+        .unwrap(),
+        Err(err) => panic!("{:?}", err),
+    }
+
+    let notif = notif_recv(&listener).unwrap();
+    notif_send(&listener, &SeccompNotifResp::continue_syscall(notif.id)).unwrap();
+    true
+}
+
+fn target() -> bool {
+    let ppid = unsafe { libc::getppid() };
+    let (sender, receiver) = mpsc::channel();
+    let (_reader, writer) = rustix::pipe::pipe().unwrap();
+    let supervisor = std::thread::spawn(move || supervise(receiver.recv().unwrap(), writer));
+
+    // Only this thread is filtered, since `TSYNC` isn't used.
+    let filter = SyscallFilter::new(SeccompAction::Allow)
+        .rule(libc::SYS_getppid as u32, SeccompAction::UserNotif)
+        .build();
+    unsafe { libc::prctl(libc::PR_SET_NO_NEW_PRIVS, 1, 0, 0, 0) };
+    let listener = match set_mode_filter_listener(SeccompFilterFlags::empty(), &filter) {
+        Ok(listener) => listener,
+        // `SECCOMP_FILTER_FLAG_NEW_LISTENER` requires Linux 5.0.
+        Err(io::Errno::INVAL) => return true,
+        Err(err) => panic!("{:?}", err),
+    };
+    sender.send(listener).unwrap();
+
+    let getppid = || unsafe { libc::syscall(libc::SYS_getppid) };
+
+    let first = getppid();
+
+    let second = getppid();
+    let second_ok = if second >= 0 {
+        let flags = unsafe { libc::fcntl(second as i32, libc::F_GETFD) };
+        flags == libc::FD_CLOEXEC
+    } else {
+        std::io::Error::last_os_error().raw_os_error() == Some(libc::ENOSYS)
+    };
+
+    let third = getppid();
+
+    supervisor.join().unwrap() && first == 4242 && second_ok && third == i64::from(ppid)
+}
+
+#[test]
+fn test_notify() {
+    assert!(std::thread::spawn(target).join().unwrap());
+}
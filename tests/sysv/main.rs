@@ -0,0 +1,36 @@
+//! Tests for [`rustix::sysv`].
+
+#![cfg(feature = "sysv")]
+#![cfg(all(
+    linux_kernel,
+    any(
+        target_arch = "aarch64",
+        target_arch = "arm",
+        target_arch = "riscv64",
+        target_arch = "x86",
+        all(target_arch = "x86_64", target_pointer_width = "64"),
+    )
+))]
+
+mod msg;
+mod sem;
+mod shm;
+
+#[test]
+fn test_ftok() {
+    use rustix::sysv::{ftok, Key};
+    use std::ffi::CString;
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = CString::new(dir.path().to_str().unwrap()).unwrap();
+    for proj_id in [1_u8, b'x', 0xff] {
+        let key = ftok(&path, proj_id).unwrap();
+        let expected = unsafe { libc::ftok(path.as_ptr(), proj_id.into()) };
+        assert_eq!(key, Key::from_raw(expected));
+    }
+
+    assert_eq!(
+        ftok("/no/such/path", 1).unwrap_err(),
+        rustix::io::Errno::NOENT
+    );
+}
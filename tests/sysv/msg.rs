@@ -0,0 +1,69 @@
+use rustix::io::Errno;
+use rustix::process::getpid;
+use rustix::sysv::{
+    msgctl_rmid, msgctl_set, msgctl_stat, msgget, msgrcv, msgsnd, IpcFlags, Key, Mode, MsgBuf,
+    MsgFlags,
+};
+
+#[test]
+fn test_msg() {
+    let msqid = msgget(
+        Key::PRIVATE,
+        IpcFlags::CREATE | IpcFlags::EXCL,
+        Mode::RUSR | Mode::WUSR,
+    )
+    .unwrap();
+
+    let ds = msgctl_stat(msqid).unwrap();
+    assert_eq!(ds.qnum, 0);
+    assert_eq!(ds.cbytes, 0);
+    assert_eq!(ds.lspid, None);
+    assert_eq!(ds.lrpid, None);
+    assert_ne!(ds.qbytes, 0);
+
+    let mut msg = MsgBuf::<16>::new(2);
+    msg.mtext[..5].copy_from_slice(b"hello");
+    msgsnd(msqid, &msg, 5, MsgFlags::empty()).unwrap();
+    msg.mtype = 1;
+    msg.mtext[..3].copy_from_slice(b"one");
+    msgsnd(msqid, &msg, 3, MsgFlags::empty()).unwrap();
+    assert_eq!(
+        msgsnd(msqid, &msg, 17, MsgFlags::empty()),
+        Err(Errno::INVAL)
+    );
+
+    let ds = msgctl_stat(msqid).unwrap();
+    assert_eq!(ds.qnum, 2);
+    assert_eq!(ds.cbytes, 8);
+    assert_eq!(ds.lspid, Some(getpid()));
+
+    // Receive by type, out of order.
+    let mut buf = MsgBuf::<16>::new(0);
+    assert_eq!(msgrcv(msqid, &mut buf, 2, MsgFlags::empty()).unwrap(), 5);
+    assert_eq!(buf.mtype, 2);
+    assert_eq!(&buf.mtext[..5], b"hello");
+
+    // Messages that don't fit are an error, unless `NOERROR` is used.
+    let mut small = MsgBuf::<2>::new(0);
+    assert_eq!(
+        msgrcv(msqid, &mut small, 0, MsgFlags::NOWAIT),
+        Err(Errno::TOOBIG)
+    );
+    assert_eq!(msgrcv(msqid, &mut small, 0, MsgFlags::NOERROR).unwrap(), 2);
+    assert_eq!(small.mtype, 1);
+    assert_eq!(&small.mtext, b"on");
+
+    assert_eq!(
+        msgrcv(msqid, &mut buf, 0, MsgFlags::NOWAIT),
+        Err(Errno::NOMSG)
+    );
+    assert_eq!(msgctl_stat(msqid).unwrap().lrpid, Some(getpid()));
+
+    let mut ds = msgctl_stat(msqid).unwrap();
+    ds.qbytes = 64;
+    msgctl_set(msqid, &ds).unwrap();
+    assert_eq!(msgctl_stat(msqid).unwrap().qbytes, 64);
+
+    msgctl_rmid(msqid).unwrap();
+    assert_eq!(msgctl_stat(msqid).unwrap_err(), Errno::INVAL);
+}
@@ -0,0 +1,98 @@
+use rustix::io::Errno;
+use rustix::process::getpid;
+use rustix::sysv::{
+    semctl_getall, semctl_getncnt, semctl_getpid, semctl_getval, semctl_getzcnt, semctl_rmid,
+    semctl_set, semctl_setall, semctl_setval, semctl_stat, semget, semop, semtimedop, IpcFlags,
+    Key, Mode, SemBuf, SemFlags,
+};
+use rustix::time::Timespec;
+
+#[test]
+fn test_sem() {
+    let semid = semget(
+        Key::PRIVATE,
+        3,
+        IpcFlags::CREATE | IpcFlags::EXCL,
+        Mode::RUSR | Mode::WUSR,
+    )
+    .unwrap();
+
+    let ds = semctl_stat(semid).unwrap();
+    assert_eq!(ds.nsems, 3);
+    assert_eq!(ds.otime, 0);
+    assert_eq!(ds.perm.mode, Mode::RUSR | Mode::WUSR);
+
+    // New semaphores start at zero.
+    let mut vals = [7_u16; 3];
+    semctl_getall(semid, &mut vals).unwrap();
+    assert_eq!(vals, [0, 0, 0]);
+    assert_eq!(semctl_getpid(semid, 0).unwrap(), None);
+
+    semctl_setval(semid, 1, 5).unwrap();
+    assert_eq!(semctl_getval(semid, 1).unwrap(), 5);
+    semctl_setall(semid, &[1, 2, 3]).unwrap();
+    semctl_getall(semid, &mut vals).unwrap();
+    assert_eq!(vals, [1, 2, 3]);
+
+    // The slices must have one element per semaphore.
+    assert_eq!(semctl_setall(semid, &[1, 2]), Err(Errno::INVAL));
+    assert_eq!(semctl_getall(semid, &mut [0; 4]), Err(Errno::INVAL));
+
+    // Operations are applied atomically.
+    semop(
+        semid,
+        &[
+            SemBuf::new(0, -1, SemFlags::empty()),
+            SemBuf::new(2, 2, SemFlags::empty()),
+        ],
+    )
+    .unwrap();
+    semctl_getall(semid, &mut vals).unwrap();
+    assert_eq!(vals, [0, 2, 5]);
+    assert_eq!(semctl_getpid(semid, 0).unwrap(), Some(getpid()));
+    assert_ne!(semctl_stat(semid).unwrap().otime, 0);
+
+    assert_eq!(
+        semop(
+            semid,
+            &[
+                SemBuf::new(2, -1, SemFlags::NOWAIT),
+                SemBuf::new(0, -1, SemFlags::NOWAIT),
+            ]
+        ),
+        Err(Errno::AGAIN)
+    );
+    semctl_getall(semid, &mut vals).unwrap();
+    assert_eq!(vals, [0, 2, 5]);
+
+    assert_eq!(semctl_getncnt(semid, 0).unwrap(), 0);
+    assert_eq!(semctl_getzcnt(semid, 0).unwrap(), 0);
+
+    semctl_rmid(semid).unwrap();
+    assert_eq!(semctl_stat(semid).unwrap_err(), Errno::INVAL);
+}
+
+#[test]
+fn test_semtimedop() {
+    let semid = semget(Key::PRIVATE, 1, IpcFlags::CREATE, Mode::RUSR | Mode::WUSR).unwrap();
+
+    let timeout = Timespec {
+        tv_sec: 0,
+        tv_nsec: 1_000_000,
+    };
+    assert_eq!(
+        semtimedop(semid, &[SemBuf::new(0, -1, SemFlags::empty())], &timeout),
+        Err(Errno::AGAIN)
+    );
+    semtimedop(semid, &[SemBuf::new(0, 1, SemFlags::UNDO)], &timeout).unwrap();
+    assert_eq!(semctl_getval(semid, 0).unwrap(), 1);
+    semtimedop(semid, &[SemBuf::new(0, -1, SemFlags::UNDO)], &timeout).unwrap();
+    assert_eq!(semctl_getval(semid, 0).unwrap(), 0);
+
+    let mut ds = semctl_stat(semid).unwrap();
+    ds.perm.mode = Mode::RUSR;
+    semctl_set(semid, &ds).unwrap();
+    assert_eq!(semctl_stat(semid).unwrap().perm.mode, Mode::RUSR);
+
+    semctl_rmid(semid).unwrap();
+}
@@ -0,0 +1,109 @@
+use rustix::io::Errno;
+use rustix::process::{getgid, getpid, getuid};
+use rustix::sysv::{
+    shmat, shmctl_rmid, shmctl_set, shmctl_stat, shmdt, shmget, IpcFlags, Key, Mode, ShmatFlags,
+};
+use std::ptr::null_mut;
+
+#[test]
+fn test_shm() {
+    let shmid = shmget(
+        Key::PRIVATE,
+        8192,
+        IpcFlags::CREATE | IpcFlags::EXCL,
+        Mode::RUSR | Mode::WUSR,
+    )
+    .unwrap();
+
+    let ds = shmctl_stat(shmid).unwrap();
+    assert_eq!(ds.perm.key, Key::PRIVATE);
+    assert_eq!(ds.perm.uid, getuid());
+    assert_eq!(ds.perm.gid, getgid());
+    assert_eq!(ds.perm.cuid, getuid());
+    assert_eq!(ds.perm.cgid, getgid());
+    assert_eq!(ds.perm.mode, Mode::RUSR | Mode::WUSR);
+    assert_eq!(ds.segsz, 8192);
+    assert_eq!(ds.cpid, Some(getpid()));
+    assert_eq!(ds.lpid, None);
+    assert_eq!(ds.nattch, 0);
+    assert_eq!(ds.atime, 0);
+    assert_ne!(ds.ctime, 0);
+
+    unsafe {
+        // Two attachments of the same segment see the same memory.
+        let a = shmat(shmid, null_mut(), ShmatFlags::empty()).unwrap();
+        let b = shmat(shmid, null_mut(), ShmatFlags::RDONLY).unwrap();
+        assert_ne!(a, b);
+        a.cast::<u8>().add(100).write(42);
+        assert_eq!(b.cast::<u8>().add(100).read(), 42);
+
+        let ds = shmctl_stat(shmid).unwrap();
+        assert_eq!(ds.nattch, 2);
+        assert_eq!(ds.lpid, Some(getpid()));
+        assert_ne!(ds.atime, 0);
+
+        shmdt(b).unwrap();
+        assert_eq!(shmdt(b), Err(Errno::INVAL));
+        assert_eq!(shmctl_stat(shmid).unwrap().nattch, 1);
+
+        // Removing an attached segment defers the removal until it's
+        // detached.
+        shmctl_rmid(shmid).unwrap();
+        assert_eq!(a.cast::<u8>().add(100).read(), 42);
+        shmdt(a).unwrap();
+    }
+
+    assert_eq!(shmctl_stat(shmid).unwrap_err(), Errno::INVAL);
+}
+
+#[test]
+fn test_shm_set() {
+    let shmid = shmget(
+        Key::PRIVATE,
+        4096,
+        IpcFlags::CREATE,
+        Mode::RUSR | Mode::WUSR,
+    )
+    .unwrap();
+
+    let mut ds = shmctl_stat(shmid).unwrap();
+    ds.perm.mode = Mode::RUSR | Mode::WUSR | Mode::RGRP;
+    shmctl_set(shmid, &ds).unwrap();
+    assert_eq!(
+        shmctl_stat(shmid).unwrap().perm.mode,
+        Mode::RUSR | Mode::WUSR | Mode::RGRP
+    );
+
+    shmctl_rmid(shmid).unwrap();
+    assert_eq!(shmctl_rmid(shmid), Err(Errno::INVAL));
+}
+
+#[test]
+fn test_shm_key() {
+    // Use a key that's unlikely to collide with anything else on the system.
+    let key = Key::from_raw(0x7275_0000 | (getpid().as_raw_nonzero().get() & 0xffff));
+    let shmid = shmget(
+        key,
+        4096,
+        IpcFlags::CREATE | IpcFlags::EXCL,
+        Mode::RUSR | Mode::WUSR,
+    )
+    .unwrap();
+
+    // Getting an existing segment by key returns the same ID.
+    assert_eq!(
+        shmget(key, 0, IpcFlags::empty(), Mode::empty()).unwrap(),
+        shmid
+    );
+    assert_eq!(
+        shmget(key, 4096, IpcFlags::CREATE | IpcFlags::EXCL, Mode::RUSR),
+        Err(Errno::EXIST)
+    );
+    assert_eq!(shmctl_stat(shmid).unwrap().perm.key, key);
+
+    shmctl_rmid(shmid).unwrap();
+    assert_eq!(
+        shmget(key, 0, IpcFlags::empty(), Mode::empty()),
+        Err(Errno::NOENT)
+    );
+}
@@ -0,0 +1,35 @@
+use rustix::io;
+use rustix::process::getpid;
+use rustix::thread::{gettid, tgkill, Pid, Signal};
+
+#[test]
+fn test_tgkill() {
+    // `SIGWINCH` is ignored by default.
+    tgkill(getpid(), gettid(), Signal::WINCH).unwrap();
+
+    let tid = std::thread::spawn(gettid).join().unwrap();
+
+    // The thread has exited, so its ID no longer refers to a thread in our
+    // thread group.
+    assert_eq!(tgkill(getpid(), tid, Signal::WINCH), Err(io::Errno::SRCH));
+
+    // `tgkill` checks that the thread is in the given thread group.
+    assert_eq!(
+        tgkill(Pid::INIT, gettid(), Signal::WINCH),
+        Err(io::Errno::SRCH)
+    );
+}
+
+#[test]
+fn test_tgkill_other_thread() {
+    let (tx, rx) = std::sync::mpsc::channel();
+    let (done_tx, done_rx) = std::sync::mpsc::channel::<()>();
+    let thread = std::thread::spawn(move || {
+        tx.send(gettid()).unwrap();
+        done_rx.recv().unwrap();
+    });
+    let tid = rx.recv().unwrap();
+    tgkill(getpid(), tid, Signal::WINCH).unwrap();
+    done_tx.send(()).unwrap();
+    thread.join().unwrap();
+}
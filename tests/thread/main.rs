@@ -11,6 +11,9 @@ mod futex;
 #[cfg(linux_kernel)]
 mod id;
 #[cfg(linux_kernel)]
+#[cfg(feature = "process")]
+mod kill;
+#[cfg(linux_kernel)]
 mod libcap;
 #[cfg(linux_kernel)]
 mod membarrier;
@@ -18,4 +21,6 @@ mod membarrier;
 mod prctl;
 mod sched;
 #[cfg(linux_kernel)]
+mod sched_attr;
+#[cfg(linux_kernel)]
 mod setns;
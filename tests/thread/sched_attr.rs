@@ -0,0 +1,50 @@
+use rustix::thread::{
+    gettid, sched_get_priority_max, sched_get_priority_min, sched_getattr, sched_getparam,
+    sched_setattr, sched_setscheduler, SchedAttr, SchedPolicy,
+};
+
+#[test]
+fn test_sched_getattr() {
+    let attr = sched_getattr(None).unwrap();
+    assert_eq!(sched_getattr(Some(gettid())).unwrap().policy, attr.policy);
+    if attr.policy == SchedPolicy::Other {
+        assert_eq!(attr.priority, 0);
+        assert_eq!(sched_getparam(None).unwrap(), 0);
+    }
+}
+
+#[test]
+fn test_sched_setattr() {
+    // Change the policy of a separate thread, so that it doesn't affect other
+    // tests. Lowering our own priority doesn't need any privileges.
+    std::thread::spawn(|| {
+        sched_setattr(
+            None,
+            &SchedAttr {
+                policy: SchedPolicy::Batch,
+                nice: 5,
+                ..SchedAttr::default()
+            },
+        )
+        .unwrap();
+        let attr = sched_getattr(None).unwrap();
+        assert_eq!(attr.policy, SchedPolicy::Batch);
+        assert_eq!(attr.nice, 5);
+
+        sched_setscheduler(None, SchedPolicy::Idle, 0).unwrap();
+        assert_eq!(sched_getattr(None).unwrap().policy, SchedPolicy::Idle);
+        assert_eq!(sched_getparam(None).unwrap(), 0);
+    })
+    .join()
+    .unwrap();
+}
+
+#[test]
+fn test_sched_priority_range() {
+    assert_eq!(sched_get_priority_min(SchedPolicy::Fifo).unwrap(), 1);
+    assert_eq!(sched_get_priority_max(SchedPolicy::Fifo).unwrap(), 99);
+    assert_eq!(sched_get_priority_min(SchedPolicy::Rr).unwrap(), 1);
+    assert_eq!(sched_get_priority_max(SchedPolicy::Rr).unwrap(), 99);
+    assert_eq!(sched_get_priority_min(SchedPolicy::Other).unwrap(), 0);
+    assert_eq!(sched_get_priority_max(SchedPolicy::Other).unwrap(), 0);
+}